@@ -500,6 +500,9 @@ fn benchmark_audit(c: &mut Criterion) {
         limit: Some(10),
         offset: Some(0),
         joins: None,
+        timeout_ms: None,
+        request_id: None,
+        read_primary: None,
     };
 
     group.bench_with_input(
@@ -530,6 +533,9 @@ fn benchmark_audit(c: &mut Criterion) {
             limit: None,
             offset: None,
             joins: None,
+            timeout_ms: None,
+            request_id: None,
+            read_primary: None,
         };
 
         group.bench_with_input(
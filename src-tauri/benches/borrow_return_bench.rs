@@ -558,6 +558,9 @@ fn benchmark_borrow_return(c: &mut Criterion) {
         limit: Some(10),
         offset: Some(0),
         joins: None,
+        timeout_ms: None,
+        request_id: None,
+        read_primary: None,
     };
 
     group.bench_with_input(
@@ -587,6 +590,9 @@ fn benchmark_borrow_return(c: &mut Criterion) {
         limit: Some(10),
         offset: Some(0),
         joins: None,
+        timeout_ms: None,
+        request_id: None,
+        read_primary: None,
     };
 
     group.bench_with_input(
@@ -616,6 +622,9 @@ fn benchmark_borrow_return(c: &mut Criterion) {
         limit: Some(10),
         offset: Some(0),
         joins: None,
+        timeout_ms: None,
+        request_id: None,
+        read_primary: None,
     };
 
     group.bench_with_input(
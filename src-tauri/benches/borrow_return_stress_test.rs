@@ -1032,6 +1032,9 @@ async fn measure_throughput(
                             limit: Some(10),
                             offset: Some(0),
                             joins: None,
+                            timeout_ms: None,
+                            request_id: None,
+                            read_primary: None,
                         };
                         fetch_ready_borrow_devices(&app_state_clone, &params)
                             .await
@@ -1049,6 +1052,9 @@ async fn measure_throughput(
                             limit: Some(10),
                             offset: Some(0),
                             joins: None,
+                            timeout_ms: None,
+                            request_id: None,
+                            read_primary: None,
                         };
                         fetch_borrowing_devices(&app_state_clone, &params)
                             .await
@@ -1066,6 +1072,9 @@ async fn measure_throughput(
                             limit: Some(10),
                             offset: Some(0),
                             joins: None,
+                            timeout_ms: None,
+                            request_id: None,
+                            read_primary: None,
                         };
                         fetch_returned_devices(&app_state_clone, &params)
                             .await
@@ -2,8 +2,9 @@ use dotenvy::dotenv;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use hmi_lib::db::connection::Database;
+use hmi_lib::db::connection::{Database, DbResult};
 use hmi_lib::db::schema::DatabaseSchema;
+use hmi_lib::db::settings::{DatabaseConfig, Profile};
 
 pub struct AppState {
     pub db: Database,
@@ -11,10 +12,14 @@ pub struct AppState {
     pub schema: Arc<Mutex<Option<DatabaseSchema>>>,
 }
 
+async fn connect_bench_db() -> DbResult<Database> {
+    Database::connect(DatabaseConfig::for_profile(Profile::Bench)?).await
+}
+
 #[allow(dead_code)]
 pub async fn ensure_bench_env() -> AppState {
     dotenv().ok();
-    let db = Database::new()
+    let db = connect_bench_db()
         .await
         .expect("Failed to connect to test database for benchmark");
 
@@ -740,7 +745,7 @@ impl Clone for AppState {
         let rt = tokio::runtime::Runtime::new()
             .expect("Failed to create Tokio runtime for AppState clone");
         let db = rt.block_on(async {
-            Database::new()
+            connect_bench_db()
                 .await
                 .expect("Failed to connect to database in AppState clone")
         });
@@ -1015,6 +1015,9 @@ fn benchmark_device(c: &mut Criterion) {
         limit: Some(10),
         offset: Some(0),
         joins: None,
+        timeout_ms: None,
+        request_id: None,
+        read_primary: None,
     };
 
     group.bench_with_input(
@@ -1045,6 +1048,9 @@ fn benchmark_device(c: &mut Criterion) {
             limit: None,
             offset: None,
             joins: None,
+            timeout_ms: None,
+            request_id: None,
+            read_primary: None,
         };
 
         group.bench_with_input(
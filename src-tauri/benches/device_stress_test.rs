@@ -433,6 +433,9 @@ async fn measure_throughput(
                             limit: Some(10),
                             offset: Some(0),
                             joins: None,
+                            timeout_ms: None,
+                            request_id: None,
+                            read_primary: None,
                         };
                         fetch_devices(&app_state_clone, &params).await.map(|_| ())
                     }
@@ -543,6 +543,9 @@ fn benchmark_maintenance(c: &mut Criterion) {
         limit: Some(10),
         offset: Some(0),
         joins: None,
+        timeout_ms: None,
+        request_id: None,
+        read_primary: None,
     };
 
     group.bench_with_input(
@@ -573,6 +576,9 @@ fn benchmark_maintenance(c: &mut Criterion) {
             limit: None,
             offset: None,
             joins: None,
+            timeout_ms: None,
+            request_id: None,
+            read_primary: None,
         };
 
         group.bench_with_input(
@@ -348,6 +348,9 @@ fn benchmark_search(c: &mut Criterion) {
         limit: Some(20),
         offset: Some(0),
         joins: None,
+        timeout_ms: None,
+        request_id: None,
+        read_primary: None,
     };
 
     group.bench_with_input(
@@ -451,6 +451,9 @@ fn benchmark_user(c: &mut Criterion) {
         limit: Some(10),
         offset: Some(0),
         joins: None,
+        timeout_ms: None,
+        request_id: None,
+        read_primary: None,
     };
 
     group.bench_with_input(
@@ -481,6 +484,9 @@ fn benchmark_user(c: &mut Criterion) {
             limit: None,
             offset: None,
             joins: None,
+            timeout_ms: None,
+            request_id: None,
+            read_primary: None,
         };
 
         group.bench_with_input(
@@ -0,0 +1,97 @@
+use tauri::State;
+
+use crate::db::audit::{self, Audit, AuditError, AuditReport, AuditedDevice, NewAudit};
+
+use super::db_commands::{ensure_available, CommandError, CommandResult};
+use super::AppState;
+
+impl From<AuditError> for CommandError {
+    fn from(error: AuditError) -> Self {
+        let message = error.to_string();
+        match error {
+            AuditError::Invalid(fields) => {
+                CommandError::with_fields(format!("Audit rejected: {}", message), fields)
+            }
+            _ => CommandError::new(message),
+        }
+    }
+}
+
+/// Starts an audit of the lab, moving the devices scanned so far to
+/// `assessing`. Rejected if the lab already has an audit in progress.
+#[tauri::command]
+pub async fn create_audit(state: State<'_, AppState>, mut audit: NewAudit) -> CommandResult<Audit> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    audit.auditor_id = session.user_id().to_string();
+    audit.lab_id = session.lab_id().to_string();
+    Ok(audit::create_audit(&state.db, &audit).await?)
+}
+
+/// Adds scanned devices, or unscanned ones with a chosen condition, to an
+/// audit in progress. Devices already in it only have their condition
+/// updated.
+#[tauri::command]
+pub async fn add_audit_devices(
+    state: State<'_, AppState>,
+    audit_id: String,
+    devices: Vec<AuditedDevice>,
+) -> CommandResult<Audit> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(audit::add_audit_devices(&state.db, &audit_id, session.lab_id(), &devices).await?)
+}
+
+/// Changes the condition recorded for devices already in the audit.
+#[tauri::command]
+pub async fn update_audit_conditions(
+    state: State<'_, AppState>,
+    audit_id: String,
+    devices: Vec<AuditedDevice>,
+) -> CommandResult<Audit> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(audit::update_audit_conditions(&state.db, &audit_id, session.lab_id(), &devices).await?)
+}
+
+/// Takes a device out of the audit, restoring its previous status.
+#[tauri::command]
+pub async fn remove_audit_device(
+    state: State<'_, AppState>,
+    audit_id: String,
+    device_id: String,
+) -> CommandResult<Audit> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(audit::remove_audit_device(&state.db, &audit_id, session.lab_id(), &device_id).await?)
+}
+
+/// Completes the audit and reports missing, extra and wrong-lab devices.
+/// `markMissingLost` overrides `HMI_AUDIT_MARK_MISSING_LOST` for this call.
+#[tauri::command]
+pub async fn complete_audit(
+    state: State<'_, AppState>,
+    audit_id: String,
+    note: Option<String>,
+    mark_missing_lost: Option<bool>,
+) -> CommandResult<AuditReport> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    let mark_missing_lost = mark_missing_lost.unwrap_or(state.audit.mark_missing_lost);
+    Ok(audit::complete_audit(
+        &state.db,
+        &audit_id,
+        session.lab_id(),
+        note.as_deref(),
+        mark_missing_lost,
+    )
+    .await?)
+}
+
+/// Cancels the audit, giving every device in it back its previous status.
+#[tauri::command]
+pub async fn cancel_audit(state: State<'_, AppState>, audit_id: String) -> CommandResult<Audit> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(audit::cancel_audit(&state.db, &audit_id, session.lab_id()).await?)
+}
@@ -1,47 +1,143 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::error::Error;
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
 use tokio_postgres::types::{FromSql, ToSql, Type};
+use tokio_postgres::Row;
 use uuid::Uuid;
 
-use crate::db::{connection::DbError, queries::builder::QueryBuilder, schema::DatabaseSchema};
+use crate::db::{
+    cancel::QueryControl,
+    codegen::{json_schema::WriteMode, naming::to_camel_case},
+    connection::{Database, DbError},
+    lifecycle,
+    listener::InvalidChannel,
+    metrics::DbHealth,
+    queries::builder::QueryBuilder,
+    replica::is_read_only,
+    schema::{self, DatabaseSchema},
+    supervisor::DbStatus,
+    validation::{summarize, validate_write, FieldError},
+    watcher,
+};
 
 use super::AppState;
 
 #[derive(Debug, Serialize)]
 pub struct CommandError {
     message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<FieldError>,
 }
 
-impl From<DbError> for CommandError {
-    fn from(error: DbError) -> Self {
+impl CommandError {
+    pub fn new(message: impl Into<String>) -> Self {
         CommandError {
-            message: error.to_string(),
+            message: message.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// An error whose details are reported per field, for the UI to show
+    /// next to each input.
+    pub fn with_fields(message: impl Into<String>, fields: Vec<FieldError>) -> Self {
+        CommandError {
+            message: message.into(),
+            fields,
         }
     }
 }
 
-type CommandResult<T> = Result<T, CommandError>;
+impl From<DbError> for CommandError {
+    fn from(error: DbError) -> Self {
+        CommandError::new(error.to_string())
+    }
+}
 
-fn to_camel_case(snake_str: &str) -> String {
-    let mut result = String::with_capacity(snake_str.len());
-    let mut capitalize_next = false;
+impl From<InvalidChannel> for CommandError {
+    fn from(error: InvalidChannel) -> Self {
+        CommandError::new(error.to_string())
+    }
+}
 
-    for c in snake_str.chars() {
-        if c == '_' {
-            capitalize_next = true;
-        } else if capitalize_next {
-            result.push(c.to_ascii_uppercase());
-            capitalize_next = false;
-        } else {
-            result.push(c);
-        }
+impl From<Vec<FieldError>> for CommandError {
+    fn from(fields: Vec<FieldError>) -> Self {
+        CommandError::with_fields(format!("Invalid payload: {}", summarize(&fields)), fields)
+    }
+}
+
+pub(crate) type CommandResult<T> = Result<T, CommandError>;
+
+/// Fails fast with the outage reason instead of waiting on the pool while
+/// the database is known to be unreachable.
+pub(crate) fn ensure_available(state: &AppState) -> CommandResult<()> {
+    match &*state.status.borrow() {
+        DbStatus::Available => Ok(()),
+        DbStatus::Connecting => Err(CommandError::new("Database is still connecting")),
+        DbStatus::Unavailable { reason, .. } => Err(CommandError::new(format!(
+            "Database unavailable: {}",
+            reason
+        ))),
+    }
+}
+
+#[tauri::command]
+pub async fn db_status(state: State<'_, AppState>) -> CommandResult<DbStatus> {
+    Ok(state.status.borrow().clone())
+}
+
+/// Cancels the `query_table`/`query_raw` call or the stream started with
+/// `request_id`. Returns `false` when it has already finished.
+#[tauri::command]
+pub async fn cancel_query(state: State<'_, AppState>, request_id: String) -> CommandResult<bool> {
+    let stream_closed = state.streams.close(&request_id);
+    let cancelled = state.db.cancel(&request_id).await?;
+    Ok(stream_closed || cancelled)
+}
+
+/// Pool usage and a live probe of the server, of the main database or the
+/// one registered as `database`. Also answers during an outage, with the
+/// probe error in `error`.
+#[tauri::command]
+pub async fn db_health(
+    state: State<'_, AppState>,
+    database: Option<String>,
+) -> CommandResult<DbHealth> {
+    match database {
+        Some(name) => match state.databases.get(&name) {
+            Some(db) => Ok(db.health().await),
+            None => Err(CommandError::new(format!("Unknown database '{}'", name))),
+        },
+        None => Ok(state.db.health().await),
     }
-    result
 }
 
-fn convert_json_keys_to_camel_case(value: JsonValue) -> JsonValue {
+/// Names usable as `database` in `query_raw` and `db_health`.
+#[tauri::command]
+pub async fn list_databases(state: State<'_, AppState>) -> CommandResult<Vec<String>> {
+    Ok(state.databases.names())
+}
+
+/// Starts forwarding `NOTIFY`s on `channel` as `db://notification` events.
+/// Every call needs a matching `unsubscribe_channel`. Returns the channels
+/// now listened to.
+#[tauri::command]
+pub async fn subscribe_channel(
+    state: State<'_, AppState>,
+    channel: String,
+) -> CommandResult<Vec<String>> {
+    Ok(state.listener.subscribe(&channel)?)
+}
+
+#[tauri::command]
+pub async fn unsubscribe_channel(
+    state: State<'_, AppState>,
+    channel: String,
+) -> CommandResult<Vec<String>> {
+    Ok(state.listener.unsubscribe(&channel))
+}
+
+pub(crate) fn convert_json_keys_to_camel_case(value: JsonValue) -> JsonValue {
     match value {
         JsonValue::Object(obj) => {
             let mut new_obj = serde_json::Map::new();
@@ -76,25 +172,43 @@ impl<'a> FromSql<'a> for PostgresEnum {
     }
 }
 
+/// Re-reads the schema from the database and swaps it into the cache,
+/// notifying the frontend if it changed. Does not touch any files.
+#[tauri::command]
+pub async fn sync_schema(app: AppHandle, state: State<'_, AppState>) -> CommandResult<()> {
+    ensure_available(&state)?;
+    if let Some(change) = watcher::reload(&state.db, &state.databases, &state.schema).await? {
+        let _ = app.emit(watcher::SCHEMA_CHANGED_EVENT, change);
+    }
+    Ok(())
+}
+
+/// Development only: rewrites the generated frontend files under
+/// `src/types/db` from the cached schema. Returns the paths written.
 #[tauri::command]
-pub async fn sync_schema(state: State<'_, AppState>) -> CommandResult<()> {
-    let schema = DatabaseSchema::fetch(&state.db).await?;
+pub async fn regenerate_schema_files(state: State<'_, AppState>) -> CommandResult<Vec<String>> {
+    if !cfg!(debug_assertions) {
+        return Err(CommandError::new(
+            "Schema file generation is only available in development builds".to_string(),
+        ));
+    }
 
-    schema
-        .save_to_file("db-schema.json")
-        .map_err(|e| CommandError {
-            message: format!("Failed to save schema: {}", e),
-        })?;
+    let schema_guard = state.schema.read().await;
+    let schema = schema_guard
+        .as_ref()
+        .ok_or_else(|| CommandError::new("Schema not synchronized".to_string()))?;
 
-    schema
-        .generate_typescript_types("src/lib/db/types.ts")
-        .map_err(|e| CommandError {
-            message: format!("Failed to generate TypeScript types: {}", e),
-        })?;
+    let workspace_dir = schema::workspace_dir()
+        .map_err(|e| CommandError::new(format!("Failed to write schema files: {}", e)))?;
 
-    *state.schema.lock().await = Some(schema);
+    let paths = schema
+        .write_generated_files(&workspace_dir)
+        .map_err(|e| CommandError::new(format!("Failed to write schema files: {}", e)))?;
 
-    Ok(())
+    Ok(paths
+        .into_iter()
+        .map(|path| path.display().to_string())
+        .collect())
 }
 
 #[derive(Debug, Deserialize)]
@@ -115,6 +229,12 @@ pub struct QueryParams {
     pub limit: Option<i64>,
     pub offset: Option<i64>,
     pub joins: Option<Vec<JoinParams>>,
+    /// Overrides the session `statement_timeout` for this query.
+    pub timeout_ms: Option<u64>,
+    /// Lets `cancel_query` interrupt this query while it runs.
+    pub request_id: Option<String>,
+    /// Skips the replica, for reads that must see the latest writes.
+    pub read_primary: Option<bool>,
 }
 
 #[tauri::command]
@@ -122,13 +242,41 @@ pub async fn query_table(
     state: State<'_, AppState>,
     params: QueryParams,
 ) -> CommandResult<Vec<serde_json::Value>> {
-    let schema_guard = state.schema.lock().await;
-    let schema = schema_guard.as_ref().ok_or_else(|| CommandError {
-        message: "Database schema not initialized. Please restart the application.".to_string(),
+    state.session.require()?;
+    ensure_available(&state)?;
+    let control = QueryControl::new(params.request_id.clone(), params.timeout_ms);
+    let read_primary = params.read_primary.unwrap_or(false);
+
+    let schema_guard = state.schema.read().await;
+    let schema = schema_guard.as_ref().ok_or_else(|| {
+        CommandError::new(
+            "Database schema not initialized. Please restart the application.".to_string(),
+        )
     })?;
 
-    let mut builder = QueryBuilder::new(schema, &params.table).ok_or_else(|| CommandError {
-        message: format!("Table '{}' not found in the database schema", params.table),
+    let (query, params) = table_select(schema, params)?;
+    let (db, mut client) = state.db.get_read_client(read_primary).await?;
+
+    let params_slice: Vec<&(dyn ToSql + Sync)> =
+        params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
+
+    let rows = db
+        .run_controlled(&mut client, &control, &query, &params_slice)
+        .await?;
+
+    Ok(rows.iter().map(row_to_json).collect())
+}
+
+/// Builds the `SELECT` for `params` against the cached schema.
+pub(crate) fn table_select(
+    schema: &DatabaseSchema,
+    params: QueryParams,
+) -> CommandResult<(String, Vec<Box<dyn ToSql + Sync + Send>>)> {
+    let mut builder = QueryBuilder::new(schema, &params.table).ok_or_else(|| {
+        CommandError::new(format!(
+            "Table '{}' not found in the database schema",
+            params.table
+        ))
     })?;
 
     if let Some(columns) = params.columns {
@@ -202,124 +350,7 @@ pub async fn query_table(
         builder = builder.offset(offset);
     }
 
-    let (query, params) = builder.build_select();
-    let client = state.db.get_client().await?;
-
-    let params_slice: Vec<&(dyn ToSql + Sync)> =
-        params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
-
-    let rows = client
-        .query(&query, &params_slice)
-        .await
-        .map_err(DbError::from)?;
-
-    Ok(rows
-        .iter()
-        .map(|row| {
-            let mut obj = serde_json::Map::new();
-            for (i, column) in row.columns().iter().enumerate() {
-                let name = to_camel_case(column.name());
-                let value = match column.type_() {
-                    &Type::VARCHAR | &Type::TEXT => {
-                        let s: Option<String> = row.get(i);
-                        match s {
-                            Some(val) => serde_json::Value::String(val),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::INT4 => {
-                        let n: Option<i32> = row.get(i);
-                        match n {
-                            Some(val) => serde_json::Value::Number(val.into()),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::INT8 => {
-                        let n: Option<i64> = row.get(i);
-                        match n {
-                            Some(val) => serde_json::Value::Number(val.into()),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::FLOAT8 => {
-                        let n: Option<f64> = row.get(i);
-                        serde_json::json!(n)
-                    }
-                    &Type::BOOL => {
-                        let b: Option<bool> = row.get(i);
-                        match b {
-                            Some(val) => serde_json::Value::Bool(val),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::TIMESTAMPTZ => {
-                        let ts: Option<chrono::DateTime<chrono::Utc>> = row.get(i);
-                        ts.map(|t| serde_json::Value::String(t.to_rfc3339()))
-                            .unwrap_or(serde_json::Value::Null)
-                    }
-                    &Type::JSON | &Type::JSONB => match row.try_get::<_, Option<JsonValue>>(i) {
-                        Ok(Some(json_val)) => convert_json_keys_to_camel_case(json_val),
-                        Ok(None) => serde_json::Value::Null,
-                        Err(_) => {
-                            if let Ok(Some(json_str)) = row.try_get::<_, Option<String>>(i) {
-                                if let Ok(parsed) = serde_json::from_str(&json_str) {
-                                    convert_json_keys_to_camel_case(parsed)
-                                } else {
-                                    serde_json::Value::String(json_str)
-                                }
-                            } else {
-                                serde_json::Value::Null
-                            }
-                        }
-                    },
-                    t if t.to_string().starts_with("_") => {
-                        let arr: Option<Vec<String>> = row.try_get(i).ok().flatten();
-                        match arr {
-                            Some(val) => serde_json::Value::Array(
-                                val.into_iter().map(serde_json::Value::String).collect(),
-                            ),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    t => {
-                        let type_name = t.to_string();
-                        if type_name == "uuid" {
-                            match row.try_get::<_, uuid::Uuid>(i) {
-                                Ok(uuid) => serde_json::Value::String(uuid.to_string()),
-                                _ => {
-                                    let uuid_str: Option<String> = row.try_get(i).ok().flatten();
-                                    match uuid_str {
-                                        Some(val) => serde_json::Value::String(val),
-                                        None => serde_json::Value::Null,
-                                    }
-                                }
-                            }
-                        } else if t.oid() >= 16384 {
-                            let enum_value: Result<Option<PostgresEnum>, _> = row.try_get(i);
-                            match enum_value {
-                                Ok(Some(PostgresEnum(val))) => serde_json::Value::String(val),
-                                _ => {
-                                    let str_val: Option<String> = row.try_get(i).ok().flatten();
-                                    match str_val {
-                                        Some(val) => serde_json::Value::String(val),
-                                        None => serde_json::Value::Null,
-                                    }
-                                }
-                            }
-                        } else {
-                            let val: Option<String> = row.try_get(i).ok().flatten();
-                            match val {
-                                Some(s) => serde_json::Value::String(s),
-                                None => serde_json::Value::Null,
-                            }
-                        }
-                    }
-                };
-                obj.insert(name, value);
-            }
-            serde_json::Value::Object(obj)
-        })
-        .collect())
+    Ok(builder.build_select())
 }
 
 #[derive(Debug, Deserialize)]
@@ -333,14 +364,31 @@ pub async fn insert_into_table(
     state: State<'_, AppState>,
     params: InsertParams,
 ) -> CommandResult<serde_json::Value> {
-    let schema_guard = state.schema.lock().await;
-    let schema = schema_guard.as_ref().ok_or_else(|| CommandError {
-        message: "Schema not synchronized".to_string(),
-    })?;
+    state.session.require()?;
+    ensure_available(&state)?;
+
+    let schema_guard = state.schema.read().await;
+    let schema = schema_guard
+        .as_ref()
+        .ok_or_else(|| CommandError::new("Schema not synchronized".to_string()))?;
+
+    if let Some(table) = schema.tables.get(&params.table) {
+        if lifecycle::GUARDED_TABLES.contains(&table.qualified_name().as_str()) {
+            return Err(CommandError::new(format!(
+                "Table {} can only be changed through device commands",
+                table.qualified_name()
+            )));
+        }
+    }
+
+    validate_write(schema, &params.table, &params.value, WriteMode::Insert)?;
 
     let (query, insert_params) = QueryBuilder::build_insert(&params.value, schema, &params.table)
-        .ok_or_else(|| CommandError {
-        message: format!("Failed to build insert query for table {}", params.table),
+        .ok_or_else(|| {
+        CommandError::new(format!(
+            "Failed to build insert query for table {}",
+            params.table
+        ))
     })?;
 
     let client = state.db.get_client().await?;
@@ -353,65 +401,192 @@ pub async fn insert_into_table(
         .query_one(&query, &params_slice)
         .await
         .map_err(DbError::from)?;
+    state.db.mark_write();
+
+    Ok(row_to_json(&row))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RawQueryParams {
+    #[allow(dead_code)]
+    pub sql: String,
+    #[allow(dead_code)]
+    pub params: Option<Vec<serde_json::Value>>,
+    pub timeout_ms: Option<u64>,
+    pub request_id: Option<String>,
+    /// Keeps a read-only statement off the replica.
+    pub read_primary: Option<bool>,
+    /// Runs against a database registered by name instead of the main one.
+    pub database: Option<String>,
+}
 
+/// The database registered as `name`, or the main one when it is
+/// available.
+pub(crate) fn target_database(state: &AppState, name: Option<&str>) -> CommandResult<Database> {
+    match name {
+        Some(name) => state
+            .databases
+            .get(name)
+            .ok_or_else(|| CommandError::new(format!("Unknown database '{}'", name))),
+        None => {
+            ensure_available(state)?;
+            Ok(state.db.clone())
+        }
+    }
+}
+
+/// Runs a read-only statement; anything that could write is refused.
+#[tauri::command]
+pub async fn query_raw(
+    state: State<'_, AppState>,
+    params: RawQueryParams,
+) -> CommandResult<Vec<serde_json::Value>> {
+    state.session.require()?;
+    if !is_read_only(&params.sql) {
+        return Err(CommandError::new("Only read-only statements can be run"));
+    }
+    let target = &target_database(&state, params.database.as_deref())?;
+    let (db, mut client) = target
+        .get_read_client(params.read_primary.unwrap_or(false))
+        .await?;
+
+    let param_values = raw_params(params.params.clone());
+
+    let params_slice: Vec<&(dyn ToSql + Sync)> = param_values
+        .iter()
+        .map(|p| &**p as &(dyn ToSql + Sync))
+        .collect();
+
+    let control = QueryControl::new(params.request_id.clone(), params.timeout_ms);
+    let rows = db
+        .run_controlled(&mut client, &control, &params.sql, &params_slice)
+        .await
+        .map_err(|e| match e {
+            DbError::Cancelled => CommandError::from(e),
+            e => CommandError::new(format!(
+                "Query execution failed: {}. SQL: {}, Params: {:?}",
+                e, params.sql, params.params
+            )),
+        })?;
+
+    Ok(rows.iter().map(row_to_json).collect())
+}
+
+/// One result row as a JSON object with camelCase keys.
+pub(crate) fn row_to_json(row: &Row) -> JsonValue {
     let mut obj = serde_json::Map::new();
     for (i, column) in row.columns().iter().enumerate() {
         let name = to_camel_case(column.name());
         let value = match column.type_() {
             &Type::VARCHAR | &Type::TEXT => {
                 let s: Option<String> = row.get(i);
-                serde_json::Value::String(s.unwrap_or_default())
+                match s {
+                    Some(val) => serde_json::Value::String(val),
+                    None => serde_json::Value::Null,
+                }
             }
             &Type::INT4 => {
                 let n: Option<i32> = row.get(i);
-                serde_json::Value::Number(n.unwrap_or_default().into())
+                match n {
+                    Some(val) => serde_json::Value::Number(val.into()),
+                    None => serde_json::Value::Null,
+                }
             }
             &Type::INT8 => {
                 let n: Option<i64> = row.get(i);
-                serde_json::Value::Number(n.unwrap_or_default().into())
+                match n {
+                    Some(val) => serde_json::Value::Number(val.into()),
+                    None => serde_json::Value::Null,
+                }
             }
             &Type::FLOAT8 => {
                 let n: Option<f64> = row.get(i);
-                serde_json::json!(n)
+                match n {
+                    Some(val) => serde_json::json!(val),
+                    None => serde_json::Value::Null,
+                }
             }
             &Type::BOOL => {
                 let b: Option<bool> = row.get(i);
-                serde_json::Value::Bool(b.unwrap_or_default())
+                match b {
+                    Some(val) => serde_json::Value::Bool(val),
+                    None => serde_json::Value::Null,
+                }
             }
             &Type::TIMESTAMPTZ => {
                 let ts: Option<chrono::DateTime<chrono::Utc>> = row.get(i);
-                ts.map(|t| serde_json::Value::String(t.to_rfc3339()))
-                    .unwrap_or(serde_json::Value::Null)
+                match ts {
+                    Some(t) => serde_json::Value::String(t.to_rfc3339()),
+                    None => serde_json::Value::Null,
+                }
             }
-            &Type::JSON | &Type::JSONB => {
-                let json: Option<JsonValue> = row.try_get(i).ok().flatten();
-                convert_json_keys_to_camel_case(json.unwrap_or(JsonValue::Null))
+            &Type::JSON | &Type::JSONB => match row.try_get::<_, Option<JsonValue>>(i) {
+                Ok(Some(json_val)) => convert_json_keys_to_camel_case(json_val),
+                Ok(None) => serde_json::Value::Null,
+                Err(_) => {
+                    if let Ok(Some(json_str)) = row.try_get::<_, Option<String>>(i) {
+                        if let Ok(parsed) = serde_json::from_str(&json_str) {
+                            convert_json_keys_to_camel_case(parsed)
+                        } else {
+                            serde_json::Value::String(json_str)
+                        }
+                    } else {
+                        serde_json::Value::Null
+                    }
+                }
+            },
+            t if t.to_string().starts_with("_") => {
+                let arr: Option<Vec<String>> = row.try_get(i).ok().flatten();
+                match arr {
+                    Some(val) => serde_json::Value::Array(
+                        val.into_iter().map(serde_json::Value::String).collect(),
+                    ),
+                    None => serde_json::Value::Null,
+                }
+            }
+            t => {
+                let type_name = t.to_string();
+                if type_name == "uuid" {
+                    match row.try_get::<_, uuid::Uuid>(i) {
+                        Ok(uuid) => serde_json::Value::String(uuid.to_string()),
+                        _ => {
+                            let uuid_str: Option<String> = row.try_get(i).ok().flatten();
+                            match uuid_str {
+                                Some(val) => serde_json::Value::String(val),
+                                None => serde_json::Value::Null,
+                            }
+                        }
+                    }
+                } else if t.oid() >= 16384 {
+                    let enum_value: Result<Option<PostgresEnum>, _> = row.try_get(i);
+                    match enum_value {
+                        Ok(Some(PostgresEnum(val))) => serde_json::Value::String(val),
+                        _ => {
+                            let str_val: Option<String> = row.try_get(i).ok().flatten();
+                            match str_val {
+                                Some(val) => serde_json::Value::String(val),
+                                None => serde_json::Value::Null,
+                            }
+                        }
+                    }
+                } else {
+                    let val: Option<String> = row.try_get(i).ok().flatten();
+                    match val {
+                        Some(s) => serde_json::Value::String(s),
+                        None => serde_json::Value::Null,
+                    }
+                }
             }
-            _ => serde_json::Value::Null,
         };
         obj.insert(name, value);
     }
-
-    Ok(serde_json::Value::Object(obj))
+    serde_json::Value::Object(obj)
 }
 
-#[derive(Debug, Deserialize)]
-pub struct RawQueryParams {
-    #[allow(dead_code)]
-    pub sql: String,
-    #[allow(dead_code)]
-    pub params: Option<Vec<serde_json::Value>>,
-}
-
-#[tauri::command]
-pub async fn query_raw(
-    state: State<'_, AppState>,
-    params: RawQueryParams,
-) -> CommandResult<Vec<serde_json::Value>> {
-    let client = state.db.get_client().await?;
-
-    let params_clone = params.params.clone();
-    let param_values: Vec<Box<dyn ToSql + Send + Sync>> = params_clone
+/// Binds JSON values for `query_raw`: strings that parse as RFC 3339
+/// timestamps or UUIDs are sent as such, arrays as text arrays.
+pub(crate) fn raw_params(values: Option<Vec<JsonValue>>) -> Vec<Box<dyn ToSql + Send + Sync>> {
+    values
         .unwrap_or_default()
         .into_iter()
         .map(|v| match v {
@@ -446,133 +621,5 @@ pub async fn query_raw(
             }
             _ => Box::new("") as Box<dyn ToSql + Send + Sync>,
         })
-        .collect();
-
-    let params_slice: Vec<&(dyn ToSql + Sync)> = param_values
-        .iter()
-        .map(|p| &**p as &(dyn ToSql + Sync))
-        .collect();
-
-    let rows = client
-        .query(&params.sql, params_slice.as_slice())
-        .await
-        .map_err(|e| CommandError {
-            message: format!(
-                "Query execution failed: {}. SQL: {}, Params: {:?}",
-                e, params.sql, params.params
-            ),
-        })?;
-
-    Ok(rows
-        .iter()
-        .map(|row| {
-            let mut obj = serde_json::Map::new();
-            for (i, column) in row.columns().iter().enumerate() {
-                let name = to_camel_case(column.name());
-                let value = match column.type_() {
-                    &Type::VARCHAR | &Type::TEXT => {
-                        let s: Option<String> = row.get(i);
-                        match s {
-                            Some(val) => serde_json::Value::String(val),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::INT4 => {
-                        let n: Option<i32> = row.get(i);
-                        match n {
-                            Some(val) => serde_json::Value::Number(val.into()),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::INT8 => {
-                        let n: Option<i64> = row.get(i);
-                        match n {
-                            Some(val) => serde_json::Value::Number(val.into()),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::FLOAT8 => {
-                        let n: Option<f64> = row.get(i);
-                        match n {
-                            Some(val) => serde_json::json!(val),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::BOOL => {
-                        let b: Option<bool> = row.get(i);
-                        match b {
-                            Some(val) => serde_json::Value::Bool(val),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::TIMESTAMPTZ => {
-                        let ts: Option<chrono::DateTime<chrono::Utc>> = row.get(i);
-                        match ts {
-                            Some(t) => serde_json::Value::String(t.to_rfc3339()),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    &Type::JSON | &Type::JSONB => match row.try_get::<_, Option<JsonValue>>(i) {
-                        Ok(Some(json_val)) => convert_json_keys_to_camel_case(json_val),
-                        Ok(None) => serde_json::Value::Null,
-                        Err(_) => {
-                            if let Ok(Some(json_str)) = row.try_get::<_, Option<String>>(i) {
-                                if let Ok(parsed) = serde_json::from_str(&json_str) {
-                                    convert_json_keys_to_camel_case(parsed)
-                                } else {
-                                    serde_json::Value::String(json_str)
-                                }
-                            } else {
-                                serde_json::Value::Null
-                            }
-                        }
-                    },
-                    t if t.to_string().starts_with("_") => {
-                        let arr: Option<Vec<String>> = row.try_get(i).ok().flatten();
-                        match arr {
-                            Some(val) => serde_json::Value::Array(
-                                val.into_iter().map(serde_json::Value::String).collect(),
-                            ),
-                            None => serde_json::Value::Null,
-                        }
-                    }
-                    t => {
-                        let type_name = t.to_string();
-                        if type_name == "uuid" {
-                            match row.try_get::<_, uuid::Uuid>(i) {
-                                Ok(uuid) => serde_json::Value::String(uuid.to_string()),
-                                _ => {
-                                    let uuid_str: Option<String> = row.try_get(i).ok().flatten();
-                                    match uuid_str {
-                                        Some(val) => serde_json::Value::String(val),
-                                        None => serde_json::Value::Null,
-                                    }
-                                }
-                            }
-                        } else if t.oid() >= 16384 {
-                            let enum_value: Result<Option<PostgresEnum>, _> = row.try_get(i);
-                            match enum_value {
-                                Ok(Some(PostgresEnum(val))) => serde_json::Value::String(val),
-                                _ => {
-                                    let str_val: Option<String> = row.try_get(i).ok().flatten();
-                                    match str_val {
-                                        Some(val) => serde_json::Value::String(val),
-                                        None => serde_json::Value::Null,
-                                    }
-                                }
-                            }
-                        } else {
-                            let val: Option<String> = row.try_get(i).ok().flatten();
-                            match val {
-                                Some(s) => serde_json::Value::String(s),
-                                None => serde_json::Value::Null,
-                            }
-                        }
-                    }
-                };
-                obj.insert(name, value);
-            }
-            serde_json::Value::Object(obj)
-        })
-        .collect())
+        .collect()
 }
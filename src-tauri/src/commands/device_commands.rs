@@ -0,0 +1,181 @@
+use tauri::State;
+
+use crate::db::access::{
+    self, AccessError, Accessory, DeviceAuditDetail, DeviceDetail, DeviceMaintenanceDetail,
+    DeviceReceiptDetail, DeviceShipmentDetail,
+};
+use crate::db::lifecycle::{self, StatusChange};
+
+use super::db_commands::{ensure_available, CommandError, CommandResult};
+use super::AppState;
+
+/// Entries returned by `device_status_history` when no limit is given.
+const DEFAULT_HISTORY_LIMIT: i64 = 50;
+
+impl From<AccessError> for CommandError {
+    fn from(error: AccessError) -> Self {
+        CommandError::new(error.to_string())
+    }
+}
+
+/// The device's details. Denied, with the roles that may view it, unless
+/// the signed-in user's roles permit viewing its kind. With `lab_id`, only
+/// a device of that lab is found.
+#[tauri::command]
+pub async fn device_detail(
+    state: State<'_, AppState>,
+    device_id: String,
+    lab_id: Option<String>,
+) -> CommandResult<DeviceDetail> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(access::device_detail(
+        &state.db,
+        &session.role_keys(),
+        &device_id,
+        lab_id.as_deref(),
+    )
+    .await?)
+}
+
+/// The device with `device_id`, or else a device of `kind_id`, in `lab_id`
+/// if given; `null` if there is none. Denied unless the signed-in user may
+/// view its kind.
+#[tauri::command]
+pub async fn find_device(
+    state: State<'_, AppState>,
+    device_id: Option<String>,
+    kind_id: Option<String>,
+    lab_id: Option<String>,
+) -> CommandResult<Option<DeviceDetail>> {
+    if device_id.is_none() && kind_id.is_none() {
+        return Err(CommandError::new(
+            "Either a device ID or a kind ID is required",
+        ));
+    }
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(access::find_device(
+        &state.db,
+        &session.role_keys(),
+        device_id.as_deref(),
+        kind_id.as_deref(),
+        lab_id.as_deref(),
+    )
+    .await?)
+}
+
+/// The device and its open loan, for the borrow and return screen. Only a
+/// device of the session's lab whose kind the signed-in user may view.
+#[tauri::command]
+pub async fn device_receipt_detail(
+    state: State<'_, AppState>,
+    device_id: String,
+) -> CommandResult<DeviceReceiptDetail> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(access::device_receipt_detail(
+        &state.db,
+        &session.role_keys(),
+        &device_id,
+        session.lab_id(),
+    )
+    .await?)
+}
+
+/// The device and its condition in the lab's open audit, for the audit
+/// screen. Only a device of the session's lab whose kind the signed-in user
+/// may view.
+#[tauri::command]
+pub async fn device_audit_detail(
+    state: State<'_, AppState>,
+    device_id: String,
+) -> CommandResult<DeviceAuditDetail> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(access::device_audit_detail(
+        &state.db,
+        &session.role_keys(),
+        &device_id,
+        session.lab_id(),
+    )
+    .await?)
+}
+
+/// The device and its latest maintenance job. Denied unless the signed-in
+/// user may view its kind; with `lab_id`, only a device of that lab.
+#[tauri::command]
+pub async fn device_maintenance_detail(
+    state: State<'_, AppState>,
+    device_id: String,
+    lab_id: Option<String>,
+) -> CommandResult<DeviceMaintenanceDetail> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(access::device_maintenance_detail(
+        &state.db,
+        &session.role_keys(),
+        &device_id,
+        lab_id.as_deref(),
+    )
+    .await?)
+}
+
+/// The device and its latest shipment. Denied unless the signed-in user
+/// may view its kind; with `lab_id`, only a device of that lab.
+#[tauri::command]
+pub async fn device_shipment_detail(
+    state: State<'_, AppState>,
+    device_id: String,
+    lab_id: Option<String>,
+) -> CommandResult<DeviceShipmentDetail> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(access::device_shipment_detail(
+        &state.db,
+        &session.role_keys(),
+        &device_id,
+        lab_id.as_deref(),
+    )
+    .await?)
+}
+
+/// The accessories of a device kind, in the given labs or in all of them.
+/// Denied unless the signed-in user may view the kind; accessories whose
+/// own kind they may not view are left out.
+#[tauri::command]
+pub async fn device_accessories(
+    state: State<'_, AppState>,
+    kind_id: String,
+    lab_ids: Option<Vec<String>>,
+) -> CommandResult<Vec<Accessory>> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(access::accessories(
+        &state.db,
+        &session.role_keys(),
+        &kind_id,
+        &lab_ids.unwrap_or_default(),
+    )
+    .await?)
+}
+
+/// The device's recorded status changes, newest first, with the activity,
+/// user and reason behind each. Denied unless the signed-in user may view
+/// its kind.
+#[tauri::command]
+pub async fn device_status_history(
+    state: State<'_, AppState>,
+    device_id: String,
+    limit: Option<i64>,
+) -> CommandResult<Vec<StatusChange>> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    access::check_device_view(&state.db, &session.role_keys(), &device_id).await?;
+    Ok(lifecycle::history(
+        &state.db,
+        &device_id,
+        limit.unwrap_or(DEFAULT_HISTORY_LIMIT),
+    )
+    .await?)
+}
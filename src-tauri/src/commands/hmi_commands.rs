@@ -0,0 +1,29 @@
+use tauri::State;
+
+use crate::db::hmi::{self, HmiCodeStatus, HmiError, IssuedCode};
+
+use super::db_commands::{ensure_available, CommandError, CommandResult};
+use super::AppState;
+
+impl From<HmiError> for CommandError {
+    fn from(error: HmiError) -> Self {
+        CommandError::new(error.to_string())
+    }
+}
+
+/// Issues a login code for the kiosk to show, valid for ten minutes.
+#[tauri::command]
+pub async fn issue_hmi_code(state: State<'_, AppState>) -> CommandResult<IssuedCode> {
+    ensure_available(&state)?;
+    Ok(hmi::issue_code(&state.db).await?)
+}
+
+/// Polled by the login screen until the code is used or expires.
+#[tauri::command]
+pub async fn hmi_code_status(
+    state: State<'_, AppState>,
+    code: String,
+) -> CommandResult<HmiCodeStatus> {
+    ensure_available(&state)?;
+    Ok(hmi::code_status(&state.db, &code).await?)
+}
@@ -0,0 +1,144 @@
+use tauri::State;
+
+use crate::db::maintenance::{
+    self, MaintainedDevice, Maintenance, MaintenanceError, MaintenanceOutcome, NewMaintenance,
+};
+
+use super::db_commands::{ensure_available, CommandError, CommandResult};
+use super::AppState;
+
+impl From<MaintenanceError> for CommandError {
+    fn from(error: MaintenanceError) -> Self {
+        let message = error.to_string();
+        match error {
+            MaintenanceError::Invalid(fields) => {
+                CommandError::with_fields(format!("Maintenance rejected: {}", message), fields)
+            }
+            _ => CommandError::new(message),
+        }
+    }
+}
+
+/// Starts a maintenance job assigned to the technician, or to the signed-in
+/// user when none is given, moving the devices to `maintaining`.
+#[tauri::command]
+pub async fn create_maintenance(
+    state: State<'_, AppState>,
+    mut maintenance: NewMaintenance,
+) -> CommandResult<Maintenance> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    if maintenance.technician_id.trim().is_empty() {
+        maintenance.technician_id = session.user_id().to_string();
+    }
+    maintenance.lab_id = session.lab_id().to_string();
+    Ok(maintenance::create_maintenance(&state.db, &maintenance).await?)
+}
+
+/// Adds devices to a job in progress. Devices already in it only have
+/// their outcome updated.
+#[tauri::command]
+pub async fn add_maintenance_devices(
+    state: State<'_, AppState>,
+    maintenance_id: String,
+    devices: Vec<MaintainedDevice>,
+) -> CommandResult<Maintenance> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(
+        maintenance::add_maintenance_devices(
+            &state.db,
+            &maintenance_id,
+            session.lab_id(),
+            &devices,
+        )
+        .await?,
+    )
+}
+
+/// Changes the expected outcome of devices already in the job.
+#[tauri::command]
+pub async fn update_maintenance_outcomes(
+    state: State<'_, AppState>,
+    maintenance_id: String,
+    devices: Vec<MaintainedDevice>,
+) -> CommandResult<Maintenance> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(maintenance::update_maintenance_outcomes(
+        &state.db,
+        &maintenance_id,
+        session.lab_id(),
+        &devices,
+    )
+    .await?)
+}
+
+/// Takes a device out of the job, restoring its previous status.
+#[tauri::command]
+pub async fn remove_maintenance_device(
+    state: State<'_, AppState>,
+    maintenance_id: String,
+    device_id: String,
+) -> CommandResult<Maintenance> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(maintenance::remove_maintenance_device(
+        &state.db,
+        &maintenance_id,
+        session.lab_id(),
+        &device_id,
+    )
+    .await?)
+}
+
+/// Hands a job in progress over to another technician.
+#[tauri::command]
+pub async fn assign_maintenance_technician(
+    state: State<'_, AppState>,
+    maintenance_id: String,
+    technician_id: String,
+) -> CommandResult<Maintenance> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(maintenance::assign_maintenance_technician(
+        &state.db,
+        &maintenance_id,
+        session.lab_id(),
+        &technician_id,
+    )
+    .await?)
+}
+
+/// Completes the job, giving every device its outcome. Rejected, with a
+/// reason per device in `fields`, if a device left `broken` or `discarded`
+/// has no note.
+#[tauri::command]
+pub async fn complete_maintenance(
+    state: State<'_, AppState>,
+    maintenance_id: String,
+    devices: Option<Vec<MaintenanceOutcome>>,
+    note: Option<String>,
+) -> CommandResult<Maintenance> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(maintenance::complete_maintenance(
+        &state.db,
+        &maintenance_id,
+        session.lab_id(),
+        &devices.unwrap_or_default(),
+        note.as_deref(),
+    )
+    .await?)
+}
+
+/// Cancels the job, giving every device in it back its previous status.
+#[tauri::command]
+pub async fn cancel_maintenance(
+    state: State<'_, AppState>,
+    maintenance_id: String,
+) -> CommandResult<Maintenance> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(maintenance::cancel_maintenance(&state.db, &maintenance_id, session.lab_id()).await?)
+}
@@ -1,11 +1,40 @@
+use crate::db::audit::AuditSettings;
 use crate::db::connection::Database;
-use crate::db::schema::DatabaseSchema;
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use crate::db::listener::Listener;
+use crate::db::offline::store::OfflineStore;
+use crate::db::otp::OtpSettings;
+use crate::db::registry::NamedDatabases;
+use crate::db::schema::SharedSchema;
+use crate::db::session::SessionStore;
+use crate::db::stream::ActiveStreams;
+use crate::db::supervisor::DbStatus;
+use tokio::sync::watch;
 
 pub struct AppState {
     pub db: Database,
-    pub schema: Arc<Mutex<Option<DatabaseSchema>>>,
+    pub databases: NamedDatabases,
+    pub schema: SharedSchema,
+    pub status: watch::Sender<DbStatus>,
+    pub listener: Listener,
+    pub streams: ActiveStreams,
+    /// `None` when the SQLite file could not be opened; the app then works
+    /// online only.
+    pub offline: Option<OfflineStore>,
+    pub audit: AuditSettings,
+    pub otp: OtpSettings,
+    /// Who is signed in at the kiosk; domain commands take their actor and
+    /// lab from it rather than from the UI.
+    pub session: SessionStore,
 }
 
+pub mod audit_commands;
 pub mod db_commands;
+pub mod device_commands;
+pub mod hmi_commands;
+pub mod maintenance_commands;
+pub mod offline_commands;
+pub mod otp_commands;
+pub mod receipt_commands;
+pub mod session_commands;
+pub mod shipment_commands;
+pub mod stream_commands;
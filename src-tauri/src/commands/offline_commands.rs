@@ -0,0 +1,155 @@
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use tauri::{AppHandle, Emitter, State};
+
+use crate::db::{
+    connection::DbError,
+    offline::{
+        operation::OfflineOperation,
+        replay::{self, ReplayOutcome, OUTBOX_CHANGED_EVENT},
+        store::{
+            CachedEntity, OfflineError, OfflineStore, OutboxEntry, OutboxState, OutboxSummary,
+        },
+    },
+};
+
+use super::db_commands::{
+    convert_json_keys_to_camel_case, ensure_available, CommandError, CommandResult,
+};
+use super::AppState;
+
+impl From<OfflineError> for CommandError {
+    fn from(error: OfflineError) -> Self {
+        CommandError::new(error.to_string())
+    }
+}
+
+/// Result of [`submit_operation`].
+#[derive(Debug, Serialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub enum SubmitOutcome {
+    /// Written to the database.
+    Applied,
+    /// Recorded in the outbox, to be replayed when the database is back.
+    Queued { seq: i64 },
+}
+
+fn offline_store(state: &AppState) -> CommandResult<&OfflineStore> {
+    state
+        .offline
+        .as_ref()
+        .ok_or_else(|| CommandError::new("Offline store is not available"))
+}
+
+fn emit_summary(app: &AppHandle, store: &OfflineStore) {
+    match store.summary() {
+        Ok(summary) => {
+            if let Err(e) = app.emit(OUTBOX_CHANGED_EVENT, summary) {
+                eprintln!("Warning: Failed to emit outbox summary: {}", e);
+            }
+        }
+        Err(e) => eprintln!("Warning: Failed to read outbox: {}", e),
+    }
+}
+
+/// Performs a borrow, return or audit. Applied directly while the database
+/// is reachable; otherwise checked against the offline cache and queued in
+/// the outbox. Conflicts found while online are returned as errors. The
+/// checker or auditor and the lab are those of the kiosk session.
+#[tauri::command]
+pub async fn submit_operation(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    mut operation: OfflineOperation,
+) -> CommandResult<SubmitOutcome> {
+    let session = state.session.require()?;
+    operation.bind_session(session.user_id(), session.lab_id());
+
+    if ensure_available(&state).is_ok() {
+        match replay::apply(&state.db, &operation).await {
+            Ok(ReplayOutcome::Applied) | Ok(ReplayOutcome::AlreadyApplied) => {
+                return Ok(SubmitOutcome::Applied)
+            }
+            Ok(ReplayOutcome::Conflict(conflicts)) => {
+                return Err(CommandError::new(conflicts.join("; ")))
+            }
+            // The server answered and rejected it; queueing would not help.
+            Err(DbError::Postgres(e)) if e.as_db_error().is_some() => {
+                return Err(DbError::Postgres(e).into())
+            }
+            Err(e) => eprintln!("Warning: Database unreachable, queueing operation: {}", e),
+        }
+    }
+
+    let store = offline_store(&state)?;
+    let seq = store.enqueue(&operation)?;
+    emit_summary(&app, store);
+    Ok(SubmitOutcome::Queued { seq })
+}
+
+/// One cached row of `entity` (`devices`, `device_kinds`, `users` or
+/// `open_receipts`, the latter keyed by device ID).
+#[tauri::command]
+pub async fn offline_lookup(
+    state: State<'_, AppState>,
+    entity: String,
+    id: String,
+) -> CommandResult<Option<JsonValue>> {
+    let entity: CachedEntity = entity.parse()?;
+    Ok(offline_store(&state)?
+        .cached(entity, &id)?
+        .map(convert_json_keys_to_camel_case))
+}
+
+#[tauri::command]
+pub async fn offline_list(
+    state: State<'_, AppState>,
+    entity: String,
+) -> CommandResult<Vec<JsonValue>> {
+    let entity: CachedEntity = entity.parse()?;
+    Ok(offline_store(&state)?
+        .cached_all(entity)?
+        .into_iter()
+        .map(convert_json_keys_to_camel_case)
+        .collect())
+}
+
+#[tauri::command]
+pub async fn outbox_summary(state: State<'_, AppState>) -> CommandResult<OutboxSummary> {
+    Ok(offline_store(&state)?.summary()?)
+}
+
+#[tauri::command]
+pub async fn outbox_entries(
+    state: State<'_, AppState>,
+    outbox_state: Option<OutboxState>,
+) -> CommandResult<Vec<OutboxEntry>> {
+    Ok(offline_store(&state)?.entries(outbox_state)?)
+}
+
+/// Queues a conflicted or failed entry for another replay, e.g. after staff
+/// fixed the device's state.
+#[tauri::command]
+pub async fn retry_outbox_entry(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    seq: i64,
+) -> CommandResult<bool> {
+    let store = offline_store(&state)?;
+    let retried = store.retry(seq)?;
+    emit_summary(&app, store);
+    Ok(retried)
+}
+
+/// Drops an entry that will not be applied.
+#[tauri::command]
+pub async fn discard_outbox_entry(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    seq: i64,
+) -> CommandResult<bool> {
+    let store = offline_store(&state)?;
+    let discarded = store.discard(seq)?;
+    emit_summary(&app, store);
+    Ok(discarded)
+}
@@ -0,0 +1,25 @@
+use tauri::State;
+
+use crate::db::hmi::HmiUser;
+use crate::db::otp::{self, OtpError};
+
+use super::db_commands::{ensure_available, CommandError, CommandResult};
+use super::AppState;
+
+impl From<OtpError> for CommandError {
+    fn from(error: OtpError) -> Self {
+        CommandError::new(error.to_string())
+    }
+}
+
+/// Checks the code from a user's one-time QR and returns the user. Each
+/// code is accepted only once.
+#[tauri::command]
+pub async fn verify_one_time_qr(
+    state: State<'_, AppState>,
+    user_id: String,
+    token: String,
+) -> CommandResult<HmiUser> {
+    ensure_available(&state)?;
+    Ok(otp::verify_one_time_qr(&state.db, &state.otp, &user_id, &token).await?)
+}
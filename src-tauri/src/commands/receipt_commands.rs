@@ -0,0 +1,51 @@
+use tauri::State;
+
+use crate::db::receipts::{
+    self, BorrowReceipt, NewBorrowReceipt, NewReturnReceipt, ReceiptError, ReturnReceipt,
+};
+
+use super::db_commands::{ensure_available, CommandError, CommandResult};
+use super::AppState;
+
+impl From<ReceiptError> for CommandError {
+    fn from(error: ReceiptError) -> Self {
+        let message = error.to_string();
+        match error {
+            ReceiptError::Invalid(fields) => {
+                CommandError::with_fields(format!("Receipt rejected: {}", message), fields)
+            }
+            _ => CommandError::new(message),
+        }
+    }
+}
+
+/// Lends the listed devices to the borrower. Rejected, with a reason per
+/// device in `fields`, unless every device is healthy, not deleted and
+/// permitted for the borrower's roles.
+#[tauri::command]
+pub async fn create_borrow_receipt(
+    state: State<'_, AppState>,
+    mut receipt: NewBorrowReceipt,
+) -> CommandResult<BorrowReceipt> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    receipt.checker_id = session.user_id().to_string();
+    receipt.lab_id = session.lab_id().to_string();
+    Ok(receipts::create_borrow_receipt(&state.db, &receipt).await?)
+}
+
+/// Takes back the listed devices, which may be only part of what was
+/// borrowed. Rejected, with a reason per device in `fields`, unless every
+/// device is currently borrowed by the returner and returned to its
+/// expected lab.
+#[tauri::command]
+pub async fn create_return_receipt(
+    state: State<'_, AppState>,
+    mut receipt: NewReturnReceipt,
+) -> CommandResult<ReturnReceipt> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    receipt.checker_id = session.user_id().to_string();
+    receipt.lab_id = session.lab_id().to_string();
+    Ok(receipts::create_return_receipt(&state.db, &receipt).await?)
+}
@@ -0,0 +1,44 @@
+use tauri::State;
+
+use crate::db::hmi;
+use crate::db::session::{Session, SessionError};
+
+use super::db_commands::{ensure_available, CommandError, CommandResult};
+use super::AppState;
+
+impl From<SessionError> for CommandError {
+    fn from(error: SessionError) -> Self {
+        CommandError::new(error.to_string())
+    }
+}
+
+/// Starts the kiosk session once someone has signed in with the login
+/// code and picked the lab. The code cannot be used again.
+#[tauri::command]
+pub async fn login_with_hmi_code(
+    state: State<'_, AppState>,
+    code: String,
+) -> CommandResult<Session> {
+    ensure_available(&state)?;
+    let (user, lab) = hmi::redeem_code(&state.db, &code).await?;
+    Ok(state.session.begin(user, lab))
+}
+
+/// Who is signed in, if anyone. Does not count as activity.
+#[tauri::command]
+pub async fn current_session(state: State<'_, AppState>) -> CommandResult<Option<Session>> {
+    Ok(state.session.current())
+}
+
+/// Restarts the idle timer; the UI calls it when the user interacts with
+/// the kiosk.
+#[tauri::command]
+pub async fn touch_session(state: State<'_, AppState>) -> CommandResult<Option<Session>> {
+    Ok(state.session.touch())
+}
+
+/// Signs out. Returns `false` when nobody was signed in.
+#[tauri::command]
+pub async fn logout(state: State<'_, AppState>) -> CommandResult<bool> {
+    Ok(state.session.end())
+}
@@ -0,0 +1,135 @@
+use tauri::State;
+
+use crate::db::shipment::{
+    self, ArrivedDevice, Manifest, NewShipment, Shipment, ShipmentError, ShipmentReport,
+};
+
+use super::db_commands::{ensure_available, CommandError, CommandResult};
+use super::AppState;
+
+impl From<ShipmentError> for CommandError {
+    fn from(error: ShipmentError) -> Self {
+        let message = error.to_string();
+        match error {
+            ShipmentError::Invalid(fields) => {
+                CommandError::with_fields(format!("Shipment rejected: {}", message), fields)
+            }
+            _ => CommandError::new(message),
+        }
+    }
+}
+
+/// Starts preparing a shipment from the start lab to the destination.
+#[tauri::command]
+pub async fn create_shipment(
+    state: State<'_, AppState>,
+    mut shipment: NewShipment,
+) -> CommandResult<Shipment> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    shipment.sender_id = session.user_id().to_string();
+    shipment.start_lab_id = session.lab_id().to_string();
+    Ok(shipment::create_shipment(&state.db, &shipment).await?)
+}
+
+/// Adds devices to a shipment that is being prepared.
+#[tauri::command]
+pub async fn add_shipment_devices(
+    state: State<'_, AppState>,
+    shipment_id: String,
+    devices: Vec<String>,
+) -> CommandResult<Shipment> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(shipment::add_shipment_devices(&state.db, &shipment_id, session.lab_id(), &devices).await?)
+}
+
+/// Takes a device off a shipment that is being prepared.
+#[tauri::command]
+pub async fn remove_shipment_device(
+    state: State<'_, AppState>,
+    shipment_id: String,
+    device_id: String,
+) -> CommandResult<Shipment> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(
+        shipment::remove_shipment_device(&state.db, &shipment_id, session.lab_id(), &device_id)
+            .await?,
+    )
+}
+
+/// Sends the shipment off, moving its devices to `shipping`.
+#[tauri::command]
+pub async fn dispatch_shipment(
+    state: State<'_, AppState>,
+    shipment_id: String,
+    note: Option<String>,
+) -> CommandResult<Shipment> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(
+        shipment::dispatch_shipment(&state.db, &shipment_id, session.lab_id(), note.as_deref())
+            .await?,
+    )
+}
+
+/// Confirms devices at the destination in the condition they arrived in,
+/// moving them to the destination lab.
+#[tauri::command]
+pub async fn confirm_shipment_arrivals(
+    state: State<'_, AppState>,
+    shipment_id: String,
+    devices: Vec<ArrivedDevice>,
+) -> CommandResult<Shipment> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(shipment::confirm_shipment_arrivals(
+        &state.db,
+        &shipment_id,
+        session.lab_id(),
+        session.user_id(),
+        &devices,
+    )
+    .await?)
+}
+
+/// Closes an arrived shipment and reports the devices that never arrived.
+#[tauri::command]
+pub async fn close_shipment(
+    state: State<'_, AppState>,
+    shipment_id: String,
+    note: Option<String>,
+) -> CommandResult<ShipmentReport> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(shipment::close_shipment(
+        &state.db,
+        &shipment_id,
+        session.lab_id(),
+        session.user_id(),
+        note.as_deref(),
+    )
+    .await?)
+}
+
+/// Cancels a shipment that has not been received, restoring its devices.
+#[tauri::command]
+pub async fn cancel_shipment(
+    state: State<'_, AppState>,
+    shipment_id: String,
+) -> CommandResult<Shipment> {
+    let session = state.session.require()?;
+    ensure_available(&state)?;
+    Ok(shipment::cancel_shipment(&state.db, &shipment_id, session.lab_id()).await?)
+}
+
+/// The shipment with each device's kind, current status and lab.
+#[tauri::command]
+pub async fn shipment_manifest(
+    state: State<'_, AppState>,
+    shipment_id: String,
+) -> CommandResult<Manifest> {
+    ensure_available(&state)?;
+    Ok(shipment::shipment_manifest(&state.db, &shipment_id).await?)
+}
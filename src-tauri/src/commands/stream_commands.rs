@@ -0,0 +1,128 @@
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use tauri::ipc::Channel;
+use tauri::State;
+use tokio_postgres::types::ToSql;
+
+use crate::db::{
+    cancel::QueryControl,
+    replica::is_read_only,
+    stream::{self, StreamSummary, DEFAULT_CHUNK_SIZE},
+};
+
+use super::db_commands::{
+    ensure_available, raw_params, row_to_json, table_select, target_database, CommandError,
+    CommandResult, QueryParams, RawQueryParams,
+};
+use super::AppState;
+
+/// One batch of rows sent over the stream's channel. The frontend answers
+/// every chunk with `ack_stream_chunk`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamChunk {
+    pub seq: u32,
+    pub rows: Vec<JsonValue>,
+}
+
+/// `query_table` with the rows delivered in chunks on `on_chunk` instead
+/// of in the response, for exports and other large reads. `stream_id` is
+/// used by `ack_stream_chunk` and `cancel_query`. Resolves once the last
+/// chunk was sent.
+#[tauri::command]
+pub async fn stream_table(
+    state: State<'_, AppState>,
+    params: QueryParams,
+    stream_id: String,
+    chunk_size: Option<usize>,
+    on_chunk: Channel<StreamChunk>,
+) -> CommandResult<StreamSummary> {
+    ensure_available(&state)?;
+    let control = QueryControl::new(Some(stream_id), params.timeout_ms);
+    let read_primary = params.read_primary.unwrap_or(false);
+
+    // Not held while streaming, which can take a while.
+    let (query, params) = {
+        let schema_guard = state.schema.read().await;
+        let schema = schema_guard.as_ref().ok_or_else(|| {
+            CommandError::new(
+                "Database schema not initialized. Please restart the application.".to_string(),
+            )
+        })?;
+        table_select(schema, params)?
+    };
+
+    let (db, mut client) = state.db.get_read_client(read_primary).await?;
+    let params_slice: Vec<&(dyn ToSql + Sync)> =
+        params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
+
+    Ok(stream::stream_rows(
+        db,
+        &mut client,
+        &state.streams,
+        &control,
+        &query,
+        &params_slice,
+        chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE),
+        |seq, rows| send_chunk(&on_chunk, seq, &rows),
+    )
+    .await?)
+}
+
+/// `query_raw` for a read-only statement, streamed like `stream_table`.
+#[tauri::command]
+pub async fn stream_raw(
+    state: State<'_, AppState>,
+    params: RawQueryParams,
+    stream_id: String,
+    chunk_size: Option<usize>,
+    on_chunk: Channel<StreamChunk>,
+) -> CommandResult<StreamSummary> {
+    if !is_read_only(&params.sql) {
+        return Err(CommandError::new(
+            "Only read-only statements can be streamed",
+        ));
+    }
+    let target = target_database(&state, params.database.as_deref())?;
+    let control = QueryControl::new(Some(stream_id), params.timeout_ms);
+
+    let (db, mut client) = target
+        .get_read_client(params.read_primary.unwrap_or(false))
+        .await?;
+    let param_values = raw_params(params.params);
+    let params_slice: Vec<&(dyn ToSql + Sync)> = param_values
+        .iter()
+        .map(|p| &**p as &(dyn ToSql + Sync))
+        .collect();
+
+    Ok(stream::stream_rows(
+        db,
+        &mut client,
+        &state.streams,
+        &control,
+        &params.sql,
+        &params_slice,
+        chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE),
+        |seq, rows| send_chunk(&on_chunk, seq, &rows),
+    )
+    .await?)
+}
+
+/// Lets the stream fetch its next chunk. Returns `false` once the stream
+/// has ended.
+#[tauri::command]
+pub async fn ack_stream_chunk(
+    state: State<'_, AppState>,
+    stream_id: String,
+) -> CommandResult<bool> {
+    Ok(state.streams.ack(&stream_id))
+}
+
+/// `false` when the webview is gone, which ends the stream.
+fn send_chunk(channel: &Channel<StreamChunk>, seq: u32, rows: &[tokio_postgres::Row]) -> bool {
+    let chunk = StreamChunk {
+        seq,
+        rows: rows.iter().map(row_to_json).collect(),
+    };
+    channel.send(chunk).is_ok()
+}
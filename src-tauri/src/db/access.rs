@@ -0,0 +1,717 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use tokio_postgres::Row;
+
+use super::connection::{Database, DbError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AccessError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error("Device {0} not found")]
+    NotFound(String),
+    #[error("Device {0} does not belong to this lab")]
+    OtherLab(String),
+    #[error(transparent)]
+    Denied(#[from] Denial),
+}
+
+impl From<tokio_postgres::Error> for AccessError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        AccessError::Db(error.into())
+    }
+}
+
+pub type AccessResult<T> = Result<T, AccessError>;
+
+/// Why a device kind may not be seen or lent, worded for the kiosk to show
+/// as is.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Denial {
+    #[error("Viewing {kind} is restricted to {}", role_list(.allowed))]
+    View { kind: String, allowed: Vec<String> },
+    #[error("Borrowing {kind} is restricted to {}", role_list(.allowed))]
+    Borrow { kind: String, allowed: Vec<String> },
+    #[error("Device {device_id} ({kind}) can only be borrowed in its own lab")]
+    LabOnlyBorrow { device_id: String, kind: String },
+    #[error("Device {device_id} ({kind}) must be returned to the lab it was borrowed from")]
+    LabOnlyReturn { device_id: String, kind: String },
+}
+
+fn role_list(allowed: &[String]) -> String {
+    allowed.join(", ")
+}
+
+/// The access rules of a device kind, from `device_kinds`. A missing or
+/// empty role list, the columns' default, places no restriction.
+#[derive(Debug, Clone, Default)]
+pub struct KindRules {
+    pub kind_name: Option<String>,
+    pub allowed_view_roles: Option<Vec<String>>,
+    pub allowed_borrow_roles: Option<Vec<String>>,
+    /// `is_borrowable_lab_only`: lent only in the device's own lab and
+    /// returned there.
+    pub lab_only: bool,
+}
+
+impl KindRules {
+    fn kind(&self) -> String {
+        self.kind_name
+            .clone()
+            .unwrap_or_else(|| "this kind".to_string())
+    }
+
+    pub fn check_view(&self, roles: &[String]) -> Result<(), Denial> {
+        match &self.allowed_view_roles {
+            Some(allowed) if !admits(allowed, roles) => Err(Denial::View {
+                kind: self.kind(),
+                allowed: allowed.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn check_borrow(&self, roles: &[String]) -> Result<(), Denial> {
+        match &self.allowed_borrow_roles {
+            Some(allowed) if !admits(allowed, roles) => Err(Denial::Borrow {
+                kind: self.kind(),
+                allowed: allowed.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// A lab-only device is lent in the lab it belongs to, and is expected
+    /// back in that same lab.
+    pub fn check_borrow_lab(
+        &self,
+        device_id: &str,
+        device_lab_id: Option<&str>,
+        lab_id: &str,
+        return_lab_id: &str,
+    ) -> Result<(), Denial> {
+        if !self.lab_only {
+            return Ok(());
+        }
+        if device_lab_id != Some(lab_id) {
+            return Err(Denial::LabOnlyBorrow {
+                device_id: device_id.to_string(),
+                kind: self.kind(),
+            });
+        }
+        if return_lab_id != lab_id {
+            return Err(Denial::LabOnlyReturn {
+                device_id: device_id.to_string(),
+                kind: self.kind(),
+            });
+        }
+        Ok(())
+    }
+
+    /// A lab-only device comes back to the lab it was borrowed from,
+    /// whatever the loan says it is expected in.
+    pub fn check_return_lab(
+        &self,
+        device_id: &str,
+        borrowed_lab_id: &str,
+        lab_id: &str,
+    ) -> Result<(), Denial> {
+        if self.lab_only && borrowed_lab_id != lab_id {
+            return Err(Denial::LabOnlyReturn {
+                device_id: device_id.to_string(),
+                kind: self.kind(),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn admits(allowed: &[String], roles: &[String]) -> bool {
+    allowed.is_empty() || allowed.iter().any(|role| roles.contains(role))
+}
+
+/// A device as shown on its detail page.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceDetail {
+    pub id: String,
+    pub full_id: Option<String>,
+    pub status: String,
+    pub image: Option<JsonValue>,
+    pub unit: Option<String>,
+    pub device_name: Option<String>,
+    pub allowed_borrow_roles: Option<Vec<String>>,
+    pub allowed_view_roles: Option<Vec<String>>,
+    pub brand: Option<String>,
+    pub manufacturer: Option<String>,
+    pub description: Option<String>,
+    pub is_borrowable_lab_only: bool,
+    pub category_name: Option<String>,
+    pub lab_id: Option<String>,
+    pub lab_room: Option<String>,
+    pub lab_branch: Option<String>,
+    pub kind: Option<String>,
+}
+
+/// A device kept as an accessory of a kind.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Accessory {
+    pub id: String,
+    pub full_id: Option<String>,
+    pub status: String,
+    pub image: Option<JsonValue>,
+    pub name: Option<String>,
+    pub brand: Option<String>,
+    pub unit: Option<String>,
+}
+
+/// Columns of [`DeviceDetail`], for a device that is not deleted.
+const DETAIL_QUERY: &str = "
+    SELECT d.id::text AS id, d.full_id::text AS full_id, d.status::text AS status,
+           dk.image::jsonb AS image, dk.unit::text AS unit, dk.name::text AS device_name,
+           dk.allowed_borrow_roles::text[] AS allowed_borrow_roles,
+           dk.allowed_view_roles::text[] AS allowed_view_roles,
+           dk.brand::text AS brand, dk.manufacturer::text AS manufacturer,
+           dk.description::text AS description,
+           COALESCE(dk.is_borrowable_lab_only, false) AS is_borrowable_lab_only,
+           c.name::text AS category_name, d.lab_id::text AS lab_id,
+           l.room::text AS lab_room, l.branch::text AS lab_branch, d.kind::text AS kind
+    FROM devices d
+    LEFT JOIN device_kinds dk ON dk.id = d.kind
+    LEFT JOIN labs l ON l.id = d.lab_id
+    LEFT JOIN categories c ON c.id = dk.category_id
+    WHERE d.deleted_at IS NULL";
+
+fn detail_from_row(row: &Row) -> DeviceDetail {
+    DeviceDetail {
+        id: row.get("id"),
+        full_id: row.get("full_id"),
+        status: row.get("status"),
+        image: row.get("image"),
+        unit: row.get("unit"),
+        device_name: row.get("device_name"),
+        allowed_borrow_roles: row.get("allowed_borrow_roles"),
+        allowed_view_roles: row.get("allowed_view_roles"),
+        brand: row.get("brand"),
+        manufacturer: row.get("manufacturer"),
+        description: row.get("description"),
+        is_borrowable_lab_only: row.get("is_borrowable_lab_only"),
+        category_name: row.get("category_name"),
+        lab_id: row.get("lab_id"),
+        lab_room: row.get("lab_room"),
+        lab_branch: row.get("lab_branch"),
+        kind: row.get("kind"),
+    }
+}
+
+/// Checks that `roles` may view the kind of a looked-up device, whose
+/// query selected the kind's `device_name` and `allowed_view_roles`.
+fn check_row_view(row: &Row, roles: &[String]) -> Result<(), Denial> {
+    KindRules {
+        kind_name: row.get("device_name"),
+        allowed_view_roles: row.get("allowed_view_roles"),
+        ..KindRules::default()
+    }
+    .check_view(roles)
+}
+
+/// With `lab_id`, a device registered in another lab is refused.
+fn check_lab(
+    device_id: &str,
+    device_lab_id: Option<&str>,
+    lab_id: Option<&str>,
+) -> AccessResult<()> {
+    match lab_id {
+        Some(lab_id) if device_lab_id != Some(lab_id) => {
+            Err(AccessError::OtherLab(device_id.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The device's details, if `roles` may view its kind. With `lab_id`,
+/// a device registered in another lab is refused.
+pub async fn device_detail(
+    db: &Database,
+    roles: &[String],
+    device_id: &str,
+    lab_id: Option<&str>,
+) -> AccessResult<DeviceDetail> {
+    let client = db.get_client().await?;
+    let row = client
+        .query_opt(
+            &format!("{} AND d.id::text = $1", DETAIL_QUERY),
+            &[&device_id],
+        )
+        .await?
+        .ok_or_else(|| AccessError::NotFound(device_id.to_string()))?;
+    let detail = detail_from_row(&row);
+    check_lab(device_id, detail.lab_id.as_deref(), lab_id)?;
+    check_row_view(&row, roles)?;
+    Ok(detail)
+}
+
+/// Checks that `roles` may view the kind of the device, deleted or not.
+pub async fn check_device_view(
+    db: &Database,
+    roles: &[String],
+    device_id: &str,
+) -> AccessResult<()> {
+    let client = db.get_client().await?;
+    let row = client
+        .query_opt(
+            "SELECT dk.name::text AS device_name, dk.allowed_view_roles::text[] AS allowed_view_roles
+             FROM devices d
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             WHERE d.id::text = $1",
+            &[&device_id],
+        )
+        .await?
+        .ok_or_else(|| AccessError::NotFound(device_id.to_string()))?;
+    Ok(check_row_view(&row, roles)?)
+}
+
+/// The device with `device_id`, or else a device of `kind_id`, optionally
+/// only in `lab_id`; `None` if there is none. Denied if `roles` may not
+/// view its kind.
+pub async fn find_device(
+    db: &Database,
+    roles: &[String],
+    device_id: Option<&str>,
+    kind_id: Option<&str>,
+    lab_id: Option<&str>,
+) -> AccessResult<Option<DeviceDetail>> {
+    let client = db.get_client().await?;
+    let Some(row) = client
+        .query_opt(
+            &format!(
+                "{} AND (d.id::text = $1 OR ($1::text IS NULL AND d.kind::text = $2))
+                   AND ($3::text IS NULL OR d.lab_id::text = $3)
+                 ORDER BY d.id
+                 LIMIT 1",
+                DETAIL_QUERY
+            ),
+            &[&device_id, &kind_id, &lab_id],
+        )
+        .await?
+    else {
+        return Ok(None);
+    };
+    check_row_view(&row, roles)?;
+    Ok(Some(detail_from_row(&row)))
+}
+
+/// Who borrowed a device that is out.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Borrower {
+    pub id: String,
+    pub name: Option<String>,
+    pub image: Option<String>,
+}
+
+/// A device as the borrow and return screen shows it, with its open loan
+/// if it has one.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceReceiptDetail {
+    pub full_id: Option<String>,
+    pub status: String,
+    pub prev_quality: Option<String>,
+    pub image: Option<JsonValue>,
+    pub unit: Option<String>,
+    pub device_name: Option<String>,
+    pub allowed_borrow_roles: Option<Vec<String>>,
+    pub allowed_view_roles: Option<Vec<String>>,
+    pub brand: Option<String>,
+    pub manufacturer: Option<String>,
+    pub description: Option<String>,
+    pub is_borrowable_lab_only: bool,
+    pub category_name: Option<String>,
+    pub lab_room: Option<String>,
+    pub lab_branch: Option<String>,
+    pub kind: Option<String>,
+    pub receipt_id: Option<String>,
+    pub borrower: Option<Borrower>,
+    pub borrowed_at: Option<DateTime<Utc>>,
+    pub expected_returned_at: Option<DateTime<Utc>>,
+    pub borrowed_lab: Option<String>,
+    pub expected_return_lab: Option<String>,
+}
+
+/// The device for the borrow and return screen of `lab_id`.
+pub async fn device_receipt_detail(
+    db: &Database,
+    roles: &[String],
+    device_id: &str,
+    lab_id: &str,
+) -> AccessResult<DeviceReceiptDetail> {
+    let client = db.get_client().await?;
+    let row = client
+        .query_opt(
+            "SELECT d.full_id::text AS full_id, d.status::text AS status, d.lab_id::text AS lab_id,
+                    d.kind::text AS kind, dk.image::jsonb AS image, dk.unit::text AS unit,
+                    dk.name::text AS device_name,
+                    dk.allowed_borrow_roles::text[] AS allowed_borrow_roles,
+                    dk.allowed_view_roles::text[] AS allowed_view_roles,
+                    dk.brand::text AS brand, dk.manufacturer::text AS manufacturer,
+                    dk.description::text AS description,
+                    COALESCE(dk.is_borrowable_lab_only, false) AS is_borrowable_lab_only,
+                    c.name::text AS category_name, l.room::text AS lab_room,
+                    l.branch::text AS lab_branch, r.id::text AS receipt_id,
+                    a.created_at AS borrowed_at, rd.expected_returned_at,
+                    rd.prev_quality::text AS prev_quality,
+                    bl.room || ', ' || bl.branch AS borrowed_lab,
+                    rl.room || ', ' || rl.branch AS expected_return_lab,
+                    actor.id::text AS borrower_id, actor.name::text AS borrower_name,
+                    to_jsonb(actor) ->> 'image' AS borrower_image
+             FROM devices d
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             LEFT JOIN labs l ON l.id = d.lab_id
+             LEFT JOIN categories c ON c.id = dk.category_id
+             LEFT JOIN receipts_devices rd
+                    ON rd.device_id = d.id AND rd.returned_receipt_id IS NULL
+             LEFT JOIN receipts r ON r.id = rd.borrowed_receipt_id
+             LEFT JOIN users actor ON actor.id = r.actor_id
+             LEFT JOIN labs bl ON bl.id = r.lab_id
+             LEFT JOIN labs rl ON rl.id = rd.expected_returned_lab_id
+             LEFT JOIN activities a ON a.id = rd.borrow_id
+             WHERE d.id::text = $1 AND d.deleted_at IS NULL",
+            &[&device_id],
+        )
+        .await?
+        .ok_or_else(|| AccessError::NotFound(device_id.to_string()))?;
+    check_lab(device_id, row.get("lab_id"), Some(lab_id))?;
+    check_row_view(&row, roles)?;
+    Ok(DeviceReceiptDetail {
+        full_id: row.get("full_id"),
+        status: row.get("status"),
+        prev_quality: row.get("prev_quality"),
+        image: row.get("image"),
+        unit: row.get("unit"),
+        device_name: row.get("device_name"),
+        allowed_borrow_roles: row.get("allowed_borrow_roles"),
+        allowed_view_roles: row.get("allowed_view_roles"),
+        brand: row.get("brand"),
+        manufacturer: row.get("manufacturer"),
+        description: row.get("description"),
+        is_borrowable_lab_only: row.get("is_borrowable_lab_only"),
+        category_name: row.get("category_name"),
+        lab_room: row.get("lab_room"),
+        lab_branch: row.get("lab_branch"),
+        kind: row.get("kind"),
+        receipt_id: row.get("receipt_id"),
+        borrower: row
+            .get::<_, Option<String>>("borrower_id")
+            .map(|id| Borrower {
+                id,
+                name: row.get("borrower_name"),
+                image: row.get("borrower_image"),
+            }),
+        borrowed_at: row.get("borrowed_at"),
+        expected_returned_at: row.get("expected_returned_at"),
+        borrowed_lab: row.get("borrowed_lab"),
+        expected_return_lab: row.get("expected_return_lab"),
+    })
+}
+
+/// A device as the audit screen shows it. `status` is the one it had
+/// before the lab's open audit, if it is in one.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceAuditDetail {
+    pub id: String,
+    pub full_id: Option<String>,
+    pub status: String,
+    pub current_status: String,
+    pub audit_condition: Option<String>,
+    pub image: Option<JsonValue>,
+    pub unit: Option<String>,
+    pub device_name: Option<String>,
+    pub is_borrowable_lab_only: bool,
+    pub lab_room: Option<String>,
+    pub lab_branch: Option<String>,
+    pub kind: Option<String>,
+    pub category_name: Option<String>,
+}
+
+/// The device for the audit screen of `lab_id`.
+pub async fn device_audit_detail(
+    db: &Database,
+    roles: &[String],
+    device_id: &str,
+    lab_id: &str,
+) -> AccessResult<DeviceAuditDetail> {
+    let client = db.get_client().await?;
+    let row = client
+        .query_opt(
+            "WITH active_assessment AS (
+                 SELECT ia.id FROM inventory_assessments ia
+                 WHERE ia.status = 'assessing' AND ia.finished_at IS NULL
+                   AND ia.lab_id::text = $2
+                 LIMIT 1
+             )
+             SELECT d.id::text AS id, d.full_id::text AS full_id,
+                    CASE WHEN d.status = 'assessing' THEN COALESCE(iad.prev_status, d.status)
+                         ELSE d.status
+                    END::text AS status,
+                    iad.after_status::text AS audit_condition,
+                    d.status::text AS current_status, d.kind::text AS kind,
+                    d.lab_id::text AS lab_id, dk.image::jsonb AS image, dk.unit::text AS unit,
+                    dk.name::text AS device_name,
+                    dk.allowed_view_roles::text[] AS allowed_view_roles,
+                    COALESCE(dk.is_borrowable_lab_only, false) AS is_borrowable_lab_only,
+                    l.room::text AS lab_room, l.branch::text AS lab_branch,
+                    c.name::text AS category_name
+             FROM devices d
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             LEFT JOIN labs l ON l.id = d.lab_id
+             LEFT JOIN categories c ON c.id = dk.category_id
+             LEFT JOIN active_assessment aa ON true
+             LEFT JOIN inventory_assessments_devices iad
+                    ON iad.device_id = d.id AND iad.assessing_id = aa.id
+             WHERE d.id::text = $1 AND d.deleted_at IS NULL",
+            &[&device_id, &lab_id],
+        )
+        .await?
+        .ok_or_else(|| AccessError::NotFound(device_id.to_string()))?;
+    check_lab(device_id, row.get("lab_id"), Some(lab_id))?;
+    check_row_view(&row, roles)?;
+    Ok(DeviceAuditDetail {
+        id: row.get("id"),
+        full_id: row.get("full_id"),
+        status: row.get("status"),
+        current_status: row.get("current_status"),
+        audit_condition: row.get("audit_condition"),
+        image: row.get("image"),
+        unit: row.get("unit"),
+        device_name: row.get("device_name"),
+        is_borrowable_lab_only: row.get("is_borrowable_lab_only"),
+        lab_room: row.get("lab_room"),
+        lab_branch: row.get("lab_branch"),
+        kind: row.get("kind"),
+        category_name: row.get("category_name"),
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Technician {
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+/// A device as the maintenance screen shows it, with its latest job.
+/// `status` is the one it had before that job, while it is in it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceMaintenanceDetail {
+    pub id: String,
+    pub maintenance_id: Option<String>,
+    pub technician: Technician,
+    pub status: String,
+    pub current_status: String,
+    pub outcome: Option<String>,
+    pub kind: Option<String>,
+    pub device_name: Option<String>,
+    pub image: Option<JsonValue>,
+    pub unit: Option<String>,
+    pub is_borrowable_lab_only: bool,
+    pub lab_room: Option<String>,
+    pub lab_branch: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// The device for the maintenance screen, optionally of `lab_id` only.
+pub async fn device_maintenance_detail(
+    db: &Database,
+    roles: &[String],
+    device_id: &str,
+    lab_id: Option<&str>,
+) -> AccessResult<DeviceMaintenanceDetail> {
+    let client = db.get_client().await?;
+    let row = client
+        .query_opt(
+            "SELECT d.id::text AS id,
+                    CASE WHEN d.status = 'maintaining' THEN COALESCE(md.prev_status, d.status)
+                         ELSE d.status
+                    END::text AS status,
+                    d.status::text AS current_status, md.after_status::text AS outcome,
+                    d.kind::text AS kind, d.lab_id::text AS lab_id, dk.image::jsonb AS image,
+                    dk.unit::text AS unit, dk.name::text AS device_name,
+                    dk.allowed_view_roles::text[] AS allowed_view_roles,
+                    COALESCE(dk.is_borrowable_lab_only, false) AS is_borrowable_lab_only,
+                    l.room::text AS lab_room, l.branch::text AS lab_branch,
+                    m.id::text AS maintenance_id, m.maintainer_id::text AS technician_id,
+                    u.name::text AS technician_name, a.note::text AS notes, a.created_at
+             FROM devices d
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             LEFT JOIN labs l ON l.id = d.lab_id
+             LEFT JOIN maintenances_devices md ON md.device_id = d.id
+             LEFT JOIN maintenances m ON m.id = md.maintaining_id
+             LEFT JOIN users u ON u.id = m.maintainer_id
+             LEFT JOIN activities a ON a.id = m.id
+             WHERE d.id::text = $1 AND d.deleted_at IS NULL
+             ORDER BY a.created_at DESC
+             LIMIT 1",
+            &[&device_id],
+        )
+        .await?
+        .ok_or_else(|| AccessError::NotFound(device_id.to_string()))?;
+    check_lab(device_id, row.get("lab_id"), lab_id)?;
+    check_row_view(&row, roles)?;
+    Ok(DeviceMaintenanceDetail {
+        id: row.get("id"),
+        maintenance_id: row.get("maintenance_id"),
+        technician: Technician {
+            id: row.get("technician_id"),
+            name: row.get("technician_name"),
+        },
+        status: row.get("status"),
+        current_status: row.get("current_status"),
+        outcome: row.get("outcome"),
+        kind: row.get("kind"),
+        device_name: row.get("device_name"),
+        image: row.get("image"),
+        unit: row.get("unit"),
+        is_borrowable_lab_only: row.get("is_borrowable_lab_only"),
+        lab_room: row.get("lab_room"),
+        lab_branch: row.get("lab_branch"),
+        notes: row.get("notes"),
+        created_at: row.get("created_at"),
+    })
+}
+
+/// A device as the transport screen shows it, with its latest shipment.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceShipmentDetail {
+    pub status: String,
+    pub prev_condition: Option<String>,
+    pub after_condition: Option<String>,
+    pub shipment_id: Option<String>,
+    pub source_location: Option<String>,
+    pub destination_location: Option<String>,
+    pub sender_name: Option<String>,
+    pub receiver_name: Option<String>,
+    pub image: Option<JsonValue>,
+    pub unit: Option<String>,
+    pub device_name: Option<String>,
+    pub is_borrowable_lab_only: bool,
+}
+
+/// The device for the transport screen, optionally of `lab_id` only.
+pub async fn device_shipment_detail(
+    db: &Database,
+    roles: &[String],
+    device_id: &str,
+    lab_id: Option<&str>,
+) -> AccessResult<DeviceShipmentDetail> {
+    let client = db.get_client().await?;
+    let row = client
+        .query_opt(
+            "SELECT d.status::text AS status, d.lab_id::text AS lab_id,
+                    dk.image::jsonb AS image, dk.unit::text AS unit,
+                    dk.name::text AS device_name,
+                    dk.allowed_view_roles::text[] AS allowed_view_roles,
+                    COALESCE(dk.is_borrowable_lab_only, false) AS is_borrowable_lab_only,
+                    sd.prev_status::text AS prev_condition,
+                    sd.after_status::text AS after_condition, s.id::text AS shipment_id,
+                    s_start.room || ', ' || s_start.branch AS source_location,
+                    s_arrive.room || ', ' || s_arrive.branch AS destination_location,
+                    sender.name::text AS sender_name, receiver.name::text AS receiver_name
+             FROM devices d
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             LEFT JOIN shipments_devices sd ON sd.device_id = d.id
+             LEFT JOIN shipments s ON s.id = sd.shipment_id
+             LEFT JOIN labs s_start ON s_start.id = s.start_lab_id
+             LEFT JOIN labs s_arrive ON s_arrive.id = s.arrive_lab_id
+             LEFT JOIN users sender ON sender.id = s.sender_id
+             LEFT JOIN users receiver ON receiver.id = s.receiver_id
+             WHERE d.id::text = $1 AND d.deleted_at IS NULL
+             ORDER BY s.from_at DESC
+             LIMIT 1",
+            &[&device_id],
+        )
+        .await?
+        .ok_or_else(|| AccessError::NotFound(device_id.to_string()))?;
+    check_lab(device_id, row.get("lab_id"), lab_id)?;
+    check_row_view(&row, roles)?;
+    Ok(DeviceShipmentDetail {
+        status: row.get("status"),
+        prev_condition: row.get("prev_condition"),
+        after_condition: row.get("after_condition"),
+        shipment_id: row.get("shipment_id"),
+        source_location: row.get("source_location"),
+        destination_location: row.get("destination_location"),
+        sender_name: row.get("sender_name"),
+        receiver_name: row.get("receiver_name"),
+        image: row.get("image"),
+        unit: row.get("unit"),
+        device_name: row.get("device_name"),
+        is_borrowable_lab_only: row.get("is_borrowable_lab_only"),
+    })
+}
+
+/// The accessories of a kind, optionally only those in `lab_ids`. Denied
+/// if `roles` may not view the kind itself; accessories of kinds they may
+/// not view are left out.
+pub async fn accessories(
+    db: &Database,
+    roles: &[String],
+    kind_id: &str,
+    lab_ids: &[String],
+) -> AccessResult<Vec<Accessory>> {
+    let client = db.get_client().await?;
+    if let Some(row) = client
+        .query_opt(
+            "SELECT name::text, allowed_view_roles::text[]
+             FROM device_kinds WHERE id::text = $1 AND deleted_at IS NULL",
+            &[&kind_id],
+        )
+        .await?
+    {
+        KindRules {
+            kind_name: row.get(0),
+            allowed_view_roles: row.get(1),
+            ..KindRules::default()
+        }
+        .check_view(roles)?;
+    }
+
+    let rows = client
+        .query(
+            "SELECT d.id::text, d.full_id::text, d.status::text, dk.image::jsonb, dk.name::text,
+                    dk.brand::text, dk.unit::text, dk.allowed_view_roles::text[]
+             FROM devices d
+             JOIN device_kinds dk ON dk.id = d.kind
+             WHERE d.accessory_for_kind_id::text = $1 AND d.deleted_at IS NULL
+               AND (cardinality($2::text[]) = 0 OR d.lab_id::text = ANY($2))
+             ORDER BY dk.name, d.id",
+            &[&kind_id, &lab_ids],
+        )
+        .await?;
+    Ok(rows
+        .iter()
+        .filter(|row| {
+            KindRules {
+                allowed_view_roles: row.get(7),
+                ..KindRules::default()
+            }
+            .check_view(roles)
+            .is_ok()
+        })
+        .map(|row| Accessory {
+            id: row.get(0),
+            full_id: row.get(1),
+            status: row.get(2),
+            image: row.get(3),
+            name: row.get(4),
+            brand: row.get(5),
+            unit: row.get(6),
+        })
+        .collect())
+}
@@ -0,0 +1,766 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::Transaction;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashSet;
+
+use super::connection::{Database, DbError};
+use super::lifecycle::{
+    self, ActivityType, ChangeContext, DeviceStatus, LifecycleError, Transition,
+};
+use super::validation::{summarize, FieldError};
+
+/// Statuses of the devices an audit expects to find in the lab. Borrowed,
+/// shipped or maintained devices are elsewhere, lost and discarded ones are
+/// not looked for.
+const EXPECTED: &[DeviceStatus] = &[DeviceStatus::Healthy, DeviceStatus::Broken];
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuditError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error(transparent)]
+    Lifecycle(#[from] LifecycleError),
+    #[error("Audit {0} not found")]
+    NotFound(String),
+    #[error("Audit {id} is already {status}")]
+    Closed { id: String, status: String },
+    #[error("Lab {lab_id} already has an audit in progress ({audit_id})")]
+    AlreadyOpen { lab_id: String, audit_id: String },
+    #[error("Audit {0} does not belong to this lab")]
+    OtherLab(String),
+    #[error("{}", summarize(.0))]
+    Invalid(Vec<FieldError>),
+}
+
+impl From<tokio_postgres::Error> for AuditError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        AuditError::Db(error.into())
+    }
+}
+
+pub type AuditResult<T> = Result<T, AuditError>;
+
+/// How audits are completed, from the environment.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AuditSettings {
+    /// Whether completing an audit marks the lab's devices that were not
+    /// scanned as `lost`. `HMI_AUDIT_MARK_MISSING_LOST`, off by default;
+    /// `complete_audit` can override it per call.
+    pub mark_missing_lost: bool,
+}
+
+impl AuditSettings {
+    pub fn from_env() -> Self {
+        let mark_missing_lost = std::env::var("HMI_AUDIT_MARK_MISSING_LOST")
+            .map(|value| {
+                matches!(
+                    value.trim().to_ascii_lowercase().as_str(),
+                    "1" | "true" | "yes" | "on"
+                )
+            })
+            .unwrap_or(false);
+        AuditSettings { mark_missing_lost }
+    }
+}
+
+/// A new audit. `create_audit` fills in the auditor and lab from the kiosk
+/// session.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewAudit {
+    #[serde(default)]
+    pub auditor_id: String,
+    #[serde(default)]
+    pub lab_id: String,
+    /// Devices scanned before the audit was started.
+    #[serde(default)]
+    pub devices: Vec<AuditedDevice>,
+    pub note: Option<String>,
+}
+
+/// A device and the condition it was found in.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditedDevice {
+    pub id: String,
+    pub condition: DeviceStatus,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Audit {
+    pub id: String,
+    pub lab_id: String,
+    pub auditor_id: Option<String>,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub devices: Vec<AuditDevice>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditDevice {
+    pub device_id: String,
+    /// The device's status when it was added to the audit, restored if the
+    /// audit is cancelled.
+    pub prev_status: Option<DeviceStatus>,
+    /// The condition it was found in, applied when the audit completes.
+    pub after_status: Option<DeviceStatus>,
+}
+
+/// The outcome of [`complete_audit`]: the audit, plus how what was found
+/// differs from what the lab's records expected.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditReport {
+    pub audit: Audit,
+    /// Devices of the lab that were expected but not found: not scanned,
+    /// or recorded as `lost`.
+    pub missing: Vec<String>,
+    /// Devices found that the lab's records did not expect, e.g. ones that
+    /// were lost.
+    pub extra: Vec<String>,
+    /// Devices found here that belong to another lab.
+    pub wrong_lab: Vec<WrongLabDevice>,
+    /// Missing devices that were marked `lost` by this completion.
+    pub marked_lost: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WrongLabDevice {
+    pub device_id: String,
+    pub lab_id: Option<String>,
+}
+
+/// The audit row, locked.
+struct OpenAudit {
+    id: String,
+    lab_id: String,
+    auditor_id: Option<String>,
+}
+
+/// Starts an audit of `lab_id` with the devices scanned so far. Only one
+/// audit per lab can be in progress.
+pub async fn create_audit(db: &Database, audit: &NewAudit) -> AuditResult<Audit> {
+    let mut errors = Vec::new();
+    for (field, value) in [("auditorId", &audit.auditor_id), ("labId", &audit.lab_id)] {
+        if value.trim().is_empty() {
+            errors.push(FieldError::new(field, "Must not be empty"));
+        }
+    }
+    errors.extend(check_devices(&audit.devices));
+    if !errors.is_empty() {
+        return Err(AuditError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+
+    // Serialises audit creation per lab, so two kiosks cannot both see
+    // no open audit and start one.
+    tx.execute(
+        "SELECT pg_advisory_xact_lock(hashtext('inventory_assessments:' || $1))",
+        &[&audit.lab_id],
+    )
+    .await?;
+    if let Some(row) = tx
+        .query_opt(
+            "SELECT id::text FROM inventory_assessments
+             WHERE lab_id::text = $1 AND status = 'assessing'
+             LIMIT 1",
+            &[&audit.lab_id],
+        )
+        .await?
+    {
+        return Err(AuditError::AlreadyOpen {
+            lab_id: audit.lab_id.clone(),
+            audit_id: row.get(0),
+        });
+    }
+
+    let id: String = tx
+        .query_one(
+            "INSERT INTO activities (type, note) VALUES ('assessment'::activity_type, $1)
+             RETURNING id::text",
+            &[&audit.note],
+        )
+        .await?
+        .get(0);
+    let row = json!({
+        "id": id,
+        "lab_id": audit.lab_id,
+        "accountant_id": audit.auditor_id,
+        "status": "assessing",
+    });
+    tx.execute(
+        "INSERT INTO inventory_assessments (id, lab_id, accountant_id, status)
+         SELECT id, lab_id, accountant_id, status
+         FROM json_populate_record(NULL::inventory_assessments, $1)",
+        &[&row],
+    )
+    .await?;
+
+    let open = OpenAudit {
+        id,
+        lab_id: audit.lab_id.clone(),
+        auditor_id: Some(audit.auditor_id.clone()),
+    };
+    add_devices(&tx, &open, &audit.devices).await?;
+    let created = load(&tx, &open.id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(created)
+}
+
+/// Adds scanned devices to an audit in progress, or records devices that
+/// were not scanned with the condition staff chose for them. A device that
+/// is already in the audit only has its condition updated.
+pub async fn add_audit_devices(
+    db: &Database,
+    audit_id: &str,
+    lab_id: &str,
+    devices: &[AuditedDevice],
+) -> AuditResult<Audit> {
+    let errors = check_devices(devices);
+    if !errors.is_empty() {
+        return Err(AuditError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let audit = lock_open(&tx, audit_id, lab_id).await?;
+    add_devices(&tx, &audit, devices).await?;
+    let updated = load(&tx, audit_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(updated)
+}
+
+/// Changes the condition recorded for devices already in the audit.
+pub async fn update_audit_conditions(
+    db: &Database,
+    audit_id: &str,
+    lab_id: &str,
+    devices: &[AuditedDevice],
+) -> AuditResult<Audit> {
+    let mut errors = check_devices(devices);
+    if !errors.is_empty() {
+        return Err(AuditError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    lock_open(&tx, audit_id, lab_id).await?;
+
+    let rows: JsonValue = devices
+        .iter()
+        .map(|d| json!({ "device_id": d.id, "after_status": d.condition }))
+        .collect();
+    let updated: Vec<String> = tx
+        .query(
+            "UPDATE inventory_assessments_devices iad
+             SET after_status = v.after_status
+             FROM json_populate_recordset(NULL::inventory_assessments_devices, $1) v
+             WHERE iad.assessing_id::text = $2 AND iad.device_id = v.device_id
+             RETURNING iad.device_id::text",
+            &[&rows, &audit_id],
+        )
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    for (index, device) in devices.iter().enumerate() {
+        if !updated.contains(&device.id) {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} is not in this audit", device.id),
+            ));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(AuditError::Invalid(errors));
+    }
+
+    let audit = load(&tx, audit_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(audit)
+}
+
+/// Takes a device out of the audit and gives it back the status it had
+/// when it was added.
+pub async fn remove_audit_device(
+    db: &Database,
+    audit_id: &str,
+    lab_id: &str,
+    device_id: &str,
+) -> AuditResult<Audit> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let audit = lock_open(&tx, audit_id, lab_id).await?;
+
+    let Some(row) = tx
+        .query_opt(
+            "DELETE FROM inventory_assessments_devices
+             WHERE assessing_id::text = $1 AND device_id::text = $2
+             RETURNING prev_status",
+            &[&audit_id, &device_id],
+        )
+        .await?
+    else {
+        return Err(AuditError::Invalid(vec![FieldError::new(
+            "deviceId",
+            format!("Device {} is not in this audit", device_id),
+        )]));
+    };
+    let prev: Option<DeviceStatus> = row.try_get(0)?;
+
+    let locked = lock_devices(&tx, &[device_id]).await?;
+    let transitions = restore(&locked, &[(device_id.to_string(), prev)]);
+    let reason = format!("Removed from audit {}", audit_id);
+    move_devices(&tx, &audit, &reason, &transitions).await?;
+
+    let updated = load(&tx, audit_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(updated)
+}
+
+/// Finishes the audit: every device in it takes the condition it was found
+/// in, and the lab's records are compared with what was found. With
+/// `mark_missing_lost`, expected devices that were not scanned are added
+/// to the audit as `lost`.
+pub async fn complete_audit(
+    db: &Database,
+    audit_id: &str,
+    lab_id: &str,
+    note: Option<&str>,
+    mark_missing_lost: bool,
+) -> AuditResult<AuditReport> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let audit = lock_open(&tx, audit_id, lab_id).await?;
+
+    let rows = tx
+        .query(
+            "SELECT iad.device_id::text, iad.prev_status, iad.after_status,
+                    d.status, d.lab_id::text
+             FROM inventory_assessments_devices iad
+             JOIN devices d ON d.id = iad.device_id
+             WHERE iad.assessing_id::text = $1
+             ORDER BY iad.device_id
+             FOR UPDATE OF d",
+            &[&audit_id],
+        )
+        .await?;
+
+    let mut transitions = Vec::new();
+    let mut missing = Vec::new();
+    let mut extra = Vec::new();
+    let mut wrong_lab = Vec::new();
+    let mut audited = HashSet::new();
+    for row in &rows {
+        let device_id: String = row.get(0);
+        let prev: Option<DeviceStatus> = row.try_get(1)?;
+        let after: Option<DeviceStatus> = row.try_get(2)?;
+        let current: DeviceStatus = row.try_get(3)?;
+        let lab_id: Option<String> = row.get(4);
+        audited.insert(device_id.clone());
+
+        let expected = prev.is_some_and(|p| EXPECTED.contains(&p));
+        if lab_id.as_deref() != Some(audit.lab_id.as_str()) {
+            wrong_lab.push(WrongLabDevice {
+                device_id: device_id.clone(),
+                lab_id,
+            });
+        } else if expected && after == Some(DeviceStatus::Lost) {
+            missing.push(device_id.clone());
+        } else if !expected {
+            extra.push(device_id.clone());
+        }
+
+        if current == DeviceStatus::Assessing {
+            transitions.push(Transition {
+                device_id,
+                from: current,
+                to: after.or(prev).unwrap_or(DeviceStatus::Healthy),
+            });
+        }
+    }
+
+    // Expected devices of the lab that nobody scanned.
+    let expected: Vec<&str> = EXPECTED.iter().map(|s| s.as_str()).collect();
+    let unscanned = tx
+        .query(
+            "SELECT id::text, status FROM devices
+             WHERE lab_id::text = $1 AND deleted_at IS NULL
+               AND status::text = ANY($2)
+             ORDER BY id
+             FOR UPDATE",
+            &[&audit.lab_id, &expected],
+        )
+        .await?;
+    let mut marked_lost = Vec::new();
+    let mut lost_rows = Vec::new();
+    for row in &unscanned {
+        let device_id: String = row.get(0);
+        if audited.contains(&device_id) {
+            continue;
+        }
+        missing.push(device_id.clone());
+        if mark_missing_lost {
+            let current: DeviceStatus = row.try_get(1)?;
+            lost_rows.push(json!({
+                "assessing_id": audit_id,
+                "device_id": device_id,
+                "prev_status": current,
+                "after_status": DeviceStatus::Lost,
+            }));
+            transitions.push(Transition {
+                device_id: device_id.clone(),
+                from: current,
+                to: DeviceStatus::Lost,
+            });
+            marked_lost.push(device_id);
+        }
+    }
+    if !lost_rows.is_empty() {
+        tx.execute(
+            "INSERT INTO inventory_assessments_devices (prev_status, after_status, assessing_id, device_id)
+             SELECT prev_status, after_status, assessing_id, device_id
+             FROM json_populate_recordset(NULL::inventory_assessments_devices, $1)",
+            &[&JsonValue::Array(lost_rows)],
+        )
+        .await?;
+    }
+
+    let reason = format!("Audit {} completed", audit_id);
+    move_devices(&tx, &audit, &reason, &transitions).await?;
+    close(&tx, audit_id, "completed", note).await?;
+
+    let report = AuditReport {
+        audit: load(&tx, audit_id).await?,
+        missing,
+        extra,
+        wrong_lab,
+        marked_lost,
+    };
+    tx.commit().await?;
+    db.mark_write();
+    Ok(report)
+}
+
+/// Abandons the audit and gives every device in it back the status it had
+/// when it was added.
+pub async fn cancel_audit(db: &Database, audit_id: &str, lab_id: &str) -> AuditResult<Audit> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let audit = lock_open(&tx, audit_id, lab_id).await?;
+
+    let rows = tx
+        .query(
+            "SELECT device_id::text, prev_status FROM inventory_assessments_devices
+             WHERE assessing_id::text = $1",
+            &[&audit_id],
+        )
+        .await?;
+    let mut previous = Vec::new();
+    for row in &rows {
+        previous.push((row.get::<_, String>(0), row.try_get(1)?));
+    }
+    let ids: Vec<&str> = previous.iter().map(|(id, _)| id.as_str()).collect();
+    let locked = lock_devices(&tx, &ids).await?;
+    let transitions = restore(&locked, &previous);
+
+    let reason = format!("Audit {} cancelled", audit_id);
+    move_devices(&tx, &audit, &reason, &transitions).await?;
+    close(&tx, audit_id, "cancelled", None).await?;
+
+    let cancelled = load(&tx, audit_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(cancelled)
+}
+
+/// Checks that need no database access.
+fn check_devices(devices: &[AuditedDevice]) -> Vec<FieldError> {
+    let conditions = lifecycle::targets(ActivityType::Assessment, DeviceStatus::Assessing);
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+    for (index, device) in devices.iter().enumerate() {
+        if !seen.insert(device.id.as_str()) {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} is listed more than once", device.id),
+            ));
+        }
+        if !conditions.contains(&device.condition) {
+            errors.push(FieldError::new(
+                format!("devices[{}].condition", index),
+                format!(
+                    "Must be one of {}, not {}",
+                    conditions
+                        .iter()
+                        .map(|c| c.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    device.condition
+                ),
+            ));
+        }
+    }
+    errors
+}
+
+/// Locks the audit and checks that it is still in progress and belongs to
+/// `lab_id`.
+async fn lock_open(tx: &Transaction<'_>, audit_id: &str, lab_id: &str) -> AuditResult<OpenAudit> {
+    let Some(row) = tx
+        .query_opt(
+            "SELECT id::text, lab_id::text, accountant_id::text, status::text
+             FROM inventory_assessments
+             WHERE id::text = $1
+             FOR UPDATE",
+            &[&audit_id],
+        )
+        .await?
+    else {
+        return Err(AuditError::NotFound(audit_id.to_string()));
+    };
+    let status: String = row.get(3);
+    if status != "assessing" {
+        return Err(AuditError::Closed {
+            id: audit_id.to_string(),
+            status,
+        });
+    }
+    let audit = OpenAudit {
+        id: row.get(0),
+        lab_id: row.get(1),
+        auditor_id: row.get(2),
+    };
+    if audit.lab_id != lab_id {
+        return Err(AuditError::OtherLab(audit_id.to_string()));
+    }
+    Ok(audit)
+}
+
+/// `(id, status)` of the given devices that exist and are not deleted,
+/// locked.
+async fn lock_devices(
+    tx: &Transaction<'_>,
+    ids: &[&str],
+) -> AuditResult<Vec<(String, DeviceStatus)>> {
+    let rows = tx
+        .query(
+            "SELECT id::text, status FROM devices
+             WHERE id::text = ANY($1) AND deleted_at IS NULL
+             FOR UPDATE",
+            &[&ids],
+        )
+        .await?;
+    rows.iter()
+        .map(|row| Ok((row.get(0), row.try_get(1)?)))
+        .collect()
+}
+
+async fn add_devices(
+    tx: &Transaction<'_>,
+    audit: &OpenAudit,
+    devices: &[AuditedDevice],
+) -> AuditResult<()> {
+    if devices.is_empty() {
+        return Ok(());
+    }
+    let ids: Vec<&str> = devices.iter().map(|d| d.id.as_str()).collect();
+    let locked = lock_devices(tx, &ids).await?;
+    let present: Vec<String> = tx
+        .query(
+            "SELECT device_id::text FROM inventory_assessments_devices
+             WHERE assessing_id::text = $1 AND device_id::text = ANY($2)",
+            &[&audit.id, &ids],
+        )
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+
+    let mut errors = Vec::new();
+    let mut transitions = Vec::new();
+    let mut inserted = Vec::new();
+    let mut updated = Vec::new();
+    for (index, device) in devices.iter().enumerate() {
+        let row = json!({
+            "assessing_id": audit.id,
+            "device_id": device.id,
+            "after_status": device.condition,
+        });
+        if present.contains(&device.id) {
+            updated.push(row);
+            continue;
+        }
+        let Some((_, status)) = locked.iter().find(|(id, _)| *id == device.id) else {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} does not exist", device.id),
+            ));
+            continue;
+        };
+        if let Err(e) = lifecycle::check(
+            ActivityType::Assessment,
+            &device.id,
+            *status,
+            DeviceStatus::Assessing,
+        ) {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                e.to_string(),
+            ));
+            continue;
+        }
+        let mut row = row;
+        row["prev_status"] = json!(status);
+        inserted.push(row);
+        transitions.push(Transition {
+            device_id: device.id.clone(),
+            from: *status,
+            to: DeviceStatus::Assessing,
+        });
+    }
+    if !errors.is_empty() {
+        return Err(AuditError::Invalid(errors));
+    }
+
+    if !inserted.is_empty() {
+        tx.execute(
+            "INSERT INTO inventory_assessments_devices (prev_status, after_status, assessing_id, device_id)
+             SELECT prev_status, after_status, assessing_id, device_id
+             FROM json_populate_recordset(NULL::inventory_assessments_devices, $1)",
+            &[&JsonValue::Array(inserted)],
+        )
+        .await?;
+    }
+    if !updated.is_empty() {
+        tx.execute(
+            "UPDATE inventory_assessments_devices iad
+             SET after_status = v.after_status
+             FROM json_populate_recordset(NULL::inventory_assessments_devices, $1) v
+             WHERE iad.assessing_id = v.assessing_id AND iad.device_id = v.device_id",
+            &[&JsonValue::Array(updated)],
+        )
+        .await?;
+    }
+
+    let reason = format!("Added to audit {}", audit.id);
+    move_devices(tx, audit, &reason, &transitions).await
+}
+
+/// Moves the locked devices that are still `assessing` back to their
+/// previous status, `healthy` if none was recorded.
+fn restore(
+    locked: &[(String, DeviceStatus)],
+    previous: &[(String, Option<DeviceStatus>)],
+) -> Vec<Transition> {
+    previous
+        .iter()
+        .filter_map(|(device_id, prev)| {
+            let (_, current) = locked.iter().find(|(id, _)| id == device_id)?;
+            (*current == DeviceStatus::Assessing).then(|| Transition {
+                device_id: device_id.clone(),
+                from: *current,
+                to: prev.unwrap_or(DeviceStatus::Healthy),
+            })
+        })
+        .collect()
+}
+
+async fn move_devices(
+    tx: &Transaction<'_>,
+    audit: &OpenAudit,
+    reason: &str,
+    transitions: &[Transition],
+) -> AuditResult<()> {
+    if transitions.is_empty() {
+        return Ok(());
+    }
+    lifecycle::apply(
+        tx,
+        ChangeContext {
+            activity: ActivityType::Assessment,
+            activity_id: Some(&audit.id),
+            actor_id: audit.auditor_id.as_deref(),
+            reason: Some(reason),
+        },
+        transitions,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Ends the audit, adding `note` on a new line after the note it was
+/// started with.
+async fn close(
+    tx: &Transaction<'_>,
+    audit_id: &str,
+    status: &str,
+    note: Option<&str>,
+) -> AuditResult<()> {
+    tx.execute(
+        "UPDATE inventory_assessments
+         SET status = $2::text::assessment_status, finished_at = now()
+         WHERE id::text = $1",
+        &[&audit_id, &status],
+    )
+    .await?;
+    if let Some(note) = note.filter(|n| !n.trim().is_empty()) {
+        tx.execute(
+            "UPDATE activities SET note = concat_ws(E'\\n', note, $2::text) WHERE id::text = $1",
+            &[&audit_id, &note],
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn load(tx: &Transaction<'_>, audit_id: &str) -> AuditResult<Audit> {
+    let row = tx
+        .query_one(
+            "SELECT ia.id::text, ia.lab_id::text, ia.accountant_id::text, ia.status::text,
+                    a.created_at, ia.finished_at
+             FROM inventory_assessments ia
+             JOIN activities a ON a.id = ia.id
+             WHERE ia.id::text = $1",
+            &[&audit_id],
+        )
+        .await?;
+    let devices = tx
+        .query(
+            "SELECT device_id::text, prev_status, after_status
+             FROM inventory_assessments_devices
+             WHERE assessing_id::text = $1
+             ORDER BY device_id",
+            &[&audit_id],
+        )
+        .await?;
+    Ok(Audit {
+        id: row.get(0),
+        lab_id: row.get(1),
+        auditor_id: row.get(2),
+        status: row.get(3),
+        created_at: row.get(4),
+        finished_at: row.get(5),
+        devices: devices
+            .iter()
+            .map(|row| {
+                Ok(AuditDevice {
+                    device_id: row.get(0),
+                    prev_status: row.try_get(1)?,
+                    after_status: row.try_get(2)?,
+                })
+            })
+            .collect::<Result<_, tokio_postgres::Error>>()?,
+    })
+}
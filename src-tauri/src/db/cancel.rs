@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_postgres::CancelToken;
+
+/// Per-call overrides for a single statement.
+#[derive(Debug, Clone, Default)]
+pub struct QueryControl {
+    /// Frontend-chosen ID under which the query can be cancelled while it
+    /// runs.
+    pub request_id: Option<String>,
+    /// Replaces the session `statement_timeout` for this statement only.
+    pub timeout: Option<Duration>,
+}
+
+impl QueryControl {
+    pub fn new(request_id: Option<String>, timeout_ms: Option<u64>) -> Self {
+        QueryControl {
+            request_id,
+            timeout: timeout_ms.map(Duration::from_millis),
+        }
+    }
+}
+
+pub(crate) struct InFlight {
+    pub token: CancelToken,
+    pub cancelled: AtomicBool,
+}
+
+/// Queries currently running under a request ID.
+#[derive(Clone, Default)]
+pub(crate) struct InFlightQueries(Arc<Mutex<HashMap<String, Arc<InFlight>>>>);
+
+impl InFlightQueries {
+    /// Registers `token` under `request_id` until the returned guard is
+    /// dropped. A later query with the same ID replaces the earlier one.
+    pub fn register(&self, request_id: &str, token: CancelToken) -> InFlightGuard {
+        let entry = Arc::new(InFlight {
+            token,
+            cancelled: AtomicBool::new(false),
+        });
+        self.0
+            .lock()
+            .unwrap()
+            .insert(request_id.to_string(), entry.clone());
+        InFlightGuard {
+            queries: self.clone(),
+            request_id: request_id.to_string(),
+            entry,
+        }
+    }
+
+    /// Marks the query as cancelled and returns its token, or `None` when
+    /// nothing is running under `request_id`.
+    pub fn take_for_cancel(&self, request_id: &str) -> Option<CancelToken> {
+        let entry = self.0.lock().unwrap().get(request_id).cloned()?;
+        entry.cancelled.store(true, Ordering::SeqCst);
+        Some(entry.token.clone())
+    }
+}
+
+pub(crate) struct InFlightGuard {
+    queries: InFlightQueries,
+    request_id: String,
+    entry: Arc<InFlight>,
+}
+
+impl InFlightGuard {
+    pub fn was_cancelled(&self) -> bool {
+        self.entry.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let mut queries = self.queries.0.lock().unwrap();
+        // Only remove our own entry, not a newer query that reused the ID.
+        if queries
+            .get(&self.request_id)
+            .is_some_and(|current| Arc::ptr_eq(current, &self.entry))
+        {
+            queries.remove(&self.request_id);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use serde_json::{json, Map, Value};
+
+use crate::db::schema::{ColumnInfo, DatabaseSchema, TableInfo};
+
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Which write payload a document describes. Inserts require every column
+/// that has no default; updates make every column optional.
+#[derive(Clone, Copy, PartialEq)]
+pub enum WriteMode {
+    Insert,
+    Update,
+}
+
+impl WriteMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WriteMode::Insert => "insert",
+            WriteMode::Update => "update",
+        }
+    }
+}
+
+/// One document holding an insert and an update schema per table under
+/// `$defs`, keyed `<schema>.<table>.<mode>`.
+pub fn generate(schema: &DatabaseSchema) -> Value {
+    let mut defs = Map::new();
+    for table in schema.unique_tables() {
+        for mode in [WriteMode::Insert, WriteMode::Update] {
+            defs.insert(
+                format!("{}.{}", table.qualified_name(), mode.as_str()),
+                table_schema(schema, table, mode),
+            );
+        }
+    }
+
+    json!({
+        "$schema": DIALECT,
+        "$defs": defs,
+    })
+}
+
+/// Schema for the `value` object passed to `insert_into_table` (keyed by
+/// column name, not the camelCase names rows come back with).
+pub fn table_schema(schema: &DatabaseSchema, table: &TableInfo, mode: WriteMode) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for column in table.columns.iter().filter(|c| !c.is_generated) {
+        if properties.contains_key(&column.name) {
+            continue;
+        }
+        properties.insert(column.name.clone(), column_schema(schema, column));
+        if mode == WriteMode::Insert && !column.is_optional_on_insert() {
+            required.push(Value::String(column.name.clone()));
+        }
+    }
+
+    json!({
+        "title": format!("{} {}", table.qualified_name(), mode.as_str()),
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn column_schema(schema: &DatabaseSchema, column: &ColumnInfo) -> Value {
+    let value = match &column.enum_values {
+        Some(values) => json!({ "type": "string", "enum": values }),
+        None => match column.udt_name.strip_prefix('_') {
+            Some(element) if column.type_name == "ARRAY" => json!({
+                "type": "array",
+                "items": element_schema(schema, element),
+            }),
+            _ => type_schema(if column.udt_name.is_empty() {
+                &column.type_name
+            } else {
+                &column.udt_name
+            }),
+        },
+    };
+
+    if column.is_nullable {
+        json!({ "anyOf": [value, { "type": "null" }] })
+    } else {
+        value
+    }
+}
+
+fn element_schema(schema: &DatabaseSchema, udt_name: &str) -> Value {
+    match schema.enums.get(udt_name) {
+        Some(enum_info) => json!({ "type": "string", "enum": enum_info.values }),
+        None => type_schema(udt_name),
+    }
+}
+
+fn type_schema(pg_type: &str) -> Value {
+    match pg_type {
+        "int2" | "smallint" => json!({
+            "type": "integer",
+            "minimum": i16::MIN,
+            "maximum": i16::MAX,
+        }),
+        "int4" | "integer" | "serial" => json!({
+            "type": "integer",
+            "minimum": i32::MIN,
+            "maximum": i32::MAX,
+        }),
+        "int8" | "bigint" | "bigserial" => json!({ "type": "integer" }),
+        "float4" | "float8" | "real" | "double precision" | "numeric" | "decimal" => {
+            json!({ "type": "number" })
+        }
+        "bool" | "boolean" => json!({ "type": "boolean" }),
+        "uuid" => json!({ "type": "string", "format": "uuid" }),
+        "date" => json!({ "type": "string", "format": "date" }),
+        "timestamptz"
+        | "timestamp"
+        | "timestamp with time zone"
+        | "timestamp without time zone" => json!({
+            "anyOf": [
+                { "type": "string", "format": "date-time" },
+                { "type": "integer" },
+            ],
+        }),
+        "json" | "jsonb" => json!({}),
+        _ => json!({ "type": "string" }),
+    }
+}
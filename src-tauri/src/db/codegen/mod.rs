@@ -0,0 +1,5 @@
+pub mod json_schema;
+pub mod naming;
+pub mod rust;
+pub mod typescript;
+pub mod validators;
@@ -0,0 +1,335 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::db::schema::DatabaseSchema;
+
+/// Names the generated TypeScript declares itself, plus global types a
+/// generated declaration must not shadow.
+const TS_RESERVED_TYPES: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "Awaited",
+    "BigInt",
+    "Boolean",
+    "Capitalize",
+    "DataView",
+    "Date",
+    "Error",
+    "Exclude",
+    "Extract",
+    "FieldError",
+    "Function",
+    "InstanceType",
+    "Iterable",
+    "Iterator",
+    "JSON",
+    "Json",
+    "JsonSchema",
+    "Lowercase",
+    "Map",
+    "Math",
+    "NonNullable",
+    "Number",
+    "Object",
+    "Omit",
+    "Parameters",
+    "Partial",
+    "Pick",
+    "Promise",
+    "Proxy",
+    "Readonly",
+    "Record",
+    "Reflect",
+    "RegExp",
+    "Required",
+    "ReturnType",
+    "Set",
+    "String",
+    "Symbol",
+    "TableName",
+    "Uncapitalize",
+    "Uppercase",
+    "ValidationResult",
+    "WeakMap",
+    "WeakSet",
+];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Every declaration emitted for a table, as suffixes of its base name.
+pub const TABLE_DECLARATIONS: &[&str] = &["", "Insert", "Update", "Relations", "Columns", "Column"];
+
+/// Splits a database identifier into words on anything that is not an ASCII
+/// letter or digit, and on lower-to-upper case changes (`deviceKind`).
+pub fn words(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for chunk in s.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut word = String::new();
+        let mut prev_lower = false;
+        for c in chunk.chars() {
+            if c.is_ascii_uppercase() && prev_lower && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+            word.push(c);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+/// `device_kinds` -> `DeviceKinds`. Only the first letter of each word is
+/// changed, so `hmi_QR_codes` becomes `HmiQRCodes`.
+pub fn pascal_case(s: &str) -> String {
+    words(s)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `in-use` -> `IN_USE`.
+pub fn screaming_snake_case(s: &str) -> String {
+    words(s)
+        .iter()
+        .map(|word| word.to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// The key a column gets in rows returned by `query_table`/`query_raw`:
+/// each `_` is dropped and the following character upper-cased, everything
+/// else is kept as is. Generated row types must use exactly this mapping.
+pub fn to_camel_case(snake_str: &str) -> String {
+    let mut result = String::with_capacity(snake_str.len());
+    let mut capitalize_next = false;
+
+    for c in snake_str.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn is_ts_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// A single-quoted TypeScript string literal.
+pub fn ts_string(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('\'');
+    for c in value.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '\'' => literal.push_str("\\'"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\u{2028}' => literal.push_str("\\u2028"),
+            '\u{2029}' => literal.push_str("\\u2029"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('\'');
+    literal
+}
+
+/// A property key, quoted when it is not a valid identifier.
+pub fn ts_property(name: &str) -> String {
+    if is_ts_identifier(name) {
+        name.to_string()
+    } else {
+        ts_string(name)
+    }
+}
+
+/// A PascalCase type name that is a valid identifier and does not shadow a
+/// reserved or global type.
+pub fn ts_type_name(s: &str) -> String {
+    let name = pascal_case(s);
+    match name.chars().next() {
+        None => "Unnamed".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("T{}", name),
+        Some(_) if TS_RESERVED_TYPES.contains(&name.as_str()) => format!("{}_", name),
+        Some(_) => name,
+    }
+}
+
+/// A SCREAMING_SNAKE_CASE enum member name.
+pub fn ts_enum_member(value: &str) -> String {
+    let name = screaming_snake_case(value);
+    match name.chars().next() {
+        None => "EMPTY".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("_{}", name),
+        Some(_) => name,
+    }
+}
+
+/// Enum member names for `values`, in order, with collisions such as
+/// `in-use`/`in_use` disambiguated by a numeric suffix.
+pub fn ts_enum_members(values: &[String]) -> Vec<String> {
+    let mut registry = NameRegistry::default();
+    values
+        .iter()
+        .map(|value| registry.claim(&[ts_enum_member(value)], &[""]))
+        .collect()
+}
+
+/// UpperCamelCase Rust type or variant name.
+pub fn rust_type_ident(s: &str) -> String {
+    let ident: String = words(s)
+        .iter()
+        .map(|word| {
+            let lower = word.to_ascii_lowercase();
+            let mut chars = lower.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    match ident.chars().next() {
+        None => "Unnamed".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("V{}", ident),
+        Some(_) if ident == "Self" => "Self_".to_string(),
+        Some(_) => ident,
+    }
+}
+
+/// snake_case Rust field name, raw or suffixed when it is a keyword.
+pub fn rust_field_ident(s: &str) -> String {
+    let ident = words(s)
+        .iter()
+        .map(|w| w.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("_");
+    if ident.is_empty() {
+        "unnamed".to_string()
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", ident)
+    } else if RUST_KEYWORDS.contains(&ident.as_str()) {
+        if matches!(ident.as_str(), "self" | "super" | "crate") {
+            format!("{}_", ident)
+        } else {
+            format!("r#{}", ident)
+        }
+    } else {
+        ident
+    }
+}
+
+/// Hands out names that are unique within one generated file.
+#[derive(Default)]
+pub struct NameRegistry {
+    taken: HashSet<String>,
+}
+
+impl NameRegistry {
+    /// Claims the first candidate for which every `suffixes` variant is still
+    /// free, falling back to numbered variants of the first candidate.
+    pub fn claim(&mut self, candidates: &[String], suffixes: &[&str]) -> String {
+        let free = |taken: &HashSet<String>, base: &str| {
+            suffixes
+                .iter()
+                .all(|suffix| !taken.contains(&format!("{}{}", base, suffix)))
+        };
+
+        let chosen = candidates
+            .iter()
+            .find(|c| free(&self.taken, c))
+            .cloned()
+            .unwrap_or_else(|| {
+                let base = &candidates[0];
+                (2..)
+                    .map(|n| format!("{}{}", base, n))
+                    .find(|c| free(&self.taken, c))
+                    .unwrap_or_default()
+            });
+
+        for suffix in suffixes {
+            self.taken.insert(format!("{}{}", chosen, suffix));
+        }
+        chosen
+    }
+}
+
+/// TypeScript names for every enum and table, decided once per schema so
+/// that types, validators and references to them always agree.
+pub struct TypeScriptNames {
+    enums: HashMap<String, String>,
+    tables: HashMap<String, String>,
+}
+
+impl TypeScriptNames {
+    pub fn new(schema: &DatabaseSchema) -> Self {
+        let mut registry = NameRegistry::default();
+
+        let enums = schema
+            .sorted_enums()
+            .into_iter()
+            .map(|e| {
+                (
+                    e.name.clone(),
+                    registry.claim(&[ts_type_name(&e.name)], &[""]),
+                )
+            })
+            .collect();
+
+        // Tables that own their unqualified name are named first so they get
+        // the plain name even when a same-named table in another schema
+        // sorts earlier.
+        let mut tables_in_order = schema.unique_tables();
+        tables_in_order.sort_by_key(|t| !schema.owns_short_name(t));
+
+        let tables = tables_in_order
+            .into_iter()
+            .map(|table| {
+                let plain = ts_type_name(&table.name);
+                let qualified = format!("{}_{}", plain, pascal_case(&table.schema));
+                let candidates = if schema.owns_short_name(table) {
+                    vec![plain, qualified]
+                } else {
+                    vec![qualified]
+                };
+                (
+                    table.qualified_name(),
+                    registry.claim(&candidates, TABLE_DECLARATIONS),
+                )
+            })
+            .collect();
+
+        TypeScriptNames { enums, tables }
+    }
+
+    /// Name of the TypeScript enum generated for a Postgres enum type.
+    pub fn enum_name(&self, pg_name: &str) -> Option<&str> {
+        self.enums.get(pg_name).map(String::as_str)
+    }
+
+    /// Base interface name for a table, keyed by qualified name.
+    pub fn table_name(&self, qualified_name: &str) -> Option<&str> {
+        self.tables.get(qualified_name).map(String::as_str)
+    }
+}
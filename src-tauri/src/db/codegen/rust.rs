@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use super::naming::{rust_field_ident, rust_type_ident, NameRegistry};
+use crate::db::schema::{ColumnInfo, DatabaseSchema, EnumInfo, TableInfo};
+
+const HEADER: &str = "// This file is auto-generated by `hmi codegen`. Do not edit manually.
+#![allow(dead_code)]
+";
+
+/// Rust definitions for every enum and table: enums map onto the Postgres
+/// enum by name, table structs derive `FromSql`/`ToSql` for the table's
+/// composite row type and implement `TryFrom<&Row>` for plain queries.
+pub fn generate(schema: &DatabaseSchema) -> String {
+    let names = type_names(schema);
+    let mut rust = String::from(HEADER);
+
+    for enum_info in schema.sorted_enums() {
+        push_enum(&mut rust, enum_info, &names[&enum_info.name]);
+    }
+
+    for table in schema.unique_tables() {
+        push_struct(&mut rust, &names, table, &names[&table.qualified_name()]);
+    }
+
+    rust
+}
+
+/// Enums and structs share one namespace, so a table and an enum that would
+/// map onto the same identifier are told apart by their schema or a suffix.
+/// Enums are keyed by type name, tables by qualified name.
+fn type_names(schema: &DatabaseSchema) -> HashMap<String, String> {
+    let mut registry = NameRegistry::default();
+    let mut names = HashMap::new();
+
+    for enum_info in schema.sorted_enums() {
+        let name = registry.claim(&[rust_type_ident(&enum_info.name)], &[""]);
+        names.insert(enum_info.name.clone(), name);
+    }
+
+    let mut tables = schema.unique_tables();
+    tables.sort_by_key(|t| !schema.owns_short_name(t));
+    for table in tables {
+        let plain = rust_type_ident(&table.name);
+        let qualified = format!("{}{}", plain, rust_type_ident(&table.schema));
+        let candidates = if schema.owns_short_name(table) {
+            vec![plain, qualified]
+        } else {
+            vec![qualified]
+        };
+        names.insert(table.qualified_name(), registry.claim(&candidates, &[""]));
+    }
+
+    names
+}
+
+fn push_enum(rust: &mut String, enum_info: &EnumInfo, name: &str) {
+    rust.push_str(&format!(
+        "\n#[derive(\n    Debug,\n    Clone,\n    Copy,\n    PartialEq,\n    Eq,\n    Hash,\n    serde::Serialize,\n    serde::Deserialize,\n    postgres_types::ToSql,\n    postgres_types::FromSql,\n)]\n#[postgres(name = \"{}\")]\npub enum {} {{\n",
+        enum_info.name, name
+    ));
+
+    let mut variants = NameRegistry::default();
+    for value in &enum_info.values {
+        let variant = variants.claim(&[rust_type_ident(value)], &[""]);
+        rust.push_str(&format!(
+            "    #[postgres(name = {value:?})]\n    #[serde(rename = {value:?})]\n    {variant},\n"
+        ));
+    }
+    rust.push_str("}\n");
+}
+
+fn push_struct(rust: &mut String, names: &HashMap<String, String>, table: &TableInfo, name: &str) {
+    let mut fields = Vec::new();
+    let mut seen_columns = std::collections::HashSet::new();
+    let mut idents = NameRegistry::default();
+    for column in &table.columns {
+        if !seen_columns.insert(column.name.clone()) {
+            continue;
+        }
+        fields.push((
+            idents.claim(&[rust_field_ident(&column.name)], &[""]),
+            column,
+        ));
+    }
+
+    rust.push_str(&format!(
+        "\n#[derive(\n    Debug,\n    Clone,\n    serde::Serialize,\n    serde::Deserialize,\n    postgres_types::ToSql,\n    postgres_types::FromSql,\n)]\n#[serde(rename_all = \"camelCase\")]\n#[postgres(name = \"{}\")]\npub struct {} {{\n",
+        table.name, name
+    ));
+    for (ident, column) in &fields {
+        if ident.trim_start_matches("r#") != column.name {
+            rust.push_str(&format!("    #[postgres(name = {:?})]\n", column.name));
+        }
+        rust.push_str(&format!(
+            "    pub {}: {},\n",
+            ident,
+            column_type(names, column)
+        ));
+    }
+    rust.push_str("}\n");
+
+    rust.push_str(&format!(
+        "\nimpl TryFrom<&tokio_postgres::Row> for {} {{\n    type Error = tokio_postgres::Error;\n\n    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {{\n        Ok(Self {{\n",
+        name
+    ));
+    for (ident, column) in &fields {
+        rust.push_str(&format!(
+            "            {}: row.try_get({:?})?,\n",
+            ident, column.name
+        ));
+    }
+    rust.push_str("        })\n    }\n}\n");
+}
+
+fn column_type(names: &HashMap<String, String>, column: &ColumnInfo) -> String {
+    let base = match names
+        .get(&column.type_name)
+        .filter(|_| column.enum_values.is_some())
+    {
+        Some(enum_name) => enum_name.clone(),
+        None => match column.udt_name.strip_prefix('_') {
+            Some(element) if column.type_name == "ARRAY" => {
+                let element_type = match names.get(element) {
+                    Some(enum_name) => enum_name.clone(),
+                    None => scalar_type(element),
+                };
+                format!("Vec<{}>", element_type)
+            }
+            _ => scalar_type(if column.udt_name.is_empty() {
+                &column.type_name
+            } else {
+                &column.udt_name
+            }),
+        },
+    };
+
+    if column.is_nullable {
+        format!("Option<{}>", base)
+    } else {
+        base
+    }
+}
+
+fn scalar_type(pg_type: &str) -> String {
+    match pg_type {
+        "int2" | "smallint" => "i16",
+        "int4" | "integer" | "serial" => "i32",
+        "int8" | "bigint" | "bigserial" => "i64",
+        "oid" => "u32",
+        "float4" | "real" => "f32",
+        "float8" | "double precision" => "f64",
+        "numeric" | "decimal" => "rust_decimal::Decimal",
+        "bool" | "boolean" => "bool",
+        "uuid" => "uuid::Uuid",
+        "date" => "chrono::NaiveDate",
+        "timestamp" | "timestamp without time zone" => "chrono::NaiveDateTime",
+        "timestamptz" | "timestamp with time zone" => "chrono::DateTime<chrono::Utc>",
+        "json" | "jsonb" => "serde_json::Value",
+        "bytea" => "Vec<u8>",
+        "inet" => "std::net::IpAddr",
+        _ => "String",
+    }
+    .to_string()
+}
@@ -0,0 +1,276 @@
+use super::naming::{to_camel_case, ts_enum_members, ts_property, ts_string, TypeScriptNames};
+use crate::db::schema::{ColumnInfo, DatabaseSchema, TableInfo};
+
+/// Which side of the IPC boundary a generated type describes. Rows come back
+/// from `query_table`/`query_raw` with camelCase keys and timestamps as
+/// RFC 3339 strings; insert and update payloads are keyed by column name and
+/// also accept timestamps as epoch milliseconds.
+#[derive(Clone, Copy, PartialEq)]
+enum Shape {
+    Row,
+    Write,
+}
+
+const JSON_TYPE: &str = "export type Json =
+  | string
+  | number
+  | boolean
+  | null
+  | { [key: string]: Json | undefined }
+  | Json[]
+";
+
+pub fn generate(schema: &DatabaseSchema) -> String {
+    let names = TypeScriptNames::new(schema);
+    let mut typescript = String::new();
+
+    typescript.push_str("// This file is auto-generated. Do not edit manually.\n\n");
+    typescript.push_str(JSON_TYPE);
+    typescript.push('\n');
+
+    for enum_info in schema.sorted_enums() {
+        let Some(enum_name) = names.enum_name(&enum_info.name) else {
+            continue;
+        };
+        typescript.push_str(&format!("export enum {} {{\n", enum_name));
+        let members = ts_enum_members(&enum_info.values);
+        for (member, value) in members.iter().zip(&enum_info.values) {
+            typescript.push_str(&format!("  {} = {},\n", member, ts_string(value)));
+        }
+        typescript.push_str("}\n\n");
+    }
+
+    let tables = schema.unique_tables();
+
+    for table in &tables {
+        let Some(name) = names.table_name(&table.qualified_name()) else {
+            continue;
+        };
+        push_row(&mut typescript, &names, table, name);
+        push_insert(&mut typescript, &names, table, name);
+        push_update(&mut typescript, &names, table, name);
+        push_relations(&mut typescript, table, name, &names);
+        push_columns(&mut typescript, table, name);
+    }
+
+    let mut keys: Vec<(String, &str)> = Vec::new();
+    for table in &tables {
+        let Some(name) = names.table_name(&table.qualified_name()) else {
+            continue;
+        };
+        keys.push((table.qualified_name(), name));
+        if schema.owns_short_name(table) {
+            keys.push((table.name.clone(), name));
+        }
+    }
+    keys.sort();
+
+    if keys.is_empty() {
+        typescript.push_str("export type TableName = never\n\n");
+    } else {
+        typescript.push_str("export type TableName =\n");
+        for (key, _) in &keys {
+            typescript.push_str(&format!("  | {}\n", ts_string(key)));
+        }
+        typescript.push('\n');
+    }
+
+    typescript.push_str("declare module '@/lib/db/client' {\n");
+    typescript.push_str("  interface DatabaseTables {\n");
+    for (key, name) in &keys {
+        typescript.push_str(&format!(
+            "    {}: {{\n      Row: {name}\n      Insert: {name}Insert\n      Update: {name}Update\n      Relations: {name}Relations\n      Column: {name}Column\n    }}\n",
+            ts_string(key)
+        ));
+    }
+    typescript.push_str("  }\n}\n");
+
+    typescript
+}
+
+fn columns(table: &TableInfo) -> impl Iterator<Item = (String, &ColumnInfo)> {
+    let mut seen = std::collections::HashSet::new();
+    table.columns.iter().filter_map(move |column| {
+        let clean_name = column.name.replace('\u{200B}', "");
+        seen.insert(clean_name.clone())
+            .then_some((clean_name, column))
+    })
+}
+
+fn push_row(typescript: &mut String, names: &TypeScriptNames, table: &TableInfo, name: &str) {
+    typescript.push_str(&format!("export interface {} {{\n", name));
+    for (clean_name, column) in columns(table) {
+        let nullable = if column.is_nullable { " | null" } else { "" };
+        typescript.push_str(&format!(
+            "  {}: {}{}\n",
+            ts_property(&to_camel_case(&clean_name)),
+            column_type(names, column, Shape::Row),
+            nullable
+        ));
+    }
+    typescript.push_str("}\n\n");
+}
+
+fn push_insert(typescript: &mut String, names: &TypeScriptNames, table: &TableInfo, name: &str) {
+    typescript.push_str(&format!("export interface {}Insert {{\n", name));
+    for (clean_name, column) in columns(table).filter(|(_, c)| !c.is_generated) {
+        let optional = if column.is_optional_on_insert() {
+            "?"
+        } else {
+            ""
+        };
+        let nullable = if column.is_nullable { " | null" } else { "" };
+        typescript.push_str(&format!(
+            "  {}{}: {}{}\n",
+            ts_property(&clean_name),
+            optional,
+            column_type(names, column, Shape::Write),
+            nullable
+        ));
+    }
+    typescript.push_str("}\n\n");
+}
+
+fn push_update(typescript: &mut String, names: &TypeScriptNames, table: &TableInfo, name: &str) {
+    typescript.push_str(&format!("export interface {}Update {{\n", name));
+    for (clean_name, column) in columns(table).filter(|(_, c)| !c.is_generated) {
+        let nullable = if column.is_nullable { " | null" } else { "" };
+        typescript.push_str(&format!(
+            "  {}?: {}{}\n",
+            ts_property(&clean_name),
+            column_type(names, column, Shape::Write),
+            nullable
+        ));
+    }
+    typescript.push_str("}\n\n");
+}
+
+/// One property per foreign key, typed as the referenced row. Single-column
+/// keys are named after the column, composite keys after the constraint.
+fn push_relations(typescript: &mut String, table: &TableInfo, name: &str, names: &TypeScriptNames) {
+    let mut relations = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for foreign_key in &table.foreign_keys {
+        let referenced = format!(
+            "{}.{}",
+            foreign_key.referenced_schema, foreign_key.referenced_table
+        );
+        let Some(referenced_name) = names.table_name(&referenced) else {
+            continue;
+        };
+
+        let key = match foreign_key.columns.as_slice() {
+            [column] => to_camel_case(column),
+            _ => to_camel_case(&foreign_key.name),
+        };
+        if !seen.insert(key.clone()) {
+            continue;
+        }
+        let nullable = foreign_key.columns.iter().any(|fk_column| {
+            table
+                .columns
+                .iter()
+                .any(|c| &c.name == fk_column && c.is_nullable)
+        });
+
+        relations.push(format!(
+            "  {}: {}{}\n",
+            ts_property(&key),
+            referenced_name,
+            if nullable { " | null" } else { "" }
+        ));
+    }
+
+    if relations.is_empty() {
+        typescript.push_str(&format!("export interface {}Relations {{}}\n\n", name));
+        return;
+    }
+
+    typescript.push_str(&format!("export interface {}Relations {{\n", name));
+    for relation in relations {
+        typescript.push_str(&relation);
+    }
+    typescript.push_str("}\n\n");
+}
+
+/// Column name constants so `TableQueryBuilder` calls can be checked at
+/// compile time instead of passing bare strings.
+fn push_columns(typescript: &mut String, table: &TableInfo, name: &str) {
+    typescript.push_str(&format!("export const {}Columns = {{\n", name));
+    for (clean_name, _) in columns(table) {
+        typescript.push_str(&format!(
+            "  {}: {},\n",
+            ts_property(&to_camel_case(&clean_name)),
+            ts_string(&clean_name)
+        ));
+    }
+    typescript.push_str("} as const\n\n");
+    typescript.push_str(&format!(
+        "export type {name}Column = (typeof {name}Columns)[keyof typeof {name}Columns]\n\n"
+    ));
+}
+
+fn column_type(names: &TypeScriptNames, column: &ColumnInfo, shape: Shape) -> String {
+    // `udt_name` is the element type prefixed with `_` for arrays and the
+    // type's own name for enums; older snapshots without it fall back to
+    // `type_name`.
+    let pg_type = if column.udt_name.is_empty() {
+        column.type_name.as_str()
+    } else {
+        column.udt_name.as_str()
+    };
+
+    if column.type_name == "ARRAY" || column.udt_name.is_empty() {
+        if let Some(element) = pg_type.strip_prefix('_') {
+            let element_type = match names.enum_name(element) {
+                Some(enum_name) => enum_name.to_string(),
+                None => scalar_type(element, shape),
+            };
+            return format!("{}[]", wrap_union(element_type));
+        }
+    }
+
+    match names.enum_name(pg_type) {
+        Some(enum_name) => enum_name.to_string(),
+        None => scalar_type(&column.type_name, shape),
+    }
+}
+
+fn wrap_union(ts_type: String) -> String {
+    if ts_type.contains(" | ") {
+        format!("({})", ts_type)
+    } else {
+        ts_type
+    }
+}
+
+fn scalar_type(pg_type: &str, shape: Shape) -> String {
+    match pg_type_to_typescript(pg_type) {
+        "Date" => match shape {
+            Shape::Row => "string".to_string(),
+            Shape::Write => "string | number".to_string(),
+        },
+        ts_type => ts_type.to_string(),
+    }
+}
+
+/// Maps a scalar Postgres type, by either its SQL name or its `udt_name`.
+/// Enums and arrays are resolved by the caller against the schema.
+fn pg_type_to_typescript(pg_type: &str) -> &'static str {
+    match pg_type {
+        "integer" | "smallint" | "bigint" | "serial" | "bigserial" => "number",
+        "int2" | "int4" | "int8" => "number",
+        "numeric" | "decimal" | "real" | "double precision" => "number",
+        "float4" | "float8" => "number",
+        "character varying" | "text" | "character" | "varchar" | "bpchar" => "string",
+        "boolean" | "bool" => "boolean",
+        "timestamp with time zone"
+        | "timestamp without time zone"
+        | "timestamp"
+        | "timestamptz" => "Date",
+        "json" | "jsonb" => "Json",
+        "uuid" => "string",
+        "bytea" => "unknown",
+        _ => "string", // Default to string for unknown types
+    }
+}
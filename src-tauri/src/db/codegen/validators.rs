@@ -0,0 +1,207 @@
+use super::json_schema::{self, WriteMode};
+use super::naming::{pascal_case, ts_string, TypeScriptNames};
+use crate::db::schema::DatabaseSchema;
+
+/// Interprets the JSON Schema documents below. Kept in step with
+/// `db::validation` so the frontend and `insert_into_table` report the same
+/// field errors for the same payload.
+const RUNTIME: &str = r#"export interface FieldError {
+  field: string
+  message: string
+}
+
+export type ValidationResult<T> =
+  | { ok: true; value: T }
+  | { ok: false; errors: FieldError[] }
+
+export interface JsonSchema {
+  title?: string
+  type?: string | string[]
+  enum?: unknown[]
+  format?: string
+  minimum?: number
+  maximum?: number
+  anyOf?: JsonSchema[]
+  items?: JsonSchema
+  properties?: Record<string, JsonSchema>
+  required?: string[]
+  additionalProperties?: boolean
+}
+
+const UUID_PATTERN = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$/i
+const DATE_TIME_PATTERN =
+  /^\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$/
+const DATE_PATTERN = /^\d{4}-\d{2}-\d{2}$/
+
+function isObject(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null && !Array.isArray(value)
+}
+
+function isType(name: string, value: unknown): boolean {
+  switch (name) {
+    case 'null':
+      return value === null
+    case 'boolean':
+      return typeof value === 'boolean'
+    case 'string':
+      return typeof value === 'string'
+    case 'number':
+      return typeof value === 'number' && Number.isFinite(value)
+    case 'integer':
+      return Number.isInteger(value)
+    case 'array':
+      return Array.isArray(value)
+    case 'object':
+      return isObject(value)
+    default:
+      return false
+  }
+}
+
+function acceptsType(schema: JsonSchema, value: unknown): boolean {
+  if (typeof schema.type === 'string') return isType(schema.type, value)
+  if (Array.isArray(schema.type)) return schema.type.some((name) => isType(name, value))
+  if (schema.anyOf) return schema.anyOf.some((branch) => acceptsType(branch, value))
+  return true
+}
+
+function describe(schema: JsonSchema): string {
+  if (schema.anyOf) return schema.anyOf.map(describe).join(' or ')
+  if (schema.type === undefined) return 'any value'
+  const name = Array.isArray(schema.type) ? schema.type.join(' or ') : schema.type
+  return schema.format ? `${name} (${schema.format})` : name
+}
+
+function formatError(format: string, value: string): string | null {
+  switch (format) {
+    case 'uuid':
+      return UUID_PATTERN.test(value) ? null : 'must be a UUID'
+    case 'date-time':
+      return DATE_TIME_PATTERN.test(value) && !Number.isNaN(Date.parse(value))
+        ? null
+        : 'must be an RFC 3339 timestamp'
+    case 'date':
+      return DATE_PATTERN.test(value) && !Number.isNaN(Date.parse(value))
+        ? null
+        : 'must be a date (YYYY-MM-DD)'
+    default:
+      return null
+  }
+}
+
+function check(schema: JsonSchema, value: unknown, path: string, errors: FieldError[]): void {
+  const fail = (message: string) => errors.push({ field: path, message })
+
+  if (schema.anyOf) {
+    let candidate: FieldError[] | undefined
+    for (const branch of schema.anyOf) {
+      const branchErrors: FieldError[] = []
+      check(branch, value, path, branchErrors)
+      if (branchErrors.length === 0) return
+      if (!candidate && acceptsType(branch, value)) candidate = branchErrors
+    }
+    if (candidate) errors.push(...candidate)
+    else fail(`expected ${describe(schema)}`)
+    return
+  }
+
+  if (!acceptsType(schema, value)) {
+    fail(`expected ${describe(schema)}`)
+    return
+  }
+
+  if (schema.enum && !schema.enum.includes(value)) {
+    fail(`must be one of: ${schema.enum.map(String).join(', ')}`)
+    return
+  }
+
+  if (schema.format && typeof value === 'string') {
+    const message = formatError(schema.format, value)
+    if (message) {
+      fail(message)
+      return
+    }
+  }
+
+  if (typeof value === 'number') {
+    const { minimum, maximum } = schema
+    if ((minimum !== undefined && value < minimum) || (maximum !== undefined && value > maximum)) {
+      fail(`must be between ${minimum} and ${maximum}`)
+      return
+    }
+  }
+
+  const items = schema.items
+  if (items && Array.isArray(value)) {
+    value.forEach((item, i) => check(items, item, `${path}[${i}]`, errors))
+  }
+
+  if (isObject(value)) {
+    const child = (key: string) => (path ? `${path}.${key}` : key)
+
+    for (const key of schema.required ?? []) {
+      if (value[key] === undefined) errors.push({ field: child(key), message: 'is required' })
+    }
+
+    for (const [key, item] of Object.entries(value)) {
+      if (item === undefined) continue
+      const property = schema.properties?.[key]
+      if (property) check(property, item, child(key), errors)
+      else if (schema.additionalProperties === false)
+        errors.push({ field: child(key), message: 'is not a column of this table' })
+    }
+  }
+}
+
+export function validate<T>(schema: JsonSchema, value: unknown): ValidationResult<T> {
+  const errors: FieldError[] = []
+  check(schema, value, '', errors)
+  return errors.length === 0 ? { ok: true, value: value as T } : { ok: false, errors }
+}
+"#;
+
+pub fn generate(schema: &DatabaseSchema) -> String {
+    let tables = schema.unique_tables();
+    let names = TypeScriptNames::new(schema);
+    let document = json_schema::generate(schema);
+
+    let mut typescript = String::new();
+    typescript.push_str("// This file is auto-generated. Do not edit manually.\n\n");
+
+    if !tables.is_empty() {
+        typescript.push_str("import type {\n");
+        let mut imports: Vec<String> = tables
+            .iter()
+            .filter_map(|table| names.table_name(&table.qualified_name()))
+            .flat_map(|name| [format!("{}Insert", name), format!("{}Update", name)])
+            .collect();
+        imports.sort();
+        for import in imports {
+            typescript.push_str(&format!("  {},\n", import));
+        }
+        typescript.push_str("} from '@/types/db/generated'\n\n");
+    }
+
+    typescript.push_str(RUNTIME);
+    typescript.push('\n');
+
+    typescript.push_str(&format!(
+        "export const schemas: Record<string, JsonSchema> = {}\n\n",
+        serde_json::to_string_pretty(&document["$defs"]).unwrap_or_else(|_| "{}".to_string())
+    ));
+
+    for table in &tables {
+        let Some(name) = names.table_name(&table.qualified_name()) else {
+            continue;
+        };
+        for mode in [WriteMode::Insert, WriteMode::Update] {
+            let suffix = pascal_case(mode.as_str());
+            typescript.push_str(&format!(
+                "export const validate{name}{suffix} = (value: unknown) =>\n  validate<{name}{suffix}>(schemas[{}], value)\n\n",
+                ts_string(&format!("{}.{}", table.qualified_name(), mode.as_str())),
+            ));
+        }
+    }
+
+    typescript
+}
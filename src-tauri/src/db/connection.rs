@@ -1,39 +1,23 @@
-use deadpool_postgres::{CreatePoolError, Manager, Pool};
-use dotenvy::dotenv;
+use chrono::Utc;
+use deadpool_postgres::{
+    CreatePoolError, Manager, ManagerConfig, Pool, RecyclingMethod, Runtime, Timeouts,
+};
 use futures::future::BoxFuture;
-use serde::Deserialize;
-use tokio_postgres::NoTls;
-
-#[derive(Debug, Deserialize)]
-pub struct DatabaseConfig {
-    pub host: String,
-    pub port: u16,
-    pub user: String,
-    pub password: String,
-    pub database: String,
-    pub max_connections: usize,
-}
+use futures::StreamExt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::mpsc;
+use tokio_postgres::config::SslMode as PgSslMode;
+use tokio_postgres::error::SqlState;
+use tokio_postgres::types::ToSql;
+use tokio_postgres::{AsyncMessage, CancelToken, Connection, NoTls, Row, Statement};
 
-impl DatabaseConfig {
-    pub fn from_env() -> Result<Self, config::ConfigError> {
-        dotenv().ok();
-
-        Ok(DatabaseConfig {
-            host: std::env::var("POSTGRES_HOST").unwrap_or_else(|_| "localhost".to_string()),
-            port: std::env::var("POSTGRES_PORT")
-                .unwrap_or_else(|_| "5432".to_string())
-                .parse()
-                .unwrap_or(5432),
-            user: std::env::var("POSTGRES_USER").unwrap_or_else(|_| "postgres".to_string()),
-            password: std::env::var("POSTGRES_PASSWORD").expect("POSTGRES_PASSWORD must be set"),
-            database: std::env::var("POSTGRES_DB").expect("POSTGRES_DB must be set"),
-            max_connections: std::env::var("POSTGRES_MAX_CONNECTIONS")
-                .unwrap_or_else(|_| "5".to_string())
-                .parse()
-                .unwrap_or(5),
-        })
-    }
-}
+use super::cancel::{InFlightGuard, InFlightQueries, QueryControl};
+use super::metrics::{DbHealth, PoolMetrics, StatementMetrics};
+use super::replica::ReadRouting;
+use super::settings::{DatabaseConfig, SslMode};
+use super::tls::RustlsConnect;
 
 #[derive(Debug, thiserror::Error)]
 pub enum DbError {
@@ -45,48 +29,400 @@ pub enum DbError {
     Postgres(#[from] tokio_postgres::Error),
     #[error("Pool creation error: {0}")]
     CreatePool(#[from] CreatePoolError),
+    #[error("Query cancelled")]
+    Cancelled,
+    #[error("Stream abandoned: no chunk acknowledged for {0:?}")]
+    Stalled(Duration),
+    #[error("TLS error: {0}")]
+    Tls(String),
     #[error("Build error: {0}")]
     Build(String),
 }
 
 pub type DbResult<T> = Result<T, DbError>;
 
+/// Bounds how long opening a connection may take, so an unreachable host
+/// fails instead of hanging on the TCP handshake.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone)]
 pub struct Database {
     pool: Pool,
+    /// The pool's connection settings, for connections kept outside it.
+    pg_config: tokio_postgres::Config,
+    metrics: Arc<PoolMetrics>,
+    statement_metrics: Arc<StatementMetrics>,
+    statement_cache_size: usize,
+    /// Needed again to open the side connection that delivers a cancel
+    /// request.
+    tls: Option<RustlsConnect>,
+    in_flight: InFlightQueries,
+    routing: Option<ReadRouting>,
 }
 
 impl Database {
+    /// Connects with the layered configuration for the current profile.
     pub async fn new() -> DbResult<Self> {
-        let config = DatabaseConfig::from_env()?;
+        Self::connect(DatabaseConfig::from_env()?).await
+    }
+
+    /// Builds the pool and checks that the server answers.
+    pub async fn connect(config: DatabaseConfig) -> DbResult<Self> {
+        let db = Self::build(config)?;
+
+        db.ping().await.map_err(|e| {
+            eprintln!("Database connection test failed: {}", e);
+            eprintln!("Please check your database credentials and ensure the database is running.");
+            e
+        })?;
+
+        println!("Successfully connected to PostgreSQL!");
+
+        Ok(db)
+    }
 
-        let pool_config = tokio_postgres::config::Config::new()
+    /// Builds the pool, and the replica pool when `replica_url` is set,
+    /// without connecting; connections are opened on first use. Only fails
+    /// on configuration problems such as unreadable certificates.
+    pub fn build(config: DatabaseConfig) -> DbResult<Self> {
+        let routing = match &config.replica_url {
+            Some(url) => {
+                let mut replica_config = config.with_url(url)?;
+                replica_config.application_name = format!("{}-replica", config.application_name);
+                Some(ReadRouting::new(
+                    Self::build_pool(replica_config)?,
+                    config.read_your_writes_window,
+                ))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            routing,
+            ..Self::build_pool(config)?
+        })
+    }
+
+    fn build_pool(config: DatabaseConfig) -> DbResult<Self> {
+        let mut pool_config = tokio_postgres::config::Config::new()
             .host(&config.host)
             .port(config.port)
             .user(&config.user)
             .password(&config.password)
             .dbname(&config.database)
+            .application_name(&config.application_name)
+            .ssl_mode(match config.ssl_mode {
+                SslMode::Disable => PgSslMode::Disable,
+                SslMode::Prefer => PgSslMode::Prefer,
+                SslMode::Require | SslMode::VerifyCa | SslMode::VerifyFull => PgSslMode::Require,
+            })
+            .connect_timeout(CONNECT_TIMEOUT)
             .to_owned();
+        // Sent in the startup packet, so it is the session default that
+        // `RESET statement_timeout` returns to.
+        if let Some(timeout) = config.statement_timeout {
+            pool_config.options(format!("-c statement_timeout={}", timeout.as_millis()));
+        }
 
-        let mgr = Manager::new(pool_config, NoTls);
+        // Verified recycling runs a trivial query before handing out an idle
+        // connection, so connections killed by a server restart are replaced
+        // instead of failing the caller's query.
+        let manager_config = ManagerConfig {
+            recycling_method: RecyclingMethod::Verified,
+        };
+        let tls = RustlsConnect::from_config(&config)?;
+        let mgr = match tls.clone() {
+            Some(tls) => Manager::from_config(pool_config.clone(), tls, manager_config),
+            None => Manager::from_config(pool_config.clone(), NoTls, manager_config),
+        };
         let pool = Pool::builder(mgr)
             .max_size(config.max_connections)
+            .timeouts(Timeouts {
+                wait: config.pool_wait_timeout,
+                create: config.pool_create_timeout,
+                recycle: config.pool_recycle_timeout,
+            })
+            .runtime(Runtime::Tokio1)
             .build()
             .map_err(|e| DbError::Build(e.to_string()))?;
 
-        let client = pool.get().await.map_err(|e| DbError::Pool(e))?;
-        client.query("SELECT 1", &[]).await.map_err(|e| {
-            eprintln!("Database connection test failed: {}", e);
-            eprintln!("Please check your database credentials and ensure the database is running.");
-            DbError::Postgres(e)
-        })?;
+        Ok(Self {
+            pool,
+            pg_config: pool_config,
+            metrics: Arc::new(PoolMetrics::default()),
+            statement_metrics: Arc::new(StatementMetrics::default()),
+            statement_cache_size: config.statement_cache_size,
+            tls,
+            in_flight: InFlightQueries::default(),
+            routing: None,
+        })
+    }
 
-        println!("Successfully connected to PostgreSQL!");
+    /// Round trip to the server on a pooled connection.
+    pub async fn ping(&self) -> DbResult<()> {
+        let client = self.get_client().await?;
+        client.simple_query("SELECT 1").await?;
+        Ok(())
+    }
 
-        Ok(Self { pool })
+    /// Drops every idle connection so the next checkout opens a fresh one.
+    pub fn close_idle(&self) {
+        self.pool.retain(|_, _| false);
     }
 
     pub async fn get_client(&self) -> DbResult<deadpool_postgres::Client> {
-        Ok(self.pool.get().await?)
+        let started = Instant::now();
+        match self.pool.get().await {
+            Ok(client) => {
+                self.metrics.record_checkout(started.elapsed());
+                Ok(client)
+            }
+            Err(e) => {
+                self.metrics.record_pool_error(&e);
+                Err(e.into())
+            }
+        }
+    }
+
+    /// A connection for a read. Goes to the replica when there is one,
+    /// unless `read_primary` is set or this process wrote within the
+    /// read-your-writes window; falls back to the primary when the replica
+    /// cannot be reached. Returns the database the connection belongs to,
+    /// for [`Database::run_controlled`].
+    pub async fn get_read_client(
+        &self,
+        read_primary: bool,
+    ) -> DbResult<(&Database, deadpool_postgres::Client)> {
+        if let Some(routing) = self.routing.as_ref().filter(|_| !read_primary) {
+            if routing.use_replica() {
+                match routing.replica.get_client().await {
+                    Ok(client) => return Ok((&routing.replica, client)),
+                    Err(e) => {
+                        eprintln!("Warning: Replica unavailable, reading from primary: {}", e);
+                        routing.mark_down();
+                    }
+                }
+            }
+        }
+        Ok((self, self.get_client().await?))
+    }
+
+    /// Opens a connection outside the pool, for sessions that must outlive a
+    /// checkout such as `LISTEN`. Notifications and notices arrive on
+    /// `messages`; the sender is dropped when the connection closes.
+    pub(crate) async fn connect_unpooled(
+        &self,
+        messages: mpsc::UnboundedSender<AsyncMessage>,
+    ) -> DbResult<tokio_postgres::Client> {
+        match self.tls.clone() {
+            Some(tls) => {
+                let (client, connection) = self.pg_config.connect(tls).await?;
+                forward_messages(connection, messages);
+                Ok(client)
+            }
+            None => {
+                let (client, connection) = self.pg_config.connect(NoTls).await?;
+                forward_messages(connection, messages);
+                Ok(client)
+            }
+        }
+    }
+
+    /// Prepares `sql` through the connection's statement cache, so repeated
+    /// queries skip parsing and planning on the server. A connection whose
+    /// cache outgrows `statement_cache_size` starts over with an empty one.
+    pub async fn prepare_cached(
+        &self,
+        client: &deadpool_postgres::Client,
+        sql: &str,
+    ) -> Result<Statement, tokio_postgres::Error> {
+        if self.statement_cache_size == 0 {
+            return client.prepare(sql).await;
+        }
+
+        let cache = &client.statement_cache;
+        let before = cache.size();
+        let statement = client.prepare_cached(sql).await?;
+        let size = cache.size();
+        if size > before {
+            self.statement_metrics.record_miss();
+            if size > self.statement_cache_size {
+                cache.clear();
+                self.statement_metrics.record_eviction();
+            }
+        } else {
+            self.statement_metrics.record_hit();
+        }
+        Ok(statement)
+    }
+
+    /// Runs `sql` as a cached statement. A statement whose plan went stale
+    /// through a schema change the watcher has not seen yet is prepared
+    /// again once.
+    pub async fn query_cached(
+        &self,
+        client: &deadpool_postgres::Client,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, tokio_postgres::Error> {
+        let result = async {
+            let statement = self.prepare_cached(client, sql).await?;
+            match client.query(&statement, params).await {
+                Err(e) if is_stale_plan(&e) => {
+                    client.statement_cache.remove(sql, &[]);
+                    let statement = self.prepare_cached(client, sql).await?;
+                    client.query(&statement, params).await
+                }
+                result => result,
+            }
+        }
+        .await;
+        if let Err(e) = &result {
+            self.metrics.record_query_error(e);
+        }
+        result
+    }
+
+    /// Empties the statement cache of every connection, here and on the
+    /// replica. Cached plans can be invalid once the schema changed.
+    pub fn clear_statement_caches(&self) {
+        self.pool.manager().statement_caches.clear();
+        self.statement_metrics.record_invalidation();
+        if let Some(routing) = &self.routing {
+            routing.replica.clear_statement_caches();
+        }
+    }
+
+    /// Records a write so that following reads see it; see
+    /// [`Database::get_read_client`].
+    pub fn mark_write(&self) {
+        if let Some(routing) = &self.routing {
+            routing.mark_write();
+        }
+    }
+
+    /// Runs the read-only `sql` on `client` under `control`: in a read-only
+    /// transaction with its own `statement_timeout` if one is given, and
+    /// registered under its request ID so [`Database::cancel`] can interrupt
+    /// it. The timeout is `SET LOCAL`, so it ends with the transaction even
+    /// when the caller gives up on the query.
+    pub async fn run_controlled(
+        &self,
+        client: &mut deadpool_postgres::Client,
+        control: &QueryControl,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> DbResult<Vec<Row>> {
+        let guard = self.track(control, client.cancel_token());
+
+        let mut result = self.query_read_only(client, control, sql, params).await;
+        if matches!(&result, Err(e) if is_stale_plan(e)) {
+            // The failed statement aborted its transaction; retry in a new one.
+            client.statement_cache.remove(sql, &[]);
+            result = self.query_read_only(client, control, sql, params).await;
+        }
+
+        match result {
+            Ok(rows) => Ok(rows),
+            Err(_) if guard.as_ref().is_some_and(|g| g.was_cancelled()) => Err(DbError::Cancelled),
+            Err(e) => {
+                self.metrics.record_query_error(&e);
+                Err(e.into())
+            }
+        }
+    }
+
+    async fn query_read_only(
+        &self,
+        client: &mut deadpool_postgres::Client,
+        control: &QueryControl,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, tokio_postgres::Error> {
+        let statement = self.prepare_cached(client, sql).await?;
+        let tx = client.build_transaction().read_only(true).start().await?;
+        if let Some(timeout) = control.timeout {
+            tx.batch_execute(&format!(
+                "SET LOCAL statement_timeout = {}",
+                timeout.as_millis()
+            ))
+            .await?;
+        }
+        let rows = tx.query(&statement, params).await?;
+        tx.commit().await?;
+        Ok(rows)
+    }
+
+    /// Registers `token` under the request ID of `control`, if any, until
+    /// the guard is dropped, so [`Database::cancel`] can reach the session.
+    pub(crate) fn track(
+        &self,
+        control: &QueryControl,
+        token: CancelToken,
+    ) -> Option<InFlightGuard> {
+        control
+            .request_id
+            .as_deref()
+            .map(|id| self.in_flight.register(id, token))
+    }
+
+    /// Asks the server to cancel the query running under `request_id`.
+    /// Returns `false` when no such query is running.
+    pub async fn cancel(&self, request_id: &str) -> DbResult<bool> {
+        let Some(token) = self.in_flight.take_for_cancel(request_id) else {
+            return match &self.routing {
+                Some(routing) => Box::pin(routing.replica.cancel(request_id)).await,
+                None => Ok(false),
+            };
+        };
+        match self.tls.clone() {
+            Some(tls) => token.cancel_query(tls).await?,
+            None => token.cancel_query(NoTls).await?,
+        }
+        Ok(true)
+    }
+
+    /// Pool counters plus a round trip to the server, for the primary and
+    /// the replica. Never fails: when a server cannot be reached its probe
+    /// fields are empty and `error` says why.
+    pub async fn health(&self) -> DbHealth {
+        let mut health = self.probe().await;
+        if let Some(routing) = &self.routing {
+            health.replica = Some(Box::new(routing.replica.probe().await));
+        }
+        health
+    }
+
+    async fn probe(&self) -> DbHealth {
+        let probe = async {
+            let client = self.get_client().await?;
+            let started = Instant::now();
+            let row = client.query_one("SHOW server_version", &[]).await?;
+            let latency = started.elapsed();
+            DbResult::Ok((latency, row.get::<_, String>(0)))
+        };
+
+        let (latency_ms, server_version, error) = match probe.await {
+            Ok((latency, version)) => (Some(latency.as_secs_f64() * 1000.0), Some(version), None),
+            Err(e) => {
+                if !matches!(e, DbError::Pool(_)) {
+                    self.metrics.record_error(false);
+                }
+                (None, None, Some(e.to_string()))
+            }
+        };
+
+        DbHealth {
+            pool: self.pool.status().into(),
+            waits: self.metrics.waits(),
+            errors: self.metrics.errors(),
+            statements: self.statement_metrics.stats(self.statement_cache_size),
+            latency_ms,
+            server_version,
+            error,
+            checked_at: Utc::now(),
+            replica: None,
+        }
     }
 
     #[allow(dead_code)]
@@ -99,3 +435,38 @@ impl Database {
         operation(client).await
     }
 }
+
+/// Postgres refuses to reuse a prepared statement whose result columns
+/// changed under it.
+fn is_stale_plan(error: &tokio_postgres::Error) -> bool {
+    error.as_db_error().is_some_and(|e| {
+        *e.code() == SqlState::FEATURE_NOT_SUPPORTED && e.message().starts_with("cached plan")
+    })
+}
+
+/// Drives `connection` on its own task, passing on what the server sends
+/// outside of query responses.
+fn forward_messages<S, T>(
+    mut connection: Connection<S, T>,
+    messages: mpsc::UnboundedSender<AsyncMessage>,
+) where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    T: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut incoming = futures::stream::poll_fn(move |cx| connection.poll_message(cx));
+        while let Some(message) = incoming.next().await {
+            match message {
+                Ok(message) => {
+                    if messages.send(message).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Warning: Unpooled connection closed: {}", e);
+                    break;
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,393 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::Transaction;
+use rand::Rng;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use super::connection::{Database, DbError};
+use super::supervisor::DbStatus;
+
+/// How long a login code can be used after it is issued.
+pub const CODE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Attempts at picking an unused code before giving up.
+const CODE_ATTEMPTS: usize = 10;
+
+/// How often [`expire_codes`] sweeps the table.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long expired codes are kept, so a login screen still polling one
+/// sees `expired` rather than an unknown code.
+const PURGE_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, thiserror::Error)]
+pub enum HmiError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error("Login code {0} not found")]
+    NotFound(String),
+    #[error("Login code must be six digits, got {0:?}")]
+    InvalidCode(String),
+    #[error("No unused login code found after {0} attempts")]
+    Exhausted(usize),
+    #[error("Login code {0} has not been signed in with, or has no lab selected")]
+    NotReady(String),
+    #[error("Login code {0} has already been used")]
+    Used(String),
+    #[error("Login code {0} has expired")]
+    Expired(String),
+}
+
+impl From<tokio_postgres::Error> for HmiError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        HmiError::Db(error.into())
+    }
+}
+
+pub type HmiResult<T> = Result<T, HmiError>;
+
+/// A login code shown on the kiosk until someone signs in with it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuedCode {
+    pub code: String,
+    /// Seconds the code stays valid.
+    pub expires_in: u64,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HmiCodeState {
+    /// Nobody has signed in with the code yet.
+    Pending,
+    /// A user signed in with the code; the lab may still be unset.
+    Authenticated,
+    /// The code lapsed before anyone signed in with it.
+    Expired,
+    /// The code has started a kiosk session and cannot start another.
+    Used,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmiRole {
+    pub key: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmiUser {
+    pub id: String,
+    pub name: String,
+    pub email: Option<String>,
+    pub tel: Option<String>,
+    pub image: Option<String>,
+    pub last_active_at: Option<DateTime<Utc>>,
+    pub roles: Vec<HmiRole>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmiLab {
+    pub id: String,
+    pub name: Option<String>,
+    pub room: Option<String>,
+    pub branch: Option<String>,
+}
+
+/// What the login screen needs to know about its code.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmiCodeStatus {
+    pub code: String,
+    pub status: HmiCodeState,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Set once authenticated.
+    pub token: Option<String>,
+    pub user: Option<HmiUser>,
+    /// Set once authenticated and the user has picked the lab.
+    pub lab: Option<HmiLab>,
+}
+
+/// Issues a new six-digit login code, valid for [`CODE_TTL`].
+///
+/// Codes come from the thread-local CSPRNG. The check for a code already
+/// in `hmi_codes` and the insert run under one transaction-scoped lock, so
+/// two kiosks cannot be given the same code; a taken code is replaced by a
+/// fresh one.
+pub async fn issue_code(db: &Database) -> HmiResult<IssuedCode> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    tx.execute("SELECT pg_advisory_xact_lock(hashtext('hmi_codes'))", &[])
+        .await?;
+
+    let ttl = CODE_TTL.as_secs() as i32;
+    for _ in 0..CODE_ATTEMPTS {
+        let code: i32 = rand::rng().random_range(100_000..1_000_000);
+        let row = tx
+            .query_opt(
+                "INSERT INTO hmi_codes (code, status, created_at, updated_at, expires_at)
+                 SELECT $1::int, 'pending', now(), now(), now() + make_interval(secs => $2::int)
+                 WHERE NOT EXISTS (SELECT 1 FROM hmi_codes WHERE code = $1::int)
+                 RETURNING expires_at::timestamptz",
+                &[&code, &ttl],
+            )
+            .await?;
+        if let Some(row) = row {
+            tx.commit().await?;
+            db.mark_write();
+            return Ok(IssuedCode {
+                code: code.to_string(),
+                expires_in: CODE_TTL.as_secs(),
+                expires_at: row.get(0),
+            });
+        }
+    }
+    Err(HmiError::Exhausted(CODE_ATTEMPTS))
+}
+
+/// Where the login with `code` stands. A pending code past its expiry
+/// reads as expired even before [`expire_codes`] has marked it.
+pub async fn code_status(db: &Database, code: &str) -> HmiResult<HmiCodeStatus> {
+    let code = parse_code(code)?;
+    let mut client = db.get_client().await?;
+    let tx = client.build_transaction().read_only(true).start().await?;
+
+    let row = tx
+        .query_opt(
+            "SELECT status, expires_at::timestamptz, COALESCE(expires_at <= now(), false),
+                    user_id::text, auth_token, lab_id::text
+             FROM hmi_codes
+             WHERE code = $1::int
+             ORDER BY created_at DESC
+             LIMIT 1",
+            &[&code],
+        )
+        .await?
+        .ok_or_else(|| HmiError::NotFound(format!("{:06}", code)))?;
+
+    let status: String = row.get(0);
+    let expires_at: Option<DateTime<Utc>> = row.get(1);
+    let lapsed: bool = row.get(2);
+    let user_id: Option<String> = row.get(3);
+    let token: Option<String> = row.get(4);
+    let lab_id: Option<String> = row.get(5);
+
+    let mut result = HmiCodeStatus {
+        code: code.to_string(),
+        status: HmiCodeState::Pending,
+        expires_at,
+        token: None,
+        user: None,
+        lab: None,
+    };
+    match (user_id, token) {
+        (Some(user_id), Some(token)) => {
+            result.status = HmiCodeState::Authenticated;
+            result.token = Some(token);
+            result.user = load_user(&tx, &user_id).await?;
+            if let Some(lab_id) = lab_id {
+                result.lab = load_lab(&tx, &lab_id).await?;
+            }
+        }
+        _ if lapsed || status == "expired" => result.status = HmiCodeState::Expired,
+        _ => {}
+    }
+    if status == "used" {
+        result.status = HmiCodeState::Used;
+    }
+    Ok(result)
+}
+
+/// Takes the user and lab a code was signed in with, to start a kiosk
+/// session. The code is marked `used`, so it starts at most one session;
+/// a code past its expiry starts none, even before the sweep marks it.
+pub async fn redeem_code(db: &Database, code: &str) -> HmiResult<(HmiUser, HmiLab)> {
+    let code = parse_code(code)?;
+    let display = format!("{:06}", code);
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+
+    let row = tx
+        .query_opt(
+            "SELECT status, user_id::text, auth_token, lab_id::text,
+                    COALESCE(expires_at <= now(), false), ctid::text
+             FROM hmi_codes
+             WHERE code = $1::int
+             ORDER BY created_at DESC
+             LIMIT 1
+             FOR UPDATE",
+            &[&code],
+        )
+        .await?
+        .ok_or_else(|| HmiError::NotFound(display.clone()))?;
+
+    let status: String = row.get(0);
+    if status == "used" {
+        return Err(HmiError::Used(display));
+    }
+    if status == "expired" || row.get::<_, bool>(4) {
+        return Err(HmiError::Expired(display));
+    }
+    let (user_id, lab_id) = match (
+        row.get::<_, Option<String>>(1),
+        row.get::<_, Option<String>>(2),
+        row.get::<_, Option<String>>(3),
+    ) {
+        (Some(user_id), Some(_), Some(lab_id)) => (user_id, lab_id),
+        _ => return Err(HmiError::NotReady(display)),
+    };
+    let user = load_user(&tx, &user_id)
+        .await?
+        .ok_or_else(|| HmiError::NotReady(display.clone()))?;
+    let lab = load_lab(&tx, &lab_id)
+        .await?
+        .ok_or_else(|| HmiError::NotReady(display.clone()))?;
+
+    // Codes are reused once old ones are purged, so only the row locked
+    // above is marked.
+    let ctid: String = row.get(5);
+    tx.execute(
+        "UPDATE hmi_codes SET status = 'used', updated_at = now() WHERE ctid = $1::text::tid",
+        &[&ctid],
+    )
+    .await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok((user, lab))
+}
+
+/// Keeps `hmi_codes` tidy while the database is reachable: pending codes
+/// past their expiry are marked `expired`, and expired codes are deleted
+/// after [`PURGE_AFTER`].
+pub async fn expire_codes(db: Database, mut status: watch::Receiver<DbStatus>) {
+    loop {
+        if status
+            .wait_for(|s| matches!(s, DbStatus::Available))
+            .await
+            .is_err()
+        {
+            return;
+        }
+
+        if let Err(e) = sweep(&db).await {
+            eprintln!("Warning: Failed to expire login codes: {}", e);
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(SWEEP_INTERVAL) => {}
+            changed = status.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+async fn sweep(db: &Database) -> HmiResult<()> {
+    let client = db.get_client().await?;
+    let marked = client
+        .execute(
+            "UPDATE hmi_codes SET status = 'expired', updated_at = now()
+             WHERE status = 'pending' AND user_id IS NULL AND expires_at <= now()",
+            &[],
+        )
+        .await?;
+    let purged = client
+        .execute(
+            "DELETE FROM hmi_codes
+             WHERE expires_at <= now() - make_interval(secs => $1::int)",
+            &[&(PURGE_AFTER.as_secs() as i32)],
+        )
+        .await?;
+    if marked + purged > 0 {
+        db.mark_write();
+    }
+    Ok(())
+}
+
+/// Accepts the code as shown on screen, with or without the space.
+fn parse_code(code: &str) -> HmiResult<i32> {
+    let digits: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(HmiError::InvalidCode(code.to_string()));
+    }
+    digits
+        .parse()
+        .map_err(|_| HmiError::InvalidCode(code.to_string()))
+}
+
+/// Columns the `users` and `labs` tables may not have are read through
+/// `to_jsonb`, so they come back empty instead of failing the query.
+pub(super) async fn load_user(
+    tx: &Transaction<'_>,
+    user_id: &str,
+) -> Result<Option<HmiUser>, DbError> {
+    let row = tx
+        .query_opt(
+            "SELECT u.id::text, u.name, to_jsonb(u) ->> 'email', to_jsonb(u) ->> 'image',
+                    to_jsonb(u) ->> 'tel', (to_jsonb(u) ->> 'last_active_at')::timestamptz,
+                    COALESCE(
+                        jsonb_agg(jsonb_build_object('key', r.key, 'name', r.name))
+                            FILTER (WHERE r.id IS NOT NULL),
+                        '[]'
+                    )
+             FROM users u
+             LEFT JOIN user_roles ur ON ur.user_id = u.id
+             LEFT JOIN roles r ON r.id = ur.role_id
+             WHERE u.id::text = $1 AND u.deleted_at IS NULL
+             GROUP BY u.id",
+            &[&user_id],
+        )
+        .await?;
+    Ok(row.map(|row| {
+        let roles: JsonValue = row.get(6);
+        HmiUser {
+            id: row.get(0),
+            name: row.get(1),
+            email: row.get(2),
+            tel: row.get(4),
+            image: row.get(3),
+            last_active_at: row.get(5),
+            roles: roles
+                .as_array()
+                .map(|roles| {
+                    roles
+                        .iter()
+                        .filter_map(|role| {
+                            Some(HmiRole {
+                                key: role.get("key")?.as_str()?.to_string(),
+                                name: role
+                                    .get("name")
+                                    .and_then(JsonValue::as_str)
+                                    .map(String::from),
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }))
+}
+
+async fn load_lab(tx: &Transaction<'_>, lab_id: &str) -> Result<Option<HmiLab>, DbError> {
+    let row = tx
+        .query_opt(
+            "SELECT l.id::text, to_jsonb(l) ->> 'name', to_jsonb(l) ->> 'room', to_jsonb(l) ->> 'branch'
+             FROM labs l
+             WHERE l.id::text = $1 AND l.deleted_at IS NULL",
+            &[&lab_id],
+        )
+        .await?;
+    Ok(row.map(|row| HmiLab {
+        id: row.get(0),
+        name: row.get(1),
+        room: row.get(2),
+        branch: row.get(3),
+    }))
+}
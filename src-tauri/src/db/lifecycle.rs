@@ -0,0 +1,336 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::Transaction;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use tokio_postgres::types::{FromSql, Type};
+
+use super::connection::{Database, DbError, DbResult};
+
+/// Mirrors the `device_status` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceStatus {
+    Healthy,
+    Broken,
+    Discarded,
+    Lost,
+    Assessing,
+    Shipping,
+    Maintaining,
+    Borrowing,
+}
+
+impl DeviceStatus {
+    pub const ALL: [DeviceStatus; 8] = [
+        DeviceStatus::Healthy,
+        DeviceStatus::Broken,
+        DeviceStatus::Discarded,
+        DeviceStatus::Lost,
+        DeviceStatus::Assessing,
+        DeviceStatus::Shipping,
+        DeviceStatus::Maintaining,
+        DeviceStatus::Borrowing,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DeviceStatus::Healthy => "healthy",
+            DeviceStatus::Broken => "broken",
+            DeviceStatus::Discarded => "discarded",
+            DeviceStatus::Lost => "lost",
+            DeviceStatus::Assessing => "assessing",
+            DeviceStatus::Shipping => "shipping",
+            DeviceStatus::Maintaining => "maintaining",
+            DeviceStatus::Borrowing => "borrowing",
+        }
+    }
+}
+
+impl fmt::Display for DeviceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown device status {0:?}")]
+pub struct UnknownStatus(pub String);
+
+impl FromStr for DeviceStatus {
+    type Err = UnknownStatus;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DeviceStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == s)
+            .ok_or_else(|| UnknownStatus(s.to_string()))
+    }
+}
+
+/// Reads a `device_status` column, or one cast to text. A status this
+/// build does not know is a conversion error rather than a panic.
+impl<'a> FromSql<'a> for DeviceStatus {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(std::str::from_utf8(raw)?.parse()?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        ty.name() == "device_status" || *ty == Type::TEXT
+    }
+}
+
+/// Mirrors the `activity_type` enum; every status change happens as part
+/// of one of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityType {
+    Borrow,
+    Return,
+    Maintenance,
+    Shipment,
+    Assessment,
+}
+
+impl ActivityType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ActivityType::Borrow => "borrow",
+            ActivityType::Return => "return",
+            ActivityType::Maintenance => "maintenance",
+            ActivityType::Shipment => "shipment",
+            ActivityType::Assessment => "assessment",
+        }
+    }
+}
+
+impl fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+use DeviceStatus::{Assessing, Borrowing, Broken, Discarded, Healthy, Lost, Maintaining, Shipping};
+
+/// The moves each activity may make, as `(activity, from, to)`. Moves back
+/// to the previous status, e.g. when a job is cancelled, are listed like
+/// any other. `discarded` is final.
+const TRANSITIONS: &[(ActivityType, &[DeviceStatus], &[DeviceStatus])] = &[
+    (ActivityType::Borrow, &[Healthy], &[Borrowing]),
+    (ActivityType::Return, &[Borrowing], &[Healthy, Broken]),
+    (
+        ActivityType::Maintenance,
+        &[Healthy, Broken],
+        &[Maintaining],
+    ),
+    (
+        ActivityType::Maintenance,
+        &[Maintaining],
+        &[Healthy, Broken, Discarded],
+    ),
+    (ActivityType::Shipment, &[Healthy, Broken], &[Shipping]),
+    (
+        ActivityType::Shipment,
+        &[Shipping],
+        &[Healthy, Broken, Lost],
+    ),
+    (
+        ActivityType::Assessment,
+        &[Healthy, Broken, Lost],
+        &[Assessing],
+    ),
+    (
+        ActivityType::Assessment,
+        &[Assessing],
+        &[Healthy, Broken, Lost, Discarded],
+    ),
+    // Devices an audit expected but never saw.
+    (ActivityType::Assessment, &[Healthy, Broken], &[Lost]),
+];
+
+/// Statuses `activity` may move a device in status `from` to.
+pub fn targets(activity: ActivityType, from: DeviceStatus) -> Vec<DeviceStatus> {
+    TRANSITIONS
+        .iter()
+        .filter(|(a, sources, _)| *a == activity && sources.contains(&from))
+        .flat_map(|(_, _, targets)| targets.iter().copied())
+        .collect()
+}
+
+/// A status change that [`TRANSITIONS`] does not allow.
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+#[serde(rename_all = "camelCase")]
+#[error("{activity} cannot move device {device_id} from {from} to {to}")]
+pub struct IllegalTransition {
+    pub device_id: String,
+    pub activity: ActivityType,
+    pub from: DeviceStatus,
+    pub to: DeviceStatus,
+    /// What `activity` could have moved the device to instead.
+    pub allowed: Vec<DeviceStatus>,
+}
+
+/// Checks one move.
+pub fn check(
+    activity: ActivityType,
+    device_id: &str,
+    from: DeviceStatus,
+    to: DeviceStatus,
+) -> Result<(), IllegalTransition> {
+    let allowed = targets(activity, from);
+    if allowed.contains(&to) {
+        return Ok(());
+    }
+    Err(IllegalTransition {
+        device_id: device_id.to_string(),
+        activity,
+        from,
+        to,
+        allowed,
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LifecycleError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error("{}", .0.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "))]
+    Illegal(Vec<IllegalTransition>),
+    /// The device's status was not the `from` given; callers must lock the
+    /// devices before reading their status.
+    #[error("Device {0} changed status while it was being updated")]
+    Changed(String),
+}
+
+impl From<tokio_postgres::Error> for LifecycleError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        LifecycleError::Db(error.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Transition {
+    pub device_id: String,
+    pub from: DeviceStatus,
+    pub to: DeviceStatus,
+}
+
+/// Tables only written through [`apply`], so every status change is
+/// checked and recorded. Generic write commands refuse them.
+pub const GUARDED_TABLES: [&str; 2] = ["public.devices", "public.device_status_changes"];
+
+/// Who made a set of changes and why, kept with each of them.
+#[derive(Debug, Clone, Copy)]
+pub struct ChangeContext<'a> {
+    pub activity: ActivityType,
+    pub activity_id: Option<&'a str>,
+    pub actor_id: Option<&'a str>,
+    pub reason: Option<&'a str>,
+}
+
+/// Moves the devices to their new statuses and records each change in
+/// `device_status_changes`. Nothing is written unless every move is
+/// allowed. The devices must already be locked by `tx`, with `from` read
+/// under that lock.
+pub async fn apply(
+    tx: &Transaction<'_>,
+    context: ChangeContext<'_>,
+    transitions: &[Transition],
+) -> Result<(), LifecycleError> {
+    let illegal: Vec<IllegalTransition> = transitions
+        .iter()
+        .filter_map(|t| check(context.activity, &t.device_id, t.from, t.to).err())
+        .collect();
+    if !illegal.is_empty() {
+        return Err(LifecycleError::Illegal(illegal));
+    }
+
+    let rows: JsonValue = transitions
+        .iter()
+        .map(|t| {
+            json!({
+                "device_id": t.device_id,
+                "from_status": t.from,
+                "to_status": t.to,
+            })
+        })
+        .collect();
+    let changed = tx
+        .query(
+            "WITH changes AS (
+                SELECT * FROM json_populate_recordset(NULL::device_status_changes, $1)
+             ),
+             updated AS (
+                UPDATE devices d SET status = c.to_status
+                FROM changes c
+                WHERE d.id::text = c.device_id AND d.status = c.from_status
+                RETURNING d.id::text AS device_id, c.from_status, c.to_status
+             ),
+             recorded AS (
+                INSERT INTO device_status_changes (
+                    device_id, from_status, to_status, activity_type, activity_id, actor_id, reason
+                )
+                SELECT device_id, from_status, to_status, $2::text::activity_type, $3, $4, $5
+                FROM updated
+             )
+             SELECT device_id FROM updated",
+            &[
+                &rows,
+                &context.activity.as_str(),
+                &context.activity_id,
+                &context.actor_id,
+                &context.reason,
+            ],
+        )
+        .await?;
+
+    let updated: Vec<String> = changed.iter().map(|row| row.get(0)).collect();
+    if let Some(missed) = transitions.iter().find(|t| !updated.contains(&t.device_id)) {
+        return Err(LifecycleError::Changed(missed.device_id.clone()));
+    }
+    Ok(())
+}
+
+/// One recorded status change.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusChange {
+    pub from: String,
+    pub to: String,
+    pub activity: String,
+    pub activity_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub reason: Option<String>,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// The device's status changes, newest first.
+pub async fn history(db: &Database, device_id: &str, limit: i64) -> DbResult<Vec<StatusChange>> {
+    let client = db.get_client().await?;
+    let rows = db
+        .query_cached(
+            &client,
+            "SELECT from_status::text, to_status::text, activity_type::text,
+                    activity_id, actor_id, reason, changed_at
+             FROM device_status_changes
+             WHERE device_id = $1
+             ORDER BY changed_at DESC, id DESC
+             LIMIT $2",
+            &[&device_id, &limit],
+        )
+        .await?;
+    Ok(rows
+        .iter()
+        .map(|row| StatusChange {
+            from: row.get(0),
+            to: row.get(1),
+            activity: row.get(2),
+            activity_id: row.get(3),
+            actor_id: row.get(4),
+            reason: row.get(5),
+            changed_at: row.get(6),
+        })
+        .collect())
+}
@@ -0,0 +1,216 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, watch, Notify};
+use tokio_postgres::{AsyncMessage, Notification};
+
+use super::connection::{Database, DbResult};
+use super::supervisor::{Backoff, DbStatus};
+
+/// Event carrying every [`DbNotification`] to the frontend.
+pub const NOTIFICATION_EVENT: &str = "db://notification";
+
+/// Postgres truncates identifiers to this many bytes.
+const MAX_CHANNEL_LEN: usize = 63;
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid channel name {0:?}: {1}")]
+pub struct InvalidChannel(pub String, &'static str);
+
+/// Channel names are sent quoted, so anything Postgres accepts as an
+/// identifier is allowed.
+pub fn check_channel(channel: &str) -> Result<(), InvalidChannel> {
+    let reason = if channel.is_empty() {
+        "empty"
+    } else if channel.len() > MAX_CHANNEL_LEN {
+        "longer than 63 bytes"
+    } else if channel.contains('\0') {
+        "contains a NUL byte"
+    } else {
+        return Ok(());
+    };
+    Err(InvalidChannel(channel.to_string(), reason))
+}
+
+/// A `NOTIFY` received on a subscribed channel. Payloads that are valid
+/// JSON are passed on parsed, anything else as a string.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbNotification {
+    pub channel: String,
+    pub payload: JsonValue,
+    /// Backend process that sent it.
+    pub process_id: i32,
+    pub received_at: DateTime<Utc>,
+}
+
+impl From<Notification> for DbNotification {
+    fn from(notification: Notification) -> Self {
+        let payload = match notification.payload() {
+            "" => JsonValue::Null,
+            raw => serde_json::from_str(raw).unwrap_or_else(|_| JsonValue::String(raw.into())),
+        };
+        DbNotification {
+            channel: notification.channel().to_string(),
+            payload,
+            process_id: notification.process_id(),
+            received_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Subscriptions {
+    /// Subscribers per channel from `subscribe`.
+    counts: BTreeMap<String, usize>,
+    /// Channels from the configuration, listened to regardless of counts.
+    configured: BTreeSet<String>,
+}
+
+impl Subscriptions {
+    fn wanted(&self) -> BTreeSet<String> {
+        self.configured
+            .iter()
+            .chain(self.counts.keys())
+            .cloned()
+            .collect()
+    }
+}
+
+/// The set of channels the listener connection should be subscribed to.
+/// Cheap to clone; [`run`] picks up changes made through any clone.
+#[derive(Clone, Default)]
+pub struct Listener {
+    subscriptions: Arc<Mutex<Subscriptions>>,
+    changed: Arc<Notify>,
+}
+
+impl Listener {
+    /// `configured` comes from `listen_channels` and is already checked.
+    pub fn new(configured: &[String]) -> Self {
+        let listener = Listener::default();
+        listener.subscriptions.lock().unwrap().configured = configured.iter().cloned().collect();
+        listener
+    }
+
+    /// Adds a subscriber to `channel`. Each call needs a matching
+    /// [`Listener::unsubscribe`]. Returns the channels now listened to.
+    pub fn subscribe(&self, channel: &str) -> Result<Vec<String>, InvalidChannel> {
+        check_channel(channel)?;
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        *subscriptions.counts.entry(channel.to_string()).or_default() += 1;
+        self.changed.notify_one();
+        Ok(subscriptions.wanted().into_iter().collect())
+    }
+
+    /// Removes a subscriber from `channel`; the channel is dropped with the
+    /// last one unless it is configured.
+    pub fn unsubscribe(&self, channel: &str) -> Vec<String> {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        if let Some(count) = subscriptions.counts.get_mut(channel) {
+            *count -= 1;
+            if *count == 0 {
+                subscriptions.counts.remove(channel);
+                self.changed.notify_one();
+            }
+        }
+        subscriptions.wanted().into_iter().collect()
+    }
+
+    fn wanted(&self) -> BTreeSet<String> {
+        self.subscriptions.lock().unwrap().wanted()
+    }
+}
+
+/// Keeps a dedicated connection listening on the channels of `listener`
+/// and calls `on_notification` for everything that arrives. Waits while the
+/// database is not [`DbStatus::Available`], and reconnects with backoff and
+/// subscribes again when the connection drops. Runs until the status sender
+/// is dropped.
+pub async fn run<F>(
+    db: Database,
+    listener: Listener,
+    mut status: watch::Receiver<DbStatus>,
+    on_notification: F,
+) where
+    F: Fn(DbNotification) + Send + 'static,
+{
+    let mut backoff = Backoff::default();
+
+    loop {
+        if status
+            .wait_for(|s| matches!(s, DbStatus::Available))
+            .await
+            .is_err()
+        {
+            return;
+        }
+
+        let (messages_tx, mut messages) = mpsc::unbounded_channel();
+        let client = match db.connect_unpooled(messages_tx).await {
+            Ok(client) => client,
+            Err(e) => {
+                let delay = backoff.next_delay();
+                eprintln!(
+                    "Warning: Notification listener failed to connect ({}), retrying in {:?}",
+                    e, delay
+                );
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+        };
+
+        let mut active = BTreeSet::new();
+        if let Err(e) = resubscribe(&client, &mut active, listener.wanted()).await {
+            eprintln!("Warning: Failed to subscribe to channels: {}", e);
+            tokio::time::sleep(backoff.next_delay()).await;
+            continue;
+        }
+        backoff = Backoff::default();
+
+        loop {
+            tokio::select! {
+                message = messages.recv() => match message {
+                    Some(AsyncMessage::Notification(notification)) => {
+                        on_notification(notification.into());
+                    }
+                    Some(_) => {}
+                    None => break,
+                },
+                _ = listener.changed.notified() => {
+                    if let Err(e) = resubscribe(&client, &mut active, listener.wanted()).await {
+                        eprintln!("Warning: Failed to update channel subscriptions: {}", e);
+                        break;
+                    }
+                }
+            }
+        }
+        eprintln!("Warning: Notification listener disconnected, reconnecting");
+    }
+}
+
+/// Brings the connection's subscriptions from `active` to `wanted`.
+async fn resubscribe(
+    client: &tokio_postgres::Client,
+    active: &mut BTreeSet<String>,
+    wanted: BTreeSet<String>,
+) -> DbResult<()> {
+    let mut statements = String::new();
+    for channel in wanted.difference(active) {
+        statements.push_str(&format!("LISTEN {};", quote_ident(channel)));
+    }
+    for channel in active.difference(&wanted) {
+        statements.push_str(&format!("UNLISTEN {};", quote_ident(channel)));
+    }
+    if !statements.is_empty() {
+        client.batch_execute(&statements).await?;
+    }
+    *active = wanted;
+    Ok(())
+}
+
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
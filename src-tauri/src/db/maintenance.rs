@@ -0,0 +1,775 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::Transaction;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashSet;
+
+use super::connection::{Database, DbError};
+use super::lifecycle::{
+    self, ActivityType, ChangeContext, DeviceStatus, LifecycleError, Transition,
+};
+use super::validation::{summarize, FieldError};
+
+/// Outcomes that need a note saying why: a device that could not be
+/// repaired, or is being discarded.
+const NOTE_REQUIRED: &[DeviceStatus] = &[DeviceStatus::Broken, DeviceStatus::Discarded];
+
+/// Role keys, any of which lets a user be assigned maintenance jobs: the
+/// lab's staff.
+const TECHNICIAN_ROLES: &[&str] = &["lab_admin", "sysadmin"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error(transparent)]
+    Lifecycle(#[from] LifecycleError),
+    #[error("Maintenance {0} not found")]
+    NotFound(String),
+    #[error("Maintenance {id} is already {status}")]
+    Closed { id: String, status: String },
+    #[error("Maintenance {0} does not belong to this lab")]
+    OtherLab(String),
+    #[error("{}", summarize(.0))]
+    Invalid(Vec<FieldError>),
+}
+
+impl From<tokio_postgres::Error> for MaintenanceError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        MaintenanceError::Db(error.into())
+    }
+}
+
+pub type MaintenanceResult<T> = Result<T, MaintenanceError>;
+
+/// A new maintenance job in the session's lab. `create_maintenance` assigns
+/// it to the signed-in user when no technician is given.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMaintenance {
+    #[serde(default)]
+    pub technician_id: String,
+    #[serde(default)]
+    pub lab_id: String,
+    #[serde(default)]
+    pub devices: Vec<MaintainedDevice>,
+    pub note: Option<String>,
+}
+
+/// A device and the outcome expected of its repair: `healthy` when
+/// repaired, `broken` when it could not be, `discarded` when it is beyond
+/// repair.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintainedDevice {
+    pub id: String,
+    pub outcome: DeviceStatus,
+}
+
+/// A device's final outcome, given when the job is completed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceOutcome {
+    pub id: String,
+    pub outcome: DeviceStatus,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Maintenance {
+    pub id: String,
+    pub lab_id: String,
+    pub technician_id: Option<String>,
+    pub status: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Seconds from creation to completion or cancellation.
+    pub duration_secs: Option<i64>,
+    pub devices: Vec<MaintenanceDevice>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceDevice {
+    pub device_id: String,
+    /// The device's status when it was added, restored if the job is
+    /// cancelled.
+    pub prev_status: Option<DeviceStatus>,
+    /// The outcome, applied when the job completes.
+    pub after_status: Option<DeviceStatus>,
+}
+
+/// The job row, locked.
+struct OpenJob {
+    id: String,
+    technician_id: Option<String>,
+}
+
+/// Starts a maintenance job in `lab_id`, assigned to the technician, and
+/// moves the devices to `maintaining`.
+pub async fn create_maintenance(
+    db: &Database,
+    job: &NewMaintenance,
+) -> MaintenanceResult<Maintenance> {
+    let mut errors = Vec::new();
+    for (field, value) in [("technicianId", &job.technician_id), ("labId", &job.lab_id)] {
+        if value.trim().is_empty() {
+            errors.push(FieldError::new(field, "Must not be empty"));
+        }
+    }
+    errors.extend(check_devices(&job.devices));
+    if !errors.is_empty() {
+        return Err(MaintenanceError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    check_technician(&tx, &job.technician_id).await?;
+
+    let id: String = tx
+        .query_one(
+            "INSERT INTO activities (type, note) VALUES ('maintenance'::activity_type, $1)
+             RETURNING id::text",
+            &[&job.note],
+        )
+        .await?
+        .get(0);
+    let row = json!({
+        "id": id,
+        "lab_id": job.lab_id,
+        "maintainer_id": job.technician_id,
+        "status": "maintaining",
+    });
+    tx.execute(
+        "INSERT INTO maintenances (id, lab_id, maintainer_id, status)
+         SELECT id, lab_id, maintainer_id, status
+         FROM json_populate_record(NULL::maintenances, $1)",
+        &[&row],
+    )
+    .await?;
+
+    let open = OpenJob {
+        id,
+        technician_id: Some(job.technician_id.clone()),
+    };
+    add_devices(&tx, &open, &job.devices).await?;
+    let created = load(&tx, &open.id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(created)
+}
+
+/// Adds devices to a job in progress. A device that is already in the job
+/// only has its outcome updated.
+pub async fn add_maintenance_devices(
+    db: &Database,
+    maintenance_id: &str,
+    lab_id: &str,
+    devices: &[MaintainedDevice],
+) -> MaintenanceResult<Maintenance> {
+    let errors = check_devices(devices);
+    if !errors.is_empty() {
+        return Err(MaintenanceError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let job = lock_open(&tx, maintenance_id, lab_id).await?;
+    add_devices(&tx, &job, devices).await?;
+    let updated = load(&tx, maintenance_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(updated)
+}
+
+/// Changes the expected outcome of devices already in the job.
+pub async fn update_maintenance_outcomes(
+    db: &Database,
+    maintenance_id: &str,
+    lab_id: &str,
+    devices: &[MaintainedDevice],
+) -> MaintenanceResult<Maintenance> {
+    let mut errors = check_devices(devices);
+    if !errors.is_empty() {
+        return Err(MaintenanceError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    lock_open(&tx, maintenance_id, lab_id).await?;
+
+    let rows: JsonValue = devices
+        .iter()
+        .map(|d| json!({ "device_id": d.id, "after_status": d.outcome }))
+        .collect();
+    let updated = set_outcomes(&tx, maintenance_id, &rows).await?;
+    for (index, device) in devices.iter().enumerate() {
+        if !updated.contains(&device.id) {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} is not in this maintenance job", device.id),
+            ));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(MaintenanceError::Invalid(errors));
+    }
+
+    let job = load(&tx, maintenance_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(job)
+}
+
+/// Takes a device out of the job and gives it back the status it had when
+/// it was added.
+pub async fn remove_maintenance_device(
+    db: &Database,
+    maintenance_id: &str,
+    lab_id: &str,
+    device_id: &str,
+) -> MaintenanceResult<Maintenance> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let job = lock_open(&tx, maintenance_id, lab_id).await?;
+
+    let Some(row) = tx
+        .query_opt(
+            "DELETE FROM maintenances_devices
+             WHERE maintaining_id::text = $1 AND device_id::text = $2
+             RETURNING prev_status",
+            &[&maintenance_id, &device_id],
+        )
+        .await?
+    else {
+        return Err(MaintenanceError::Invalid(vec![FieldError::new(
+            "deviceId",
+            format!("Device {} is not in this maintenance job", device_id),
+        )]));
+    };
+    let prev: Option<DeviceStatus> = row.try_get(0)?;
+
+    let locked = lock_devices(&tx, &[device_id]).await?;
+    let transitions = restore(&locked, &[(device_id.to_string(), prev)]);
+    let reason = format!("Removed from maintenance {}", maintenance_id);
+    move_devices(&tx, &job, &reason, &transitions).await?;
+
+    let updated = load(&tx, maintenance_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(updated)
+}
+
+/// Hands a job in progress over to another technician.
+pub async fn assign_maintenance_technician(
+    db: &Database,
+    maintenance_id: &str,
+    lab_id: &str,
+    technician_id: &str,
+) -> MaintenanceResult<Maintenance> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    lock_open(&tx, maintenance_id, lab_id).await?;
+    check_technician(&tx, technician_id).await?;
+
+    tx.execute(
+        "UPDATE maintenances SET maintainer_id = $2 WHERE id::text = $1",
+        &[&maintenance_id, &technician_id],
+    )
+    .await?;
+
+    let job = load(&tx, maintenance_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(job)
+}
+
+/// Finishes the job: `outcomes` overrides the outcome recorded for the
+/// listed devices, and every device in the job takes its outcome. Devices
+/// left `broken` or `discarded` need a note, which is added to the
+/// activity's note after `note`.
+pub async fn complete_maintenance(
+    db: &Database,
+    maintenance_id: &str,
+    lab_id: &str,
+    outcomes: &[MaintenanceOutcome],
+    note: Option<&str>,
+) -> MaintenanceResult<Maintenance> {
+    let conditions = lifecycle::targets(ActivityType::Maintenance, DeviceStatus::Maintaining);
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+    for (index, device) in outcomes.iter().enumerate() {
+        if !seen.insert(device.id.as_str()) {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} is listed more than once", device.id),
+            ));
+        }
+        if !conditions.contains(&device.outcome) {
+            errors.push(outcome_error(index, &conditions, device.outcome));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(MaintenanceError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let job = lock_open(&tx, maintenance_id, lab_id).await?;
+
+    if !outcomes.is_empty() {
+        let rows: JsonValue = outcomes
+            .iter()
+            .map(|d| json!({ "device_id": d.id, "after_status": d.outcome }))
+            .collect();
+        let updated = set_outcomes(&tx, maintenance_id, &rows).await?;
+        for (index, device) in outcomes.iter().enumerate() {
+            if !updated.contains(&device.id) {
+                errors.push(FieldError::new(
+                    format!("devices[{}]", index),
+                    format!("Device {} is not in this maintenance job", device.id),
+                ));
+            }
+        }
+    }
+
+    let rows = tx
+        .query(
+            "SELECT md.device_id::text, md.prev_status, md.after_status, d.status
+             FROM maintenances_devices md
+             JOIN devices d ON d.id = md.device_id
+             WHERE md.maintaining_id::text = $1
+             ORDER BY md.device_id
+             FOR UPDATE OF d",
+            &[&maintenance_id],
+        )
+        .await?;
+    let mut transitions = Vec::new();
+    let mut notes = Vec::new();
+    for row in &rows {
+        let device_id: String = row.get(0);
+        let prev: Option<DeviceStatus> = row.try_get(1)?;
+        let after: Option<DeviceStatus> = row.try_get(2)?;
+        let current: DeviceStatus = row.try_get(3)?;
+        let outcome = after.or(prev).unwrap_or(DeviceStatus::Healthy);
+
+        let given = outcomes.iter().position(|d| d.id == device_id);
+        let device_note = given
+            .and_then(|index| outcomes[index].note.as_deref())
+            .filter(|n| !n.trim().is_empty());
+        match device_note {
+            Some(device_note) => notes.push(format!("{}: {}", device_id, device_note)),
+            None if NOTE_REQUIRED.contains(&outcome) => {
+                let field = match given {
+                    Some(index) => format!("devices[{}].note", index),
+                    None => "devices".to_string(),
+                };
+                errors.push(FieldError::new(
+                    field,
+                    format!("Device {} is {} and needs a note", device_id, outcome),
+                ));
+            }
+            None => {}
+        }
+
+        if current == DeviceStatus::Maintaining {
+            transitions.push(Transition {
+                device_id,
+                from: current,
+                to: outcome,
+            });
+        }
+    }
+    if !errors.is_empty() {
+        return Err(MaintenanceError::Invalid(errors));
+    }
+
+    let reason = format!("Maintenance {} completed", maintenance_id);
+    move_devices(&tx, &job, &reason, &transitions).await?;
+
+    let mut lines: Vec<String> = note
+        .filter(|n| !n.trim().is_empty())
+        .map(str::to_string)
+        .into_iter()
+        .collect();
+    lines.extend(notes);
+    let note = (!lines.is_empty()).then(|| lines.join("\n"));
+    close(&tx, maintenance_id, "completed", note.as_deref()).await?;
+
+    let completed = load(&tx, maintenance_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(completed)
+}
+
+/// Abandons the job and gives every device in it back the status it had
+/// when it was added.
+pub async fn cancel_maintenance(
+    db: &Database,
+    maintenance_id: &str,
+    lab_id: &str,
+) -> MaintenanceResult<Maintenance> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let job = lock_open(&tx, maintenance_id, lab_id).await?;
+
+    let rows = tx
+        .query(
+            "SELECT device_id::text, prev_status FROM maintenances_devices
+             WHERE maintaining_id::text = $1",
+            &[&maintenance_id],
+        )
+        .await?;
+    let mut previous = Vec::new();
+    for row in &rows {
+        previous.push((row.get::<_, String>(0), row.try_get(1)?));
+    }
+    let ids: Vec<&str> = previous.iter().map(|(id, _)| id.as_str()).collect();
+    let locked = lock_devices(&tx, &ids).await?;
+    let transitions = restore(&locked, &previous);
+
+    let reason = format!("Maintenance {} cancelled", maintenance_id);
+    move_devices(&tx, &job, &reason, &transitions).await?;
+    close(&tx, maintenance_id, "cancelled", None).await?;
+
+    let cancelled = load(&tx, maintenance_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(cancelled)
+}
+
+/// Checks that need no database access.
+fn check_devices(devices: &[MaintainedDevice]) -> Vec<FieldError> {
+    let conditions = lifecycle::targets(ActivityType::Maintenance, DeviceStatus::Maintaining);
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+    for (index, device) in devices.iter().enumerate() {
+        if !seen.insert(device.id.as_str()) {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} is listed more than once", device.id),
+            ));
+        }
+        if !conditions.contains(&device.outcome) {
+            errors.push(outcome_error(index, &conditions, device.outcome));
+        }
+    }
+    errors
+}
+
+fn outcome_error(index: usize, allowed: &[DeviceStatus], outcome: DeviceStatus) -> FieldError {
+    FieldError::new(
+        format!("devices[{}].outcome", index),
+        format!(
+            "Must be one of {}, not {}",
+            allowed
+                .iter()
+                .map(|c| c.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            outcome
+        ),
+    )
+}
+
+/// Checks that the user exists and has one of [`TECHNICIAN_ROLES`].
+async fn check_technician(tx: &Transaction<'_>, technician_id: &str) -> MaintenanceResult<()> {
+    let row = tx
+        .query_opt(
+            "SELECT EXISTS (
+                SELECT 1 FROM user_roles ur
+                JOIN roles r ON r.id = ur.role_id
+                WHERE ur.user_id = u.id AND r.key = ANY($2)
+             )
+             FROM users u
+             WHERE u.id::text = $1 AND u.deleted_at IS NULL",
+            &[&technician_id, &TECHNICIAN_ROLES],
+        )
+        .await?;
+    let message = match row {
+        None => format!("User {} does not exist", technician_id),
+        Some(row) if !row.get::<_, bool>(0) => {
+            format!(
+                "User {} cannot maintain devices; that needs one of the roles {}",
+                technician_id,
+                TECHNICIAN_ROLES.join(", ")
+            )
+        }
+        Some(_) => return Ok(()),
+    };
+    Err(MaintenanceError::Invalid(vec![FieldError::new(
+        "technicianId",
+        message,
+    )]))
+}
+
+/// Locks the job and checks that it is still in progress and belongs to
+/// `lab_id`.
+async fn lock_open(
+    tx: &Transaction<'_>,
+    maintenance_id: &str,
+    lab_id: &str,
+) -> MaintenanceResult<OpenJob> {
+    let Some(row) = tx
+        .query_opt(
+            "SELECT id::text, maintainer_id::text, status::text, lab_id::text
+             FROM maintenances
+             WHERE id::text = $1
+             FOR UPDATE",
+            &[&maintenance_id],
+        )
+        .await?
+    else {
+        return Err(MaintenanceError::NotFound(maintenance_id.to_string()));
+    };
+    let status: String = row.get(2);
+    if status != "maintaining" {
+        return Err(MaintenanceError::Closed {
+            id: maintenance_id.to_string(),
+            status,
+        });
+    }
+    if row.get::<_, String>(3) != lab_id {
+        return Err(MaintenanceError::OtherLab(maintenance_id.to_string()));
+    }
+    Ok(OpenJob {
+        id: row.get(0),
+        technician_id: row.get(1),
+    })
+}
+
+/// `(id, status)` of the given devices that exist and are not deleted,
+/// locked.
+async fn lock_devices(
+    tx: &Transaction<'_>,
+    ids: &[&str],
+) -> MaintenanceResult<Vec<(String, DeviceStatus)>> {
+    let rows = tx
+        .query(
+            "SELECT id::text, status FROM devices
+             WHERE id::text = ANY($1) AND deleted_at IS NULL
+             FOR UPDATE",
+            &[&ids],
+        )
+        .await?;
+    rows.iter()
+        .map(|row| Ok((row.get(0), row.try_get(1)?)))
+        .collect()
+}
+
+/// Sets `after_status` from `rows` of `{device_id, after_status}` and
+/// returns the devices that were in the job.
+async fn set_outcomes(
+    tx: &Transaction<'_>,
+    maintenance_id: &str,
+    rows: &JsonValue,
+) -> MaintenanceResult<Vec<String>> {
+    Ok(tx
+        .query(
+            "UPDATE maintenances_devices md
+             SET after_status = v.after_status
+             FROM json_populate_recordset(NULL::maintenances_devices, $1) v
+             WHERE md.maintaining_id::text = $2 AND md.device_id = v.device_id
+             RETURNING md.device_id::text",
+            &[rows, &maintenance_id],
+        )
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect())
+}
+
+async fn add_devices(
+    tx: &Transaction<'_>,
+    job: &OpenJob,
+    devices: &[MaintainedDevice],
+) -> MaintenanceResult<()> {
+    if devices.is_empty() {
+        return Ok(());
+    }
+    let ids: Vec<&str> = devices.iter().map(|d| d.id.as_str()).collect();
+    let locked = lock_devices(tx, &ids).await?;
+    let present: Vec<String> = tx
+        .query(
+            "SELECT device_id::text FROM maintenances_devices
+             WHERE maintaining_id::text = $1 AND device_id::text = ANY($2)",
+            &[&job.id, &ids],
+        )
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+
+    let mut errors = Vec::new();
+    let mut transitions = Vec::new();
+    let mut inserted = Vec::new();
+    let mut updated = Vec::new();
+    for (index, device) in devices.iter().enumerate() {
+        let mut row = json!({
+            "maintaining_id": job.id,
+            "device_id": device.id,
+            "after_status": device.outcome,
+        });
+        if present.contains(&device.id) {
+            updated.push(row);
+            continue;
+        }
+        let Some((_, status)) = locked.iter().find(|(id, _)| *id == device.id) else {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} does not exist", device.id),
+            ));
+            continue;
+        };
+        if let Err(e) = lifecycle::check(
+            ActivityType::Maintenance,
+            &device.id,
+            *status,
+            DeviceStatus::Maintaining,
+        ) {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                e.to_string(),
+            ));
+            continue;
+        }
+        row["prev_status"] = json!(status);
+        inserted.push(row);
+        transitions.push(Transition {
+            device_id: device.id.clone(),
+            from: *status,
+            to: DeviceStatus::Maintaining,
+        });
+    }
+    if !errors.is_empty() {
+        return Err(MaintenanceError::Invalid(errors));
+    }
+
+    if !inserted.is_empty() {
+        tx.execute(
+            "INSERT INTO maintenances_devices (prev_status, after_status, maintaining_id, device_id)
+             SELECT prev_status, after_status, maintaining_id, device_id
+             FROM json_populate_recordset(NULL::maintenances_devices, $1)",
+            &[&JsonValue::Array(inserted)],
+        )
+        .await?;
+    }
+    if !updated.is_empty() {
+        set_outcomes(tx, &job.id, &JsonValue::Array(updated)).await?;
+    }
+
+    let reason = format!("Added to maintenance {}", job.id);
+    move_devices(tx, job, &reason, &transitions).await
+}
+
+/// Moves the locked devices that are still `maintaining` back to their
+/// previous status, `healthy` if none was recorded.
+fn restore(
+    locked: &[(String, DeviceStatus)],
+    previous: &[(String, Option<DeviceStatus>)],
+) -> Vec<Transition> {
+    previous
+        .iter()
+        .filter_map(|(device_id, prev)| {
+            let (_, current) = locked.iter().find(|(id, _)| id == device_id)?;
+            (*current == DeviceStatus::Maintaining).then(|| Transition {
+                device_id: device_id.clone(),
+                from: *current,
+                to: prev.unwrap_or(DeviceStatus::Healthy),
+            })
+        })
+        .collect()
+}
+
+async fn move_devices(
+    tx: &Transaction<'_>,
+    job: &OpenJob,
+    reason: &str,
+    transitions: &[Transition],
+) -> MaintenanceResult<()> {
+    if transitions.is_empty() {
+        return Ok(());
+    }
+    lifecycle::apply(
+        tx,
+        ChangeContext {
+            activity: ActivityType::Maintenance,
+            activity_id: Some(&job.id),
+            actor_id: job.technician_id.as_deref(),
+            reason: Some(reason),
+        },
+        transitions,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Ends the job, adding `note` on a new line after the note it was started
+/// with.
+async fn close(
+    tx: &Transaction<'_>,
+    maintenance_id: &str,
+    status: &str,
+    note: Option<&str>,
+) -> MaintenanceResult<()> {
+    tx.execute(
+        "UPDATE maintenances
+         SET status = $2::text::maintenance_status, finished_at = now()
+         WHERE id::text = $1",
+        &[&maintenance_id, &status],
+    )
+    .await?;
+    if let Some(note) = note {
+        tx.execute(
+            "UPDATE activities SET note = concat_ws(E'\\n', note, $2::text) WHERE id::text = $1",
+            &[&maintenance_id, &note],
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn load(tx: &Transaction<'_>, maintenance_id: &str) -> MaintenanceResult<Maintenance> {
+    let row = tx
+        .query_one(
+            "SELECT m.id::text, m.lab_id::text, m.maintainer_id::text, m.status::text,
+                    a.note, a.created_at, m.finished_at,
+                    EXTRACT(EPOCH FROM m.finished_at - a.created_at)::bigint
+             FROM maintenances m
+             JOIN activities a ON a.id = m.id
+             WHERE m.id::text = $1",
+            &[&maintenance_id],
+        )
+        .await?;
+    let devices = tx
+        .query(
+            "SELECT device_id::text, prev_status, after_status
+             FROM maintenances_devices
+             WHERE maintaining_id::text = $1
+             ORDER BY device_id",
+            &[&maintenance_id],
+        )
+        .await?;
+    Ok(Maintenance {
+        id: row.get(0),
+        lab_id: row.get(1),
+        technician_id: row.get(2),
+        status: row.get(3),
+        note: row.get(4),
+        created_at: row.get(5),
+        finished_at: row.get(6),
+        duration_secs: row.get(7),
+        devices: devices
+            .iter()
+            .map(|row| {
+                Ok(MaintenanceDevice {
+                    device_id: row.get(0),
+                    prev_status: row.try_get(1)?,
+                    after_status: row.try_get(2)?,
+                })
+            })
+            .collect::<Result<_, tokio_postgres::Error>>()?,
+    })
+}
@@ -0,0 +1,215 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::{PoolError, Status};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio_postgres::error::SqlState;
+
+/// Errors older than this no longer count as recent.
+const RECENT_WINDOW: Duration = Duration::from_secs(300);
+
+/// Counters for pool checkouts, shared by every clone of a `Database`.
+#[derive(Default)]
+pub struct PoolMetrics {
+    checkouts: AtomicU64,
+    wait_total_us: AtomicU64,
+    wait_max_us: AtomicU64,
+    timeouts: AtomicU64,
+    errors: AtomicU64,
+    recent_errors: Mutex<VecDeque<Instant>>,
+}
+
+impl PoolMetrics {
+    /// A successful checkout that waited `wait` for a connection.
+    pub fn record_checkout(&self, wait: Duration) {
+        let us = wait.as_micros().min(u64::MAX as u128) as u64;
+        self.checkouts.fetch_add(1, Ordering::Relaxed);
+        self.wait_total_us.fetch_add(us, Ordering::Relaxed);
+        self.wait_max_us.fetch_max(us, Ordering::Relaxed);
+    }
+
+    /// A failed checkout or query.
+    pub fn record_error(&self, timed_out: bool) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+        if timed_out {
+            self.timeouts.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let now = Instant::now();
+        let mut recent = self.recent_errors.lock().unwrap();
+        prune(&mut recent, now);
+        recent.push_back(now);
+    }
+
+    pub fn record_pool_error(&self, error: &PoolError) {
+        self.record_error(matches!(error, PoolError::Timeout(_)));
+    }
+
+    /// A failed query; one stopped by `statement_timeout` counts as a
+    /// timeout.
+    pub fn record_query_error(&self, error: &tokio_postgres::Error) {
+        let timed_out = error
+            .as_db_error()
+            .is_some_and(|e| *e.code() == SqlState::QUERY_CANCELED);
+        self.record_error(timed_out);
+    }
+
+    pub fn waits(&self) -> WaitStats {
+        let checkouts = self.checkouts.load(Ordering::Relaxed);
+        let total_us = self.wait_total_us.load(Ordering::Relaxed);
+        WaitStats {
+            checkouts,
+            avg_wait_ms: if checkouts == 0 {
+                0.0
+            } else {
+                total_us as f64 / checkouts as f64 / 1000.0
+            },
+            max_wait_ms: self.wait_max_us.load(Ordering::Relaxed) as f64 / 1000.0,
+        }
+    }
+
+    pub fn errors(&self) -> ErrorStats {
+        let mut recent = self.recent_errors.lock().unwrap();
+        prune(&mut recent, Instant::now());
+        ErrorStats {
+            total: self.errors.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            recent: recent.len() as u64,
+            recent_window_secs: RECENT_WINDOW.as_secs(),
+        }
+    }
+}
+
+/// Prepared statement cache counters, shared by every clone of a
+/// `Database`.
+#[derive(Default)]
+pub struct StatementMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+impl StatementMetrics {
+    pub fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_eviction(&self) {
+        self.evictions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_invalidation(&self) {
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self, capacity: usize) -> StatementStats {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        StatementStats {
+            capacity,
+            hits,
+            misses,
+            hit_rate: if hits + misses == 0 {
+                0.0
+            } else {
+                hits as f64 / (hits + misses) as f64
+            },
+            evictions: self.evictions.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn prune(recent: &mut VecDeque<Instant>, now: Instant) {
+    while recent
+        .front()
+        .is_some_and(|at| now.duration_since(*at) > RECENT_WINDOW)
+    {
+        recent.pop_front();
+    }
+}
+
+/// Connection counts at the time of the check.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolStats {
+    pub max_size: usize,
+    pub size: usize,
+    pub idle: usize,
+    pub in_use: usize,
+    /// Callers queued for a connection.
+    pub waiting: usize,
+}
+
+impl From<Status> for PoolStats {
+    fn from(status: Status) -> Self {
+        PoolStats {
+            max_size: status.max_size,
+            size: status.size,
+            idle: status.available,
+            in_use: status.size.saturating_sub(status.available),
+            waiting: status.waiting,
+        }
+    }
+}
+
+/// Prepared statement cache use since startup.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementStats {
+    /// Statements kept per connection; `0` when caching is off.
+    pub capacity: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub hit_rate: f64,
+    /// Connection caches emptied because they were full.
+    pub evictions: u64,
+    /// Times every cache was emptied after a schema change.
+    pub invalidations: u64,
+}
+
+/// Time spent waiting for a connection, since startup.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitStats {
+    pub checkouts: u64,
+    pub avg_wait_ms: f64,
+    pub max_wait_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorStats {
+    /// Failed checkouts, queries and health probes since startup.
+    pub total: u64,
+    /// Of those, checkouts that hit a pool timeout and queries stopped by
+    /// `statement_timeout`.
+    pub timeouts: u64,
+    /// Failures within the last `recent_window_secs`.
+    pub recent: u64,
+    pub recent_window_secs: u64,
+}
+
+/// Result of `Database::health`. The probe fields are `None` and `error` is
+/// set when the server could not be reached.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbHealth {
+    pub pool: PoolStats,
+    pub waits: WaitStats,
+    pub errors: ErrorStats,
+    pub statements: StatementStats,
+    pub latency_ms: Option<f64>,
+    pub server_version: Option<String>,
+    pub error: Option<String>,
+    pub checked_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replica: Option<Box<DbHealth>>,
+}
@@ -1,3 +1,26 @@
+pub mod access;
+pub mod audit;
+pub mod cancel;
+pub mod codegen;
 pub mod connection;
+pub mod hmi;
+pub mod lifecycle;
+pub mod listener;
+pub mod maintenance;
+pub mod metrics;
+pub mod models;
+pub mod offline;
+pub mod otp;
+pub mod queries;
+pub mod receipts;
+pub mod registry;
+pub mod replica;
 pub mod schema;
-pub mod queries; 
\ No newline at end of file
+pub mod session;
+pub mod settings;
+pub mod shipment;
+pub mod stream;
+pub mod supervisor;
+pub mod tls;
+pub mod validation;
+pub mod watcher;
@@ -0,0 +1,2647 @@
+// This file is auto-generated by `hmi codegen`. Do not edit manually.
+#![allow(dead_code)]
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[postgres(name = "activity_type")]
+pub enum ActivityType {
+    #[postgres(name = "borrow")]
+    #[serde(rename = "borrow")]
+    Borrow,
+    #[postgres(name = "return")]
+    #[serde(rename = "return")]
+    Return,
+    #[postgres(name = "maintenance")]
+    #[serde(rename = "maintenance")]
+    Maintenance,
+    #[postgres(name = "shipment")]
+    #[serde(rename = "shipment")]
+    Shipment,
+    #[postgres(name = "assessment")]
+    #[serde(rename = "assessment")]
+    Assessment,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[postgres(name = "assessment_status")]
+pub enum AssessmentStatus {
+    #[postgres(name = "assessing")]
+    #[serde(rename = "assessing")]
+    Assessing,
+    #[postgres(name = "completed")]
+    #[serde(rename = "completed")]
+    Completed,
+    #[postgres(name = "cancelled")]
+    #[serde(rename = "cancelled")]
+    Cancelled,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[postgres(name = "device_status")]
+pub enum DeviceStatus {
+    #[postgres(name = "healthy")]
+    #[serde(rename = "healthy")]
+    Healthy,
+    #[postgres(name = "broken")]
+    #[serde(rename = "broken")]
+    Broken,
+    #[postgres(name = "discarded")]
+    #[serde(rename = "discarded")]
+    Discarded,
+    #[postgres(name = "assessing")]
+    #[serde(rename = "assessing")]
+    Assessing,
+    #[postgres(name = "maintaining")]
+    #[serde(rename = "maintaining")]
+    Maintaining,
+    #[postgres(name = "shipping")]
+    #[serde(rename = "shipping")]
+    Shipping,
+    #[postgres(name = "borrowing")]
+    #[serde(rename = "borrowing")]
+    Borrowing,
+    #[postgres(name = "lost")]
+    #[serde(rename = "lost")]
+    Lost,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[postgres(name = "maintenance_status")]
+pub enum MaintenanceStatus {
+    #[postgres(name = "maintaining")]
+    #[serde(rename = "maintaining")]
+    Maintaining,
+    #[postgres(name = "completed")]
+    #[serde(rename = "completed")]
+    Completed,
+    #[postgres(name = "cancelled")]
+    #[serde(rename = "cancelled")]
+    Cancelled,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[postgres(name = "request_status")]
+pub enum RequestStatus {
+    #[postgres(name = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[postgres(name = "approved")]
+    #[serde(rename = "approved")]
+    Approved,
+    #[postgres(name = "rejected")]
+    #[serde(rename = "rejected")]
+    Rejected,
+    #[postgres(name = "cancelled")]
+    #[serde(rename = "cancelled")]
+    Cancelled,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[postgres(name = "reservation_status")]
+pub enum ReservationStatus {
+    #[postgres(name = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[postgres(name = "approved")]
+    #[serde(rename = "approved")]
+    Approved,
+    #[postgres(name = "ready")]
+    #[serde(rename = "ready")]
+    Ready,
+    #[postgres(name = "cancelled")]
+    #[serde(rename = "cancelled")]
+    Cancelled,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[postgres(name = "shipment_status")]
+pub enum ShipmentStatus {
+    #[postgres(name = "preparing")]
+    #[serde(rename = "preparing")]
+    Preparing,
+    #[postgres(name = "shipping")]
+    #[serde(rename = "shipping")]
+    Shipping,
+    #[postgres(name = "completed")]
+    #[serde(rename = "completed")]
+    Completed,
+    #[postgres(name = "cancelled")]
+    #[serde(rename = "cancelled")]
+    Cancelled,
+    #[postgres(name = "received")]
+    #[serde(rename = "received")]
+    Received,
+    #[postgres(name = "partially_received")]
+    #[serde(rename = "partially_received")]
+    PartiallyReceived,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[postgres(name = "user_role")]
+pub enum UserRole {
+    #[postgres(name = "sysadmin")]
+    #[serde(rename = "sysadmin")]
+    Sysadmin,
+    #[postgres(name = "lab_admin")]
+    #[serde(rename = "lab_admin")]
+    LabAdmin,
+    #[postgres(name = "teacher")]
+    #[serde(rename = "teacher")]
+    Teacher,
+    #[postgres(name = "student")]
+    #[serde(rename = "student")]
+    Student,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "extensions")]
+pub struct Extensions {
+    pub id: uuid::Uuid,
+    pub r#type: Option<String>,
+    pub settings: Option<serde_json::Value>,
+    pub tenant_external_id: Option<String>,
+    pub inserted_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Extensions {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            r#type: row.try_get("type")?,
+            settings: row.try_get("settings")?,
+            tenant_external_id: row.try_get("tenant_external_id")?,
+            inserted_at: row.try_get("inserted_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "schema_migrations")]
+pub struct SchemaMigrationsRealtime {
+    pub version: i64,
+    pub inserted_at: Option<chrono::NaiveDateTime>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for SchemaMigrationsRealtime {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: row.try_get("version")?,
+            inserted_at: row.try_get("inserted_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "tenants")]
+pub struct Tenants {
+    pub id: uuid::Uuid,
+    pub name: Option<String>,
+    pub external_id: Option<String>,
+    pub jwt_secret: Option<String>,
+    pub max_concurrent_users: i32,
+    pub inserted_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+    pub max_events_per_second: i32,
+    pub postgres_cdc_default: Option<String>,
+    pub max_bytes_per_second: i32,
+    pub max_channels_per_client: i32,
+    pub max_joins_per_second: i32,
+    pub suspend: Option<bool>,
+    pub jwt_jwks: Option<serde_json::Value>,
+    pub notify_private_alpha: Option<bool>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Tenants {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            external_id: row.try_get("external_id")?,
+            jwt_secret: row.try_get("jwt_secret")?,
+            max_concurrent_users: row.try_get("max_concurrent_users")?,
+            inserted_at: row.try_get("inserted_at")?,
+            updated_at: row.try_get("updated_at")?,
+            max_events_per_second: row.try_get("max_events_per_second")?,
+            postgres_cdc_default: row.try_get("postgres_cdc_default")?,
+            max_bytes_per_second: row.try_get("max_bytes_per_second")?,
+            max_channels_per_client: row.try_get("max_channels_per_client")?,
+            max_joins_per_second: row.try_get("max_joins_per_second")?,
+            suspend: row.try_get("suspend")?,
+            jwt_jwks: row.try_get("jwt_jwks")?,
+            notify_private_alpha: row.try_get("notify_private_alpha")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "audit_log_entries")]
+pub struct AuditLogEntries {
+    pub instance_id: Option<uuid::Uuid>,
+    pub id: uuid::Uuid,
+    pub payload: Option<serde_json::Value>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub ip_address: String,
+}
+
+impl TryFrom<&tokio_postgres::Row> for AuditLogEntries {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            instance_id: row.try_get("instance_id")?,
+            id: row.try_get("id")?,
+            payload: row.try_get("payload")?,
+            created_at: row.try_get("created_at")?,
+            ip_address: row.try_get("ip_address")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "flow_state")]
+pub struct FlowState {
+    pub id: uuid::Uuid,
+    pub user_id: Option<uuid::Uuid>,
+    pub auth_code: String,
+    pub code_challenge_method: String,
+    pub code_challenge: String,
+    pub provider_type: String,
+    pub provider_access_token: Option<String>,
+    pub provider_refresh_token: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub authentication_method: String,
+    pub auth_code_issued_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for FlowState {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            user_id: row.try_get("user_id")?,
+            auth_code: row.try_get("auth_code")?,
+            code_challenge_method: row.try_get("code_challenge_method")?,
+            code_challenge: row.try_get("code_challenge")?,
+            provider_type: row.try_get("provider_type")?,
+            provider_access_token: row.try_get("provider_access_token")?,
+            provider_refresh_token: row.try_get("provider_refresh_token")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            authentication_method: row.try_get("authentication_method")?,
+            auth_code_issued_at: row.try_get("auth_code_issued_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "identities")]
+pub struct Identities {
+    pub provider_id: String,
+    pub user_id: uuid::Uuid,
+    pub identity_data: serde_json::Value,
+    pub provider: String,
+    pub last_sign_in_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub email: Option<String>,
+    pub id: uuid::Uuid,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Identities {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            provider_id: row.try_get("provider_id")?,
+            user_id: row.try_get("user_id")?,
+            identity_data: row.try_get("identity_data")?,
+            provider: row.try_get("provider")?,
+            last_sign_in_at: row.try_get("last_sign_in_at")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            email: row.try_get("email")?,
+            id: row.try_get("id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "instances")]
+pub struct Instances {
+    pub id: uuid::Uuid,
+    pub uuid: Option<uuid::Uuid>,
+    pub raw_base_config: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Instances {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            uuid: row.try_get("uuid")?,
+            raw_base_config: row.try_get("raw_base_config")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "mfa_amr_claims")]
+pub struct MfaAmrClaims {
+    pub session_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub authentication_method: String,
+    pub id: uuid::Uuid,
+}
+
+impl TryFrom<&tokio_postgres::Row> for MfaAmrClaims {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            session_id: row.try_get("session_id")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            authentication_method: row.try_get("authentication_method")?,
+            id: row.try_get("id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "mfa_challenges")]
+pub struct MfaChallenges {
+    pub id: uuid::Uuid,
+    pub factor_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub ip_address: std::net::IpAddr,
+    pub otp_code: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for MfaChallenges {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            factor_id: row.try_get("factor_id")?,
+            created_at: row.try_get("created_at")?,
+            verified_at: row.try_get("verified_at")?,
+            ip_address: row.try_get("ip_address")?,
+            otp_code: row.try_get("otp_code")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "mfa_factors")]
+pub struct MfaFactors {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub friendly_name: Option<String>,
+    pub factor_type: String,
+    pub status: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub secret: Option<String>,
+    pub phone: Option<String>,
+    pub last_challenged_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for MfaFactors {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            user_id: row.try_get("user_id")?,
+            friendly_name: row.try_get("friendly_name")?,
+            factor_type: row.try_get("factor_type")?,
+            status: row.try_get("status")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            secret: row.try_get("secret")?,
+            phone: row.try_get("phone")?,
+            last_challenged_at: row.try_get("last_challenged_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "one_time_tokens")]
+pub struct OneTimeTokens {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub token_type: String,
+    pub token_hash: String,
+    pub relates_to: String,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl TryFrom<&tokio_postgres::Row> for OneTimeTokens {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            user_id: row.try_get("user_id")?,
+            token_type: row.try_get("token_type")?,
+            token_hash: row.try_get("token_hash")?,
+            relates_to: row.try_get("relates_to")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "refresh_tokens")]
+pub struct RefreshTokens {
+    pub instance_id: Option<uuid::Uuid>,
+    pub id: i64,
+    pub token: Option<String>,
+    pub user_id: Option<String>,
+    pub revoked: Option<bool>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub parent: Option<String>,
+    pub session_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for RefreshTokens {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            instance_id: row.try_get("instance_id")?,
+            id: row.try_get("id")?,
+            token: row.try_get("token")?,
+            user_id: row.try_get("user_id")?,
+            revoked: row.try_get("revoked")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            parent: row.try_get("parent")?,
+            session_id: row.try_get("session_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "saml_providers")]
+pub struct SamlProviders {
+    pub id: uuid::Uuid,
+    pub sso_provider_id: uuid::Uuid,
+    pub entity_id: String,
+    pub metadata_xml: String,
+    pub metadata_url: Option<String>,
+    pub attribute_mapping: Option<serde_json::Value>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub name_id_format: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for SamlProviders {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            sso_provider_id: row.try_get("sso_provider_id")?,
+            entity_id: row.try_get("entity_id")?,
+            metadata_xml: row.try_get("metadata_xml")?,
+            metadata_url: row.try_get("metadata_url")?,
+            attribute_mapping: row.try_get("attribute_mapping")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            name_id_format: row.try_get("name_id_format")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "saml_relay_states")]
+pub struct SamlRelayStates {
+    pub id: uuid::Uuid,
+    pub sso_provider_id: uuid::Uuid,
+    pub request_id: String,
+    pub for_email: Option<String>,
+    pub redirect_to: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub flow_state_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for SamlRelayStates {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            sso_provider_id: row.try_get("sso_provider_id")?,
+            request_id: row.try_get("request_id")?,
+            for_email: row.try_get("for_email")?,
+            redirect_to: row.try_get("redirect_to")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            flow_state_id: row.try_get("flow_state_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "schema_migrations")]
+pub struct SchemaMigrationsAuth {
+    pub version: String,
+}
+
+impl TryFrom<&tokio_postgres::Row> for SchemaMigrationsAuth {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: row.try_get("version")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "sessions")]
+pub struct Sessions {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub factor_id: Option<uuid::Uuid>,
+    pub aal: Option<String>,
+    pub not_after: Option<chrono::DateTime<chrono::Utc>>,
+    pub refreshed_at: Option<chrono::NaiveDateTime>,
+    pub user_agent: Option<String>,
+    pub ip: Option<std::net::IpAddr>,
+    pub tag: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Sessions {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            user_id: row.try_get("user_id")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            factor_id: row.try_get("factor_id")?,
+            aal: row.try_get("aal")?,
+            not_after: row.try_get("not_after")?,
+            refreshed_at: row.try_get("refreshed_at")?,
+            user_agent: row.try_get("user_agent")?,
+            ip: row.try_get("ip")?,
+            tag: row.try_get("tag")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "sso_domains")]
+pub struct SsoDomains {
+    pub id: uuid::Uuid,
+    pub sso_provider_id: uuid::Uuid,
+    pub domain: String,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for SsoDomains {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            sso_provider_id: row.try_get("sso_provider_id")?,
+            domain: row.try_get("domain")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "sso_providers")]
+pub struct SsoProviders {
+    pub id: uuid::Uuid,
+    pub resource_id: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for SsoProviders {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            resource_id: row.try_get("resource_id")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "users")]
+pub struct UsersAuth {
+    pub instance_id: Option<uuid::Uuid>,
+    pub id: uuid::Uuid,
+    pub aud: Option<String>,
+    pub role: Option<String>,
+    pub email: Option<String>,
+    pub encrypted_password: Option<String>,
+    pub email_confirmed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub invited_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub confirmation_token: Option<String>,
+    pub confirmation_sent_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub recovery_token: Option<String>,
+    pub recovery_sent_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub email_change_token_new: Option<String>,
+    pub email_change: Option<String>,
+    pub email_change_sent_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_sign_in_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub raw_app_meta_data: Option<serde_json::Value>,
+    pub raw_user_meta_data: Option<serde_json::Value>,
+    pub is_super_admin: Option<bool>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub phone: Option<String>,
+    pub phone_confirmed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub phone_change: Option<String>,
+    pub phone_change_token: Option<String>,
+    pub phone_change_sent_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub confirmed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub email_change_token_current: Option<String>,
+    pub email_change_confirm_status: Option<i16>,
+    pub banned_until: Option<chrono::DateTime<chrono::Utc>>,
+    pub reauthentication_token: Option<String>,
+    pub reauthentication_sent_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub is_sso_user: bool,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub is_anonymous: bool,
+}
+
+impl TryFrom<&tokio_postgres::Row> for UsersAuth {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            instance_id: row.try_get("instance_id")?,
+            id: row.try_get("id")?,
+            aud: row.try_get("aud")?,
+            role: row.try_get("role")?,
+            email: row.try_get("email")?,
+            encrypted_password: row.try_get("encrypted_password")?,
+            email_confirmed_at: row.try_get("email_confirmed_at")?,
+            invited_at: row.try_get("invited_at")?,
+            confirmation_token: row.try_get("confirmation_token")?,
+            confirmation_sent_at: row.try_get("confirmation_sent_at")?,
+            recovery_token: row.try_get("recovery_token")?,
+            recovery_sent_at: row.try_get("recovery_sent_at")?,
+            email_change_token_new: row.try_get("email_change_token_new")?,
+            email_change: row.try_get("email_change")?,
+            email_change_sent_at: row.try_get("email_change_sent_at")?,
+            last_sign_in_at: row.try_get("last_sign_in_at")?,
+            raw_app_meta_data: row.try_get("raw_app_meta_data")?,
+            raw_user_meta_data: row.try_get("raw_user_meta_data")?,
+            is_super_admin: row.try_get("is_super_admin")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            phone: row.try_get("phone")?,
+            phone_confirmed_at: row.try_get("phone_confirmed_at")?,
+            phone_change: row.try_get("phone_change")?,
+            phone_change_token: row.try_get("phone_change_token")?,
+            phone_change_sent_at: row.try_get("phone_change_sent_at")?,
+            confirmed_at: row.try_get("confirmed_at")?,
+            email_change_token_current: row.try_get("email_change_token_current")?,
+            email_change_confirm_status: row.try_get("email_change_confirm_status")?,
+            banned_until: row.try_get("banned_until")?,
+            reauthentication_token: row.try_get("reauthentication_token")?,
+            reauthentication_sent_at: row.try_get("reauthentication_sent_at")?,
+            is_sso_user: row.try_get("is_sso_user")?,
+            deleted_at: row.try_get("deleted_at")?,
+            is_anonymous: row.try_get("is_anonymous")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "pg_stat_statements")]
+pub struct PgStatStatements {
+    pub userid: Option<u32>,
+    pub dbid: Option<u32>,
+    pub toplevel: Option<bool>,
+    pub queryid: Option<i64>,
+    pub query: Option<String>,
+    pub plans: Option<i64>,
+    pub total_plan_time: Option<f64>,
+    pub min_plan_time: Option<f64>,
+    pub max_plan_time: Option<f64>,
+    pub mean_plan_time: Option<f64>,
+    pub stddev_plan_time: Option<f64>,
+    pub calls: Option<i64>,
+    pub total_exec_time: Option<f64>,
+    pub min_exec_time: Option<f64>,
+    pub max_exec_time: Option<f64>,
+    pub mean_exec_time: Option<f64>,
+    pub stddev_exec_time: Option<f64>,
+    pub rows: Option<i64>,
+    pub shared_blks_hit: Option<i64>,
+    pub shared_blks_read: Option<i64>,
+    pub shared_blks_dirtied: Option<i64>,
+    pub shared_blks_written: Option<i64>,
+    pub local_blks_hit: Option<i64>,
+    pub local_blks_read: Option<i64>,
+    pub local_blks_dirtied: Option<i64>,
+    pub local_blks_written: Option<i64>,
+    pub temp_blks_read: Option<i64>,
+    pub temp_blks_written: Option<i64>,
+    pub blk_read_time: Option<f64>,
+    pub blk_write_time: Option<f64>,
+    pub temp_blk_read_time: Option<f64>,
+    pub temp_blk_write_time: Option<f64>,
+    pub wal_records: Option<i64>,
+    pub wal_fpi: Option<i64>,
+    pub wal_bytes: Option<rust_decimal::Decimal>,
+    pub jit_functions: Option<i64>,
+    pub jit_generation_time: Option<f64>,
+    pub jit_inlining_count: Option<i64>,
+    pub jit_inlining_time: Option<f64>,
+    pub jit_optimization_count: Option<i64>,
+    pub jit_optimization_time: Option<f64>,
+    pub jit_emission_count: Option<i64>,
+    pub jit_emission_time: Option<f64>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for PgStatStatements {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            userid: row.try_get("userid")?,
+            dbid: row.try_get("dbid")?,
+            toplevel: row.try_get("toplevel")?,
+            queryid: row.try_get("queryid")?,
+            query: row.try_get("query")?,
+            plans: row.try_get("plans")?,
+            total_plan_time: row.try_get("total_plan_time")?,
+            min_plan_time: row.try_get("min_plan_time")?,
+            max_plan_time: row.try_get("max_plan_time")?,
+            mean_plan_time: row.try_get("mean_plan_time")?,
+            stddev_plan_time: row.try_get("stddev_plan_time")?,
+            calls: row.try_get("calls")?,
+            total_exec_time: row.try_get("total_exec_time")?,
+            min_exec_time: row.try_get("min_exec_time")?,
+            max_exec_time: row.try_get("max_exec_time")?,
+            mean_exec_time: row.try_get("mean_exec_time")?,
+            stddev_exec_time: row.try_get("stddev_exec_time")?,
+            rows: row.try_get("rows")?,
+            shared_blks_hit: row.try_get("shared_blks_hit")?,
+            shared_blks_read: row.try_get("shared_blks_read")?,
+            shared_blks_dirtied: row.try_get("shared_blks_dirtied")?,
+            shared_blks_written: row.try_get("shared_blks_written")?,
+            local_blks_hit: row.try_get("local_blks_hit")?,
+            local_blks_read: row.try_get("local_blks_read")?,
+            local_blks_dirtied: row.try_get("local_blks_dirtied")?,
+            local_blks_written: row.try_get("local_blks_written")?,
+            temp_blks_read: row.try_get("temp_blks_read")?,
+            temp_blks_written: row.try_get("temp_blks_written")?,
+            blk_read_time: row.try_get("blk_read_time")?,
+            blk_write_time: row.try_get("blk_write_time")?,
+            temp_blk_read_time: row.try_get("temp_blk_read_time")?,
+            temp_blk_write_time: row.try_get("temp_blk_write_time")?,
+            wal_records: row.try_get("wal_records")?,
+            wal_fpi: row.try_get("wal_fpi")?,
+            wal_bytes: row.try_get("wal_bytes")?,
+            jit_functions: row.try_get("jit_functions")?,
+            jit_generation_time: row.try_get("jit_generation_time")?,
+            jit_inlining_count: row.try_get("jit_inlining_count")?,
+            jit_inlining_time: row.try_get("jit_inlining_time")?,
+            jit_optimization_count: row.try_get("jit_optimization_count")?,
+            jit_optimization_time: row.try_get("jit_optimization_time")?,
+            jit_emission_count: row.try_get("jit_emission_count")?,
+            jit_emission_time: row.try_get("jit_emission_time")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "pg_stat_statements_info")]
+pub struct PgStatStatementsInfo {
+    pub dealloc: Option<i64>,
+    pub stats_reset: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for PgStatStatementsInfo {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            dealloc: row.try_get("dealloc")?,
+            stats_reset: row.try_get("stats_reset")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "_http_response")]
+pub struct HttpResponse {
+    pub id: Option<i64>,
+    pub status_code: Option<i32>,
+    pub content_type: Option<String>,
+    pub headers: Option<serde_json::Value>,
+    pub content: Option<String>,
+    pub timed_out: Option<bool>,
+    pub error_msg: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for HttpResponse {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            status_code: row.try_get("status_code")?,
+            content_type: row.try_get("content_type")?,
+            headers: row.try_get("headers")?,
+            content: row.try_get("content")?,
+            timed_out: row.try_get("timed_out")?,
+            error_msg: row.try_get("error_msg")?,
+            created: row.try_get("created")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "http_request_queue")]
+pub struct HttpRequestQueue {
+    pub id: i64,
+    pub method: String,
+    pub url: String,
+    pub headers: serde_json::Value,
+    pub body: Option<Vec<u8>>,
+    pub timeout_milliseconds: i32,
+}
+
+impl TryFrom<&tokio_postgres::Row> for HttpRequestQueue {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            method: row.try_get("method")?,
+            url: row.try_get("url")?,
+            headers: row.try_get("headers")?,
+            body: row.try_get("body")?,
+            timeout_milliseconds: row.try_get("timeout_milliseconds")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "decrypted_key")]
+pub struct DecryptedKey {
+    pub id: Option<uuid::Uuid>,
+    pub status: Option<String>,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    pub expires: Option<chrono::DateTime<chrono::Utc>>,
+    pub key_type: Option<String>,
+    pub key_id: Option<i64>,
+    pub key_context: Option<Vec<u8>>,
+    pub name: Option<String>,
+    pub associated_data: Option<String>,
+    pub raw_key: Option<Vec<u8>>,
+    pub decrypted_raw_key: Option<Vec<u8>>,
+    pub raw_key_nonce: Option<Vec<u8>>,
+    pub parent_key: Option<uuid::Uuid>,
+    pub comment: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for DecryptedKey {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            status: row.try_get("status")?,
+            created: row.try_get("created")?,
+            expires: row.try_get("expires")?,
+            key_type: row.try_get("key_type")?,
+            key_id: row.try_get("key_id")?,
+            key_context: row.try_get("key_context")?,
+            name: row.try_get("name")?,
+            associated_data: row.try_get("associated_data")?,
+            raw_key: row.try_get("raw_key")?,
+            decrypted_raw_key: row.try_get("decrypted_raw_key")?,
+            raw_key_nonce: row.try_get("raw_key_nonce")?,
+            parent_key: row.try_get("parent_key")?,
+            comment: row.try_get("comment")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "key")]
+pub struct Key {
+    pub id: uuid::Uuid,
+    pub status: Option<String>,
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub expires: Option<chrono::DateTime<chrono::Utc>>,
+    pub key_type: Option<String>,
+    pub key_id: Option<i64>,
+    pub key_context: Option<Vec<u8>>,
+    pub name: Option<String>,
+    pub associated_data: Option<String>,
+    pub raw_key: Option<Vec<u8>>,
+    pub raw_key_nonce: Option<Vec<u8>>,
+    pub parent_key: Option<uuid::Uuid>,
+    pub comment: Option<String>,
+    pub user_data: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Key {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            status: row.try_get("status")?,
+            created: row.try_get("created")?,
+            expires: row.try_get("expires")?,
+            key_type: row.try_get("key_type")?,
+            key_id: row.try_get("key_id")?,
+            key_context: row.try_get("key_context")?,
+            name: row.try_get("name")?,
+            associated_data: row.try_get("associated_data")?,
+            raw_key: row.try_get("raw_key")?,
+            raw_key_nonce: row.try_get("raw_key_nonce")?,
+            parent_key: row.try_get("parent_key")?,
+            comment: row.try_get("comment")?,
+            user_data: row.try_get("user_data")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "mask_columns")]
+pub struct MaskColumns {
+    pub attname: Option<String>,
+    pub attrelid: Option<u32>,
+    pub key_id: Option<String>,
+    pub key_id_column: Option<String>,
+    pub associated_columns: Option<String>,
+    pub nonce_column: Option<String>,
+    pub format_type: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for MaskColumns {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attname: row.try_get("attname")?,
+            attrelid: row.try_get("attrelid")?,
+            key_id: row.try_get("key_id")?,
+            key_id_column: row.try_get("key_id_column")?,
+            associated_columns: row.try_get("associated_columns")?,
+            nonce_column: row.try_get("nonce_column")?,
+            format_type: row.try_get("format_type")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "masking_rule")]
+pub struct MaskingRule {
+    pub attrelid: Option<u32>,
+    pub attnum: Option<i32>,
+    pub relnamespace: Option<String>,
+    pub relname: Option<String>,
+    pub attname: Option<String>,
+    pub format_type: Option<String>,
+    pub col_description: Option<String>,
+    pub key_id_column: Option<String>,
+    pub key_id: Option<String>,
+    pub associated_columns: Option<String>,
+    pub nonce_column: Option<String>,
+    pub view_name: Option<String>,
+    pub priority: Option<i32>,
+    pub security_invoker: Option<bool>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for MaskingRule {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attrelid: row.try_get("attrelid")?,
+            attnum: row.try_get("attnum")?,
+            relnamespace: row.try_get("relnamespace")?,
+            relname: row.try_get("relname")?,
+            attname: row.try_get("attname")?,
+            format_type: row.try_get("format_type")?,
+            col_description: row.try_get("col_description")?,
+            key_id_column: row.try_get("key_id_column")?,
+            key_id: row.try_get("key_id")?,
+            associated_columns: row.try_get("associated_columns")?,
+            nonce_column: row.try_get("nonce_column")?,
+            view_name: row.try_get("view_name")?,
+            priority: row.try_get("priority")?,
+            security_invoker: row.try_get("security_invoker")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "valid_key")]
+pub struct ValidKey {
+    pub id: Option<uuid::Uuid>,
+    pub name: Option<String>,
+    pub status: Option<String>,
+    pub key_type: Option<String>,
+    pub key_id: Option<i64>,
+    pub key_context: Option<Vec<u8>>,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    pub expires: Option<chrono::DateTime<chrono::Utc>>,
+    pub associated_data: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for ValidKey {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            status: row.try_get("status")?,
+            key_type: row.try_get("key_type")?,
+            key_id: row.try_get("key_id")?,
+            key_context: row.try_get("key_context")?,
+            created: row.try_get("created")?,
+            expires: row.try_get("expires")?,
+            associated_data: row.try_get("associated_data")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "actions")]
+pub struct Actions {
+    pub id: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub name: String,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Actions {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            name: row.try_get("name")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "activities")]
+pub struct Activities {
+    pub id: uuid::Uuid,
+    pub r#type: ActivityType,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub note: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Activities {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            r#type: row.try_get("type")?,
+            created_at: row.try_get("created_at")?,
+            note: row.try_get("note")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "categories")]
+pub struct Categories {
+    pub id: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub name: String,
+    pub quantity: Option<i32>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Categories {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            name: row.try_get("name")?,
+            quantity: row.try_get("quantity")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "device_kinds")]
+pub struct DeviceKinds {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub category_id: Option<i64>,
+    pub name: Option<String>,
+    pub meta: serde_json::Value,
+    pub image: Option<serde_json::Value>,
+    pub brand: Option<String>,
+    pub manufacturer: Option<String>,
+    pub description: Option<String>,
+    pub datasheet: Option<String>,
+    pub unit: Option<String>,
+    pub price: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub id: uuid::Uuid,
+    pub allowed_borrow_roles: Option<Vec<UserRole>>,
+    pub allowed_view_roles: Option<Vec<UserRole>>,
+    pub is_borrowable_lab_only: bool,
+}
+
+impl TryFrom<&tokio_postgres::Row> for DeviceKinds {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            created_at: row.try_get("created_at")?,
+            category_id: row.try_get("category_id")?,
+            name: row.try_get("name")?,
+            meta: row.try_get("meta")?,
+            image: row.try_get("image")?,
+            brand: row.try_get("brand")?,
+            manufacturer: row.try_get("manufacturer")?,
+            description: row.try_get("description")?,
+            datasheet: row.try_get("datasheet")?,
+            unit: row.try_get("unit")?,
+            price: row.try_get("price")?,
+            deleted_at: row.try_get("deleted_at")?,
+            id: row.try_get("id")?,
+            allowed_borrow_roles: row.try_get("allowed_borrow_roles")?,
+            allowed_view_roles: row.try_get("allowed_view_roles")?,
+            is_borrowable_lab_only: row.try_get("is_borrowable_lab_only")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "device_status_changes")]
+pub struct DeviceStatusChanges {
+    pub id: i64,
+    pub device_id: String,
+    pub from_status: DeviceStatus,
+    pub to_status: DeviceStatus,
+    pub activity_type: ActivityType,
+    pub activity_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub reason: Option<String>,
+    pub changed_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for DeviceStatusChanges {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            device_id: row.try_get("device_id")?,
+            from_status: row.try_get("from_status")?,
+            to_status: row.try_get("to_status")?,
+            activity_type: row.try_get("activity_type")?,
+            activity_id: row.try_get("activity_id")?,
+            actor_id: row.try_get("actor_id")?,
+            reason: row.try_get("reason")?,
+            changed_at: row.try_get("changed_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "devices")]
+pub struct Devices {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub kind: uuid::Uuid,
+    pub lab_id: Option<uuid::Uuid>,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub status: DeviceStatus,
+    pub id: uuid::Uuid,
+    pub full_id: String,
+    pub printed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub accessory_for_kind_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Devices {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            created_at: row.try_get("created_at")?,
+            kind: row.try_get("kind")?,
+            lab_id: row.try_get("lab_id")?,
+            deleted_at: row.try_get("deleted_at")?,
+            status: row.try_get("status")?,
+            id: row.try_get("id")?,
+            full_id: row.try_get("full_id")?,
+            printed_at: row.try_get("printed_at")?,
+            accessory_for_kind_id: row.try_get("accessory_for_kind_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "hmi_codes")]
+pub struct HmiCodes {
+    pub code: i32,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub user_id: Option<uuid::Uuid>,
+    pub auth_token: Option<String>,
+    pub status: String,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub lab_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for HmiCodes {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            code: row.try_get("code")?,
+            created_at: row.try_get("created_at")?,
+            user_id: row.try_get("user_id")?,
+            auth_token: row.try_get("auth_token")?,
+            status: row.try_get("status")?,
+            updated_at: row.try_get("updated_at")?,
+            expires_at: row.try_get("expires_at")?,
+            lab_id: row.try_get("lab_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "inventory_assessments")]
+pub struct InventoryAssessments {
+    pub id: uuid::Uuid,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub lab_id: uuid::Uuid,
+    pub accountant_id: Option<uuid::Uuid>,
+    pub status: AssessmentStatus,
+}
+
+impl TryFrom<&tokio_postgres::Row> for InventoryAssessments {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            finished_at: row.try_get("finished_at")?,
+            lab_id: row.try_get("lab_id")?,
+            accountant_id: row.try_get("accountant_id")?,
+            status: row.try_get("status")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "inventory_assessments_devices")]
+pub struct InventoryAssessmentsDevices {
+    pub id: uuid::Uuid,
+    pub prev_status: Option<DeviceStatus>,
+    pub after_status: Option<DeviceStatus>,
+    pub assessing_id: Option<uuid::Uuid>,
+    pub device_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for InventoryAssessmentsDevices {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            prev_status: row.try_get("prev_status")?,
+            after_status: row.try_get("after_status")?,
+            assessing_id: row.try_get("assessing_id")?,
+            device_id: row.try_get("device_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "labs")]
+pub struct Labs {
+    pub id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub name: Option<String>,
+    pub faculty: Option<String>,
+    pub room: Option<String>,
+    pub branch: Option<String>,
+    pub timetable: serde_json::Value,
+    pub admin_id: Option<uuid::Uuid>,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Labs {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            name: row.try_get("name")?,
+            faculty: row.try_get("faculty")?,
+            room: row.try_get("room")?,
+            branch: row.try_get("branch")?,
+            timetable: row.try_get("timetable")?,
+            admin_id: row.try_get("admin_id")?,
+            deleted_at: row.try_get("deleted_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "maintenances")]
+pub struct Maintenances {
+    pub id: uuid::Uuid,
+    pub status: MaintenanceStatus,
+    pub maintainer_id: Option<uuid::Uuid>,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub lab_id: uuid::Uuid,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Maintenances {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            status: row.try_get("status")?,
+            maintainer_id: row.try_get("maintainer_id")?,
+            finished_at: row.try_get("finished_at")?,
+            lab_id: row.try_get("lab_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "maintenances_devices")]
+pub struct MaintenancesDevices {
+    pub id: uuid::Uuid,
+    pub prev_status: Option<DeviceStatus>,
+    pub after_status: Option<DeviceStatus>,
+    pub maintaining_id: uuid::Uuid,
+    pub device_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for MaintenancesDevices {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            prev_status: row.try_get("prev_status")?,
+            after_status: row.try_get("after_status")?,
+            maintaining_id: row.try_get("maintaining_id")?,
+            device_id: row.try_get("device_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "permissions")]
+pub struct Permissions {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub role_id: i64,
+    pub resource_id: i64,
+    pub action_id: i64,
+    pub priority: i32,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Permissions {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            created_at: row.try_get("created_at")?,
+            role_id: row.try_get("role_id")?,
+            resource_id: row.try_get("resource_id")?,
+            action_id: row.try_get("action_id")?,
+            priority: row.try_get("priority")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "receipts")]
+pub struct Receipts {
+    pub id: uuid::Uuid,
+    pub actor_id: uuid::Uuid,
+    pub checker_id: uuid::Uuid,
+    pub lab_id: uuid::Uuid,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Receipts {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            actor_id: row.try_get("actor_id")?,
+            checker_id: row.try_get("checker_id")?,
+            lab_id: row.try_get("lab_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "receipts_devices")]
+pub struct ReceiptsDevices {
+    pub borrowed_receipt_id: uuid::Uuid,
+    pub device_id: uuid::Uuid,
+    pub prev_quality: Option<DeviceStatus>,
+    pub borrow_id: Option<uuid::Uuid>,
+    pub return_id: Option<uuid::Uuid>,
+    pub expected_returned_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    pub expected_returned_lab_id: Option<uuid::Uuid>,
+    pub after_quality: Option<DeviceStatus>,
+    pub returned_receipt_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for ReceiptsDevices {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            borrowed_receipt_id: row.try_get("borrowed_receipt_id")?,
+            device_id: row.try_get("device_id")?,
+            prev_quality: row.try_get("prev_quality")?,
+            borrow_id: row.try_get("borrow_id")?,
+            return_id: row.try_get("return_id")?,
+            expected_returned_at: row.try_get("expected_returned_at")?,
+            id: row.try_get("id")?,
+            expected_returned_lab_id: row.try_get("expected_returned_lab_id")?,
+            after_quality: row.try_get("after_quality")?,
+            returned_receipt_id: row.try_get("returned_receipt_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "resources")]
+pub struct Resources {
+    pub id: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub name: String,
+    pub r#type: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Resources {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            name: row.try_get("name")?,
+            r#type: row.try_get("type")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "role_histories")]
+pub struct RoleHistories {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub grantee_id: uuid::Uuid,
+    pub granter_id: uuid::Uuid,
+    pub permissions: Option<serde_json::Value>,
+    pub effective_start: chrono::DateTime<chrono::Utc>,
+    pub effective_end: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for RoleHistories {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            created_at: row.try_get("created_at")?,
+            grantee_id: row.try_get("grantee_id")?,
+            granter_id: row.try_get("granter_id")?,
+            permissions: row.try_get("permissions")?,
+            effective_start: row.try_get("effective_start")?,
+            effective_end: row.try_get("effective_end")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "roles")]
+pub struct Roles {
+    pub id: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub key: String,
+    pub name: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Roles {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            key: row.try_get("key")?,
+            name: row.try_get("name")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "shipments")]
+pub struct Shipments {
+    pub id: String,
+    pub sender_id: Option<uuid::Uuid>,
+    pub receiver_id: Option<uuid::Uuid>,
+    pub status: ShipmentStatus,
+    pub start_lab_id: uuid::Uuid,
+    pub arrive_lab_id: uuid::Uuid,
+    pub from_at: Option<uuid::Uuid>,
+    pub to_at: Option<uuid::Uuid>,
+    pub check_at_destination: Option<bool>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Shipments {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            sender_id: row.try_get("sender_id")?,
+            receiver_id: row.try_get("receiver_id")?,
+            status: row.try_get("status")?,
+            start_lab_id: row.try_get("start_lab_id")?,
+            arrive_lab_id: row.try_get("arrive_lab_id")?,
+            from_at: row.try_get("from_at")?,
+            to_at: row.try_get("to_at")?,
+            check_at_destination: row.try_get("check_at_destination")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "shipments_devices")]
+pub struct ShipmentsDevices {
+    pub id: uuid::Uuid,
+    pub prev_status: Option<DeviceStatus>,
+    pub after_status: Option<DeviceStatus>,
+    pub shipment_id: String,
+    pub device_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for ShipmentsDevices {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            prev_status: row.try_get("prev_status")?,
+            after_status: row.try_get("after_status")?,
+            shipment_id: row.try_get("shipment_id")?,
+            device_id: row.try_get("device_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "used_qr_tokens")]
+pub struct UsedQrTokens {
+    pub id: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub token: String,
+    pub user_id: uuid::Uuid,
+}
+
+impl TryFrom<&tokio_postgres::Row> for UsedQrTokens {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            token: row.try_get("token")?,
+            user_id: row.try_get("user_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "user_roles")]
+pub struct UserRoles {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub user_id: uuid::Uuid,
+    pub role_id: i64,
+}
+
+impl TryFrom<&tokio_postgres::Row> for UserRoles {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            created_at: row.try_get("created_at")?,
+            user_id: row.try_get("user_id")?,
+            role_id: row.try_get("role_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "users")]
+pub struct Users {
+    pub id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub name: String,
+    pub meta: serde_json::Value,
+    pub email: Option<String>,
+    pub password: Option<String>,
+    pub image: Option<String>,
+    pub tel: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_active_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Users {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            name: row.try_get("name")?,
+            meta: row.try_get("meta")?,
+            email: row.try_get("email")?,
+            password: row.try_get("password")?,
+            image: row.try_get("image")?,
+            tel: row.try_get("tel")?,
+            deleted_at: row.try_get("deleted_at")?,
+            last_active_at: row.try_get("last_active_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "messages")]
+pub struct Messages {
+    pub id: i64,
+    pub topic: String,
+    pub extension: String,
+    pub inserted_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Messages {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            topic: row.try_get("topic")?,
+            extension: row.try_get("extension")?,
+            inserted_at: row.try_get("inserted_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "schema_migrations")]
+pub struct SchemaMigrations {
+    pub version: i64,
+    pub inserted_at: Option<chrono::NaiveDateTime>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for SchemaMigrations {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: row.try_get("version")?,
+            inserted_at: row.try_get("inserted_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "subscription")]
+pub struct Subscription {
+    pub id: i64,
+    pub subscription_id: uuid::Uuid,
+    pub entity: String,
+    pub filters: Vec<String>,
+    pub claims: serde_json::Value,
+    pub claims_role: String,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Subscription {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            subscription_id: row.try_get("subscription_id")?,
+            entity: row.try_get("entity")?,
+            filters: row.try_get("filters")?,
+            claims: row.try_get("claims")?,
+            claims_role: row.try_get("claims_role")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "buckets")]
+pub struct Buckets {
+    pub id: String,
+    pub name: String,
+    pub owner: Option<uuid::Uuid>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub public: Option<bool>,
+    pub avif_autodetection: Option<bool>,
+    pub file_size_limit: Option<i64>,
+    pub allowed_mime_types: Option<Vec<String>>,
+    pub owner_id: Option<String>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Buckets {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            owner: row.try_get("owner")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            public: row.try_get("public")?,
+            avif_autodetection: row.try_get("avif_autodetection")?,
+            file_size_limit: row.try_get("file_size_limit")?,
+            allowed_mime_types: row.try_get("allowed_mime_types")?,
+            owner_id: row.try_get("owner_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "migrations")]
+pub struct MigrationsStorage {
+    pub id: i32,
+    pub name: String,
+    pub hash: String,
+    pub executed_at: Option<chrono::NaiveDateTime>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for MigrationsStorage {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            hash: row.try_get("hash")?,
+            executed_at: row.try_get("executed_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "objects")]
+pub struct Objects {
+    pub id: uuid::Uuid,
+    pub bucket_id: Option<String>,
+    pub name: Option<String>,
+    pub owner: Option<uuid::Uuid>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub metadata: Option<serde_json::Value>,
+    pub path_tokens: Option<Vec<String>>,
+    pub version: Option<String>,
+    pub owner_id: Option<String>,
+    pub user_metadata: Option<serde_json::Value>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Objects {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            bucket_id: row.try_get("bucket_id")?,
+            name: row.try_get("name")?,
+            owner: row.try_get("owner")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            last_accessed_at: row.try_get("last_accessed_at")?,
+            metadata: row.try_get("metadata")?,
+            path_tokens: row.try_get("path_tokens")?,
+            version: row.try_get("version")?,
+            owner_id: row.try_get("owner_id")?,
+            user_metadata: row.try_get("user_metadata")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "s3_multipart_uploads")]
+pub struct S3MultipartUploads {
+    pub id: String,
+    pub in_progress_size: i64,
+    pub upload_signature: String,
+    pub bucket_id: String,
+    pub key: String,
+    pub version: String,
+    pub owner_id: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub user_metadata: Option<serde_json::Value>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for S3MultipartUploads {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            in_progress_size: row.try_get("in_progress_size")?,
+            upload_signature: row.try_get("upload_signature")?,
+            bucket_id: row.try_get("bucket_id")?,
+            key: row.try_get("key")?,
+            version: row.try_get("version")?,
+            owner_id: row.try_get("owner_id")?,
+            created_at: row.try_get("created_at")?,
+            user_metadata: row.try_get("user_metadata")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "s3_multipart_uploads_parts")]
+pub struct S3MultipartUploadsParts {
+    pub id: uuid::Uuid,
+    pub upload_id: String,
+    pub size: i64,
+    pub part_number: i32,
+    pub bucket_id: String,
+    pub key: String,
+    pub etag: String,
+    pub owner_id: Option<String>,
+    pub version: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for S3MultipartUploadsParts {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            upload_id: row.try_get("upload_id")?,
+            size: row.try_get("size")?,
+            part_number: row.try_get("part_number")?,
+            bucket_id: row.try_get("bucket_id")?,
+            key: row.try_get("key")?,
+            etag: row.try_get("etag")?,
+            owner_id: row.try_get("owner_id")?,
+            version: row.try_get("version")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "hooks")]
+pub struct Hooks {
+    pub id: i64,
+    pub hook_table_id: i32,
+    pub hook_name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub request_id: Option<i64>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Hooks {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            hook_table_id: row.try_get("hook_table_id")?,
+            hook_name: row.try_get("hook_name")?,
+            created_at: row.try_get("created_at")?,
+            request_id: row.try_get("request_id")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "migrations")]
+pub struct Migrations {
+    pub version: String,
+    pub inserted_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Migrations {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: row.try_get("version")?,
+            inserted_at: row.try_get("inserted_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "decrypted_secrets")]
+pub struct DecryptedSecrets {
+    pub id: Option<uuid::Uuid>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub secret: Option<String>,
+    pub decrypted_secret: Option<String>,
+    pub key_id: Option<uuid::Uuid>,
+    pub nonce: Option<Vec<u8>>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for DecryptedSecrets {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            description: row.try_get("description")?,
+            secret: row.try_get("secret")?,
+            decrypted_secret: row.try_get("decrypted_secret")?,
+            key_id: row.try_get("key_id")?,
+            nonce: row.try_get("nonce")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    postgres_types::ToSql,
+    postgres_types::FromSql,
+)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "secrets")]
+pub struct Secrets {
+    pub id: uuid::Uuid,
+    pub name: Option<String>,
+    pub description: String,
+    pub secret: String,
+    pub key_id: Option<uuid::Uuid>,
+    pub nonce: Option<Vec<u8>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<&tokio_postgres::Row> for Secrets {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &tokio_postgres::Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            description: row.try_get("description")?,
+            secret: row.try_get("secret")?,
+            key_id: row.try_get("key_id")?,
+            nonce: row.try_get("nonce")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
@@ -0,0 +1,3 @@
+pub mod operation;
+pub mod replay;
+pub mod store;
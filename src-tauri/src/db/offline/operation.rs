@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::db::lifecycle::ActivityType;
+
+/// A write the kiosk can perform without the central database. Recorded in
+/// the outbox while offline and replayed once the database is back. IDs are
+/// chosen by the kiosk so a replay that is retried is recognised as already
+/// applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum OfflineOperation {
+    Borrow(BorrowOperation),
+    Return(ReturnOperation),
+    Audit(AuditOperation),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowOperation {
+    pub receipt_id: String,
+    pub borrower_id: String,
+    #[serde(default)]
+    pub checker_id: String,
+    #[serde(default)]
+    pub lab_id: String,
+    pub devices: Vec<BorrowedDevice>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowedDevice {
+    pub id: String,
+    pub expected_returned_at: DateTime<Utc>,
+    pub expected_returned_lab_id: Option<String>,
+    pub prev_quality: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnOperation {
+    pub receipt_id: String,
+    pub returner_id: String,
+    #[serde(default)]
+    pub checker_id: String,
+    #[serde(default)]
+    pub lab_id: String,
+    pub devices: Vec<ReturnedDevice>,
+    pub note: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnedDevice {
+    pub id: String,
+    pub after_quality: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditOperation {
+    pub audit_id: String,
+    #[serde(default)]
+    pub auditor_id: String,
+    #[serde(default)]
+    pub lab_id: String,
+    pub devices: Vec<AuditedDevice>,
+    pub status: Option<String>,
+    pub notes: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditedDevice {
+    pub id: String,
+    pub condition: String,
+    /// Status the kiosk saw when the device was scanned; the replay reports
+    /// a conflict if the server has a different one.
+    pub prev_status: Option<String>,
+}
+
+impl OfflineOperation {
+    /// The receipt or audit ID, unique per operation.
+    pub fn id(&self) -> &str {
+        match self {
+            OfflineOperation::Borrow(op) => &op.receipt_id,
+            OfflineOperation::Return(op) => &op.receipt_id,
+            OfflineOperation::Audit(op) => &op.audit_id,
+        }
+    }
+
+    /// Records the signed-in user as the checker or auditor, and their lab,
+    /// whatever the UI sent.
+    pub fn bind_session(&mut self, user_id: &str, lab_id: &str) {
+        let (actor, lab) = match self {
+            OfflineOperation::Borrow(op) => (&mut op.checker_id, &mut op.lab_id),
+            OfflineOperation::Return(op) => (&mut op.checker_id, &mut op.lab_id),
+            OfflineOperation::Audit(op) => (&mut op.auditor_id, &mut op.lab_id),
+        };
+        *actor = user_id.to_string();
+        *lab = lab_id.to_string();
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            OfflineOperation::Borrow(_) => "borrow",
+            OfflineOperation::Return(_) => "return",
+            OfflineOperation::Audit(_) => "audit",
+        }
+    }
+
+    /// The activity the operation performs and the status it moves each
+    /// device to, as `(device_id, status)`.
+    pub fn status_targets(&self) -> (ActivityType, Vec<(&str, &str)>) {
+        match self {
+            OfflineOperation::Borrow(op) => (
+                ActivityType::Borrow,
+                op.devices
+                    .iter()
+                    .map(|d| (d.id.as_str(), "borrowing"))
+                    .collect(),
+            ),
+            OfflineOperation::Return(op) => (
+                ActivityType::Return,
+                op.devices
+                    .iter()
+                    .map(|d| (d.id.as_str(), d.after_quality.as_str()))
+                    .collect(),
+            ),
+            OfflineOperation::Audit(op) => (
+                ActivityType::Assessment,
+                op.devices
+                    .iter()
+                    .map(|d| (d.id.as_str(), "assessing"))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn device_ids(&self) -> Vec<String> {
+        match self {
+            OfflineOperation::Borrow(op) => op.devices.iter().map(|d| d.id.clone()).collect(),
+            OfflineOperation::Return(op) => op.devices.iter().map(|d| d.id.clone()).collect(),
+            OfflineOperation::Audit(op) => op.devices.iter().map(|d| d.id.clone()).collect(),
+        }
+    }
+}
@@ -0,0 +1,648 @@
+use deadpool_postgres::Transaction;
+use serde_json::{json, Value as JsonValue};
+use std::time::Duration;
+use tokio::sync::watch;
+
+use super::operation::{AuditOperation, BorrowOperation, OfflineOperation, ReturnOperation};
+use super::store::{CachedEntity, OfflineResult, OfflineStore, OutboxState, OutboxSummary};
+use crate::db::access::KindRules;
+use crate::db::connection::{Database, DbError, DbResult};
+use crate::db::lifecycle::{
+    self, ActivityType, ChangeContext, DeviceStatus, LifecycleError, Transition,
+};
+use crate::db::receipts;
+use crate::db::supervisor::DbStatus;
+
+/// Event emitted to the frontend with the [`OutboxSummary`] after every
+/// sync and whenever an operation is queued.
+pub const OUTBOX_CHANGED_EVENT: &str = "offline://outbox-changed";
+
+/// How often the cache is refreshed while the database is reachable.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// A database error is retried this many times before the entry is marked
+/// failed; connection problems do not count.
+const MAX_ATTEMPTS: u32 = 5;
+
+/// What happened when an operation was sent to the database.
+#[derive(Debug)]
+pub enum ReplayOutcome {
+    Applied,
+    /// The operation's ID is already in the database, from an earlier
+    /// attempt whose result was lost.
+    AlreadyApplied,
+    /// The database changed in a way that makes the operation invalid,
+    /// e.g. a device was lent from another kiosk in the meantime.
+    Conflict(Vec<String>),
+}
+
+/// Applies `operation` in one transaction after checking it against the
+/// current rows, which are locked for the duration.
+pub async fn apply(db: &Database, operation: &OfflineOperation) -> DbResult<ReplayOutcome> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+
+    let devices = lock_devices(&tx, &operation.device_ids()).await?;
+    let outcome = match operation {
+        OfflineOperation::Borrow(op) => apply_borrow(&tx, op, &devices).await?,
+        OfflineOperation::Return(op) => apply_return(&tx, op, &devices).await?,
+        OfflineOperation::Audit(op) => apply_audit(&tx, op, &devices).await?,
+    };
+
+    match outcome {
+        ReplayOutcome::Applied => {
+            tx.commit().await?;
+            db.mark_write();
+        }
+        _ => tx.rollback().await?,
+    }
+    Ok(outcome)
+}
+
+/// A device row as locked for replay, with the rules of its kind.
+struct LockedDevice {
+    id: String,
+    status: String,
+    lab_id: Option<String>,
+    rules: KindRules,
+}
+
+/// The given devices that exist and are not deleted.
+async fn lock_devices(tx: &Transaction<'_>, ids: &[String]) -> DbResult<Vec<LockedDevice>> {
+    let rows = tx
+        .query(
+            "SELECT d.id::text, d.status::text, d.lab_id::text, dk.name::text,
+                    dk.allowed_borrow_roles::text[], COALESCE(dk.is_borrowable_lab_only, false)
+             FROM devices d
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             WHERE d.id::text = ANY($1) AND d.deleted_at IS NULL
+             FOR UPDATE OF d",
+            &[&ids],
+        )
+        .await?;
+    Ok(rows
+        .iter()
+        .map(|row| LockedDevice {
+            id: row.get(0),
+            status: row.get(1),
+            lab_id: row.get(2),
+            rules: KindRules {
+                kind_name: row.get(3),
+                allowed_borrow_roles: row.get(4),
+                lab_only: row.get(5),
+                ..KindRules::default()
+            },
+        })
+        .collect())
+}
+
+async fn receipt_exists(tx: &Transaction<'_>, id: &str) -> DbResult<bool> {
+    let row = tx
+        .query_one(
+            "SELECT EXISTS (SELECT 1 FROM receipts WHERE id::text = $1)",
+            &[&id],
+        )
+        .await?;
+    Ok(row.get(0))
+}
+
+fn find<'a>(devices: &'a [LockedDevice], id: &str) -> Option<&'a LockedDevice> {
+    devices.iter().find(|device| device.id == id)
+}
+
+fn status_of<'a>(devices: &'a [LockedDevice], id: &str) -> Option<&'a str> {
+    find(devices, id).map(|device| device.status.as_str())
+}
+
+/// Checks a device's move with [`lifecycle::check`], returning the
+/// transition or why it is a conflict.
+fn transition(
+    activity: ActivityType,
+    device_id: &str,
+    from: &str,
+    to: &str,
+) -> Result<Transition, String> {
+    let from: DeviceStatus = from
+        .parse()
+        .map_err(|e| format!("Device {}: {}", device_id, e))?;
+    let to: DeviceStatus = to
+        .parse()
+        .map_err(|e| format!("Device {}: {}", device_id, e))?;
+    lifecycle::check(activity, device_id, from, to).map_err(|e| e.to_string())?;
+    Ok(Transition {
+        device_id: device_id.to_string(),
+        from,
+        to,
+    })
+}
+
+/// Applies checked transitions; a device that changed in between makes the
+/// whole operation a conflict.
+async fn move_devices(
+    tx: &Transaction<'_>,
+    context: ChangeContext<'_>,
+    transitions: &[Transition],
+) -> DbResult<ReplayOutcome> {
+    match lifecycle::apply(tx, context, transitions).await {
+        Ok(()) => Ok(ReplayOutcome::Applied),
+        Err(LifecycleError::Db(e)) => Err(e),
+        Err(e) => Ok(ReplayOutcome::Conflict(vec![e.to_string()])),
+    }
+}
+
+async fn apply_borrow(
+    tx: &Transaction<'_>,
+    op: &BorrowOperation,
+    devices: &[LockedDevice],
+) -> DbResult<ReplayOutcome> {
+    if receipt_exists(tx, &op.receipt_id).await? {
+        return Ok(ReplayOutcome::AlreadyApplied);
+    }
+
+    let ids: Vec<String> = op.devices.iter().map(|d| d.id.clone()).collect();
+    let open = tx
+        .query(
+            "SELECT device_id::text, borrowed_receipt_id::text FROM receipts_devices
+             WHERE device_id::text = ANY($1) AND returned_receipt_id IS NULL",
+            &[&ids],
+        )
+        .await?;
+
+    let mut conflicts = Vec::new();
+    // The borrower's roles as they are now, not as cached when queued.
+    let roles = match receipts::user_roles(tx, &op.borrower_id).await? {
+        Some(roles) => roles,
+        None => {
+            conflicts.push(format!("User {} no longer exists", op.borrower_id));
+            Vec::new()
+        }
+    };
+    let mut transitions = Vec::new();
+    for device in &op.devices {
+        let Some(row) = find(devices, &device.id) else {
+            conflicts.push(format!("Device {} no longer exists", device.id));
+            continue;
+        };
+        match transition(ActivityType::Borrow, &device.id, &row.status, "borrowing") {
+            Ok(t) => transitions.push(t),
+            Err(_) => conflicts.push(format!("Device {} is now {}", device.id, row.status)),
+        }
+        if let Some(open) = open
+            .iter()
+            .find(|open| open.get::<_, String>(0) == device.id)
+        {
+            conflicts.push(format!(
+                "Device {} was borrowed on receipt {} in the meantime",
+                device.id,
+                open.get::<_, String>(1)
+            ));
+        }
+        let return_lab = device
+            .expected_returned_lab_id
+            .as_deref()
+            .unwrap_or(&op.lab_id);
+        let checked = row.rules.check_borrow(&roles).and_then(|_| {
+            row.rules
+                .check_borrow_lab(&row.id, row.lab_id.as_deref(), &op.lab_id, return_lab)
+        });
+        if let Err(denial) = checked {
+            conflicts.push(denial.to_string());
+        }
+    }
+    if !conflicts.is_empty() {
+        return Ok(ReplayOutcome::Conflict(conflicts));
+    }
+
+    let receipt = json!({
+        "id": op.receipt_id,
+        "actor_id": op.borrower_id,
+        "checker_id": op.checker_id,
+        "lab_id": op.lab_id,
+    });
+    let rows: JsonValue = op
+        .devices
+        .iter()
+        .map(|d| {
+            json!({
+                "borrowed_receipt_id": op.receipt_id,
+                "device_id": d.id,
+                "expected_returned_at": d.expected_returned_at,
+                "expected_returned_lab_id": d.expected_returned_lab_id.as_deref().unwrap_or(&op.lab_id),
+                "prev_quality": d.prev_quality.as_deref().unwrap_or("healthy"),
+            })
+        })
+        .collect();
+
+    // json_populate_record converts each field to the column's own type,
+    // whatever the ID columns are declared as.
+    tx.execute(
+        "INSERT INTO receipts (id, actor_id, checker_id, lab_id)
+         SELECT id, actor_id, checker_id, lab_id
+         FROM json_populate_record(NULL::receipts, $1)",
+        &[&receipt],
+    )
+    .await?;
+    let activity_id: String = tx
+        .query_one(
+            "WITH activity AS (
+                INSERT INTO activities (type, created_at)
+                VALUES ('borrow'::activity_type, $2)
+                RETURNING id
+             ),
+             inserted AS (
+                INSERT INTO receipts_devices (
+                    borrowed_receipt_id, device_id, borrow_id,
+                    expected_returned_at, expected_returned_lab_id, prev_quality
+                )
+                SELECT r.borrowed_receipt_id, r.device_id, (SELECT id FROM activity),
+                       r.expected_returned_at, r.expected_returned_lab_id, r.prev_quality
+                FROM json_populate_recordset(NULL::receipts_devices, $1) r
+             )
+             SELECT id::text FROM activity",
+            &[&rows, &op.recorded_at],
+        )
+        .await?
+        .get(0);
+
+    let reason = format!("Borrowed on receipt {} while offline", op.receipt_id);
+    move_devices(
+        tx,
+        ChangeContext {
+            activity: ActivityType::Borrow,
+            activity_id: Some(&activity_id),
+            actor_id: Some(&op.checker_id),
+            reason: Some(&reason),
+        },
+        &transitions,
+    )
+    .await
+}
+
+async fn apply_return(
+    tx: &Transaction<'_>,
+    op: &ReturnOperation,
+    devices: &[LockedDevice],
+) -> DbResult<ReplayOutcome> {
+    if receipt_exists(tx, &op.receipt_id).await? {
+        return Ok(ReplayOutcome::AlreadyApplied);
+    }
+
+    let ids: Vec<String> = op.devices.iter().map(|d| d.id.clone()).collect();
+    let open = tx
+        .query(
+            "SELECT rd.device_id::text, rd.borrowed_receipt_id::text, r.actor_id::text,
+                    COALESCE(rd.expected_returned_lab_id, r.lab_id)::text, r.lab_id::text
+             FROM receipts_devices rd
+             JOIN receipts r ON r.id = rd.borrowed_receipt_id
+             WHERE rd.device_id::text = ANY($1) AND rd.returned_receipt_id IS NULL
+             FOR UPDATE OF rd",
+            &[&ids],
+        )
+        .await?;
+
+    let mut conflicts = Vec::new();
+    let mut transitions = Vec::new();
+    for device in &op.devices {
+        let Some(loan) = open.iter().find(|row| row.get::<_, String>(0) == device.id) else {
+            conflicts.push(format!(
+                "Device {} is no longer borrowed; it was returned elsewhere",
+                device.id
+            ));
+            continue;
+        };
+        let borrowed_receipt_id: String = loan.get(1);
+        let borrower_id: String = loan.get(2);
+        let return_lab_id: String = loan.get(3);
+        let borrowed_lab_id: String = loan.get(4);
+        if borrower_id != op.returner_id {
+            conflicts.push(format!(
+                "Device {} was borrowed by someone else on receipt {}",
+                device.id, borrowed_receipt_id
+            ));
+            continue;
+        }
+        if let Some(row) = find(devices, &device.id) {
+            if let Err(denial) =
+                row.rules
+                    .check_return_lab(&device.id, &borrowed_lab_id, &op.lab_id)
+            {
+                conflicts.push(denial.to_string());
+                continue;
+            }
+        }
+        if return_lab_id != op.lab_id {
+            conflicts.push(format!(
+                "Device {} must be returned to lab {}",
+                device.id, return_lab_id
+            ));
+            continue;
+        }
+        let status = status_of(devices, &device.id).unwrap_or("missing");
+        match transition(
+            ActivityType::Return,
+            &device.id,
+            status,
+            &device.after_quality,
+        ) {
+            Ok(t) => transitions.push(t),
+            Err(conflict) => conflicts.push(conflict),
+        }
+    }
+    if !conflicts.is_empty() {
+        return Ok(ReplayOutcome::Conflict(conflicts));
+    }
+
+    let receipt = json!({
+        "id": op.receipt_id,
+        "actor_id": op.returner_id,
+        "checker_id": op.checker_id,
+        "lab_id": op.lab_id,
+    });
+    let rows: JsonValue = op
+        .devices
+        .iter()
+        .map(|d| json!({ "device_id": d.id, "after_quality": d.after_quality }))
+        .collect();
+
+    tx.execute(
+        "INSERT INTO receipts (id, actor_id, checker_id, lab_id)
+         SELECT id, actor_id, checker_id, lab_id
+         FROM json_populate_record(NULL::receipts, $1)",
+        &[&receipt],
+    )
+    .await?;
+    let activity_id: String = tx
+        .query_one(
+            "WITH activity AS (
+                INSERT INTO activities (type, note, created_at)
+                VALUES ('return'::activity_type, $3, $4)
+                RETURNING id
+             ),
+             returned AS (
+                UPDATE receipts_devices rd
+                SET returned_receipt_id = (SELECT id FROM receipts WHERE id::text = $2),
+                    after_quality = v.after_quality,
+                    return_id = (SELECT id FROM activity)
+                FROM json_populate_recordset(NULL::receipts_devices, $1) v
+                WHERE rd.device_id = v.device_id AND rd.returned_receipt_id IS NULL
+             )
+             SELECT id::text FROM activity",
+            &[&rows, &op.receipt_id, &op.note, &op.recorded_at],
+        )
+        .await?
+        .get(0);
+
+    let reason = format!("Returned on receipt {} while offline", op.receipt_id);
+    move_devices(
+        tx,
+        ChangeContext {
+            activity: ActivityType::Return,
+            activity_id: Some(&activity_id),
+            actor_id: Some(&op.checker_id),
+            reason: Some(&reason),
+        },
+        &transitions,
+    )
+    .await
+}
+
+async fn apply_audit(
+    tx: &Transaction<'_>,
+    op: &AuditOperation,
+    devices: &[LockedDevice],
+) -> DbResult<ReplayOutcome> {
+    let exists: bool = tx
+        .query_one(
+            "SELECT EXISTS (SELECT 1 FROM inventory_assessments WHERE id::text = $1)",
+            &[&op.audit_id],
+        )
+        .await?
+        .get(0);
+    if exists {
+        return Ok(ReplayOutcome::AlreadyApplied);
+    }
+
+    let mut conflicts = Vec::new();
+    let mut transitions = Vec::new();
+    for device in &op.devices {
+        match (
+            status_of(devices, &device.id),
+            device.prev_status.as_deref(),
+        ) {
+            (None, _) => conflicts.push(format!("Device {} no longer exists", device.id)),
+            (Some(now), Some(seen)) if now != seen => conflicts.push(format!(
+                "Device {} changed from {} to {} since it was audited",
+                device.id, seen, now
+            )),
+            (Some(now), _) => {
+                match transition(ActivityType::Assessment, &device.id, now, "assessing") {
+                    Ok(t) => transitions.push(t),
+                    Err(conflict) => conflicts.push(conflict),
+                }
+            }
+        }
+    }
+    if !conflicts.is_empty() {
+        return Ok(ReplayOutcome::Conflict(conflicts));
+    }
+
+    let status = op.status.as_deref().unwrap_or("assessing");
+    let assessment = json!({
+        "id": op.audit_id,
+        "type": "assessment",
+        "note": op.notes,
+        "created_at": op.recorded_at,
+        "lab_id": op.lab_id,
+        "accountant_id": op.auditor_id,
+        "status": status,
+        "finished_at": (status == "completed").then_some(op.recorded_at),
+    });
+    let rows: JsonValue = op
+        .devices
+        .iter()
+        .map(|d| {
+            json!({
+                "assessing_id": op.audit_id,
+                "device_id": d.id,
+                "prev_status": d.prev_status,
+                "after_status": d.condition,
+            })
+        })
+        .collect();
+
+    tx.execute(
+        "INSERT INTO activities (id, type, note, created_at)
+         SELECT id, type, note, created_at FROM json_populate_record(NULL::activities, $1)",
+        &[&assessment],
+    )
+    .await?;
+    tx.execute(
+        "INSERT INTO inventory_assessments (id, lab_id, accountant_id, status, finished_at)
+         SELECT id, lab_id, accountant_id, status, finished_at
+         FROM json_populate_record(NULL::inventory_assessments, $1)",
+        &[&assessment],
+    )
+    .await?;
+    tx.execute(
+        "INSERT INTO inventory_assessments_devices (prev_status, after_status, assessing_id, device_id)
+         SELECT prev_status, after_status, assessing_id, device_id
+         FROM json_populate_recordset(NULL::inventory_assessments_devices, $1)",
+        &[&rows],
+    )
+    .await?;
+
+    let reason = format!("Audited while offline in assessment {}", op.audit_id);
+    move_devices(
+        tx,
+        ChangeContext {
+            activity: ActivityType::Assessment,
+            activity_id: Some(&op.audit_id),
+            actor_id: Some(&op.auditor_id),
+            reason: Some(&reason),
+        },
+        &transitions,
+    )
+    .await
+}
+
+/// Replays pending entries in the order they were recorded and stops at
+/// the first connection problem, leaving the rest for the next sync.
+pub async fn replay_pending(db: &Database, store: &OfflineStore) -> OfflineResult<()> {
+    let pending = store.entries(Some(OutboxState::Pending))?;
+
+    for entry in pending {
+        let (state, message, count_attempt) = match apply(db, &entry.operation).await {
+            Ok(ReplayOutcome::Applied) | Ok(ReplayOutcome::AlreadyApplied) => {
+                (OutboxState::Applied, None, true)
+            }
+            Ok(ReplayOutcome::Conflict(conflicts)) => {
+                eprintln!(
+                    "Warning: Offline {} {} conflicts: {}",
+                    entry.operation.kind(),
+                    entry.operation.id(),
+                    conflicts.join("; ")
+                );
+                (OutboxState::Conflict, Some(conflicts.join("\n")), true)
+            }
+            // Rejected by the server: retrying will not help forever.
+            Err(DbError::Postgres(e)) if e.as_db_error().is_some() => {
+                let state = if entry.attempts + 1 >= MAX_ATTEMPTS {
+                    OutboxState::Failed
+                } else {
+                    OutboxState::Pending
+                };
+                (state, Some(e.to_string()), true)
+            }
+            Err(e) => return Err(e.into()),
+        };
+        store.mark(entry.seq, state, message.as_deref(), count_attempt)?;
+    }
+    Ok(())
+}
+
+/// Reloads every cached entity from the database.
+pub async fn refresh_cache(db: &Database, store: &OfflineStore) -> OfflineResult<()> {
+    let client = db.get_client().await?;
+    let lab_id = store.lab_id();
+
+    for entity in CachedEntity::ALL {
+        let rows = match entity {
+            CachedEntity::Devices => {
+                db.query_cached(
+                    &client,
+                    "SELECT d.id::text, to_jsonb(d) FROM devices d
+                     WHERE d.deleted_at IS NULL
+                       AND ($1::text IS NULL OR d.lab_id::text = $1)",
+                    &[&lab_id],
+                )
+                .await
+            }
+            CachedEntity::DeviceKinds => {
+                db.query_cached(
+                    &client,
+                    "SELECT k.id::text, to_jsonb(k) FROM device_kinds k
+                     WHERE k.deleted_at IS NULL",
+                    &[],
+                )
+                .await
+            }
+            CachedEntity::Users => {
+                db.query_cached(
+                    &client,
+                    "SELECT u.id::text, to_jsonb(u) - 'password' FROM users u
+                     WHERE u.deleted_at IS NULL",
+                    &[],
+                )
+                .await
+            }
+            CachedEntity::OpenReceipts => {
+                db.query_cached(
+                    &client,
+                    "SELECT rd.device_id::text,
+                            to_jsonb(rd) || jsonb_build_object(
+                                'actor_id', r.actor_id,
+                                'checker_id', r.checker_id,
+                                'lab_id', r.lab_id)
+                     FROM receipts_devices rd
+                     JOIN receipts r ON r.id = rd.borrowed_receipt_id
+                     JOIN devices d ON d.id = rd.device_id
+                     WHERE rd.returned_receipt_id IS NULL
+                       AND ($1::text IS NULL OR d.lab_id::text = $1)",
+                    &[&lab_id],
+                )
+                .await
+            }
+        }
+        .map_err(DbError::from)?;
+
+        let rows: Vec<(String, JsonValue)> =
+            rows.iter().map(|row| (row.get(0), row.get(1))).collect();
+        store.replace_cache(entity, &rows)?;
+    }
+    Ok(())
+}
+
+/// Keeps the offline store in step with the database: whenever the
+/// database becomes available, and then every few minutes, replays the
+/// outbox and refreshes the cache. `on_change` receives the outbox counts
+/// after each pass. Runs until the task is dropped.
+pub async fn sync<F>(
+    db: Database,
+    store: OfflineStore,
+    mut status: watch::Receiver<DbStatus>,
+    on_change: F,
+) where
+    F: Fn(OutboxSummary) + Send + 'static,
+{
+    loop {
+        if status
+            .wait_for(|s| matches!(s, DbStatus::Available))
+            .await
+            .is_err()
+        {
+            return;
+        }
+
+        if let Err(e) = replay_pending(&db, &store).await {
+            eprintln!("Warning: Outbox replay interrupted: {}", e);
+        }
+        // The replay goes first so the snapshot includes what it wrote.
+        if let Err(e) = refresh_cache(&db, &store).await {
+            eprintln!("Warning: Failed to refresh offline cache: {}", e);
+        }
+        match store.summary() {
+            Ok(summary) => on_change(summary),
+            Err(e) => eprintln!("Warning: Failed to read outbox: {}", e),
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(REFRESH_INTERVAL) => {}
+            _ = store.retried() => {}
+            changed = status.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,536 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+use super::operation::OfflineOperation;
+use crate::db::connection::DbError;
+use crate::db::lifecycle::{self, DeviceStatus};
+
+/// Directory mounted into the container by docker-compose.
+const DEFAULT_DATA_DIR: &str = "/app/data";
+
+const SCHEMA: &str = r#"
+    PRAGMA journal_mode = WAL;
+    PRAGMA synchronous = FULL;
+
+    CREATE TABLE IF NOT EXISTS cache_entries (
+        entity TEXT NOT NULL,
+        id TEXT NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (entity, id)
+    );
+
+    CREATE TABLE IF NOT EXISTS cache_refreshes (
+        entity TEXT PRIMARY KEY,
+        refreshed_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS outbox (
+        seq INTEGER PRIMARY KEY AUTOINCREMENT,
+        operation_id TEXT NOT NULL UNIQUE,
+        kind TEXT NOT NULL,
+        payload TEXT NOT NULL,
+        state TEXT NOT NULL DEFAULT 'pending',
+        attempts INTEGER NOT NULL DEFAULT 0,
+        message TEXT,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+"#;
+
+#[derive(Debug, thiserror::Error)]
+pub enum OfflineError {
+    #[error("Offline store error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    #[error("Offline store payload error: {0}")]
+    Payload(#[from] serde_json::Error),
+    #[error("Offline store I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Database(#[from] DbError),
+    #[error("{0}")]
+    Rejected(String),
+}
+
+pub type OfflineResult<T> = Result<T, OfflineError>;
+
+/// Data cached for use while the database is unreachable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CachedEntity {
+    Devices,
+    DeviceKinds,
+    Users,
+    /// Unreturned `receipts_devices` rows, keyed by device ID.
+    OpenReceipts,
+}
+
+impl CachedEntity {
+    pub const ALL: [CachedEntity; 4] = [
+        CachedEntity::Devices,
+        CachedEntity::DeviceKinds,
+        CachedEntity::Users,
+        CachedEntity::OpenReceipts,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CachedEntity::Devices => "devices",
+            CachedEntity::DeviceKinds => "device_kinds",
+            CachedEntity::Users => "users",
+            CachedEntity::OpenReceipts => "open_receipts",
+        }
+    }
+}
+
+impl FromStr for CachedEntity {
+    type Err = OfflineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CachedEntity::ALL
+            .into_iter()
+            .find(|entity| entity.as_str() == s)
+            .ok_or_else(|| OfflineError::Rejected(format!("Unknown cached entity '{}'", s)))
+    }
+}
+
+/// Where an outbox entry stands. `Conflict` and `Failed` wait for staff to
+/// retry or discard them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OutboxState {
+    Pending,
+    Applied,
+    Conflict,
+    Failed,
+}
+
+impl OutboxState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboxState::Pending => "pending",
+            OutboxState::Applied => "applied",
+            OutboxState::Conflict => "conflict",
+            OutboxState::Failed => "failed",
+        }
+    }
+
+    fn parse(s: &str) -> Self {
+        match s {
+            "applied" => OutboxState::Applied,
+            "conflict" => OutboxState::Conflict,
+            "failed" => OutboxState::Failed,
+            _ => OutboxState::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxEntry {
+    pub seq: i64,
+    pub operation: OfflineOperation,
+    pub state: OutboxState,
+    pub attempts: u32,
+    /// Conflict details or the last error.
+    pub message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Counts sent with `offline://outbox-changed`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxSummary {
+    pub pending: u32,
+    pub conflicts: u32,
+    pub failed: u32,
+    /// Age of the offline data, for showing staff how current it is.
+    pub cache_refreshed_at: Option<DateTime<Utc>>,
+}
+
+/// The kiosk's SQLite file: a snapshot of the lab's data for offline reads
+/// and the durable outbox of writes made while offline. Cheap to clone.
+#[derive(Clone)]
+pub struct OfflineStore {
+    conn: Arc<Mutex<Connection>>,
+    lab_id: Option<String>,
+    pending: Arc<Notify>,
+}
+
+impl OfflineStore {
+    /// Opens `HMI_OFFLINE_DB`, default `offline.sqlite` in `HMI_DATA_DIR`
+    /// (`/app/data`). `HMI_LAB_ID` limits the cached devices and receipts
+    /// to one lab.
+    pub fn from_env() -> OfflineResult<Self> {
+        let path = match std::env::var("HMI_OFFLINE_DB") {
+            Ok(path) if !path.trim().is_empty() => PathBuf::from(path),
+            _ => std::env::var("HMI_DATA_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from(DEFAULT_DATA_DIR))
+                .join("offline.sqlite"),
+        };
+        let lab_id = std::env::var("HMI_LAB_ID")
+            .ok()
+            .filter(|id| !id.trim().is_empty());
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let conn = Connection::open(&path)?;
+        conn.execute_batch(SCHEMA)?;
+
+        Ok(OfflineStore {
+            conn: Arc::new(Mutex::new(conn)),
+            lab_id,
+            pending: Arc::new(Notify::new()),
+        })
+    }
+
+    pub fn lab_id(&self) -> Option<&str> {
+        self.lab_id.as_deref()
+    }
+
+    /// Replaces the cached rows of `entity` with `rows` of `(id, data)`.
+    pub fn replace_cache(
+        &self,
+        entity: CachedEntity,
+        rows: &[(String, JsonValue)],
+    ) -> OfflineResult<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM cache_entries WHERE entity = ?1",
+            params![entity.as_str()],
+        )?;
+        {
+            let mut insert =
+                tx.prepare("INSERT INTO cache_entries (entity, id, data) VALUES (?1, ?2, ?3)")?;
+            for (id, data) in rows {
+                insert.execute(params![entity.as_str(), id, data.to_string()])?;
+            }
+        }
+        tx.execute(
+            "INSERT INTO cache_refreshes (entity, refreshed_at) VALUES (?1, ?2)
+             ON CONFLICT (entity) DO UPDATE SET refreshed_at = excluded.refreshed_at",
+            params![entity.as_str(), Utc::now().to_rfc3339()],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn cached(&self, entity: CachedEntity, id: &str) -> OfflineResult<Option<JsonValue>> {
+        cached_entry(&self.conn.lock().unwrap(), entity, id)
+    }
+
+    pub fn cached_all(&self, entity: CachedEntity) -> OfflineResult<Vec<JsonValue>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT data FROM cache_entries WHERE entity = ?1")?;
+        let rows = stmt.query_map(params![entity.as_str()], |row| row.get::<_, String>(0))?;
+        rows.map(|data| Ok(serde_json::from_str(&data?)?)).collect()
+    }
+
+    /// When `entity` was last refreshed from the database.
+    pub fn refreshed_at(&self, entity: CachedEntity) -> OfflineResult<Option<DateTime<Utc>>> {
+        let conn = self.conn.lock().unwrap();
+        let at: Option<String> = conn
+            .query_row(
+                "SELECT refreshed_at FROM cache_refreshes WHERE entity = ?1",
+                params![entity.as_str()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(at.as_deref().and_then(parse_time))
+    }
+
+    /// Checks `operation` against the cache, records it in the outbox and
+    /// applies its effect to the cache so later offline reads see it.
+    /// Rejects operations the cache already shows to be impossible, such
+    /// as borrowing a device that is out.
+    pub fn enqueue(&self, operation: &OfflineOperation) -> OfflineResult<i64> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        check_against_cache(&tx, operation)?;
+        let now = Utc::now().to_rfc3339();
+        tx.execute(
+            "INSERT INTO outbox (operation_id, kind, payload, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?4)",
+            params![
+                operation.id(),
+                operation.kind(),
+                serde_json::to_string(operation)?,
+                now
+            ],
+        )?;
+        let seq = tx.last_insert_rowid();
+        apply_to_cache(&tx, operation)?;
+        tx.commit()?;
+        Ok(seq)
+    }
+
+    /// Entries in order of recording, optionally only those in `state`.
+    pub fn entries(&self, state: Option<OutboxState>) -> OfflineResult<Vec<OutboxEntry>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT seq, payload, state, attempts, message, created_at, updated_at
+             FROM outbox WHERE ?1 IS NULL OR state = ?1 ORDER BY seq",
+        )?;
+        let rows = stmt.query_map(params![state.map(|s| s.as_str())], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, u32>(3)?,
+                row.get::<_, Option<String>>(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, String>(6)?,
+            ))
+        })?;
+
+        rows.map(|row| {
+            let (seq, payload, state, attempts, message, created_at, updated_at) = row?;
+            Ok(OutboxEntry {
+                seq,
+                operation: serde_json::from_str(&payload)?,
+                state: OutboxState::parse(&state),
+                attempts,
+                message,
+                created_at: parse_time(&created_at).unwrap_or_default(),
+                updated_at: parse_time(&updated_at).unwrap_or_default(),
+            })
+        })
+        .collect()
+    }
+
+    pub fn summary(&self) -> OfflineResult<OutboxSummary> {
+        let mut summary = OutboxSummary {
+            cache_refreshed_at: self.refreshed_at(CachedEntity::Devices)?,
+            ..OutboxSummary::default()
+        };
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT state, COUNT(*) FROM outbox GROUP BY state")?;
+        let rows = stmt.query_map(params![], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, u32>(1)?))
+        })?;
+
+        for row in rows {
+            let (state, count) = row?;
+            match OutboxState::parse(&state) {
+                OutboxState::Pending => summary.pending = count,
+                OutboxState::Conflict => summary.conflicts = count,
+                OutboxState::Failed => summary.failed = count,
+                OutboxState::Applied => {}
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Records the outcome of a replay attempt.
+    pub fn mark(
+        &self,
+        seq: i64,
+        state: OutboxState,
+        message: Option<&str>,
+        count_attempt: bool,
+    ) -> OfflineResult<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "UPDATE outbox
+             SET state = ?2, message = ?3, attempts = attempts + ?4, updated_at = ?5
+             WHERE seq = ?1",
+            params![
+                seq,
+                state.as_str(),
+                message,
+                count_attempt as i64,
+                Utc::now().to_rfc3339()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Puts a conflicted or failed entry back in the queue and wakes the
+    /// replay.
+    pub fn retry(&self, seq: i64) -> OfflineResult<bool> {
+        let updated = {
+            let conn = self.conn.lock().unwrap();
+            conn.execute(
+                "UPDATE outbox SET state = 'pending', updated_at = ?2
+                 WHERE seq = ?1 AND state IN ('conflict', 'failed')",
+                params![seq, Utc::now().to_rfc3339()],
+            )?
+        };
+        if updated > 0 {
+            self.pending.notify_one();
+        }
+        Ok(updated > 0)
+    }
+
+    /// Drops an entry that staff decided not to apply. Applied entries are
+    /// kept as a record and cannot be discarded.
+    pub fn discard(&self, seq: i64) -> OfflineResult<bool> {
+        let conn = self.conn.lock().unwrap();
+        let deleted = conn.execute(
+            "DELETE FROM outbox WHERE seq = ?1 AND state <> 'applied'",
+            params![seq],
+        )?;
+        Ok(deleted > 0)
+    }
+
+    /// Resolves when `retry` queued an entry.
+    pub async fn retried(&self) {
+        self.pending.notified().await
+    }
+}
+
+fn cached_entry(
+    conn: &Connection,
+    entity: CachedEntity,
+    id: &str,
+) -> OfflineResult<Option<JsonValue>> {
+    let data: Option<String> = conn
+        .query_row(
+            "SELECT data FROM cache_entries WHERE entity = ?1 AND id = ?2",
+            params![entity.as_str(), id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(data.map(|data| serde_json::from_str(&data)).transpose()?)
+}
+
+/// Rejects operations the cached rows already show to be impossible. Runs
+/// inside the enqueue transaction, so two operations recorded at once
+/// cannot both pass against the same cache.
+fn check_against_cache(conn: &Connection, operation: &OfflineOperation) -> OfflineResult<()> {
+    let mut problems = Vec::new();
+    match operation {
+        OfflineOperation::Borrow(op) => {
+            for device in &op.devices {
+                if let Some(receipt) = cached_entry(conn, CachedEntity::OpenReceipts, &device.id)? {
+                    problems.push(format!(
+                        "Device {} is already borrowed on receipt {}",
+                        device.id,
+                        receipt["borrowed_receipt_id"].as_str().unwrap_or("?")
+                    ));
+                }
+            }
+        }
+        OfflineOperation::Return(op) => {
+            for device in &op.devices {
+                match cached_entry(conn, CachedEntity::OpenReceipts, &device.id)? {
+                    None => problems.push(format!("Device {} is not borrowed", device.id)),
+                    Some(receipt)
+                        if receipt["actor_id"].as_str() != Some(op.returner_id.as_str()) =>
+                    {
+                        problems.push(format!(
+                            "Device {} was borrowed by someone else on receipt {}",
+                            device.id,
+                            receipt["borrowed_receipt_id"].as_str().unwrap_or("?")
+                        ))
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        OfflineOperation::Audit(_) => {}
+    }
+
+    // Devices missing from the cache are left for the replay to check.
+    if problems.is_empty() {
+        let (activity, targets) = operation.status_targets();
+        for (device_id, to) in targets {
+            let Some(device) = cached_entry(conn, CachedEntity::Devices, device_id)? else {
+                continue;
+            };
+            let from = device["status"].as_str().unwrap_or_default();
+            match (from.parse::<DeviceStatus>(), to.parse::<DeviceStatus>()) {
+                (Ok(from), Ok(to)) => {
+                    if let Err(e) = lifecycle::check(activity, device_id, from, to) {
+                        problems.push(e.to_string());
+                    }
+                }
+                (Err(e), _) | (_, Err(e)) => problems.push(format!("Device {}: {}", device_id, e)),
+            }
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(OfflineError::Rejected(problems.join("; ")))
+    }
+}
+
+/// Mirrors an operation's effect on the cached rows, inside the enqueue
+/// transaction.
+fn apply_to_cache(conn: &Connection, operation: &OfflineOperation) -> OfflineResult<()> {
+    let set_status = |device_id: &str, status: &str| -> OfflineResult<()> {
+        let data: Option<String> = conn
+            .query_row(
+                "SELECT data FROM cache_entries WHERE entity = 'devices' AND id = ?1",
+                params![device_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(data) = data {
+            let mut device: JsonValue = serde_json::from_str(&data)?;
+            device["status"] = JsonValue::String(status.to_string());
+            conn.execute(
+                "UPDATE cache_entries SET data = ?2 WHERE entity = 'devices' AND id = ?1",
+                params![device_id, device.to_string()],
+            )?;
+        }
+        Ok(())
+    };
+
+    match operation {
+        OfflineOperation::Borrow(op) => {
+            for device in &op.devices {
+                set_status(&device.id, "borrowing")?;
+                let receipt = serde_json::json!({
+                    "borrowed_receipt_id": op.receipt_id,
+                    "device_id": device.id,
+                    "expected_returned_at": device.expected_returned_at,
+                    "expected_returned_lab_id": device.expected_returned_lab_id,
+                    "prev_quality": device.prev_quality,
+                    "actor_id": op.borrower_id,
+                    "checker_id": op.checker_id,
+                    "lab_id": op.lab_id,
+                    "offline": true,
+                });
+                conn.execute(
+                    "INSERT OR REPLACE INTO cache_entries (entity, id, data)
+                     VALUES ('open_receipts', ?1, ?2)",
+                    params![device.id, receipt.to_string()],
+                )?;
+            }
+        }
+        OfflineOperation::Return(op) => {
+            for device in &op.devices {
+                set_status(&device.id, &device.after_quality)?;
+                conn.execute(
+                    "DELETE FROM cache_entries WHERE entity = 'open_receipts' AND id = ?1",
+                    params![device.id],
+                )?;
+            }
+        }
+        OfflineOperation::Audit(op) => {
+            for device in &op.devices {
+                set_status(&device.id, "assessing")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
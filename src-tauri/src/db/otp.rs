@@ -0,0 +1,411 @@
+use chrono::Utc;
+use ring::hmac;
+use std::fmt;
+use std::path::Path;
+
+use super::connection::{Database, DbError};
+use super::hmi::{load_user, HmiUser};
+
+/// Prefix of the message a user's key is derived with, so the master
+/// secret can be shared with other purposes without producing the same key.
+const USER_KEY_CONTEXT: &[u8] = b"one-time-qr:";
+
+#[derive(Debug, thiserror::Error)]
+pub enum OtpError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error("Invalid one-time code settings: {0}")]
+    Config(String),
+    #[error(
+        "No one-time code secret configured; set HMI_OTP_SECRET, HMI_OTP_SECRET_FILE or HMI_OTP_SECRET_NAME"
+    )]
+    NoSecret,
+    #[error("One-time code is invalid or has expired")]
+    Rejected,
+    #[error("One-time code has already been used")]
+    Replayed,
+    #[error("User {0} not found")]
+    UnknownUser(String),
+}
+
+impl From<tokio_postgres::Error> for OtpError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        OtpError::Db(error.into())
+    }
+}
+
+pub type OtpResult<T> = Result<T, OtpError>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtpAlgorithm {
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl OtpAlgorithm {
+    fn hmac(self) -> hmac::Algorithm {
+        match self {
+            OtpAlgorithm::Sha1 => hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+            OtpAlgorithm::Sha256 => hmac::HMAC_SHA256,
+            OtpAlgorithm::Sha512 => hmac::HMAC_SHA512,
+        }
+    }
+}
+
+/// Where the master secret comes from. Never serialized or printed.
+#[derive(Clone)]
+pub enum SecretSource {
+    /// From `HMI_OTP_SECRET` or the file named by `HMI_OTP_SECRET_FILE`.
+    Key(Vec<u8>),
+    /// The Supabase Vault secret named by `HMI_OTP_SECRET_NAME`, read on
+    /// each verification so it can be rotated without a restart.
+    Vault(String),
+    None,
+}
+
+impl fmt::Debug for SecretSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretSource::Key(_) => f.write_str("Key(..)"),
+            SecretSource::Vault(name) => f.debug_tuple("Vault").field(name).finish(),
+            SecretSource::None => f.write_str("None"),
+        }
+    }
+}
+
+/// How one-time codes are generated and checked, from the environment.
+#[derive(Debug, Clone)]
+pub struct OtpSettings {
+    /// `HMI_OTP_DIGITS`, 6 to 8; 6 by default.
+    pub digits: u32,
+    /// `HMI_OTP_STEP_SECS`, how long each TOTP code lasts; 60 by default.
+    pub step: u64,
+    /// `HMI_OTP_SKEW_STEPS`, how many steps either side of the current one
+    /// are accepted to allow for clock drift; 1 by default.
+    pub skew: u64,
+    /// `HMI_OTP_ALGORITHM`: `sha1`, `sha256` (default) or `sha512`.
+    pub algorithm: OtpAlgorithm,
+    pub secret: SecretSource,
+}
+
+impl Default for OtpSettings {
+    fn default() -> Self {
+        OtpSettings {
+            digits: 6,
+            step: 60,
+            skew: 1,
+            algorithm: OtpAlgorithm::Sha256,
+            secret: SecretSource::None,
+        }
+    }
+}
+
+impl OtpSettings {
+    /// Reads the settings, taking the secret from `HMI_OTP_SECRET`, then
+    /// `HMI_OTP_SECRET_FILE`, then `HMI_OTP_SECRET_NAME`. A missing secret
+    /// is not an error here; verification fails until one is configured.
+    pub fn from_env() -> OtpResult<Self> {
+        let mut settings = OtpSettings::default();
+        if let Some(digits) = env_number("HMI_OTP_DIGITS")? {
+            if !(6..=8).contains(&digits) {
+                return Err(OtpError::Config(format!(
+                    "HMI_OTP_DIGITS must be between 6 and 8, got {}",
+                    digits
+                )));
+            }
+            settings.digits = digits as u32;
+        }
+        if let Some(step) = env_number("HMI_OTP_STEP_SECS")? {
+            if step == 0 {
+                return Err(OtpError::Config(
+                    "HMI_OTP_STEP_SECS must be positive".to_string(),
+                ));
+            }
+            settings.step = step;
+        }
+        if let Some(skew) = env_number("HMI_OTP_SKEW_STEPS")? {
+            settings.skew = skew;
+        }
+        if let Some(algorithm) = env_value("HMI_OTP_ALGORITHM") {
+            settings.algorithm = match algorithm.to_ascii_lowercase().as_str() {
+                "sha1" => OtpAlgorithm::Sha1,
+                "sha256" => OtpAlgorithm::Sha256,
+                "sha512" => OtpAlgorithm::Sha512,
+                other => {
+                    return Err(OtpError::Config(format!(
+                        "Unknown HMI_OTP_ALGORITHM {:?}",
+                        other
+                    )))
+                }
+            };
+        }
+        settings.secret = if let Some(secret) = env_value("HMI_OTP_SECRET") {
+            SecretSource::Key(secret.into_bytes())
+        } else if let Some(path) = env_value("HMI_OTP_SECRET_FILE") {
+            SecretSource::Key(read_secret_file(Path::new(&path))?)
+        } else if let Some(name) = env_value("HMI_OTP_SECRET_NAME") {
+            SecretSource::Vault(name)
+        } else {
+            SecretSource::None
+        };
+        Ok(settings)
+    }
+
+    /// The TOTP counter for a Unix time in seconds.
+    pub fn counter_at(&self, unix_secs: u64) -> u64 {
+        unix_secs / self.step
+    }
+
+    /// The HOTP code for `counter` (RFC 4226).
+    pub fn hotp(&self, key: &[u8], counter: u64) -> String {
+        let key = hmac::Key::new(self.algorithm.hmac(), key);
+        let tag = hmac::sign(&key, &counter.to_be_bytes());
+        let tag = tag.as_ref();
+        let offset = (tag[tag.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            tag[offset] & 0x7f,
+            tag[offset + 1],
+            tag[offset + 2],
+            tag[offset + 3],
+        ]);
+        let code = binary % 10u32.pow(self.digits);
+        format!("{:0width$}", code, width = self.digits as usize)
+    }
+
+    /// Checks an HOTP code against `counter` and the `look_ahead` counters
+    /// after it, returning the one it matched.
+    pub fn verify_hotp(
+        &self,
+        key: &[u8],
+        code: &str,
+        counter: u64,
+        look_ahead: u64,
+    ) -> Option<u64> {
+        (counter..=counter.saturating_add(look_ahead))
+            .find(|&candidate| codes_match(&self.hotp(key, candidate), code))
+    }
+
+    /// Checks a TOTP code against the step at `unix_secs` and the
+    /// [`skew`](Self::skew) steps either side, returning the counter it
+    /// matched.
+    pub fn verify_totp(&self, key: &[u8], code: &str, unix_secs: u64) -> Option<u64> {
+        let counter = self.counter_at(unix_secs);
+        self.verify_hotp(
+            key,
+            code,
+            counter.saturating_sub(self.skew),
+            counter.min(self.skew) + self.skew,
+        )
+    }
+
+    /// Seconds a matched code stays inside the accepted window, which is
+    /// how long it must be remembered to stop it being replayed.
+    fn window_secs(&self) -> u64 {
+        (2 * self.skew + 1) * self.step
+    }
+
+    async fn master_key(&self, db: &Database) -> OtpResult<Vec<u8>> {
+        match &self.secret {
+            SecretSource::Key(key) => Ok(key.clone()),
+            SecretSource::Vault(name) => {
+                let client = db.get_client().await?;
+                let row = client
+                    .query_opt(
+                        "SELECT decrypted_secret FROM vault.decrypted_secrets WHERE name = $1",
+                        &[name],
+                    )
+                    .await?;
+                row.and_then(|row| row.get::<_, Option<String>>(0))
+                    .map(String::into_bytes)
+                    .ok_or(OtpError::NoSecret)
+            }
+            SecretSource::None => Err(OtpError::NoSecret),
+        }
+    }
+}
+
+/// The key a user's codes are generated with:
+/// `HMAC-SHA256(master, "one-time-qr:" || user_id)`. Whatever issues the
+/// codes derives it the same way, so the master secret never leaves the
+/// servers.
+pub fn user_key(master: &[u8], user_id: &str) -> Vec<u8> {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, master);
+    let mut context = hmac::Context::with_key(&key);
+    context.update(USER_KEY_CONTEXT);
+    context.update(user_id.as_bytes());
+    context.sign().as_ref().to_vec()
+}
+
+/// Verifies the code in a user's one-time QR and returns the user. A code
+/// is accepted once: it is recorded in `used_qr_tokens` under the step it
+/// matched, and records older than the accepted window are cleared.
+pub async fn verify_one_time_qr(
+    db: &Database,
+    settings: &OtpSettings,
+    user_id: &str,
+    code: &str,
+) -> OtpResult<HmiUser> {
+    let key = user_key(&settings.master_key(db).await?, user_id);
+    let now = Utc::now().timestamp().max(0) as u64;
+    let counter = settings
+        .verify_totp(&key, code.trim(), now)
+        .ok_or(OtpError::Rejected)?;
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    tx.execute(
+        "SELECT pg_advisory_xact_lock(hashtext('used_qr_tokens:' || $1))",
+        &[&user_id],
+    )
+    .await?;
+
+    let user = load_user(&tx, user_id)
+        .await?
+        .ok_or_else(|| OtpError::UnknownUser(user_id.to_string()))?;
+
+    tx.execute(
+        "DELETE FROM used_qr_tokens
+         WHERE user_id::text = $1 AND created_at < now() - make_interval(secs => $2::int)",
+        &[&user_id, &(settings.window_secs() as i32)],
+    )
+    .await?;
+    let token = format!("{}:{}", counter, code.trim());
+    let inserted = tx
+        .execute(
+            "INSERT INTO used_qr_tokens (token, user_id, created_at)
+             SELECT $1, $2, now()
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM used_qr_tokens WHERE token = $1 AND user_id::text = $2
+             )",
+            &[&token, &user_id],
+        )
+        .await?;
+    if inserted == 0 {
+        return Err(OtpError::Replayed);
+    }
+
+    tx.commit().await?;
+    db.mark_write();
+    Ok(user)
+}
+
+/// Compares without stopping at the first differing digit.
+fn codes_match(expected: &str, given: &str) -> bool {
+    expected.len() == given.len()
+        && expected
+            .bytes()
+            .zip(given.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn env_value(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn env_number(name: &str) -> OtpResult<Option<u64>> {
+    env_value(name)
+        .map(|value| {
+            value.parse().map_err(|_| {
+                OtpError::Config(format!("{} must be a number, got {:?}", name, value))
+            })
+        })
+        .transpose()
+}
+
+fn read_secret_file(path: &Path) -> OtpResult<Vec<u8>> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        OtpError::Config(format!(
+            "Failed to read secret file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    Ok(contents.trim_end_matches(['\r', '\n']).as_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(algorithm: OtpAlgorithm, digits: u32, step: u64) -> OtpSettings {
+        OtpSettings {
+            digits,
+            step,
+            skew: 1,
+            algorithm,
+            secret: SecretSource::None,
+        }
+    }
+
+    /// RFC 4226, Appendix D.
+    #[test]
+    fn hotp_matches_rfc_4226() {
+        let settings = settings(OtpAlgorithm::Sha1, 6, 30);
+        let expected = [
+            "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583",
+            "399871", "520489",
+        ];
+        for (counter, code) in expected.iter().enumerate() {
+            assert_eq!(
+                settings.hotp(b"12345678901234567890", counter as u64),
+                *code
+            );
+        }
+    }
+
+    /// RFC 6238, Appendix B. Each algorithm has its own seed length.
+    #[test]
+    fn totp_matches_rfc_6238() {
+        let sha1: &[u8] = b"12345678901234567890";
+        let sha256: &[u8] = b"12345678901234567890123456789012";
+        let sha512: &[u8] = b"1234567890123456789012345678901234567890123456789012345678901234";
+        let expected: [(u64, [&str; 3]); 6] = [
+            (59, ["94287082", "46119246", "90693936"]),
+            (1111111109, ["07081804", "68084774", "25091201"]),
+            (1111111111, ["14050471", "67062674", "99943326"]),
+            (1234567890, ["89005924", "91819424", "93441116"]),
+            (2000000000, ["69279037", "90698825", "38618901"]),
+            (20000000000, ["65353130", "77737706", "47863826"]),
+        ];
+        let algorithms = [
+            (OtpAlgorithm::Sha1, sha1),
+            (OtpAlgorithm::Sha256, sha256),
+            (OtpAlgorithm::Sha512, sha512),
+        ];
+        for (time, codes) in expected {
+            for ((algorithm, key), code) in algorithms.iter().zip(codes) {
+                let settings = settings(*algorithm, 8, 30);
+                assert_eq!(
+                    settings.hotp(key, settings.counter_at(time)),
+                    code,
+                    "{:?} at {}",
+                    algorithm,
+                    time
+                );
+                assert_eq!(
+                    settings.verify_totp(key, code, time),
+                    Some(settings.counter_at(time))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn verify_totp_accepts_only_the_skew_window() {
+        let settings = settings(OtpAlgorithm::Sha256, 6, 60);
+        let key = user_key(b"master", "u1");
+        let now = 1_800_000_000;
+        let counter = settings.counter_at(now);
+        let code = settings.hotp(&key, counter);
+
+        assert_eq!(settings.verify_totp(&key, &code, now - 60), Some(counter));
+        assert_eq!(settings.verify_totp(&key, &code, now + 60), Some(counter));
+        assert_eq!(settings.verify_totp(&key, &code, now + 120), None);
+        assert_eq!(settings.verify_totp(&key, "000000x", now), None);
+    }
+}
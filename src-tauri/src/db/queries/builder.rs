@@ -1,7 +1,10 @@
-use super::super::schema::DatabaseSchema;
-use chrono::{TimeZone, Utc};
+use super::super::schema::{ColumnInfo, DatabaseSchema};
+use bytes::BytesMut;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use rust_decimal::Decimal;
 use serde::Serialize;
-use tokio_postgres::types::ToSql;
+use tokio_postgres::types::{to_sql_checked, IsNull, Kind, ToSql, Type};
+use uuid::Uuid;
 
 pub struct QueryBuilder<'a> {
     pub schema: &'a DatabaseSchema,
@@ -252,6 +255,10 @@ impl<'a> QueryBuilder<'a> {
         (query, params)
     }
 
+    /// Builds an `INSERT ... RETURNING *` for the non-null keys of `value`.
+    /// Returns `None` if nothing would be inserted or a value cannot be
+    /// converted to its column type; callers are expected to have run
+    /// `validation::validate_write` first so that case is a bug, not bad input.
     pub fn build_insert<T: Serialize>(
         value: &T,
         schema: &DatabaseSchema,
@@ -276,58 +283,9 @@ impl<'a> QueryBuilder<'a> {
             if let Some(val) = value_map.get(&column.name) {
                 if !val.is_null() {
                     added_columns.insert(column.name.clone());
-
-                    match val {
-                        serde_json::Value::String(s) => {
-                            columns.push(column.name.clone());
-                            param_positions.push(format!("${}", params.len() + 1));
-                            params.push(Box::new(s.clone()))
-                        }
-                        serde_json::Value::Number(n) => {
-                            if column.type_name == "timestamptz"
-                                || column.type_name == "timestamp"
-                                || column.type_name == "timestamp with time zone"
-                            {
-                                if let Some(millis) = n.as_i64() {
-                                    if let Some(datetime) =
-                                        Utc.timestamp_millis_opt(millis).single()
-                                    {
-                                        columns.push(column.name.clone());
-                                        param_positions.push(format!("${}", params.len() + 1));
-                                        params.push(Box::new(datetime));
-                                    } else {
-                                        added_columns.remove(&column.name);
-                                        continue;
-                                    }
-                                } else {
-                                    added_columns.remove(&column.name);
-                                    continue;
-                                }
-                            } else {
-                                if let Some(i) = n.as_i64() {
-                                    columns.push(column.name.clone());
-                                    param_positions.push(format!("${}", params.len() + 1));
-                                    params.push(Box::new(i));
-                                } else if let Some(f) = n.as_f64() {
-                                    columns.push(column.name.clone());
-                                    param_positions.push(format!("${}", params.len() + 1));
-                                    params.push(Box::new(f));
-                                } else {
-                                    added_columns.remove(&column.name);
-                                    continue;
-                                }
-                            }
-                        }
-                        serde_json::Value::Bool(b) => {
-                            columns.push(column.name.clone());
-                            param_positions.push(format!("${}", params.len() + 1));
-                            params.push(Box::new(*b));
-                        }
-                        _ => {
-                            added_columns.remove(&column.name);
-                            continue;
-                        }
-                    }
+                    columns.push(column.name.clone());
+                    param_positions.push(format!("${}", params.len() + 1));
+                    params.push(column_param(schema, column, val)?);
                 }
             }
         }
@@ -347,3 +305,121 @@ impl<'a> QueryBuilder<'a> {
         Some((query, params))
     }
 }
+
+/// Text value bound to a Postgres enum column. `String` only accepts text
+/// types, so enum columns need their own `ToSql`.
+#[derive(Debug)]
+pub struct EnumValue(pub String);
+
+impl ToSql for EnumValue {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        out.extend_from_slice(self.0.as_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Enum(_))
+    }
+
+    to_sql_checked!();
+}
+
+type Param = Box<dyn ToSql + Sync + Send>;
+
+fn column_param(
+    schema: &DatabaseSchema,
+    column: &ColumnInfo,
+    val: &serde_json::Value,
+) -> Option<Param> {
+    if column.enum_values.is_some() {
+        return Some(Box::new(EnumValue(val.as_str()?.to_string())));
+    }
+
+    if let Some(element) = column.udt_name.strip_prefix('_') {
+        if column.type_name == "ARRAY" {
+            let items = val.as_array()?;
+            return array_param(element, schema.enums.contains_key(element), items);
+        }
+    }
+
+    let pg_type = if column.udt_name.is_empty() {
+        column.type_name.as_str()
+    } else {
+        column.udt_name.as_str()
+    };
+
+    scalar_param(pg_type, val)
+}
+
+fn scalar_param(pg_type: &str, val: &serde_json::Value) -> Option<Param> {
+    use serde_json::Value;
+
+    Some(match (pg_type, val) {
+        ("json" | "jsonb", _) => Box::new(val.clone()),
+        ("uuid", Value::String(s)) => Box::new(Uuid::parse_str(s).ok()?),
+        ("timestamptz" | "timestamp" | "timestamp with time zone", Value::String(s)) => {
+            Box::new(DateTime::parse_from_rfc3339(s).ok()?.with_timezone(&Utc))
+        }
+        ("timestamptz" | "timestamp" | "timestamp with time zone", Value::Number(n)) => {
+            Box::new(Utc.timestamp_millis_opt(n.as_i64()?).single()?)
+        }
+        ("date", Value::String(s)) => Box::new(NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?),
+        // Numbers are converted to the column's own type: the driver only
+        // binds an i64 to int8 and an f64 to float8.
+        ("int2" | "smallint", Value::Number(n)) => Box::new(i16::try_from(n.as_i64()?).ok()?),
+        ("int4" | "integer" | "serial", Value::Number(n)) => {
+            Box::new(i32::try_from(n.as_i64()?).ok()?)
+        }
+        ("int8" | "bigint" | "bigserial", Value::Number(n)) => Box::new(n.as_i64()?),
+        ("float4" | "real", Value::Number(n)) => Box::new(n.as_f64()? as f32),
+        ("float8" | "double precision", Value::Number(n)) => Box::new(n.as_f64()?),
+        ("numeric" | "decimal", Value::Number(n)) => Box::new(decimal(n)?),
+        (_, Value::Number(n)) => match n.as_i64() {
+            Some(i) => Box::new(i),
+            None => Box::new(n.as_f64()?),
+        },
+        (_, Value::String(s)) => Box::new(s.clone()),
+        (_, Value::Bool(b)) => Box::new(*b),
+        _ => return None,
+    })
+}
+
+/// The exact decimal a JSON number was written as, e.g. `0.1` rather than
+/// the nearest `f64`.
+fn decimal(n: &serde_json::Number) -> Option<Decimal> {
+    let text = n.to_string();
+    text.parse()
+        .or_else(|_| Decimal::from_scientific(&text))
+        .ok()
+}
+
+fn array_param(element: &str, is_enum: bool, items: &[serde_json::Value]) -> Option<Param> {
+    fn collect<T>(
+        items: &[serde_json::Value],
+        f: impl Fn(&serde_json::Value) -> Option<T>,
+    ) -> Option<Vec<T>> {
+        items.iter().map(f).collect()
+    }
+
+    Some(match element {
+        "uuid" => Box::new(collect(items, |v| Uuid::parse_str(v.as_str()?).ok())?),
+        "int2" => Box::new(collect(items, |v| i16::try_from(v.as_i64()?).ok())?),
+        "int4" => Box::new(collect(items, |v| i32::try_from(v.as_i64()?).ok())?),
+        "int8" => Box::new(collect(items, |v| v.as_i64())?),
+        "float4" => Box::new(collect(items, |v| v.as_f64().map(|f| f as f32))?),
+        "float8" => Box::new(collect(items, |v| v.as_f64())?),
+        "numeric" => Box::new(collect(items, |v| match v {
+            serde_json::Value::Number(n) => decimal(n),
+            _ => None,
+        })?),
+        "bool" => Box::new(collect(items, |v| v.as_bool())?),
+        _ if is_enum => Box::new(collect(items, |v| {
+            Some(EnumValue(v.as_str()?.to_string()))
+        })?),
+        _ => Box::new(collect(items, |v| v.as_str().map(str::to_string))?),
+    })
+}
@@ -1 +1 @@
-pub mod builder; 
\ No newline at end of file
+pub mod builder;
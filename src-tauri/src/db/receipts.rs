@@ -0,0 +1,783 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::Transaction;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashSet;
+
+use super::access::KindRules;
+use super::connection::{Database, DbError};
+use super::lifecycle::{
+    self, ActivityType, ChangeContext, DeviceStatus, LifecycleError, Transition,
+};
+use super::validation::{summarize, FieldError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReceiptError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error(transparent)]
+    Lifecycle(#[from] LifecycleError),
+    #[error("Receipt {0} already exists")]
+    Exists(String),
+    /// Problems with the request, reported against the field or device
+    /// (`devices[2]`) they concern.
+    #[error("{}", summarize(.0))]
+    Invalid(Vec<FieldError>),
+}
+
+impl From<tokio_postgres::Error> for ReceiptError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        ReceiptError::Db(error.into())
+    }
+}
+
+pub type ReceiptResult<T> = Result<T, ReceiptError>;
+
+/// A borrow as submitted by the kiosk. The receipt ID is chosen by the
+/// kiosk, so it can be shown and printed before the write completes. The
+/// checker and lab are those of the kiosk session.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBorrowReceipt {
+    pub id: String,
+    pub borrower_id: String,
+    #[serde(default)]
+    pub checker_id: String,
+    #[serde(default)]
+    pub lab_id: String,
+    pub devices: Vec<NewBorrowedDevice>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBorrowedDevice {
+    pub id: String,
+    pub expected_returned_at: DateTime<Utc>,
+    /// Defaults to the lab the device is borrowed from.
+    pub expected_returned_lab_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowReceipt {
+    pub id: String,
+    pub borrower_id: String,
+    pub checker_id: String,
+    pub lab_id: String,
+    pub activity_id: String,
+    pub created_at: DateTime<Utc>,
+    pub devices: Vec<BorrowedDevice>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowedDevice {
+    pub device_id: String,
+    pub expected_returned_at: DateTime<Utc>,
+    pub expected_returned_lab_id: String,
+    pub prev_quality: String,
+}
+
+/// A return as submitted by the kiosk. Any subset of the devices on one or
+/// more borrow receipts can be returned; the rest stay borrowed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewReturnReceipt {
+    pub id: String,
+    pub returner_id: String,
+    /// The signed-in user, from the kiosk session.
+    #[serde(default)]
+    pub checker_id: String,
+    /// The lab the devices are returned to: the session's lab.
+    #[serde(default)]
+    pub lab_id: String,
+    pub devices: Vec<NewReturnedDevice>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewReturnedDevice {
+    pub id: String,
+    /// Becomes the device's status; `healthy` or `broken`.
+    pub after_quality: DeviceStatus,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnReceipt {
+    pub id: String,
+    pub returner_id: String,
+    pub checker_id: String,
+    pub lab_id: String,
+    pub activity_id: String,
+    pub created_at: DateTime<Utc>,
+    pub devices: Vec<ReturnedDevice>,
+    /// Borrow receipts touched by this return that still have devices out.
+    pub still_open: Vec<OpenBorrow>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnedDevice {
+    pub device_id: String,
+    pub borrowed_receipt_id: String,
+    pub expected_returned_at: DateTime<Utc>,
+    /// Returned after `expected_returned_at`.
+    pub late: bool,
+    pub prev_quality: Option<String>,
+    pub after_quality: DeviceStatus,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenBorrow {
+    pub borrowed_receipt_id: String,
+    pub remaining: i64,
+}
+
+/// A device row as locked for a borrow, with the rules of its kind.
+struct LockedDevice {
+    id: String,
+    status: DeviceStatus,
+    lab_id: Option<String>,
+    rules: KindRules,
+}
+
+/// Checks and writes a borrow in one transaction. The devices are locked
+/// first, so two kiosks lending the same device cannot both succeed. Every
+/// problem found is reported, not just the first.
+pub async fn create_borrow_receipt(
+    db: &Database,
+    receipt: &NewBorrowReceipt,
+) -> ReceiptResult<BorrowReceipt> {
+    let device_ids: Vec<&str> = receipt.devices.iter().map(|d| d.id.as_str()).collect();
+    let mut errors = check_request(
+        [
+            ("id", &receipt.id),
+            ("borrowerId", &receipt.borrower_id),
+            ("checkerId", &receipt.checker_id),
+            ("labId", &receipt.lab_id),
+        ],
+        &device_ids,
+    );
+    if !errors.is_empty() {
+        return Err(ReceiptError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+
+    let roles = check_references(
+        &tx,
+        &receipt.id,
+        &receipt.lab_id,
+        ("borrowerId", &receipt.borrower_id),
+        &receipt.checker_id,
+        &mut errors,
+    )
+    .await?;
+
+    let ids: Vec<String> = receipt.devices.iter().map(|d| d.id.clone()).collect();
+    let locked = lock_devices(&tx, &ids).await?;
+    for (index, device) in receipt.devices.iter().enumerate() {
+        let field = format!("devices[{}]", index);
+        let Some(row) = locked.iter().find(|row| row.id == device.id) else {
+            errors.push(FieldError::new(
+                field,
+                format!("Device {} does not exist", device.id),
+            ));
+            continue;
+        };
+        if let Some(message) = check_device(receipt, device, row, &roles) {
+            errors.push(FieldError::new(field, message));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(ReceiptError::Invalid(errors));
+    }
+
+    let created = insert_borrow(&tx, receipt, &locked).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(created)
+}
+
+/// Checks that need no database access: the receipt's own fields are set
+/// and the device list is non-empty and has no duplicates.
+fn check_request(fields: [(&str, &String); 4], device_ids: &[&str]) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    for (field, value) in fields {
+        if value.trim().is_empty() {
+            errors.push(FieldError::new(field, "Must not be empty"));
+        }
+    }
+    if device_ids.is_empty() {
+        errors.push(FieldError::new("devices", "No devices specified"));
+    }
+
+    let mut seen = HashSet::new();
+    for (index, id) in device_ids.iter().enumerate() {
+        if !seen.insert(id) {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} is listed more than once", id),
+            ));
+        }
+    }
+    errors
+}
+
+/// Checks that the receipt ID is unused and that the lab and both users
+/// exist. Returns the role keys of `actor`, the borrower or returner.
+async fn check_references(
+    tx: &Transaction<'_>,
+    receipt_id: &str,
+    lab_id: &str,
+    (actor_field, actor_id): (&str, &str),
+    checker_id: &str,
+    errors: &mut Vec<FieldError>,
+) -> ReceiptResult<Vec<String>> {
+    if receipt_exists(tx, receipt_id).await? {
+        return Err(ReceiptError::Exists(receipt_id.to_string()));
+    }
+    if !lab_exists(tx, lab_id).await? {
+        errors.push(FieldError::new("labId", "Lab does not exist"));
+    }
+    let roles = match user_roles(tx, actor_id).await? {
+        Some(roles) => roles,
+        None => {
+            errors.push(FieldError::new(actor_field, "User does not exist"));
+            Vec::new()
+        }
+    };
+    if user_roles(tx, checker_id).await?.is_none() {
+        errors.push(FieldError::new("checkerId", "User does not exist"));
+    }
+    Ok(roles)
+}
+
+fn check_device(
+    receipt: &NewBorrowReceipt,
+    device: &NewBorrowedDevice,
+    row: &LockedDevice,
+    roles: &[String],
+) -> Option<String> {
+    if lifecycle::check(
+        ActivityType::Borrow,
+        &row.id,
+        row.status,
+        DeviceStatus::Borrowing,
+    )
+    .is_err()
+    {
+        return Some(format!(
+            "Device {} is {} and cannot be borrowed",
+            row.id, row.status
+        ));
+    }
+    let return_lab = device
+        .expected_returned_lab_id
+        .as_deref()
+        .unwrap_or(&receipt.lab_id);
+    let checked = row.rules.check_borrow(roles).and_then(|_| {
+        row.rules
+            .check_borrow_lab(&row.id, row.lab_id.as_deref(), &receipt.lab_id, return_lab)
+    });
+    checked.err().map(|denial| denial.to_string())
+}
+
+async fn receipt_exists(tx: &Transaction<'_>, id: &str) -> Result<bool, DbError> {
+    let row = tx
+        .query_one(
+            "SELECT EXISTS (SELECT 1 FROM receipts WHERE id::text = $1)",
+            &[&id],
+        )
+        .await?;
+    Ok(row.get(0))
+}
+
+async fn lab_exists(tx: &Transaction<'_>, id: &str) -> Result<bool, DbError> {
+    let row = tx
+        .query_one(
+            "SELECT EXISTS (SELECT 1 FROM labs WHERE id::text = $1 AND deleted_at IS NULL)",
+            &[&id],
+        )
+        .await?;
+    Ok(row.get(0))
+}
+
+/// Role keys of a user who exists and is not deleted.
+pub(crate) async fn user_roles(
+    tx: &Transaction<'_>,
+    user_id: &str,
+) -> Result<Option<Vec<String>>, DbError> {
+    let row = tx
+        .query_opt(
+            "SELECT COALESCE(
+                array_agg(r.key::text) FILTER (WHERE r.id IS NOT NULL),
+                '{}'
+             )
+             FROM users u
+             LEFT JOIN user_roles ur ON ur.user_id = u.id
+             LEFT JOIN roles r ON r.id = ur.role_id
+             WHERE u.id::text = $1 AND u.deleted_at IS NULL
+             GROUP BY u.id",
+            &[&user_id],
+        )
+        .await?;
+    Ok(row.map(|row| row.get(0)))
+}
+
+/// Locks the given devices that exist and are not deleted, with the rules
+/// of their kind.
+async fn lock_devices(tx: &Transaction<'_>, ids: &[String]) -> ReceiptResult<Vec<LockedDevice>> {
+    let rows = tx
+        .query(
+            "SELECT d.id::text, d.status::text, d.lab_id::text, dk.name::text,
+                    dk.allowed_borrow_roles::text[], COALESCE(dk.is_borrowable_lab_only, false)
+             FROM devices d
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             WHERE d.id::text = ANY($1) AND d.deleted_at IS NULL
+             FOR UPDATE OF d",
+            &[&ids],
+        )
+        .await?;
+    rows.iter()
+        .map(|row| {
+            Ok(LockedDevice {
+                id: row.get(0),
+                status: parse_status(row.get(1))?,
+                lab_id: row.get(2),
+                rules: KindRules {
+                    kind_name: row.get(3),
+                    allowed_borrow_roles: row.get(4),
+                    lab_only: row.get(5),
+                    ..KindRules::default()
+                },
+            })
+        })
+        .collect()
+}
+
+/// A status read from the database that this build does not know, e.g.
+/// after a migration added one, is reported rather than guessed at.
+fn parse_status(status: &str) -> ReceiptResult<DeviceStatus> {
+    status.parse().map_err(|e: lifecycle::UnknownStatus| {
+        ReceiptError::Invalid(vec![FieldError::new("devices", e.to_string())])
+    })
+}
+
+async fn insert_header(
+    tx: &Transaction<'_>,
+    id: &str,
+    actor_id: &str,
+    checker_id: &str,
+    lab_id: &str,
+) -> Result<(), DbError> {
+    let header = json!({
+        "id": id,
+        "actor_id": actor_id,
+        "checker_id": checker_id,
+        "lab_id": lab_id,
+    });
+    // json_populate_record converts each field to the column's own type,
+    // whatever the ID columns are declared as.
+    tx.execute(
+        "INSERT INTO receipts (id, actor_id, checker_id, lab_id)
+         SELECT id, actor_id, checker_id, lab_id
+         FROM json_populate_record(NULL::receipts, $1)",
+        &[&header],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Returns the new activity's ID and creation time.
+async fn insert_activity(
+    tx: &Transaction<'_>,
+    activity_type: &str,
+    note: Option<&str>,
+) -> Result<(String, DateTime<Utc>), DbError> {
+    let row = tx
+        .query_one(
+            "INSERT INTO activities (type, note) VALUES ($1::text::activity_type, $2)
+             RETURNING id::text, created_at",
+            &[&activity_type, &note],
+        )
+        .await?;
+    Ok((row.get(0), row.get(1)))
+}
+
+async fn insert_borrow(
+    tx: &Transaction<'_>,
+    receipt: &NewBorrowReceipt,
+    locked: &[LockedDevice],
+) -> ReceiptResult<BorrowReceipt> {
+    insert_header(
+        tx,
+        &receipt.id,
+        &receipt.borrower_id,
+        &receipt.checker_id,
+        &receipt.lab_id,
+    )
+    .await?;
+    let (activity_id, created_at) = insert_activity(tx, "borrow", None).await?;
+
+    let rows: JsonValue = receipt
+        .devices
+        .iter()
+        .map(|d| {
+            json!({
+                "borrowed_receipt_id": receipt.id,
+                "device_id": d.id,
+                "borrow_id": activity_id,
+                "expected_returned_at": d.expected_returned_at,
+                "expected_returned_lab_id": d.expected_returned_lab_id.as_deref().unwrap_or(&receipt.lab_id),
+            })
+        })
+        .collect();
+    // The devices were checked to be healthy, which becomes prev_quality.
+    let inserted = tx
+        .query(
+            "INSERT INTO receipts_devices (
+                borrowed_receipt_id, device_id, borrow_id,
+                expected_returned_at, expected_returned_lab_id, prev_quality
+             )
+             SELECT r.borrowed_receipt_id, r.device_id, r.borrow_id,
+                    r.expected_returned_at, r.expected_returned_lab_id, d.status
+             FROM json_populate_recordset(NULL::receipts_devices, $1) r
+             JOIN devices d ON d.id = r.device_id
+             RETURNING device_id::text, expected_returned_at,
+                       expected_returned_lab_id::text, prev_quality::text",
+            &[&rows],
+        )
+        .await?;
+
+    let transitions: Vec<Transition> = locked
+        .iter()
+        .map(|device| Transition {
+            device_id: device.id.clone(),
+            from: device.status,
+            to: DeviceStatus::Borrowing,
+        })
+        .collect();
+    let reason = format!("Borrowed on receipt {}", receipt.id);
+    lifecycle::apply(
+        tx,
+        ChangeContext {
+            activity: ActivityType::Borrow,
+            activity_id: Some(&activity_id),
+            actor_id: Some(&receipt.checker_id),
+            reason: Some(&reason),
+        },
+        &transitions,
+    )
+    .await?;
+
+    Ok(BorrowReceipt {
+        id: receipt.id.clone(),
+        borrower_id: receipt.borrower_id.clone(),
+        checker_id: receipt.checker_id.clone(),
+        lab_id: receipt.lab_id.clone(),
+        activity_id,
+        created_at,
+        devices: inserted
+            .iter()
+            .map(|row| BorrowedDevice {
+                device_id: row.get(0),
+                expected_returned_at: row.get(1),
+                expected_returned_lab_id: row.get(2),
+                prev_quality: row.get(3),
+            })
+            .collect(),
+    })
+}
+
+/// An open `receipts_devices` row as locked for a return.
+struct OpenLoan {
+    id: String,
+    device_id: String,
+    device_status: DeviceStatus,
+    borrowed_receipt_id: String,
+    borrower_id: String,
+    expected_returned_at: DateTime<Utc>,
+    /// `expected_returned_lab_id`, or the lab it was borrowed from.
+    return_lab_id: String,
+    borrowed_lab_id: String,
+    prev_quality: Option<String>,
+    rules: KindRules,
+}
+
+/// Checks and writes a return in one transaction, locking the open loans
+/// and their devices first. Only the listed devices are returned. Each must
+/// be currently borrowed by the returner and brought back to the lab given
+/// by its `expected_returned_lab_id`; a device returned to another lab than
+/// the one it was registered in moves to the new lab, except that a device
+/// of a lab-only kind must come back to the lab it was borrowed from.
+/// Per-device notes are appended to the return activity's note, one line
+/// per device.
+pub async fn create_return_receipt(
+    db: &Database,
+    receipt: &NewReturnReceipt,
+) -> ReceiptResult<ReturnReceipt> {
+    let device_ids: Vec<&str> = receipt.devices.iter().map(|d| d.id.as_str()).collect();
+    let mut errors = check_request(
+        [
+            ("id", &receipt.id),
+            ("returnerId", &receipt.returner_id),
+            ("checkerId", &receipt.checker_id),
+            ("labId", &receipt.lab_id),
+        ],
+        &device_ids,
+    );
+    let qualities = lifecycle::targets(ActivityType::Return, DeviceStatus::Borrowing);
+    for (index, device) in receipt.devices.iter().enumerate() {
+        if !qualities.contains(&device.after_quality) {
+            errors.push(FieldError::new(
+                format!("devices[{}].afterQuality", index),
+                format!(
+                    "Must be one of {}, not {}",
+                    qualities
+                        .iter()
+                        .map(|q| q.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    device.after_quality
+                ),
+            ));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(ReceiptError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+
+    check_references(
+        &tx,
+        &receipt.id,
+        &receipt.lab_id,
+        ("returnerId", &receipt.returner_id),
+        &receipt.checker_id,
+        &mut errors,
+    )
+    .await?;
+
+    let loans = lock_loans(&tx, &device_ids).await?;
+    let mut returned = Vec::new();
+    for (index, device) in receipt.devices.iter().enumerate() {
+        let field = format!("devices[{}]", index);
+        let Some(loan) = loans.iter().find(|loan| loan.device_id == device.id) else {
+            errors.push(FieldError::new(
+                field,
+                format!("Device {} is not currently borrowed", device.id),
+            ));
+            continue;
+        };
+        if let Some(message) = check_return(receipt, loan) {
+            errors.push(FieldError::new(field, message));
+            continue;
+        }
+        returned.push((loan, device));
+    }
+    if !errors.is_empty() {
+        return Err(ReceiptError::Invalid(errors));
+    }
+
+    let created = insert_return(&tx, receipt, &returned).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(created)
+}
+
+fn check_return(receipt: &NewReturnReceipt, loan: &OpenLoan) -> Option<String> {
+    if loan.device_status != DeviceStatus::Borrowing {
+        return Some(format!(
+            "Device {} is {}, not borrowing",
+            loan.device_id, loan.device_status
+        ));
+    }
+    if loan.borrower_id != receipt.returner_id {
+        return Some(format!(
+            "Device {} was borrowed by someone else on receipt {}",
+            loan.device_id, loan.borrowed_receipt_id
+        ));
+    }
+    if let Err(denial) =
+        loan.rules
+            .check_return_lab(&loan.device_id, &loan.borrowed_lab_id, &receipt.lab_id)
+    {
+        return Some(denial.to_string());
+    }
+    if loan.return_lab_id != receipt.lab_id {
+        return Some(format!(
+            "Device {} must be returned to lab {}",
+            loan.device_id, loan.return_lab_id
+        ));
+    }
+    None
+}
+
+/// Locks the open loans of the given devices together with the devices.
+async fn lock_loans(tx: &Transaction<'_>, device_ids: &[&str]) -> ReceiptResult<Vec<OpenLoan>> {
+    let rows = tx
+        .query(
+            "SELECT rd.id::text, rd.device_id::text, d.status::text,
+                    rd.borrowed_receipt_id::text, r.actor_id::text, rd.expected_returned_at,
+                    COALESCE(rd.expected_returned_lab_id, r.lab_id)::text, r.lab_id::text,
+                    rd.prev_quality::text, dk.name::text,
+                    COALESCE(dk.is_borrowable_lab_only, false)
+             FROM receipts_devices rd
+             JOIN receipts r ON r.id = rd.borrowed_receipt_id
+             JOIN devices d ON d.id = rd.device_id
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             WHERE rd.device_id::text = ANY($1) AND rd.returned_receipt_id IS NULL
+               AND d.deleted_at IS NULL
+             FOR UPDATE OF rd, d",
+            &[&device_ids],
+        )
+        .await?;
+    rows.iter()
+        .map(|row| {
+            Ok(OpenLoan {
+                id: row.get(0),
+                device_id: row.get(1),
+                device_status: parse_status(row.get(2))?,
+                borrowed_receipt_id: row.get(3),
+                borrower_id: row.get(4),
+                expected_returned_at: row.get(5),
+                return_lab_id: row.get(6),
+                borrowed_lab_id: row.get(7),
+                prev_quality: row.get(8),
+                rules: KindRules {
+                    kind_name: row.get(9),
+                    lab_only: row.get(10),
+                    ..KindRules::default()
+                },
+            })
+        })
+        .collect()
+}
+
+/// The return activity's note: the receipt's note followed by one line per
+/// device that has a note of its own.
+fn return_note(receipt: &NewReturnReceipt) -> Option<String> {
+    let mut lines: Vec<String> = receipt.note.iter().cloned().collect();
+    lines.extend(receipt.devices.iter().filter_map(|d| {
+        d.note
+            .as_deref()
+            .filter(|note| !note.trim().is_empty())
+            .map(|note| format!("{}: {}", d.id, note))
+    }));
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+async fn insert_return(
+    tx: &Transaction<'_>,
+    receipt: &NewReturnReceipt,
+    returned: &[(&OpenLoan, &NewReturnedDevice)],
+) -> ReceiptResult<ReturnReceipt> {
+    insert_header(
+        tx,
+        &receipt.id,
+        &receipt.returner_id,
+        &receipt.checker_id,
+        &receipt.lab_id,
+    )
+    .await?;
+    let (activity_id, created_at) =
+        insert_activity(tx, "return", return_note(receipt).as_deref()).await?;
+
+    let rows: JsonValue = returned
+        .iter()
+        .map(|(loan, device)| json!({ "id": loan.id, "after_quality": device.after_quality }))
+        .collect();
+    // Matched on the locked row's ID rather than the device, so only the
+    // loan that was checked is closed.
+    tx.execute(
+        "WITH returned AS (
+            UPDATE receipts_devices rd
+            SET returned_receipt_id = r.id,
+                after_quality = v.after_quality,
+                return_id = a.id
+            FROM json_populate_recordset(NULL::receipts_devices, $1) v,
+                 receipts r, activities a
+            WHERE rd.id = v.id AND r.id::text = $2 AND a.id::text = $3
+            RETURNING rd.device_id
+         )
+         UPDATE devices d
+         SET lab_id = (SELECT id FROM labs WHERE id::text = $4)
+         FROM returned t
+         WHERE d.id = t.device_id",
+        &[&rows, &receipt.id, &activity_id, &receipt.lab_id],
+    )
+    .await?;
+
+    let transitions: Vec<Transition> = returned
+        .iter()
+        .map(|(loan, device)| Transition {
+            device_id: loan.device_id.clone(),
+            from: loan.device_status,
+            to: device.after_quality,
+        })
+        .collect();
+    let reason = format!("Returned on receipt {}", receipt.id);
+    lifecycle::apply(
+        tx,
+        ChangeContext {
+            activity: ActivityType::Return,
+            activity_id: Some(&activity_id),
+            actor_id: Some(&receipt.checker_id),
+            reason: Some(&reason),
+        },
+        &transitions,
+    )
+    .await?;
+
+    let borrowed_ids: Vec<&str> = returned
+        .iter()
+        .map(|(loan, _)| loan.borrowed_receipt_id.as_str())
+        .collect();
+    let still_open = tx
+        .query(
+            "SELECT borrowed_receipt_id::text, count(*)
+             FROM receipts_devices
+             WHERE borrowed_receipt_id::text = ANY($1) AND returned_receipt_id IS NULL
+             GROUP BY borrowed_receipt_id
+             ORDER BY borrowed_receipt_id",
+            &[&borrowed_ids],
+        )
+        .await?;
+
+    Ok(ReturnReceipt {
+        id: receipt.id.clone(),
+        returner_id: receipt.returner_id.clone(),
+        checker_id: receipt.checker_id.clone(),
+        lab_id: receipt.lab_id.clone(),
+        activity_id,
+        created_at,
+        devices: returned
+            .iter()
+            .map(|(loan, device)| ReturnedDevice {
+                device_id: loan.device_id.clone(),
+                borrowed_receipt_id: loan.borrowed_receipt_id.clone(),
+                expected_returned_at: loan.expected_returned_at,
+                late: created_at > loan.expected_returned_at,
+                prev_quality: loan.prev_quality.clone(),
+                after_quality: device.after_quality,
+                note: device.note.clone(),
+            })
+            .collect(),
+        still_open: still_open
+            .iter()
+            .map(|row| OpenBorrow {
+                borrowed_receipt_id: row.get(0),
+                remaining: row.get(1),
+            })
+            .collect(),
+    })
+}
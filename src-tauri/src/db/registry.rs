@@ -0,0 +1,44 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use super::connection::{Database, DbResult};
+use super::settings::DatabaseConfig;
+
+/// Databases besides the main one, addressed by name from `query_raw`.
+/// Cheap to clone; clones share the same set.
+#[derive(Clone, Default)]
+pub struct NamedDatabases(Arc<RwLock<BTreeMap<String, Database>>>);
+
+impl NamedDatabases {
+    /// Builds a pool for every entry of `config.databases`. Nothing connects
+    /// until first use.
+    pub fn from_config(config: &DatabaseConfig) -> DbResult<Self> {
+        let databases = NamedDatabases::default();
+        for (name, url) in &config.databases {
+            let mut named = config.with_url(url)?;
+            named.application_name = format!("{}-{}", config.application_name, name);
+            databases.register(name, Database::build(named)?);
+        }
+        Ok(databases)
+    }
+
+    /// Adds `db` under `name`, returning the database it replaced.
+    pub fn register(&self, name: &str, db: Database) -> Option<Database> {
+        self.0.write().unwrap().insert(name.to_string(), db)
+    }
+
+    pub fn get(&self, name: &str) -> Option<Database> {
+        self.0.read().unwrap().get(name).cloned()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.0.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Empties the statement caches of every registered database.
+    pub fn clear_statement_caches(&self) {
+        for db in self.0.read().unwrap().values() {
+            db.clear_statement_caches();
+        }
+    }
+}
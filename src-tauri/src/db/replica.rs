@@ -0,0 +1,94 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::connection::Database;
+
+/// After a failed checkout the replica is skipped for this long, so reads
+/// do not each wait for a connect timeout during a replica outage.
+const REPLICA_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+/// How reads choose between the primary and the replica.
+#[derive(Clone)]
+pub(crate) struct ReadRouting {
+    pub replica: Arc<Database>,
+    window: Duration,
+    last_write: Arc<Mutex<Option<Instant>>>,
+    down_until: Arc<Mutex<Option<Instant>>>,
+}
+
+impl ReadRouting {
+    pub fn new(replica: Database, window: Duration) -> Self {
+        ReadRouting {
+            replica: Arc::new(replica),
+            window,
+            last_write: Arc::new(Mutex::new(None)),
+            down_until: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn mark_write(&self) {
+        *self.last_write.lock().unwrap() = Some(Instant::now());
+    }
+
+    pub fn mark_down(&self) {
+        *self.down_until.lock().unwrap() = Some(Instant::now() + REPLICA_RETRY_AFTER);
+    }
+
+    /// Whether a read may go to the replica right now.
+    pub fn use_replica(&self) -> bool {
+        let recent_write = self
+            .last_write
+            .lock()
+            .unwrap()
+            .is_some_and(|at| at.elapsed() < self.window);
+        let down = self
+            .down_until
+            .lock()
+            .unwrap()
+            .is_some_and(|until| Instant::now() < until);
+        !recent_write && !down
+    }
+}
+
+/// Conservative check that `sql` only reads: it must start with a reading
+/// statement and mention no keyword that writes, so data-modifying CTEs,
+/// `SELECT ... INTO`, `FOR UPDATE` and sequence calls stay on the primary.
+/// Words inside string literals count too, which only costs a trip to the
+/// primary.
+pub fn is_read_only(sql: &str) -> bool {
+    const READ_STATEMENTS: &[&str] = &["select", "with", "show", "explain", "values", "table"];
+    const WRITE_WORDS: &[&str] = &[
+        "insert", "update", "delete", "merge", "truncate", "into", "nextval", "setval", "lock",
+        "copy", "call", "do", "create", "alter", "drop", "grant", "revoke", "set",
+    ];
+
+    let sql = strip_comments(sql);
+    let mut words = sql
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase());
+
+    match words.next() {
+        Some(first) if READ_STATEMENTS.contains(&first.as_str()) => {}
+        _ => return false,
+    }
+    words.all(|word| !WRITE_WORDS.contains(&word.as_str()))
+}
+
+fn strip_comments(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut rest = sql;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("--") {
+            rest = after.find('\n').map_or("", |i| &after[i..]);
+        } else if let Some(after) = rest.strip_prefix("/*") {
+            rest = after.find("*/").map_or("", |i| &after[i + 2..]);
+            out.push(' ');
+        } else {
+            let c = rest.chars().next().unwrap();
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    out
+}
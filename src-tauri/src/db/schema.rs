@@ -1,10 +1,36 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::RwLock;
 
+use super::codegen::{json_schema, rust, typescript, validators};
 use super::connection::{Database, DbResult};
 
+/// The repository root, where the generated files go. Taken from the
+/// crate's location at build time, so the working directory does not
+/// matter.
+pub fn workspace_dir() -> std::io::Result<PathBuf> {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Could not find project root directory",
+            )
+        })
+}
+
+/// `src-tauri/src/db/models.rs`, the generated Rust row types.
+pub fn models_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("db")
+        .join("models.rs")
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct EnumInfo {
     pub name: String,
@@ -15,18 +41,48 @@ pub struct EnumInfo {
 pub struct ColumnInfo {
     pub name: String,
     pub type_name: String,
+    #[serde(default)]
+    pub udt_name: String,
     pub is_nullable: bool,
     pub is_primary: bool,
+    #[serde(default)]
+    pub is_identity: bool,
+    #[serde(default)]
+    pub is_generated: bool,
     pub default_value: Option<String>,
     pub enum_values: Option<Vec<String>>,
 }
 
+impl ColumnInfo {
+    /// Whether an INSERT may leave this column out and still succeed.
+    pub fn is_optional_on_insert(&self) -> bool {
+        self.is_nullable || self.default_value.is_some() || self.is_identity || self.is_generated
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ForeignKeyInfo {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub referenced_schema: String,
+    pub referenced_table: String,
+    pub referenced_columns: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TableInfo {
     pub name: String,
     pub schema: String,
     pub columns: Vec<ColumnInfo>,
     pub primary_keys: Vec<String>,
+    #[serde(default)]
+    pub foreign_keys: Vec<ForeignKeyInfo>,
+}
+
+impl TableInfo {
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}", self.schema, self.name)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -34,10 +90,53 @@ pub struct DatabaseSchema {
     pub tables: HashMap<String, TableInfo>,
     pub enums: HashMap<String, EnumInfo>,
     pub version: String,
+    /// Hash of the catalog this schema was read from, see [`DatabaseSchema::fingerprint`].
+    #[serde(default)]
+    pub fingerprint: String,
 }
 
+/// The schema cache shared by commands and the schema watcher. Readers hold
+/// the lock for the duration of a query; a reload swaps the whole value.
+pub type SharedSchema = Arc<RwLock<Option<DatabaseSchema>>>;
+
+const FINGERPRINT_QUERY: &str = r#"
+    SELECT md5(coalesce(string_agg(item, E'\n' ORDER BY item), ''))
+    FROM (
+        SELECT format(
+            'column %s.%s.%s %s %s %s %s %s',
+            n.nspname, c.relname, a.attname,
+            format_type(a.atttypid, a.atttypmod),
+            a.attnotnull, a.attidentity, a.attgenerated,
+            pg_get_expr(d.adbin, d.adrelid)
+        ) AS item
+        FROM pg_attribute a
+        JOIN pg_class c ON c.oid = a.attrelid
+        JOIN pg_namespace n ON n.oid = c.relnamespace
+        LEFT JOIN pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+        WHERE c.relkind IN ('r', 'v', 'm', 'p', 'f')
+            AND a.attnum > 0
+            AND NOT a.attisdropped
+            AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+            AND n.nspname NOT LIKE 'pg_toast%'
+        UNION ALL
+        SELECT format('enum %s.%s %s %s', n.nspname, t.typname, e.enumsortorder, e.enumlabel)
+        FROM pg_enum e
+        JOIN pg_type t ON t.oid = e.enumtypid
+        JOIN pg_namespace n ON n.oid = t.typnamespace
+        UNION ALL
+        SELECT format('constraint %s.%s %s', n.nspname, con.conname, pg_get_constraintdef(con.oid))
+        FROM pg_constraint con
+        JOIN pg_namespace n ON n.oid = con.connamespace
+        WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+            AND n.nspname NOT LIKE 'pg_toast%'
+    ) items
+"#;
+
 impl DatabaseSchema {
     pub async fn fetch(db: &Database) -> DbResult<Self> {
+        // Taken before reading the catalog, so a change made while fetching
+        // shows up as a mismatch on the next poll instead of being missed.
+        let fingerprint = Self::fingerprint(db).await?;
         let client = db.get_client().await?;
 
         let enum_query = r#"
@@ -69,18 +168,23 @@ impl DatabaseSchema {
                 c.udt_name,
                 c.is_nullable,
                 c.column_default,
+                c.is_identity,
+                c.is_generated,
                 tc.constraint_type
             FROM information_schema.tables t
             JOIN information_schema.columns c 
                 ON c.table_schema = t.table_schema 
                 AND c.table_name = t.table_name
-            LEFT JOIN information_schema.key_column_usage kcu
+            LEFT JOIN (
+                information_schema.key_column_usage kcu
+                JOIN information_schema.table_constraints tc
+                    ON tc.constraint_schema = kcu.constraint_schema
+                    AND tc.constraint_name = kcu.constraint_name
+                    AND tc.constraint_type = 'PRIMARY KEY'
+            )
                 ON kcu.table_schema = t.table_schema
                 AND kcu.table_name = t.table_name
                 AND kcu.column_name = c.column_name
-            LEFT JOIN information_schema.table_constraints tc
-                ON tc.constraint_schema = t.table_schema
-                AND tc.constraint_name = kcu.constraint_name
             WHERE t.table_schema NOT IN ('pg_catalog', 'information_schema')
             ORDER BY t.table_schema, t.table_name, c.ordinal_position;
         "#;
@@ -99,6 +203,8 @@ impl DatabaseSchema {
             let udt_name: String = row.get("udt_name");
             let is_nullable: String = row.get("is_nullable");
             let default_value: Option<String> = row.get("column_default");
+            let is_identity: Option<String> = row.get("is_identity");
+            let is_generated: Option<String> = row.get("is_generated");
             let constraint_type: Option<String> = row.get("constraint_type");
 
             let full_table_name = format!("{}.{}", schema, table_name);
@@ -116,8 +222,11 @@ impl DatabaseSchema {
                 } else {
                     data_type
                 },
+                udt_name,
                 is_nullable: is_nullable == "YES",
                 is_primary: constraint_type.as_deref() == Some("PRIMARY KEY"),
+                is_identity: is_identity.as_deref() == Some("YES"),
+                is_generated: is_generated.as_deref() == Some("ALWAYS"),
                 default_value,
                 enum_values,
             };
@@ -140,19 +249,71 @@ impl DatabaseSchema {
                     schema: schema.clone(),
                     columns: Vec::new(),
                     primary_keys: Vec::new(),
+                    foreign_keys: Vec::new(),
                 };
                 tables.insert(full_table_name.clone(), table_info.clone());
                 tables.insert(table_name, table_info);
             }
         }
 
-        for (full_name, table) in tables.iter_mut() {
-            if let Some(columns) = table_columns.get(full_name) {
+        let foreign_key_query = r#"
+            SELECT
+                con.conname::text AS constraint_name,
+                src_ns.nspname::text AS table_schema,
+                src.relname::text AS table_name,
+                ARRAY(
+                    SELECT a.attname::text
+                    FROM unnest(con.conkey) WITH ORDINALITY AS k(attnum, ord)
+                    JOIN pg_attribute a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+                    ORDER BY k.ord
+                ) AS columns,
+                ref_ns.nspname::text AS referenced_schema,
+                ref.relname::text AS referenced_table,
+                ARRAY(
+                    SELECT a.attname::text
+                    FROM unnest(con.confkey) WITH ORDINALITY AS k(attnum, ord)
+                    JOIN pg_attribute a ON a.attrelid = con.confrelid AND a.attnum = k.attnum
+                    ORDER BY k.ord
+                ) AS referenced_columns
+            FROM pg_constraint con
+            JOIN pg_class src ON src.oid = con.conrelid
+            JOIN pg_namespace src_ns ON src_ns.oid = src.relnamespace
+            JOIN pg_class ref ON ref.oid = con.confrelid
+            JOIN pg_namespace ref_ns ON ref_ns.oid = ref.relnamespace
+            WHERE con.contype = 'f'
+                AND src_ns.nspname NOT IN ('pg_catalog', 'information_schema')
+            ORDER BY src_ns.nspname, src.relname, con.conname;
+        "#;
+
+        let mut table_foreign_keys: HashMap<String, Vec<ForeignKeyInfo>> = HashMap::new();
+
+        for row in client.query(foreign_key_query, &[]).await? {
+            let schema: String = row.get("table_schema");
+            let table_name: String = row.get("table_name");
+
+            table_foreign_keys
+                .entry(format!("{}.{}", schema, table_name))
+                .or_default()
+                .push(ForeignKeyInfo {
+                    name: row.get("constraint_name"),
+                    columns: row.get("columns"),
+                    referenced_schema: row.get("referenced_schema"),
+                    referenced_table: row.get("referenced_table"),
+                    referenced_columns: row.get("referenced_columns"),
+                });
+        }
+
+        for table in tables.values_mut() {
+            let full_name = table.qualified_name();
+            if let Some(columns) = table_columns.get(&full_name) {
                 table.columns = columns.clone();
             }
-            if let Some(primary_keys) = table_primary_keys.get(full_name) {
+            if let Some(primary_keys) = table_primary_keys.get(&full_name) {
                 table.primary_keys = primary_keys.clone();
             }
+            if let Some(foreign_keys) = table_foreign_keys.get(&full_name) {
+                table.foreign_keys = foreign_keys.clone();
+            }
         }
 
         let version: String = client.query_one("SELECT version()", &[]).await?.get(0);
@@ -161,9 +322,46 @@ impl DatabaseSchema {
             tables,
             enums,
             version,
+            fingerprint,
         })
     }
 
+    /// Hash over every column, enum label and constraint outside the system
+    /// schemas. Any DDL that would change the fetched schema changes it.
+    pub async fn fingerprint(db: &Database) -> DbResult<String> {
+        let client = db.get_client().await?;
+        // Polled every few seconds, so worth keeping prepared.
+        let statement = db.prepare_cached(&client, FINGERPRINT_QUERY).await?;
+        Ok(client.query_one(&statement, &[]).await?.get(0))
+    }
+
+    /// Writes the schema snapshot, TypeScript types, JSON Schema and
+    /// validators into `src/types/db` under `workspace_dir`. Returns the
+    /// paths written.
+    pub fn write_generated_files(&self, workspace_dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+        let types_dir = workspace_dir.join("src").join("types").join("db");
+        fs::create_dir_all(&types_dir)?;
+
+        let schema_path = types_dir.join("schema.json");
+        self.save_to_file(&schema_path)?;
+
+        let types_path = types_dir.join("generated.ts");
+        self.generate_typescript_types(&types_path)?;
+
+        let json_schema_path = types_dir.join("validators.schema.json");
+        self.generate_json_schema(&json_schema_path)?;
+
+        let validators_path = types_dir.join("validators.ts");
+        self.generate_typescript_validators(&validators_path)?;
+
+        Ok(vec![
+            schema_path,
+            types_path,
+            json_schema_path,
+            validators_path,
+        ])
+    }
+
     pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
         let json = serde_json::to_string_pretty(self)?;
         fs::write(path, json)
@@ -176,129 +374,49 @@ impl DatabaseSchema {
     }
 
     pub fn generate_typescript_types<P: AsRef<Path>>(&self, output_path: P) -> std::io::Result<()> {
-        let mut typescript = String::new();
-
-        typescript.push_str("// This file is auto-generated. Do not edit manually.\n\n");
-
-        for (enum_name, enum_info) in &self.enums {
-            typescript.push_str(&format!("export enum {} {{\n", pascal_case(enum_name)));
-            for value in &enum_info.values {
-                typescript.push_str(&format!(
-                    "  {} = '{}',\n",
-                    value.replace('-', "_").to_uppercase(),
-                    value
-                ));
-            }
-            typescript.push_str("}\n\n");
-        }
-
-        let mut seen_tables = std::collections::HashMap::new();
-
-        for (full_name, table) in &self.tables {
-            if !full_name.contains('.')
-                && self
-                    .tables
-                    .contains_key(&format!("{}.{}", table.schema, table.name))
-            {
-                continue;
-            }
-
-            let interface_name = if seen_tables.contains_key(&table.name) {
-                format!(
-                    "{}_{}",
-                    pascal_case(&table.name),
-                    pascal_case(&table.schema)
-                )
-            } else {
-                pascal_case(&table.name)
-            };
-            seen_tables.insert(table.name.clone(), interface_name.clone());
-
-            typescript.push_str(&format!("export interface {} {{\n", interface_name));
-
-            let mut seen_columns: std::collections::HashSet<String> =
-                std::collections::HashSet::new();
-
-            for column in &table.columns {
-                let clean_name = column.name.replace('\u{200B}', "");
-                if !seen_columns.insert(clean_name.clone()) {
-                    continue;
-                }
-
-                let ts_type = if let Some(_enum_values) = &column.enum_values {
-                    pascal_case(&column.type_name)
-                } else if column.type_name.starts_with('_') || column.type_name.contains("ARRAY") {
-                    let base_type = if column.type_name.starts_with('_') {
-                        &column.type_name[1..]
-                    } else {
-                        column.type_name.trim_end_matches("[]")
-                    };
-                    format!("{}[]", pg_type_to_typescript(base_type))
-                } else {
-                    pg_type_to_typescript(&column.type_name)
-                };
+        fs::write(output_path, typescript::generate(self))
+    }
 
-                let nullable = if column.is_nullable { " | null" } else { "" };
-                typescript.push_str(&format!(
-                    "  {}: {}{}\n",
-                    camel_case(&clean_name),
-                    ts_type,
-                    nullable
-                ));
-            }
+    pub fn generate_rust_types<P: AsRef<Path>>(&self, output_path: P) -> std::io::Result<()> {
+        fs::write(output_path, rust::generate(self))
+    }
 
-            typescript.push_str("}\n\n");
-        }
+    pub fn generate_json_schema<P: AsRef<Path>>(&self, output_path: P) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&json_schema::generate(self))?;
+        fs::write(output_path, json)
+    }
 
-        fs::write(output_path, typescript)
+    pub fn generate_typescript_validators<P: AsRef<Path>>(
+        &self,
+        output_path: P,
+    ) -> std::io::Result<()> {
+        fs::write(output_path, validators::generate(self))
     }
-}
 
-fn pascal_case(s: &str) -> String {
-    s.split('_')
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(first) => {
-                    let mut result = first.to_uppercase().to_string();
-                    result.push_str(&chars.as_str().to_lowercase());
-                    result
-                }
-            }
-        })
-        .collect()
-}
+    /// Tables keyed once by their qualified name, sorted by schema then table
+    /// name so generated output does not depend on `HashMap` ordering.
+    pub fn unique_tables(&self) -> Vec<&TableInfo> {
+        let mut tables: Vec<&TableInfo> = self
+            .tables
+            .iter()
+            .filter(|(key, table)| **key == table.qualified_name())
+            .map(|(_, table)| table)
+            .collect();
+        tables.sort_by(|a, b| (&a.schema, &a.name).cmp(&(&b.schema, &b.name)));
+        tables
+    }
 
-fn camel_case(s: &str) -> String {
-    let pascal = pascal_case(s);
-    pascal
-        .chars()
-        .next()
-        .unwrap_or_default()
-        .to_lowercase()
-        .to_string()
-        + &pascal[1..]
-}
+    /// Whether the unqualified table name resolves to this table at runtime.
+    pub fn owns_short_name(&self, table: &TableInfo) -> bool {
+        self.tables
+            .get(&table.name)
+            .is_some_and(|t| t.schema == table.schema)
+    }
 
-fn pg_type_to_typescript(pg_type: &str) -> String {
-    match pg_type {
-        "integer" | "smallint" | "bigint" | "serial" | "bigserial" => "number".to_string(),
-        "numeric" | "decimal" | "real" | "double precision" => "number".to_string(),
-        "character varying" | "text" | "character" | "varchar" => "string".to_string(),
-        "boolean" => "boolean".to_string(),
-        "timestamp with time zone"
-        | "timestamp without time zone"
-        | "timestamp"
-        | "timestamptz" => "Date".to_string(),
-        "json" | "jsonb" => "any".to_string(),
-        "uuid" => "string".to_string(),
-        "bytea" => "unknown".to_string(),
-        t if t.contains("enum") => pascal_case(t.trim_end_matches("enum")),
-        t if t.starts_with('_') => {
-            let base_type = &t[1..];
-            format!("{}[]", pg_type_to_typescript(base_type))
-        }
-        _ => "string".to_string(), // Default to string for unknown types
+    /// Enums sorted by name.
+    pub fn sorted_enums(&self) -> Vec<&EnumInfo> {
+        let mut enums: Vec<&EnumInfo> = self.enums.values().collect();
+        enums.sort_by(|a, b| a.name.cmp(&b.name));
+        enums
     }
 }
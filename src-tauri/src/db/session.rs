@@ -0,0 +1,210 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+use super::hmi::{HmiLab, HmiUser};
+
+/// Tauri event carrying the session whenever someone signs in or out.
+/// `null` after a logout or an idle timeout.
+pub const SESSION_CHANGED_EVENT: &str = "session://changed";
+
+/// Idle time after which the kiosk signs out, unless
+/// `HMI_SESSION_IDLE_SECS` says otherwise.
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug, thiserror::Error)]
+pub enum SessionError {
+    #[error("Not signed in")]
+    SignedOut,
+    #[error("Signed out after {} minutes without activity", .0.as_secs() / 60)]
+    Expired(Duration),
+}
+
+pub type SessionResult<T> = Result<T, SessionError>;
+
+/// Who is signed in at the kiosk, and for which lab.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+    pub user: HmiUser,
+    pub lab: HmiLab,
+    pub started_at: DateTime<Utc>,
+    pub last_active_at: DateTime<Utc>,
+    /// When the kiosk signs out if nothing else happens; `None` when idle
+    /// sessions never time out.
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(skip)]
+    last_active: Instant,
+}
+
+impl Session {
+    pub fn user_id(&self) -> &str {
+        &self.user.id
+    }
+
+    pub fn lab_id(&self) -> &str {
+        &self.lab.id
+    }
+
+    /// Keys of the signed-in user's roles, as device kinds list them.
+    pub fn role_keys(&self) -> Vec<String> {
+        self.user
+            .roles
+            .iter()
+            .map(|role| role.key.clone())
+            .collect()
+    }
+
+    fn touch(&mut self, idle_timeout: Option<Duration>) {
+        self.last_active = Instant::now();
+        self.last_active_at = Utc::now();
+        self.expires_at = idle_timeout
+            .and_then(|timeout| chrono::Duration::from_std(timeout).ok())
+            .map(|timeout| self.last_active_at + timeout);
+    }
+
+    fn idle_remaining(&self, idle_timeout: Duration) -> Duration {
+        idle_timeout.saturating_sub(self.last_active.elapsed())
+    }
+}
+
+/// The kiosk's session, shared by every command. Subscribers are woken
+/// when someone signs in or out, not when a session is merely used.
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+    current: watch::Sender<Option<Session>>,
+    idle_timeout: Option<Duration>,
+}
+
+impl SessionStore {
+    pub fn new(idle_timeout: Option<Duration>) -> Self {
+        SessionStore {
+            current: watch::Sender::new(None),
+            idle_timeout,
+        }
+    }
+
+    /// `HMI_SESSION_IDLE_SECS` sets the idle timeout, ten minutes by
+    /// default; `0` keeps sessions open until logout.
+    pub fn from_env() -> Self {
+        let idle_timeout = match std::env::var("HMI_SESSION_IDLE_SECS") {
+            Ok(value) => match value.trim().parse::<u64>() {
+                Ok(0) => None,
+                Ok(secs) => Some(Duration::from_secs(secs)),
+                Err(_) => {
+                    eprintln!(
+                        "Warning: Ignoring HMI_SESSION_IDLE_SECS={:?}; not a number",
+                        value
+                    );
+                    Some(DEFAULT_IDLE_TIMEOUT)
+                }
+            },
+            Err(_) => Some(DEFAULT_IDLE_TIMEOUT),
+        };
+        SessionStore::new(idle_timeout)
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<Option<Session>> {
+        self.current.subscribe()
+    }
+
+    /// Signs `user` in for `lab`, replacing any current session.
+    pub fn begin(&self, user: HmiUser, lab: HmiLab) -> Session {
+        let now = Utc::now();
+        let mut session = Session {
+            user,
+            lab,
+            started_at: now,
+            last_active_at: now,
+            expires_at: None,
+            last_active: Instant::now(),
+        };
+        session.touch(self.idle_timeout);
+        self.current.send_replace(Some(session.clone()));
+        session
+    }
+
+    /// Signs out. Returns whether anyone was signed in.
+    pub fn end(&self) -> bool {
+        self.current.send_replace(None).is_some()
+    }
+
+    /// The current session, without counting as activity.
+    pub fn current(&self) -> Option<Session> {
+        self.expire_if_idle();
+        self.current.borrow().clone()
+    }
+
+    /// Counts as activity and returns the session, if there still is one.
+    pub fn touch(&self) -> Option<Session> {
+        self.require().ok()
+    }
+
+    /// The session a domain command acts for. Counts as activity, so the
+    /// idle timer restarts.
+    pub fn require(&self) -> SessionResult<Session> {
+        if let Some(timeout) = self.expire_if_idle() {
+            return Err(SessionError::Expired(timeout));
+        }
+        let mut session = None;
+        self.current.send_if_modified(|current| {
+            if let Some(current) = current {
+                current.touch(self.idle_timeout);
+                session = Some(current.clone());
+            }
+            false
+        });
+        session.ok_or(SessionError::SignedOut)
+    }
+
+    /// Ends the session if it has been idle for too long, returning the
+    /// timeout when it did.
+    fn expire_if_idle(&self) -> Option<Duration> {
+        let timeout = self.idle_timeout?;
+        let expired = self.current.send_if_modified(|current| match current {
+            Some(session) if session.idle_remaining(timeout).is_zero() => {
+                *current = None;
+                true
+            }
+            _ => false,
+        });
+        expired.then_some(timeout)
+    }
+
+    fn idle_remaining(&self) -> Option<Duration> {
+        let timeout = self.idle_timeout?;
+        self.current
+            .borrow()
+            .as_ref()
+            .map(|session| session.idle_remaining(timeout))
+    }
+}
+
+/// Signs the kiosk out once its session has been idle for the timeout,
+/// so an unattended kiosk does not stay signed in.
+pub async fn expire_idle(store: SessionStore) {
+    let mut changes = store.subscribe();
+    loop {
+        match store.idle_remaining() {
+            Some(remaining) if remaining.is_zero() => {
+                store.expire_if_idle();
+            }
+            Some(remaining) => {
+                tokio::select! {
+                    _ = tokio::time::sleep(remaining) => {}
+                    changed = changes.changed() => {
+                        if changed.is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+            None => {
+                if changes.changed().await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,538 @@
+use config::{Config, ConfigError, Environment, File, FileFormat, Map, Source, Value};
+use dotenvy::dotenv;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::listener::check_channel;
+
+/// Directory mounted into the container by docker-compose.
+const DEFAULT_CONFIG_DIR: &str = "/app/config";
+
+/// Named sets of defaults, chosen with `HMI_PROFILE`. Each profile can be
+/// refined by `database.<profile>.toml` next to `database.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Kiosk,
+    Dev,
+    Bench,
+}
+
+impl Profile {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Kiosk => "kiosk",
+            Profile::Dev => "dev",
+            Profile::Bench => "bench",
+        }
+    }
+
+    /// `HMI_PROFILE`, or `dev` for debug builds and `kiosk` otherwise.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        match std::env::var("HMI_PROFILE") {
+            Ok(value) if !value.trim().is_empty() => value.parse(),
+            _ if cfg!(debug_assertions) => Ok(Profile::Dev),
+            _ => Ok(Profile::Kiosk),
+        }
+    }
+
+    /// Benchmarks run against a local database and measure queries, not
+    /// handshakes.
+    fn ssl_mode(&self) -> SslMode {
+        match self {
+            Profile::Bench => SslMode::Disable,
+            Profile::Kiosk | Profile::Dev => SslMode::Prefer,
+        }
+    }
+
+    fn max_connections(&self) -> u64 {
+        match self {
+            Profile::Kiosk => 5,
+            Profile::Dev => 5,
+            Profile::Bench => 32,
+        }
+    }
+
+    /// The kiosk should report a saturated pool quickly rather than freeze;
+    /// stress benchmarks deliberately queue more work than connections.
+    fn pool_wait_timeout_ms(&self) -> u64 {
+        match self {
+            Profile::Kiosk | Profile::Dev => 5_000,
+            Profile::Bench => 0,
+        }
+    }
+
+    fn statement_timeout_ms(&self) -> u64 {
+        match self {
+            Profile::Kiosk | Profile::Dev => 30_000,
+            Profile::Bench => 0,
+        }
+    }
+}
+
+impl FromStr for Profile {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "kiosk" => Ok(Profile::Kiosk),
+            "dev" => Ok(Profile::Dev),
+            "bench" => Ok(Profile::Bench),
+            other => Err(ConfigError::Message(format!(
+                "Unknown profile '{}', expected kiosk, dev or bench",
+                other
+            ))),
+        }
+    }
+}
+
+/// libpq `sslmode` semantics. `prefer` and `require` encrypt without
+/// checking the server certificate, unless a root certificate is configured,
+/// in which case `require` behaves like `verify-ca`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SslMode {
+    Disable,
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+impl SslMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SslMode::Disable => "disable",
+            SslMode::Prefer => "prefer",
+            SslMode::Require => "require",
+            SslMode::VerifyCa => "verify-ca",
+            SslMode::VerifyFull => "verify-full",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct DatabaseConfig {
+    pub host: String,
+    pub port: u16,
+    pub user: String,
+    pub password: String,
+    pub database: String,
+    pub max_connections: usize,
+    pub application_name: String,
+    pub ssl_mode: SslMode,
+    /// PEM bundle of trusted CAs; the Mozilla roots are used when unset.
+    pub ssl_root_cert: Option<PathBuf>,
+    /// PEM client certificate chain and key for certificate authentication.
+    pub ssl_cert: Option<PathBuf>,
+    pub ssl_key: Option<PathBuf>,
+    /// How long a caller waits for a free connection; `None` waits forever.
+    pub pool_wait_timeout: Option<Duration>,
+    /// How long opening a new connection may take.
+    pub pool_create_timeout: Option<Duration>,
+    /// How long checking an idle connection before reuse may take.
+    pub pool_recycle_timeout: Option<Duration>,
+    /// Server-side limit for every statement on a pooled session, so a
+    /// runaway query cannot hold a connection forever.
+    pub statement_timeout: Option<Duration>,
+    /// Prepared statements kept per connection; `0` disables the cache.
+    pub statement_cache_size: usize,
+    /// Read-only replica for `query_table` and read-only `query_raw`, as a
+    /// connection string overlaid on these settings.
+    pub replica_url: Option<String>,
+    /// After a write, reads go to the primary for this long so callers see
+    /// their own changes despite replication lag.
+    pub read_your_writes_window: Duration,
+    /// Further databases by name, as connection strings overlaid on these
+    /// settings.
+    pub databases: BTreeMap<String, String>,
+    /// Channels the notification listener subscribes to at startup.
+    pub listen_channels: Vec<String>,
+}
+
+impl std::fmt::Debug for DatabaseConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabaseConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("user", &self.user)
+            .field("password", &"<redacted>")
+            .field("database", &self.database)
+            .field("max_connections", &self.max_connections)
+            .field("application_name", &self.application_name)
+            .field("ssl_mode", &self.ssl_mode)
+            .field("ssl_root_cert", &self.ssl_root_cert)
+            .field("ssl_cert", &self.ssl_cert)
+            .field("ssl_key", &self.ssl_key)
+            .field("pool_wait_timeout", &self.pool_wait_timeout)
+            .field("pool_create_timeout", &self.pool_create_timeout)
+            .field("pool_recycle_timeout", &self.pool_recycle_timeout)
+            .field("statement_timeout", &self.statement_timeout)
+            .field("statement_cache_size", &self.statement_cache_size)
+            .field(
+                "replica_url",
+                &self.replica_url.as_ref().map(|_| "<redacted>"),
+            )
+            .field("read_your_writes_window", &self.read_your_writes_window)
+            .field("databases", &self.databases.keys().collect::<Vec<_>>())
+            .field("listen_channels", &self.listen_channels)
+            .finish()
+    }
+}
+
+/// The merged layers before the password file is resolved. `db` is what
+/// `POSTGRES_DB` maps to and wins over `database` from lower layers.
+#[derive(Deserialize)]
+struct RawConfig {
+    host: String,
+    port: u16,
+    user: String,
+    password: Option<String>,
+    password_file: Option<PathBuf>,
+    database: Option<String>,
+    db: Option<String>,
+    max_connections: usize,
+    application_name: String,
+    ssl_mode: SslMode,
+    ssl_root_cert: Option<PathBuf>,
+    ssl_cert: Option<PathBuf>,
+    ssl_key: Option<PathBuf>,
+    pool_wait_timeout_ms: u64,
+    pool_create_timeout_ms: u64,
+    pool_recycle_timeout_ms: u64,
+    statement_timeout_ms: u64,
+    statement_cache_size: usize,
+    replica_url: Option<String>,
+    read_your_writes_window_ms: u64,
+    #[serde(default)]
+    databases: BTreeMap<String, String>,
+    #[serde(default)]
+    listen_channels: ChannelList,
+}
+
+/// A TOML array, or a comma-separated list from `POSTGRES_LISTEN_CHANNELS`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ChannelList {
+    List(Vec<String>),
+    Csv(String),
+}
+
+impl Default for ChannelList {
+    fn default() -> Self {
+        ChannelList::List(Vec::new())
+    }
+}
+
+impl ChannelList {
+    fn into_vec(self) -> Vec<String> {
+        let channels = match self {
+            ChannelList::List(channels) => channels,
+            ChannelList::Csv(csv) => csv.split(',').map(str::to_string).collect(),
+        };
+        channels
+            .into_iter()
+            .map(|channel| channel.trim().to_string())
+            .filter(|channel| !channel.is_empty())
+            .collect()
+    }
+}
+
+impl DatabaseConfig {
+    /// Loads the configuration for the profile selected by `HMI_PROFILE`.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::for_profile(Profile::from_env()?)
+    }
+
+    /// Merges, from lowest to highest precedence: the profile defaults,
+    /// `database.toml` and `database.<profile>.toml` from `HMI_CONFIG_DIR`
+    /// (default `/app/config`), `DATABASE_URL`, and `POSTGRES_*` variables.
+    /// A `password_file` (or `POSTGRES_PASSWORD_FILE`) takes the password
+    /// from a file such as a Docker secret.
+    pub fn for_profile(profile: Profile) -> Result<Self, ConfigError> {
+        dotenv().ok();
+
+        let config_dir = std::env::var("HMI_CONFIG_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(DEFAULT_CONFIG_DIR));
+
+        let raw: RawConfig = Config::builder()
+            .set_default("host", "localhost")?
+            .set_default("port", 5432)?
+            .set_default("user", "postgres")?
+            .set_default("max_connections", profile.max_connections())?
+            .set_default("application_name", format!("hmi-{}", profile.as_str()))?
+            .set_default("ssl_mode", profile.ssl_mode().as_str())?
+            .set_default("pool_wait_timeout_ms", profile.pool_wait_timeout_ms())?
+            .set_default("pool_create_timeout_ms", 10_000)?
+            .set_default("pool_recycle_timeout_ms", 5_000)?
+            .set_default("statement_timeout_ms", profile.statement_timeout_ms())?
+            .set_default("statement_cache_size", 256)?
+            .set_default("read_your_writes_window_ms", 5_000)?
+            .add_source(config_file(&config_dir, "database"))
+            .add_source(config_file(
+                &config_dir,
+                &format!("database.{}", profile.as_str()),
+            ))
+            .add_source(DatabaseUrl(std::env::var("DATABASE_URL").ok()))
+            .add_source(Environment::with_prefix("POSTGRES").ignore_empty(true))
+            .build()?
+            .try_deserialize()?;
+
+        let password = match (raw.password_file, raw.password) {
+            (Some(path), _) => read_password_file(&path)?,
+            (None, Some(password)) => password,
+            (None, None) => {
+                return Err(ConfigError::Message(
+                    "No password configured; set POSTGRES_PASSWORD, POSTGRES_PASSWORD_FILE or DATABASE_URL"
+                        .to_string(),
+                ))
+            }
+        };
+
+        let database = raw.db.or(raw.database).ok_or_else(|| {
+            ConfigError::Message(
+                "No database configured; set POSTGRES_DB or DATABASE_URL".to_string(),
+            )
+        })?;
+
+        if raw.max_connections == 0 {
+            return Err(ConfigError::Message(
+                "max_connections must be at least 1".to_string(),
+            ));
+        }
+
+        if raw.ssl_cert.is_some() != raw.ssl_key.is_some() {
+            return Err(ConfigError::Message(
+                "ssl_cert and ssl_key must be set together".to_string(),
+            ));
+        }
+
+        let listen_channels = raw.listen_channels.into_vec();
+        for channel in &listen_channels {
+            check_channel(channel).map_err(|e| ConfigError::Message(e.to_string()))?;
+        }
+
+        Ok(DatabaseConfig {
+            host: raw.host,
+            port: raw.port,
+            user: raw.user,
+            password,
+            database,
+            max_connections: raw.max_connections,
+            application_name: raw.application_name,
+            ssl_mode: raw.ssl_mode,
+            ssl_root_cert: raw.ssl_root_cert,
+            ssl_cert: raw.ssl_cert,
+            ssl_key: raw.ssl_key,
+            pool_wait_timeout: timeout_from_ms(raw.pool_wait_timeout_ms),
+            pool_create_timeout: timeout_from_ms(raw.pool_create_timeout_ms),
+            pool_recycle_timeout: timeout_from_ms(raw.pool_recycle_timeout_ms),
+            statement_timeout: timeout_from_ms(raw.statement_timeout_ms),
+            statement_cache_size: raw.statement_cache_size,
+            replica_url: raw.replica_url.filter(|url| !url.trim().is_empty()),
+            read_your_writes_window: Duration::from_millis(raw.read_your_writes_window_ms),
+            databases: raw.databases,
+            listen_channels,
+        })
+    }
+
+    /// These settings with the parts given in `url` replaced, in any form
+    /// `DATABASE_URL` accepts. Used for the replica and named databases,
+    /// which usually differ only in host or database name.
+    pub fn with_url(&self, url: &str) -> Result<DatabaseConfig, ConfigError> {
+        let mut derived = DatabaseConfig {
+            replica_url: None,
+            databases: BTreeMap::new(),
+            listen_channels: Vec::new(),
+            ..self.clone()
+        };
+
+        for (key, value) in DatabaseUrl(Some(url.to_string())).collect()? {
+            match key.as_str() {
+                "host" => derived.host = value.try_deserialize()?,
+                "port" => derived.port = value.try_deserialize()?,
+                "user" => derived.user = value.try_deserialize()?,
+                "password" => derived.password = value.try_deserialize()?,
+                "database" => derived.database = value.try_deserialize()?,
+                "application_name" => derived.application_name = value.try_deserialize()?,
+                "ssl_mode" => derived.ssl_mode = value.try_deserialize()?,
+                "ssl_root_cert" => derived.ssl_root_cert = Some(value.try_deserialize()?),
+                "ssl_cert" => derived.ssl_cert = Some(value.try_deserialize()?),
+                "ssl_key" => derived.ssl_key = Some(value.try_deserialize()?),
+                _ => {}
+            }
+        }
+
+        if derived.ssl_cert.is_some() != derived.ssl_key.is_some() {
+            return Err(ConfigError::Message(
+                "ssl_cert and ssl_key must be set together".to_string(),
+            ));
+        }
+
+        Ok(derived)
+    }
+}
+
+/// `0` disables a timeout.
+fn timeout_from_ms(ms: u64) -> Option<Duration> {
+    (ms > 0).then(|| Duration::from_millis(ms))
+}
+
+fn config_file(dir: &Path, name: &str) -> File<config::FileSourceFile, FileFormat> {
+    File::from(dir.join(format!("{}.toml", name)))
+        .format(FileFormat::Toml)
+        .required(false)
+}
+
+fn read_password_file(path: &Path) -> Result<String, ConfigError> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        ConfigError::Message(format!(
+            "Failed to read password file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    // Secrets written with `echo` end in a newline that is not part of the
+    // password.
+    Ok(contents.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// `DATABASE_URL` as a configuration layer. Accepts anything
+/// `tokio_postgres::Config` parses, URL or `key=value` form, plus the libpq
+/// `sslmode`, `sslrootcert`, `sslcert` and `sslkey` parameters; only the
+/// parts present in it override lower layers.
+#[derive(Debug, Clone)]
+struct DatabaseUrl(Option<String>);
+
+impl Source for DatabaseUrl {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        let mut map = Map::new();
+        let Some(url) = self.0.as_deref().filter(|url| !url.trim().is_empty()) else {
+            return Ok(map);
+        };
+
+        let origin = "DATABASE_URL".to_string();
+        let (url, ssl_params) = split_ssl_params(url);
+        for (key, value) in ssl_params {
+            map.insert(key.to_string(), Value::new(Some(&origin), value));
+        }
+
+        let parsed = tokio_postgres::Config::from_str(&url)
+            .map_err(|e| ConfigError::Message(format!("Invalid DATABASE_URL: {}", e)))?;
+
+        match parsed.get_hosts() {
+            [] => {}
+            [tokio_postgres::config::Host::Tcp(host)] => {
+                map.insert("host".to_string(), Value::new(Some(&origin), host.as_str()));
+            }
+            [_] => {
+                return Err(ConfigError::Message(
+                    "DATABASE_URL must use a TCP host".to_string(),
+                ))
+            }
+            _ => {
+                return Err(ConfigError::Message(
+                    "DATABASE_URL must name a single host".to_string(),
+                ))
+            }
+        }
+        if let [port] = parsed.get_ports() {
+            map.insert("port".to_string(), Value::new(Some(&origin), *port));
+        }
+        if let Some(user) = parsed.get_user() {
+            map.insert("user".to_string(), Value::new(Some(&origin), user));
+        }
+        if let Some(password) = parsed.get_password() {
+            let password = String::from_utf8(password.to_vec()).map_err(|_| {
+                ConfigError::Message("DATABASE_URL password is not valid UTF-8".to_string())
+            })?;
+            map.insert("password".to_string(), Value::new(Some(&origin), password));
+        }
+        if let Some(database) = parsed.get_dbname() {
+            map.insert("database".to_string(), Value::new(Some(&origin), database));
+        }
+        if let Some(application_name) = parsed.get_application_name() {
+            map.insert(
+                "application_name".to_string(),
+                Value::new(Some(&origin), application_name),
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+/// Removes the TLS parameters `tokio_postgres::Config` does not understand
+/// (it only knows `disable`/`prefer`/`require`), returning them under their
+/// `DatabaseConfig` keys.
+fn split_ssl_params(url: &str) -> (String, Vec<(&'static str, String)>) {
+    let key_for = |name: &str| match name {
+        "sslmode" => Some("ssl_mode"),
+        "sslrootcert" => Some("ssl_root_cert"),
+        "sslcert" => Some("ssl_cert"),
+        "sslkey" => Some("ssl_key"),
+        _ => None,
+    };
+
+    let mut ssl_params = Vec::new();
+
+    if url.contains("://") {
+        let Some((base, query)) = url.split_once('?') else {
+            return (url.to_string(), ssl_params);
+        };
+        let mut rest = Vec::new();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            match key_for(name) {
+                Some(key) => ssl_params.push((key, percent_decode(value))),
+                None => rest.push(pair),
+            }
+        }
+        let url = if rest.is_empty() {
+            base.to_string()
+        } else {
+            format!("{}?{}", base, rest.join("&"))
+        };
+        return (url, ssl_params);
+    }
+
+    let mut rest = Vec::new();
+    for pair in url.split_whitespace() {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        match key_for(name) {
+            Some(key) => ssl_params.push((key, value.trim_matches('\'').to_string())),
+            None => rest.push(pair),
+        }
+    }
+    (rest.join(" "), ssl_params)
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
@@ -0,0 +1,1117 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::Transaction;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use tokio_postgres::types::{FromSql, Type};
+
+use super::connection::{Database, DbError};
+use super::lifecycle::{
+    self, ActivityType, ChangeContext, DeviceStatus, LifecycleError, Transition,
+};
+use super::validation::{summarize, FieldError};
+
+/// Attempts at picking an unused shipment ID before giving up.
+const ID_ATTEMPTS: usize = 5;
+
+/// Where a shipment is in its journey. Stored in `shipment_status`, which
+/// the `shipment_status_states` migration extends with the states the
+/// original enum lacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShipmentStatus {
+    /// Being put together; devices can still be added and removed.
+    Preparing,
+    /// Dispatched; devices are `shipping` until confirmed at the destination.
+    Shipping,
+    /// Closed with every device accounted for at the destination.
+    Received,
+    /// Closed with some devices never confirmed; those are marked `lost`.
+    PartiallyReceived,
+    Cancelled,
+}
+
+impl ShipmentStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ShipmentStatus::Preparing => "preparing",
+            ShipmentStatus::Shipping => "shipping",
+            ShipmentStatus::Received => "received",
+            ShipmentStatus::PartiallyReceived => "partially_received",
+            ShipmentStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl fmt::Display for ShipmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Reads a `shipment_status` column. `completed`, written before shipments
+/// could be partially received, reads as [`ShipmentStatus::Received`].
+impl<'a> FromSql<'a> for ShipmentStatus {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(match std::str::from_utf8(raw)? {
+            "preparing" => ShipmentStatus::Preparing,
+            "shipping" => ShipmentStatus::Shipping,
+            "received" | "completed" => ShipmentStatus::Received,
+            "partially_received" => ShipmentStatus::PartiallyReceived,
+            "cancelled" => ShipmentStatus::Cancelled,
+            other => return Err(format!("Unknown shipment status {:?}", other).into()),
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        ty.name() == "shipment_status" || *ty == Type::TEXT
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ShipmentError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error(transparent)]
+    Lifecycle(#[from] LifecycleError),
+    #[error("Shipment {0} not found")]
+    NotFound(String),
+    #[error("Shipment {id} is {status}, not {expected}")]
+    WrongState {
+        id: String,
+        status: ShipmentStatus,
+        expected: ShipmentStatus,
+    },
+    #[error("Shipment {0} already exists")]
+    Exists(String),
+    #[error("Shipment {0} does not leave from this lab")]
+    NotFromLab(String),
+    #[error("Shipment {0} is not bound for this lab")]
+    NotToLab(String),
+    #[error("{}", summarize(.0))]
+    Invalid(Vec<FieldError>),
+}
+
+impl From<tokio_postgres::Error> for ShipmentError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        ShipmentError::Db(error.into())
+    }
+}
+
+pub type ShipmentResult<T> = Result<T, ShipmentError>;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewShipment {
+    /// Chosen by the kiosk, or generated as `YYYYMMDD/NNNNNN`.
+    pub id: Option<String>,
+    /// The signed-in user and their lab, from the kiosk session.
+    #[serde(default)]
+    pub sender_id: String,
+    #[serde(default)]
+    pub start_lab_id: String,
+    pub arrive_lab_id: String,
+    /// Whether each device must be confirmed at the destination. Without
+    /// it, devices not confirmed when the shipment is closed are taken as
+    /// received in the condition they left in.
+    #[serde(default = "default_check")]
+    pub check_at_destination: bool,
+    #[serde(default)]
+    pub devices: Vec<String>,
+}
+
+fn default_check() -> bool {
+    true
+}
+
+/// A device confirmed at the destination and the condition it arrived in.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArrivedDevice {
+    pub id: String,
+    pub condition: DeviceStatus,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Shipment {
+    pub id: String,
+    pub sender_id: Option<String>,
+    pub receiver_id: Option<String>,
+    pub status: ShipmentStatus,
+    pub start_lab_id: String,
+    pub arrive_lab_id: String,
+    pub check_at_destination: bool,
+    pub dispatched_at: Option<DateTime<Utc>>,
+    pub received_at: Option<DateTime<Utc>>,
+    pub devices: Vec<ShipmentDevice>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipmentDevice {
+    pub device_id: String,
+    /// The device's status when it was dispatched, restored if the
+    /// shipment is cancelled.
+    pub prev_status: Option<DeviceStatus>,
+    /// The condition it arrived in; `None` until confirmed.
+    pub after_status: Option<DeviceStatus>,
+}
+
+/// The outcome of [`close_shipment`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipmentReport {
+    pub shipment: Shipment,
+    /// Devices that reached the destination.
+    pub received: Vec<String>,
+    /// Devices that never arrived, now `lost`.
+    pub missing: Vec<String>,
+}
+
+/// A shipment with what is known about each of its devices.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    pub shipment: Shipment,
+    pub devices: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestEntry {
+    pub device_id: String,
+    pub kind_id: Option<String>,
+    pub kind_name: Option<String>,
+    pub status: DeviceStatus,
+    pub lab_id: Option<String>,
+    pub prev_status: Option<DeviceStatus>,
+    pub after_status: Option<DeviceStatus>,
+    /// Confirmed at the destination.
+    pub received: bool,
+}
+
+/// Locked shipment row.
+struct Locked {
+    id: String,
+    status: ShipmentStatus,
+    start_lab_id: String,
+    arrive_lab_id: String,
+    sender_id: Option<String>,
+    check_at_destination: bool,
+    /// The departure activity, once dispatched.
+    from_at: Option<String>,
+}
+
+impl Locked {
+    /// Checks that `lab_id` is the lab the shipment leaves from.
+    fn check_start_lab(&self, lab_id: &str) -> ShipmentResult<()> {
+        if self.start_lab_id != lab_id {
+            return Err(ShipmentError::NotFromLab(self.id.clone()));
+        }
+        Ok(())
+    }
+
+    /// Checks that `lab_id` is the shipment's destination.
+    fn check_arrive_lab(&self, lab_id: &str) -> ShipmentResult<()> {
+        if self.arrive_lab_id != lab_id {
+            return Err(ShipmentError::NotToLab(self.id.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Starts putting a shipment together. Devices must belong to the start
+/// lab and be `healthy` or `broken`; their status does not change until
+/// the shipment is dispatched.
+pub async fn create_shipment(db: &Database, shipment: &NewShipment) -> ShipmentResult<Shipment> {
+    let mut errors = Vec::new();
+    for (field, value) in [
+        ("senderId", &shipment.sender_id),
+        ("startLabId", &shipment.start_lab_id),
+        ("arriveLabId", &shipment.arrive_lab_id),
+    ] {
+        if value.trim().is_empty() {
+            errors.push(FieldError::new(field, "Must not be empty"));
+        }
+    }
+    if shipment.start_lab_id == shipment.arrive_lab_id {
+        errors.push(FieldError::new(
+            "arriveLabId",
+            "Must differ from the start lab",
+        ));
+    }
+    errors.extend(check_duplicates(&shipment.devices));
+    if !errors.is_empty() {
+        return Err(ShipmentError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+
+    if !user_exists(&tx, &shipment.sender_id).await? {
+        errors.push(FieldError::new(
+            "senderId",
+            format!("User {} does not exist", shipment.sender_id),
+        ));
+    }
+    let labs = vec![
+        shipment.start_lab_id.as_str(),
+        shipment.arrive_lab_id.as_str(),
+    ];
+    let found: Vec<String> = tx
+        .query(
+            "SELECT id::text FROM labs WHERE id::text = ANY($1)",
+            &[&labs],
+        )
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    for (field, lab_id) in [("startLabId", labs[0]), ("arriveLabId", labs[1])] {
+        if !found.iter().any(|id| id == lab_id) {
+            errors.push(FieldError::new(
+                field,
+                format!("Lab {} does not exist", lab_id),
+            ));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(ShipmentError::Invalid(errors));
+    }
+
+    let id = insert_header(&tx, shipment).await?;
+    let locked = Locked {
+        id,
+        status: ShipmentStatus::Preparing,
+        start_lab_id: shipment.start_lab_id.clone(),
+        arrive_lab_id: shipment.arrive_lab_id.clone(),
+        sender_id: Some(shipment.sender_id.clone()),
+        check_at_destination: shipment.check_at_destination,
+        from_at: None,
+    };
+    add_devices(&tx, &locked, &shipment.devices).await?;
+
+    let created = load(&tx, &locked.id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(created)
+}
+
+/// Adds devices to a shipment that is still being prepared.
+pub async fn add_shipment_devices(
+    db: &Database,
+    shipment_id: &str,
+    lab_id: &str,
+    devices: &[String],
+) -> ShipmentResult<Shipment> {
+    let errors = check_duplicates(devices);
+    if !errors.is_empty() {
+        return Err(ShipmentError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let locked = lock(&tx, shipment_id, ShipmentStatus::Preparing).await?;
+    locked.check_start_lab(lab_id)?;
+    add_devices(&tx, &locked, devices).await?;
+    let updated = load(&tx, shipment_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(updated)
+}
+
+/// Takes a device off a shipment that is still being prepared.
+pub async fn remove_shipment_device(
+    db: &Database,
+    shipment_id: &str,
+    lab_id: &str,
+    device_id: &str,
+) -> ShipmentResult<Shipment> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    lock(&tx, shipment_id, ShipmentStatus::Preparing)
+        .await?
+        .check_start_lab(lab_id)?;
+
+    let removed = tx
+        .execute(
+            "DELETE FROM shipments_devices
+             WHERE shipment_id::text = $1 AND device_id::text = $2",
+            &[&shipment_id, &device_id],
+        )
+        .await?;
+    if removed == 0 {
+        return Err(ShipmentError::Invalid(vec![FieldError::new(
+            "deviceId",
+            format!("Device {} is not on this shipment", device_id),
+        )]));
+    }
+
+    let updated = load(&tx, shipment_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(updated)
+}
+
+/// Sends the shipment off: every device moves to `shipping`, remembering
+/// the status it left in.
+pub async fn dispatch_shipment(
+    db: &Database,
+    shipment_id: &str,
+    lab_id: &str,
+    note: Option<&str>,
+) -> ShipmentResult<Shipment> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let locked = lock(&tx, shipment_id, ShipmentStatus::Preparing).await?;
+    locked.check_start_lab(lab_id)?;
+
+    let rows = tx
+        .query(
+            "SELECT sd.device_id::text, d.status, d.lab_id::text, d.deleted_at IS NOT NULL
+             FROM shipments_devices sd
+             JOIN devices d ON d.id = sd.device_id
+             WHERE sd.shipment_id::text = $1
+             ORDER BY sd.device_id
+             FOR UPDATE OF d",
+            &[&shipment_id],
+        )
+        .await?;
+    if rows.is_empty() {
+        return Err(ShipmentError::Invalid(vec![FieldError::new(
+            "devices",
+            "The shipment has no devices",
+        )]));
+    }
+
+    let mut errors = Vec::new();
+    let mut transitions = Vec::new();
+    let mut prev = Vec::new();
+    for row in &rows {
+        let device_id: String = row.get(0);
+        let status: DeviceStatus = row.try_get(1)?;
+        let lab_id: Option<String> = row.get(2);
+        let deleted: bool = row.get(3);
+        if let Some(message) = check_device(&locked, &device_id, status, lab_id, deleted) {
+            errors.push(FieldError::new("devices", message));
+            continue;
+        }
+        prev.push(json!({ "device_id": device_id, "prev_status": status }));
+        transitions.push(Transition {
+            device_id,
+            from: status,
+            to: DeviceStatus::Shipping,
+        });
+    }
+    if !errors.is_empty() {
+        return Err(ShipmentError::Invalid(errors));
+    }
+
+    tx.execute(
+        "UPDATE shipments_devices sd
+         SET prev_status = v.prev_status
+         FROM json_populate_recordset(NULL::shipments_devices, $1) v
+         WHERE sd.shipment_id::text = $2 AND sd.device_id = v.device_id",
+        &[&JsonValue::Array(prev), &shipment_id],
+    )
+    .await?;
+    let departure: String = tx
+        .query_one(
+            "WITH activity AS (
+                INSERT INTO activities (type, note) VALUES ('shipment'::activity_type, $2)
+                RETURNING id
+             )
+             UPDATE shipments
+             SET status = 'shipping', from_at = (SELECT id FROM activity)
+             WHERE id::text = $1
+             RETURNING from_at::text",
+            &[&shipment_id, &note],
+        )
+        .await?
+        .get(0);
+    let reason = format!("Dispatched on shipment {}", shipment_id);
+    move_devices(
+        &tx,
+        Some(&departure),
+        locked.sender_id.as_deref(),
+        &reason,
+        &transitions,
+    )
+    .await?;
+
+    let dispatched = load(&tx, shipment_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(dispatched)
+}
+
+/// Confirms devices at the destination. Each moves to the condition it
+/// arrived in (`healthy` or `broken`) and to the destination lab. Can be
+/// called as devices are unpacked; the first call records the receiver.
+pub async fn confirm_shipment_arrivals(
+    db: &Database,
+    shipment_id: &str,
+    lab_id: &str,
+    receiver_id: &str,
+    devices: &[ArrivedDevice],
+) -> ShipmentResult<Shipment> {
+    let conditions: Vec<DeviceStatus> =
+        lifecycle::targets(ActivityType::Shipment, DeviceStatus::Shipping)
+            .into_iter()
+            .filter(|s| *s != DeviceStatus::Lost)
+            .collect();
+    let ids: Vec<String> = devices.iter().map(|d| d.id.clone()).collect();
+    let mut errors = check_duplicates(&ids);
+    if devices.is_empty() {
+        errors.push(FieldError::new(
+            "devices",
+            "At least one device is required",
+        ));
+    }
+    for (index, device) in devices.iter().enumerate() {
+        if !conditions.contains(&device.condition) {
+            errors.push(FieldError::new(
+                format!("devices[{}].condition", index),
+                format!(
+                    "Must be one of {}, not {}",
+                    conditions
+                        .iter()
+                        .map(|c| c.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    device.condition
+                ),
+            ));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(ShipmentError::Invalid(errors));
+    }
+
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let locked = lock(&tx, shipment_id, ShipmentStatus::Shipping).await?;
+    locked.check_arrive_lab(lab_id)?;
+    if !user_exists(&tx, receiver_id).await? {
+        return Err(ShipmentError::Invalid(vec![FieldError::new(
+            "receiverId",
+            format!("User {} does not exist", receiver_id),
+        )]));
+    }
+
+    let rows = tx
+        .query(
+            "SELECT sd.device_id::text, sd.after_status, d.status
+             FROM shipments_devices sd
+             JOIN devices d ON d.id = sd.device_id
+             WHERE sd.shipment_id::text = $1 AND sd.device_id::text = ANY($2)
+             FOR UPDATE OF d",
+            &[&shipment_id, &ids],
+        )
+        .await?;
+    let mut transitions = Vec::new();
+    for (index, device) in devices.iter().enumerate() {
+        let Some(row) = rows.iter().find(|row| row.get::<_, String>(0) == device.id) else {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} is not on this shipment", device.id),
+            ));
+            continue;
+        };
+        let after: Option<DeviceStatus> = row.try_get(1)?;
+        let current: DeviceStatus = row.try_get(2)?;
+        if after.is_some() {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} was already received", device.id),
+            ));
+            continue;
+        }
+        transitions.push(Transition {
+            device_id: device.id.clone(),
+            from: current,
+            to: device.condition,
+        });
+    }
+    if !errors.is_empty() {
+        return Err(ShipmentError::Invalid(errors));
+    }
+
+    let arrival = ensure_arrival(&tx, shipment_id, receiver_id).await?;
+    receive(&tx, &locked, &arrival, receiver_id, &transitions).await?;
+    let updated = load(&tx, shipment_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(updated)
+}
+
+/// Closes a shipment that has arrived. Devices that were never confirmed
+/// are marked `lost` and reported as missing, unless the shipment was not
+/// to be checked at the destination, in which case they are taken as
+/// received in the condition they left in.
+pub async fn close_shipment(
+    db: &Database,
+    shipment_id: &str,
+    lab_id: &str,
+    receiver_id: &str,
+    note: Option<&str>,
+) -> ShipmentResult<ShipmentReport> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let locked = lock(&tx, shipment_id, ShipmentStatus::Shipping).await?;
+    locked.check_arrive_lab(lab_id)?;
+    if !user_exists(&tx, receiver_id).await? {
+        return Err(ShipmentError::Invalid(vec![FieldError::new(
+            "receiverId",
+            format!("User {} does not exist", receiver_id),
+        )]));
+    }
+
+    let rows = tx
+        .query(
+            "SELECT sd.device_id::text, sd.prev_status, sd.after_status, d.status
+             FROM shipments_devices sd
+             JOIN devices d ON d.id = sd.device_id
+             WHERE sd.shipment_id::text = $1
+             ORDER BY sd.device_id
+             FOR UPDATE OF d",
+            &[&shipment_id],
+        )
+        .await?;
+    let mut received = Vec::new();
+    let mut missing = Vec::new();
+    let mut arrived = Vec::new();
+    let mut lost = Vec::new();
+    for row in &rows {
+        let device_id: String = row.get(0);
+        let prev: Option<DeviceStatus> = row.try_get(1)?;
+        let after: Option<DeviceStatus> = row.try_get(2)?;
+        let current: DeviceStatus = row.try_get(3)?;
+        if after.is_some() {
+            received.push(device_id);
+        } else if current != DeviceStatus::Shipping {
+            // Changed by something else since dispatch; leave it be.
+            missing.push(device_id);
+        } else if !locked.check_at_destination {
+            arrived.push(Transition {
+                device_id: device_id.clone(),
+                from: current,
+                to: prev.unwrap_or(DeviceStatus::Healthy),
+            });
+            received.push(device_id);
+        } else {
+            lost.push(Transition {
+                device_id: device_id.clone(),
+                from: current,
+                to: DeviceStatus::Lost,
+            });
+            missing.push(device_id);
+        }
+    }
+
+    let arrival = ensure_arrival(&tx, shipment_id, receiver_id).await?;
+    receive(&tx, &locked, &arrival, receiver_id, &arrived).await?;
+    if !lost.is_empty() {
+        let rows: JsonValue = lost
+            .iter()
+            .map(|t| json!({ "device_id": t.device_id, "after_status": t.to }))
+            .collect();
+        tx.execute(
+            "UPDATE shipments_devices sd
+             SET after_status = v.after_status
+             FROM json_populate_recordset(NULL::shipments_devices, $1) v
+             WHERE sd.shipment_id::text = $2 AND sd.device_id = v.device_id",
+            &[&rows, &shipment_id],
+        )
+        .await?;
+        let reason = format!("Never arrived on shipment {}", shipment_id);
+        move_devices(&tx, Some(&arrival), Some(receiver_id), &reason, &lost).await?;
+    }
+
+    let status = if missing.is_empty() {
+        ShipmentStatus::Received
+    } else {
+        ShipmentStatus::PartiallyReceived
+    };
+    tx.execute(
+        "UPDATE shipments SET status = $2::text::shipment_status WHERE id::text = $1",
+        &[&shipment_id, &status.as_str()],
+    )
+    .await?;
+    if let Some(note) = note.filter(|n| !n.trim().is_empty()) {
+        tx.execute(
+            "UPDATE activities SET note = $2 WHERE id::text = $1",
+            &[&arrival, &note],
+        )
+        .await?;
+    }
+
+    let report = ShipmentReport {
+        shipment: load(&tx, shipment_id).await?,
+        received,
+        missing,
+    };
+    tx.commit().await?;
+    db.mark_write();
+    Ok(report)
+}
+
+/// Cancels a shipment that is being prepared, or one in transit of which
+/// nothing has been received yet; dispatched devices go back to the status
+/// they left in.
+pub async fn cancel_shipment(
+    db: &Database,
+    shipment_id: &str,
+    lab_id: &str,
+) -> ShipmentResult<Shipment> {
+    let mut client = db.get_client().await?;
+    let tx = client.transaction().await?;
+    let Some(locked) = lock_any(&tx, shipment_id).await? else {
+        return Err(ShipmentError::NotFound(shipment_id.to_string()));
+    };
+    locked.check_start_lab(lab_id)?;
+    if !matches!(
+        locked.status,
+        ShipmentStatus::Preparing | ShipmentStatus::Shipping
+    ) {
+        return Err(ShipmentError::WrongState {
+            id: shipment_id.to_string(),
+            status: locked.status,
+            expected: ShipmentStatus::Shipping,
+        });
+    }
+
+    if locked.status == ShipmentStatus::Shipping {
+        let rows = tx
+            .query(
+                "SELECT sd.device_id::text, sd.prev_status, sd.after_status, d.status
+                 FROM shipments_devices sd
+                 JOIN devices d ON d.id = sd.device_id
+                 WHERE sd.shipment_id::text = $1
+                 FOR UPDATE OF d",
+                &[&shipment_id],
+            )
+            .await?;
+        let mut transitions = Vec::new();
+        for row in &rows {
+            let device_id: String = row.get(0);
+            let prev: Option<DeviceStatus> = row.try_get(1)?;
+            let after: Option<DeviceStatus> = row.try_get(2)?;
+            let current: DeviceStatus = row.try_get(3)?;
+            if after.is_some() {
+                return Err(ShipmentError::Invalid(vec![FieldError::new(
+                    "devices",
+                    format!(
+                        "Device {} was already received; close the shipment instead",
+                        device_id
+                    ),
+                )]));
+            }
+            if current == DeviceStatus::Shipping {
+                transitions.push(Transition {
+                    device_id,
+                    from: current,
+                    to: prev.unwrap_or(DeviceStatus::Healthy),
+                });
+            }
+        }
+        let reason = format!("Shipment {} cancelled", shipment_id);
+        move_devices(
+            &tx,
+            locked.from_at.as_deref(),
+            locked.sender_id.as_deref(),
+            &reason,
+            &transitions,
+        )
+        .await?;
+    }
+
+    tx.execute(
+        "UPDATE shipments SET status = 'cancelled' WHERE id::text = $1",
+        &[&shipment_id],
+    )
+    .await?;
+    let cancelled = load(&tx, shipment_id).await?;
+    tx.commit().await?;
+    db.mark_write();
+    Ok(cancelled)
+}
+
+/// The shipment with each device's kind, current status and lab, and
+/// whether it has been received.
+pub async fn shipment_manifest(db: &Database, shipment_id: &str) -> ShipmentResult<Manifest> {
+    let mut client = db.get_client().await?;
+    let tx = client.build_transaction().read_only(true).start().await?;
+    let shipment = load(&tx, shipment_id).await?;
+    let rows = tx
+        .query(
+            "SELECT sd.device_id::text, d.kind::text, dk.name::text, d.status,
+                    d.lab_id::text, sd.prev_status, sd.after_status
+             FROM shipments_devices sd
+             JOIN devices d ON d.id = sd.device_id
+             LEFT JOIN device_kinds dk ON dk.id = d.kind
+             WHERE sd.shipment_id::text = $1
+             ORDER BY dk.name, sd.device_id",
+            &[&shipment_id],
+        )
+        .await?;
+    let devices = rows
+        .iter()
+        .map(|row| {
+            let after_status: Option<DeviceStatus> = row.try_get(6)?;
+            Ok(ManifestEntry {
+                device_id: row.get(0),
+                kind_id: row.get(1),
+                kind_name: row.get(2),
+                status: row.try_get(3)?,
+                lab_id: row.get(4),
+                prev_status: row.try_get(5)?,
+                received: after_status.is_some_and(|s| s != DeviceStatus::Lost),
+                after_status,
+            })
+        })
+        .collect::<Result<_, tokio_postgres::Error>>()?;
+    tx.commit().await?;
+    Ok(Manifest { shipment, devices })
+}
+
+fn check_duplicates(devices: &[String]) -> Vec<FieldError> {
+    let mut seen = HashSet::new();
+    devices
+        .iter()
+        .enumerate()
+        .filter(|(_, id)| !seen.insert(id.as_str()))
+        .map(|(index, id)| {
+            FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} is listed more than once", id),
+            )
+        })
+        .collect()
+}
+
+/// Why the device cannot leave on the shipment, if it cannot.
+fn check_device(
+    shipment: &Locked,
+    device_id: &str,
+    status: DeviceStatus,
+    lab_id: Option<String>,
+    deleted: bool,
+) -> Option<String> {
+    if deleted {
+        return Some(format!("Device {} is deleted", device_id));
+    }
+    if lab_id.as_deref() != Some(shipment.start_lab_id.as_str()) {
+        return Some(format!(
+            "Device {} does not belong to lab {}",
+            device_id, shipment.start_lab_id
+        ));
+    }
+    lifecycle::check(
+        ActivityType::Shipment,
+        device_id,
+        status,
+        DeviceStatus::Shipping,
+    )
+    .err()
+    .map(|e| e.to_string())
+}
+
+async fn user_exists(tx: &Transaction<'_>, user_id: &str) -> ShipmentResult<bool> {
+    Ok(tx
+        .query_opt(
+            "SELECT 1 FROM users WHERE id::text = $1 AND deleted_at IS NULL",
+            &[&user_id],
+        )
+        .await?
+        .is_some())
+}
+
+/// Inserts the shipment row in `preparing` and returns its ID.
+async fn insert_header(tx: &Transaction<'_>, shipment: &NewShipment) -> ShipmentResult<String> {
+    for _ in 0..ID_ATTEMPTS {
+        let id = match &shipment.id {
+            Some(id) => id.clone(),
+            None => format!(
+                "{}/{:06}",
+                Utc::now().format("%Y%m%d"),
+                rand::rng().random_range(0..1_000_000)
+            ),
+        };
+        let row = json!({
+            "id": id,
+            "sender_id": shipment.sender_id,
+            "start_lab_id": shipment.start_lab_id,
+            "arrive_lab_id": shipment.arrive_lab_id,
+            "check_at_destination": shipment.check_at_destination,
+        });
+        let inserted = tx
+            .execute(
+                "INSERT INTO shipments (id, sender_id, start_lab_id, arrive_lab_id, check_at_destination, status)
+                 SELECT id, sender_id, start_lab_id, arrive_lab_id, check_at_destination, 'preparing'
+                 FROM json_populate_record(NULL::shipments, $1)
+                 ON CONFLICT (id) DO NOTHING",
+                &[&row],
+            )
+            .await?;
+        if inserted == 1 {
+            return Ok(id);
+        }
+        if shipment.id.is_some() {
+            break;
+        }
+    }
+    Err(ShipmentError::Exists(
+        shipment.id.clone().unwrap_or_default(),
+    ))
+}
+
+/// Locks the shipment, whatever its status.
+async fn lock_any(tx: &Transaction<'_>, shipment_id: &str) -> ShipmentResult<Option<Locked>> {
+    let row = tx
+        .query_opt(
+            "SELECT id::text, status, start_lab_id::text, arrive_lab_id::text,
+                    sender_id::text, COALESCE(check_at_destination, true), from_at::text
+             FROM shipments
+             WHERE id::text = $1
+             FOR UPDATE",
+            &[&shipment_id],
+        )
+        .await?;
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    Ok(Some(Locked {
+        id: row.get(0),
+        status: row.try_get(1)?,
+        start_lab_id: row.get(2),
+        arrive_lab_id: row.get(3),
+        sender_id: row.get(4),
+        check_at_destination: row.get(5),
+        from_at: row.get(6),
+    }))
+}
+
+/// Locks the shipment and checks that it is in `expected`.
+async fn lock(
+    tx: &Transaction<'_>,
+    shipment_id: &str,
+    expected: ShipmentStatus,
+) -> ShipmentResult<Locked> {
+    let Some(locked) = lock_any(tx, shipment_id).await? else {
+        return Err(ShipmentError::NotFound(shipment_id.to_string()));
+    };
+    if locked.status != expected {
+        return Err(ShipmentError::WrongState {
+            id: shipment_id.to_string(),
+            status: locked.status,
+            expected,
+        });
+    }
+    Ok(locked)
+}
+
+async fn add_devices(
+    tx: &Transaction<'_>,
+    shipment: &Locked,
+    devices: &[String],
+) -> ShipmentResult<()> {
+    if devices.is_empty() {
+        return Ok(());
+    }
+    let rows = tx
+        .query(
+            "SELECT d.id::text, d.status, d.lab_id::text, d.deleted_at IS NOT NULL,
+                    EXISTS (
+                        SELECT 1 FROM shipments_devices sd
+                        WHERE sd.shipment_id::text = $2 AND sd.device_id = d.id
+                    )
+             FROM devices d
+             WHERE d.id::text = ANY($1)",
+            &[&devices, &shipment.id],
+        )
+        .await?;
+
+    let mut errors = Vec::new();
+    let mut inserted = Vec::new();
+    for (index, device_id) in devices.iter().enumerate() {
+        let Some(row) = rows
+            .iter()
+            .find(|row| row.get::<_, String>(0) == *device_id)
+        else {
+            errors.push(FieldError::new(
+                format!("devices[{}]", index),
+                format!("Device {} does not exist", device_id),
+            ));
+            continue;
+        };
+        if row.get::<_, bool>(4) {
+            continue;
+        }
+        let status: DeviceStatus = row.try_get(1)?;
+        if let Some(message) = check_device(shipment, device_id, status, row.get(2), row.get(3)) {
+            errors.push(FieldError::new(format!("devices[{}]", index), message));
+            continue;
+        }
+        inserted.push(json!({ "shipment_id": shipment.id, "device_id": device_id }));
+    }
+    if !errors.is_empty() {
+        return Err(ShipmentError::Invalid(errors));
+    }
+    if !inserted.is_empty() {
+        tx.execute(
+            "INSERT INTO shipments_devices (shipment_id, device_id)
+             SELECT shipment_id, device_id
+             FROM json_populate_recordset(NULL::shipments_devices, $1)",
+            &[&JsonValue::Array(inserted)],
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Records the devices as received in their new status and moves them to
+/// the destination lab, under the `arrival` activity.
+async fn receive(
+    tx: &Transaction<'_>,
+    shipment: &Locked,
+    arrival: &str,
+    receiver_id: &str,
+    transitions: &[Transition],
+) -> ShipmentResult<()> {
+    if transitions.is_empty() {
+        return Ok(());
+    }
+    let rows: JsonValue = transitions
+        .iter()
+        .map(|t| json!({ "device_id": t.device_id, "after_status": t.to }))
+        .collect();
+    tx.execute(
+        "UPDATE shipments_devices sd
+         SET after_status = v.after_status
+         FROM json_populate_recordset(NULL::shipments_devices, $1) v
+         WHERE sd.shipment_id::text = $2 AND sd.device_id = v.device_id",
+        &[&rows, &shipment.id],
+    )
+    .await?;
+    let ids: Vec<&str> = transitions.iter().map(|t| t.device_id.as_str()).collect();
+    tx.execute(
+        "UPDATE devices SET lab_id = l.id
+         FROM labs l
+         WHERE l.id::text = $1 AND devices.id::text = ANY($2)",
+        &[&shipment.arrive_lab_id, &ids],
+    )
+    .await?;
+    let reason = format!("Received on shipment {}", shipment.id);
+    move_devices(tx, Some(arrival), Some(receiver_id), &reason, transitions).await
+}
+
+/// Records the receiver and the arrival activity the first time anything
+/// is received or the shipment is closed. Returns the arrival activity.
+async fn ensure_arrival(
+    tx: &Transaction<'_>,
+    shipment_id: &str,
+    receiver_id: &str,
+) -> ShipmentResult<String> {
+    tx.execute(
+        "WITH pending AS (
+            SELECT id FROM shipments WHERE id::text = $1 AND to_at IS NULL
+         ),
+         activity AS (
+            INSERT INTO activities (type)
+            SELECT 'shipment'::activity_type FROM pending
+            RETURNING id
+         )
+         UPDATE shipments
+         SET to_at = (SELECT id FROM activity), receiver_id = $2
+         WHERE id IN (SELECT id FROM pending)",
+        &[&shipment_id, &receiver_id],
+    )
+    .await?;
+    let row = tx
+        .query_one(
+            "SELECT to_at::text FROM shipments WHERE id::text = $1",
+            &[&shipment_id],
+        )
+        .await?;
+    Ok(row.get(0))
+}
+
+/// Applies the transitions under `activity_id`, the shipment's departure
+/// or arrival activity.
+async fn move_devices(
+    tx: &Transaction<'_>,
+    activity_id: Option<&str>,
+    actor_id: Option<&str>,
+    reason: &str,
+    transitions: &[Transition],
+) -> ShipmentResult<()> {
+    if transitions.is_empty() {
+        return Ok(());
+    }
+    lifecycle::apply(
+        tx,
+        ChangeContext {
+            activity: ActivityType::Shipment,
+            activity_id,
+            actor_id,
+            reason: Some(reason),
+        },
+        transitions,
+    )
+    .await?;
+    Ok(())
+}
+
+async fn load(tx: &Transaction<'_>, shipment_id: &str) -> ShipmentResult<Shipment> {
+    let Some(row) = tx
+        .query_opt(
+            "SELECT s.id::text, s.sender_id::text, s.receiver_id::text, s.status,
+                    s.start_lab_id::text, s.arrive_lab_id::text,
+                    COALESCE(s.check_at_destination, true),
+                    a_from.created_at, a_to.created_at
+             FROM shipments s
+             LEFT JOIN activities a_from ON a_from.id::text = s.from_at::text
+             LEFT JOIN activities a_to ON a_to.id::text = s.to_at::text
+             WHERE s.id::text = $1",
+            &[&shipment_id],
+        )
+        .await?
+    else {
+        return Err(ShipmentError::NotFound(shipment_id.to_string()));
+    };
+    let devices = tx
+        .query(
+            "SELECT device_id::text, prev_status, after_status
+             FROM shipments_devices
+             WHERE shipment_id::text = $1
+             ORDER BY device_id",
+            &[&shipment_id],
+        )
+        .await?;
+    Ok(Shipment {
+        id: row.get(0),
+        sender_id: row.get(1),
+        receiver_id: row.get(2),
+        status: row.try_get(3)?,
+        start_lab_id: row.get(4),
+        arrive_lab_id: row.get(5),
+        check_at_destination: row.get(6),
+        dispatched_at: row.get(7),
+        received_at: row.get(8),
+        devices: devices
+            .iter()
+            .map(|row| {
+                Ok(ShipmentDevice {
+                    device_id: row.get(0),
+                    prev_status: row.try_get(1)?,
+                    after_status: row.try_get(2)?,
+                })
+            })
+            .collect::<Result<_, tokio_postgres::Error>>()?,
+    })
+}
@@ -0,0 +1,167 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio_postgres::types::ToSql;
+use tokio_postgres::Row;
+
+use super::cancel::QueryControl;
+use super::connection::{Database, DbError, DbResult};
+
+/// Rows per chunk when the caller does not choose.
+pub const DEFAULT_CHUNK_SIZE: usize = 500;
+pub const MAX_CHUNK_SIZE: usize = 10_000;
+
+/// Chunks sent ahead of the frontend's acknowledgements. Fetching pauses,
+/// with the cursor open on the server, once this many are outstanding.
+const WINDOW: usize = 4;
+
+/// A stream whose consumer acknowledges nothing for this long is given up,
+/// so a closed page cannot hold a connection and a transaction open.
+const ACK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Streams in progress, by the ID the frontend chose. Cheap to clone.
+#[derive(Clone, Default)]
+pub struct ActiveStreams(Arc<Mutex<HashMap<String, Arc<Semaphore>>>>);
+
+impl ActiveStreams {
+    fn open(&self, stream_id: &str) -> StreamGuard {
+        let credits = Arc::new(Semaphore::new(WINDOW));
+        self.0
+            .lock()
+            .unwrap()
+            .insert(stream_id.to_string(), credits.clone());
+        StreamGuard {
+            streams: self.clone(),
+            stream_id: stream_id.to_string(),
+            credits,
+        }
+    }
+
+    /// Records that the frontend has processed one chunk, allowing the next
+    /// one to be fetched. Returns `false` for an unknown stream.
+    pub fn ack(&self, stream_id: &str) -> bool {
+        match self.0.lock().unwrap().get(stream_id) {
+            Some(credits) => {
+                credits.add_permits(1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stops the stream before its next chunk. Returns `false` for an
+    /// unknown stream.
+    pub fn close(&self, stream_id: &str) -> bool {
+        match self.0.lock().unwrap().get(stream_id) {
+            Some(credits) => {
+                credits.close();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+struct StreamGuard {
+    streams: ActiveStreams,
+    stream_id: String,
+    credits: Arc<Semaphore>,
+}
+
+impl Drop for StreamGuard {
+    fn drop(&mut self) {
+        let mut streams = self.streams.0.lock().unwrap();
+        // Only remove our own entry, not a newer stream that reused the ID.
+        if streams
+            .get(&self.stream_id)
+            .is_some_and(|current| Arc::ptr_eq(current, &self.credits))
+        {
+            streams.remove(&self.stream_id);
+        }
+    }
+}
+
+/// How a stream ended.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamSummary {
+    pub rows: u64,
+    pub chunks: u32,
+    /// Stopped early by `cancel_query` or because the frontend went away.
+    pub cancelled: bool,
+}
+
+/// Runs `sql` through a server-side portal in a read-only transaction and
+/// passes the rows to `send` in chunks of `chunk_size`. At most a few
+/// chunks are fetched ahead of [`ActiveStreams::ack`]; the stream stops
+/// when `send` returns `false` or the stream is closed. `control` must
+/// carry the stream ID as its request ID.
+#[allow(clippy::too_many_arguments)]
+pub async fn stream_rows<F>(
+    db: &Database,
+    client: &mut deadpool_postgres::Client,
+    streams: &ActiveStreams,
+    control: &QueryControl,
+    sql: &str,
+    params: &[&(dyn ToSql + Sync)],
+    chunk_size: usize,
+    mut send: F,
+) -> DbResult<StreamSummary>
+where
+    F: FnMut(u32, Vec<Row>) -> bool,
+{
+    let stream_id = control.request_id.as_deref().unwrap_or_default();
+    let stream = streams.open(stream_id);
+    let chunk_size = chunk_size.clamp(1, MAX_CHUNK_SIZE);
+
+    let tx = client.build_transaction().read_only(true).start().await?;
+    if let Some(timeout) = control.timeout {
+        tx.batch_execute(&format!(
+            "SET LOCAL statement_timeout = {}",
+            timeout.as_millis()
+        ))
+        .await?;
+    }
+    let portal = tx.bind(sql, params).await?;
+
+    let mut summary = StreamSummary::default();
+    loop {
+        match tokio::time::timeout(ACK_TIMEOUT, stream.credits.acquire()).await {
+            Ok(Ok(permit)) => permit.forget(),
+            Ok(Err(_)) => {
+                summary.cancelled = true;
+                break;
+            }
+            Err(_) => return Err(DbError::Stalled(ACK_TIMEOUT)),
+        }
+
+        // Only registered while a fetch runs, so a late cancel request
+        // cannot hit the session once it is idle or back in the pool.
+        let guard = db.track(control, tx.cancel_token());
+        let rows = match tx.query_portal(&portal, chunk_size as i32).await {
+            Ok(rows) => rows,
+            Err(_) if guard.as_ref().is_some_and(|g| g.was_cancelled()) => {
+                return Err(DbError::Cancelled)
+            }
+            Err(e) => return Err(e.into()),
+        };
+        drop(guard);
+        let last = rows.len() < chunk_size;
+        if !rows.is_empty() {
+            summary.rows += rows.len() as u64;
+            if !send(summary.chunks, rows) {
+                summary.cancelled = true;
+                break;
+            }
+            summary.chunks += 1;
+        }
+        if last {
+            break;
+        }
+    }
+
+    tx.commit().await?;
+    Ok(summary)
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::Serialize;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use super::connection::{Database, DbResult};
+use super::registry::NamedDatabases;
+use super::schema::SharedSchema;
+use super::watcher::{self, SchemaChanged};
+
+/// Event emitted to the frontend whenever [`DbStatus`] changes.
+pub const DB_STATUS_EVENT: &str = "db://status-changed";
+
+/// How often the connection is checked when schema polling is disabled.
+const DEFAULT_PROBE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Whether the backend can currently reach the database.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub enum DbStatus {
+    /// Startup, before the first attempt has finished.
+    Connecting,
+    Available,
+    #[serde(rename_all = "camelCase")]
+    Unavailable {
+        reason: String,
+        since: DateTime<Utc>,
+        attempts: u32,
+        next_retry_ms: u64,
+    },
+}
+
+/// Exponential backoff with jitter, capped at `max`, so kiosks that lost the
+/// server together do not reconnect in lockstep.
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    attempt: u32,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Backoff {
+            base,
+            max,
+            attempt: 0,
+        }
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Delay before the next attempt: between half and all of
+    /// `base * 2^attempt`, never more than `max`.
+    pub fn next_delay(&mut self) -> Duration {
+        let ceiling = self
+            .base
+            .saturating_mul(2u32.saturating_pow(self.attempt))
+            .min(self.max);
+        self.attempt = self.attempt.saturating_add(1);
+        let millis = ceiling.as_millis() as u64;
+        Duration::from_millis(rand::rng().random_range(millis / 2..=millis))
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff::new(Duration::from_secs(1), Duration::from_secs(60))
+    }
+}
+
+/// Connects, loads the schema and then keeps checking the connection,
+/// reconnecting with backoff whenever a check fails. `status` always holds
+/// the current [`DbStatus`]; `on_schema_change` is called after every
+/// schema swap, including the first load. Runs until the task is dropped.
+pub async fn supervise<F>(
+    db: Database,
+    databases: NamedDatabases,
+    cache: SharedSchema,
+    status: watch::Sender<DbStatus>,
+    on_schema_change: F,
+) where
+    F: Fn(SchemaChanged) + Send + 'static,
+{
+    let poll_schema = watcher::poll_interval();
+    let interval = poll_schema.unwrap_or(DEFAULT_PROBE_INTERVAL);
+
+    loop {
+        let mut backoff = Backoff::default();
+        loop {
+            match establish(&db, &databases, &cache).await {
+                Ok(change) => {
+                    status.send_replace(DbStatus::Available);
+                    println!("Database available");
+                    if let Some(change) = change {
+                        on_schema_change(change);
+                    }
+                    break;
+                }
+                Err(e) => {
+                    let delay = backoff.next_delay();
+                    eprintln!(
+                        "Warning: Database unavailable ({}), retrying in {:?}",
+                        e, delay
+                    );
+                    let since = match &*status.borrow() {
+                        DbStatus::Unavailable { since, .. } => *since,
+                        _ => Utc::now(),
+                    };
+                    status.send_replace(DbStatus::Unavailable {
+                        reason: e.to_string(),
+                        since,
+                        attempts: backoff.attempts(),
+                        next_retry_ms: delay.as_millis() as u64,
+                    });
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+
+        loop {
+            tokio::time::sleep(interval).await;
+
+            let result = match poll_schema {
+                Some(_) => watcher::check(&db, &databases, &cache).await,
+                None => db.ping().await.map(|_| None),
+            };
+            match result {
+                Ok(Some(change)) => {
+                    println!("Database schema changed, reloaded ({})", change.fingerprint);
+                    on_schema_change(change);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    eprintln!("Warning: Database check failed: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// One connection attempt: drops connections left over from before an
+/// outage, checks the server answers and reloads the schema, which may have
+/// changed while it was unreachable.
+async fn establish(
+    db: &Database,
+    databases: &NamedDatabases,
+    cache: &SharedSchema,
+) -> DbResult<Option<SchemaChanged>> {
+    db.close_idle();
+    db.ping().await?;
+    watcher::reload(db, databases, cache).await
+}
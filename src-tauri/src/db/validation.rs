@@ -0,0 +1,245 @@
+use chrono::{DateTime, NaiveDate};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use uuid::Uuid;
+
+use super::codegen::json_schema::{self, WriteMode};
+use super::schema::DatabaseSchema;
+
+/// A single problem with a payload, reported against the column (or nested
+/// path such as `tags[2]`) it was found at.
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        FieldError {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.field, self.message)
+        }
+    }
+}
+
+/// All of `errors` on one line, for messages.
+pub fn summarize(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Validates a write payload for `table_name` against the same JSON Schema
+/// the codegen emits for the frontend. Returns every problem found rather
+/// than stopping at the first one.
+pub fn validate_write(
+    schema: &DatabaseSchema,
+    table_name: &str,
+    value: &Value,
+    mode: WriteMode,
+) -> Result<(), Vec<FieldError>> {
+    let Some(table) = schema.tables.get(table_name) else {
+        return Err(vec![FieldError {
+            field: String::new(),
+            message: format!("Table '{}' not found in the database schema", table_name),
+        }]);
+    };
+
+    let document = json_schema::table_schema(schema, table, mode);
+    let errors = validate(&document, value);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks `value` against the subset of JSON Schema produced by
+/// [`json_schema`]: `type`, `enum`, `format`, `minimum`/`maximum`, `anyOf`,
+/// `items`, `properties`, `required` and `additionalProperties: false`.
+pub fn validate(schema: &Value, value: &Value) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    check(schema, value, "", &mut errors);
+    errors
+}
+
+fn check(schema: &Value, value: &Value, path: &str, errors: &mut Vec<FieldError>) {
+    let mut fail = |message: String| {
+        errors.push(FieldError {
+            field: path.to_string(),
+            message,
+        })
+    };
+
+    if let Some(branches) = schema.get("anyOf").and_then(Value::as_array) {
+        let mut candidate = None;
+        for branch in branches {
+            let mut branch_errors = Vec::new();
+            check(branch, value, path, &mut branch_errors);
+            if branch_errors.is_empty() {
+                return;
+            }
+            if candidate.is_none() && accepts_type(branch, value) {
+                candidate = Some(branch_errors);
+            }
+        }
+        match candidate {
+            Some(branch_errors) => errors.extend(branch_errors),
+            None => fail(format!("expected {}", describe(schema))),
+        }
+        return;
+    }
+
+    if !accepts_type(schema, value) {
+        fail(format!("expected {}", describe(schema)));
+        return;
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            let names: Vec<String> = allowed
+                .iter()
+                .map(|v| v.as_str().map(str::to_string).unwrap_or(v.to_string()))
+                .collect();
+            fail(format!("must be one of: {}", names.join(", ")));
+            return;
+        }
+    }
+
+    if let (Some(format), Some(s)) = (schema.get("format").and_then(Value::as_str), value.as_str())
+    {
+        let message = match format {
+            "uuid" if Uuid::parse_str(s).is_err() => Some("must be a UUID"),
+            "date-time" if DateTime::parse_from_rfc3339(s).is_err() => {
+                Some("must be an RFC 3339 timestamp")
+            }
+            "date" if NaiveDate::parse_from_str(s, "%Y-%m-%d").is_err() => {
+                Some("must be a date (YYYY-MM-DD)")
+            }
+            _ => None,
+        };
+        if let Some(message) = message {
+            fail(message.to_string());
+            return;
+        }
+    }
+
+    if let Some(n) = value.as_f64() {
+        let minimum = schema.get("minimum").and_then(Value::as_f64);
+        let maximum = schema.get("maximum").and_then(Value::as_f64);
+        if minimum.is_some_and(|min| n < min) || maximum.is_some_and(|max| n > max) {
+            fail(format!(
+                "must be between {} and {}",
+                schema["minimum"], schema["maximum"]
+            ));
+            return;
+        }
+    }
+
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for (i, item) in array.iter().enumerate() {
+            check(items, item, &format!("{}[{}]", path, i), errors);
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let child = |key: &str| {
+            if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", path, key)
+            }
+        };
+
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(key) {
+                    errors.push(FieldError {
+                        field: child(key),
+                        message: "is required".to_string(),
+                    });
+                }
+            }
+        }
+
+        let closed = schema.get("additionalProperties") == Some(&Value::Bool(false));
+        for (key, item) in object {
+            match properties.and_then(|p| p.get(key)) {
+                Some(property) => check(property, item, &child(key), errors),
+                None if closed => errors.push(FieldError {
+                    field: child(key),
+                    message: "is not a column of this table".to_string(),
+                }),
+                None => {}
+            }
+        }
+    }
+}
+
+fn accepts_type(schema: &Value, value: &Value) -> bool {
+    match schema.get("type") {
+        Some(Value::String(name)) => is_type(name, value),
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| is_type(name, value)),
+        _ => match schema.get("anyOf").and_then(Value::as_array) {
+            Some(branches) => branches.iter().any(|b| accepts_type(b, value)),
+            None => true,
+        },
+    }
+}
+
+fn is_type(name: &str, value: &Value) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        // `5.0` is refused: it would not bind to an integer column.
+        "integer" => value.is_i64() || value.is_u64(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => false,
+    }
+}
+
+fn describe(schema: &Value) -> String {
+    if let Some(branches) = schema.get("anyOf").and_then(Value::as_array) {
+        return branches
+            .iter()
+            .map(describe)
+            .collect::<Vec<_>>()
+            .join(" or ");
+    }
+
+    let name = match schema.get("type") {
+        Some(Value::String(name)) => name.clone(),
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        _ => return "any value".to_string(),
+    };
+
+    match schema.get("format").and_then(Value::as_str) {
+        Some(format) => format!("{} ({})", name, format),
+        None => name,
+    }
+}
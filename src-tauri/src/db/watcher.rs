@@ -0,0 +1,93 @@
+use serde::Serialize;
+use std::time::Duration;
+
+use super::connection::{Database, DbResult};
+use super::registry::NamedDatabases;
+use super::schema::{DatabaseSchema, SharedSchema};
+
+/// Event emitted to the frontend whenever the cached schema is replaced.
+pub const SCHEMA_CHANGED_EVENT: &str = "db://schema-changed";
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Payload of [`SCHEMA_CHANGED_EVENT`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaChanged {
+    pub fingerprint: String,
+    pub previous_fingerprint: Option<String>,
+    pub table_count: usize,
+    pub enum_count: usize,
+}
+
+impl SchemaChanged {
+    fn new(schema: &DatabaseSchema, previous_fingerprint: Option<String>) -> Self {
+        SchemaChanged {
+            fingerprint: schema.fingerprint.clone(),
+            previous_fingerprint,
+            table_count: schema.unique_tables().len(),
+            enum_count: schema.enums.len(),
+        }
+    }
+}
+
+/// How often the catalog fingerprint is polled, from
+/// `SCHEMA_POLL_INTERVAL_SECS`. `0` disables polling.
+pub fn poll_interval() -> Option<Duration> {
+    match std::env::var("SCHEMA_POLL_INTERVAL_SECS") {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(0) => None,
+            Ok(secs) => Some(Duration::from_secs(secs)),
+            Err(_) => {
+                eprintln!(
+                    "Warning: Invalid SCHEMA_POLL_INTERVAL_SECS '{}', using {}s",
+                    value,
+                    DEFAULT_POLL_INTERVAL.as_secs()
+                );
+                Some(DEFAULT_POLL_INTERVAL)
+            }
+        },
+        Err(_) => Some(DEFAULT_POLL_INTERVAL),
+    }
+}
+
+/// Fetches the schema and swaps it into `cache`. Returns the change when the
+/// fingerprint differs from the cached one, `None` when nothing changed.
+/// On a change, the statement caches of `db` and of every named database
+/// are emptied.
+pub async fn reload(
+    db: &Database,
+    databases: &NamedDatabases,
+    cache: &SharedSchema,
+) -> DbResult<Option<SchemaChanged>> {
+    let schema = DatabaseSchema::fetch(db).await?;
+
+    let mut guard = cache.write().await;
+    let previous = guard.as_ref().map(|s| s.fingerprint.clone());
+    if previous.as_deref() == Some(schema.fingerprint.as_str()) {
+        return Ok(None);
+    }
+
+    let change = SchemaChanged::new(&schema, previous);
+    *guard = Some(schema);
+    db.clear_statement_caches();
+    databases.clear_statement_caches();
+    Ok(Some(change))
+}
+
+/// Compares the catalog fingerprint with the cached schema and reloads it
+/// on a mismatch. Only the fingerprint query runs when nothing changed.
+pub async fn check(
+    db: &Database,
+    databases: &NamedDatabases,
+    cache: &SharedSchema,
+) -> DbResult<Option<SchemaChanged>> {
+    let current = DatabaseSchema::fingerprint(db).await?;
+
+    let cached = cache.read().await.as_ref().map(|s| s.fingerprint.clone());
+    if cached.as_deref() == Some(current.as_str()) {
+        return Ok(None);
+    }
+
+    reload(db, databases, cache).await
+}
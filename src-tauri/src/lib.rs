@@ -4,8 +4,41 @@ fn greet(name: &str) -> String {
 }
 
 pub mod db {
+    pub mod codegen {
+        pub mod json_schema;
+        pub mod naming;
+        pub mod rust;
+        pub mod typescript;
+        pub mod validators;
+    }
+    pub mod access;
+    pub mod audit;
+    pub mod cancel;
     pub mod connection;
+    pub mod hmi;
+    pub mod lifecycle;
+    pub mod listener;
+    pub mod maintenance;
+    pub mod metrics;
+    pub mod models;
+    pub mod offline {
+        pub mod operation;
+        pub mod replay;
+        pub mod store;
+    }
+    pub mod otp;
+    pub mod receipts;
+    pub mod registry;
+    pub mod replica;
     pub mod schema;
+    pub mod session;
+    pub mod settings;
+    pub mod shipment;
+    pub mod stream;
+    pub mod supervisor;
+    pub mod tls;
+    pub mod validation;
+    pub mod watcher;
     pub mod queries {
         pub mod builder;
     }
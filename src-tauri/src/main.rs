@@ -2,74 +2,240 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tauri::Emitter;
+use tokio::sync::{watch, RwLock};
 
 mod commands;
 mod db;
 
 use commands::AppState;
-use db::connection::Database;
-use db::schema::DatabaseSchema;
+use db::audit::AuditSettings;
+use db::connection::{Database, DbError};
+use db::hmi;
+use db::listener::{self, Listener};
+use db::offline::{replay, store::OfflineStore};
+use db::otp::OtpSettings;
+use db::registry::NamedDatabases;
+use db::schema::{self, DatabaseSchema};
+use db::session::{self, SessionStore};
+use db::settings::DatabaseConfig;
+use db::stream::ActiveStreams;
+use db::supervisor::{self, DbStatus};
+use db::watcher;
 
 #[tokio::main]
 async fn main() {
-    let db = Database::new()
-        .await
-        .expect("Failed to initialize database");
-
-    if let Err(e) = sync_schema_cli().await {
-        eprintln!("Warning: Failed to sync schema: {}", e);
+    if std::env::args().nth(1).as_deref() == Some("codegen") {
+        if let Err(e) = codegen_cli().await {
+            eprintln!("Error: Failed to generate code from schema: {}", e);
+            std::process::exit(1);
+        }
+        return;
     }
 
-    let schema = DatabaseSchema::fetch(&db)
-        .await
-        .expect("Failed to fetch database schema");
+    // Run Tauri's async tasks on this runtime, where the pool lives.
+    tauri::async_runtime::set(tokio::runtime::Handle::current());
+
+    let (db, databases, listener) = match DatabaseConfig::from_env()
+        .map_err(DbError::from)
+        .and_then(|config| {
+            let databases = NamedDatabases::from_config(&config)?;
+            let listener = Listener::new(&config.listen_channels);
+            Ok((Database::build(config)?, databases, listener))
+        }) {
+        Ok(built) => built,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let otp = match OtpSettings::from_env() {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
 
-    println!("Database schema fetched successfully!");
+    let offline = match OfflineStore::from_env() {
+        Ok(store) => Some(store),
+        Err(e) => {
+            eprintln!("Warning: Offline mode disabled: {}", e);
+            None
+        }
+    };
 
+    // The window opens straight away; the supervisor connects in the
+    // background and the UI follows `db://status-changed`.
     let state = AppState {
-        db,
-        schema: Arc::new(Mutex::new(Some(schema))),
+        db: db.clone(),
+        databases,
+        schema: Arc::new(RwLock::new(None)),
+        status: watch::Sender::new(DbStatus::Connecting),
+        listener: listener.clone(),
+        streams: ActiveStreams::default(),
+        offline: offline.clone(),
+        audit: AuditSettings::from_env(),
+        otp,
+        session: SessionStore::from_env(),
     };
+    let databases = state.databases.clone();
+    let schema = state.schema.clone();
+    let status = state.status.clone();
+    let sessions = state.session.clone();
 
     tauri::Builder::default()
         .manage(state)
+        .setup(move |app| {
+            let handle = app.handle().clone();
+            let mut status_rx = status.subscribe();
+            tauri::async_runtime::spawn(async move {
+                while status_rx.changed().await.is_ok() {
+                    let current = status_rx.borrow_and_update().clone();
+                    if let Err(e) = handle.emit(supervisor::DB_STATUS_EVENT, current) {
+                        eprintln!("Warning: Failed to emit database status: {}", e);
+                    }
+                }
+            });
+
+            let handle = app.handle().clone();
+            let mut session_rx = sessions.subscribe();
+            tauri::async_runtime::spawn(async move {
+                while session_rx.changed().await.is_ok() {
+                    let current = session_rx.borrow_and_update().clone();
+                    if let Err(e) = handle.emit(session::SESSION_CHANGED_EVENT, current) {
+                        eprintln!("Warning: Failed to emit session change: {}", e);
+                    }
+                }
+            });
+            tauri::async_runtime::spawn(session::expire_idle(sessions));
+
+            tauri::async_runtime::spawn(hmi::expire_codes(db.clone(), status.subscribe()));
+
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(listener::run(
+                db.clone(),
+                listener,
+                status.subscribe(),
+                move |notification| {
+                    if let Err(e) = handle.emit(listener::NOTIFICATION_EVENT, notification) {
+                        eprintln!("Warning: Failed to emit notification: {}", e);
+                    }
+                },
+            ));
+
+            if let Some(store) = offline {
+                let handle = app.handle().clone();
+                tauri::async_runtime::spawn(replay::sync(
+                    db.clone(),
+                    store,
+                    status.subscribe(),
+                    move |summary| {
+                        if let Err(e) = handle.emit(replay::OUTBOX_CHANGED_EVENT, summary) {
+                            eprintln!("Warning: Failed to emit outbox summary: {}", e);
+                        }
+                    },
+                ));
+            }
+
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(supervisor::supervise(
+                db,
+                databases,
+                schema,
+                status,
+                move |change| {
+                    if let Err(e) = handle.emit(watcher::SCHEMA_CHANGED_EVENT, change) {
+                        eprintln!("Warning: Failed to emit schema change: {}", e);
+                    }
+                },
+            ));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
+            commands::db_commands::db_status,
+            commands::db_commands::db_health,
+            commands::db_commands::list_databases,
             commands::db_commands::sync_schema,
+            commands::db_commands::regenerate_schema_files,
             commands::db_commands::query_table,
             commands::db_commands::insert_into_table,
             commands::db_commands::query_raw,
+            commands::db_commands::cancel_query,
+            commands::db_commands::subscribe_channel,
+            commands::db_commands::unsubscribe_channel,
+            commands::receipt_commands::create_borrow_receipt,
+            commands::receipt_commands::create_return_receipt,
+            commands::session_commands::login_with_hmi_code,
+            commands::session_commands::current_session,
+            commands::session_commands::touch_session,
+            commands::session_commands::logout,
+            commands::audit_commands::create_audit,
+            commands::audit_commands::add_audit_devices,
+            commands::audit_commands::update_audit_conditions,
+            commands::audit_commands::remove_audit_device,
+            commands::audit_commands::complete_audit,
+            commands::audit_commands::cancel_audit,
+            commands::maintenance_commands::create_maintenance,
+            commands::maintenance_commands::add_maintenance_devices,
+            commands::maintenance_commands::update_maintenance_outcomes,
+            commands::maintenance_commands::remove_maintenance_device,
+            commands::maintenance_commands::assign_maintenance_technician,
+            commands::maintenance_commands::complete_maintenance,
+            commands::maintenance_commands::cancel_maintenance,
+            commands::shipment_commands::create_shipment,
+            commands::shipment_commands::add_shipment_devices,
+            commands::shipment_commands::remove_shipment_device,
+            commands::shipment_commands::dispatch_shipment,
+            commands::shipment_commands::confirm_shipment_arrivals,
+            commands::shipment_commands::close_shipment,
+            commands::shipment_commands::cancel_shipment,
+            commands::shipment_commands::shipment_manifest,
+            commands::device_commands::device_status_history,
+            commands::device_commands::device_detail,
+            commands::device_commands::device_accessories,
+            commands::device_commands::find_device,
+            commands::device_commands::device_receipt_detail,
+            commands::device_commands::device_audit_detail,
+            commands::device_commands::device_maintenance_detail,
+            commands::device_commands::device_shipment_detail,
+            commands::hmi_commands::issue_hmi_code,
+            commands::hmi_commands::hmi_code_status,
+            commands::stream_commands::stream_table,
+            commands::stream_commands::stream_raw,
+            commands::stream_commands::ack_stream_chunk,
+            commands::offline_commands::submit_operation,
+            commands::offline_commands::offline_lookup,
+            commands::offline_commands::offline_list,
+            commands::offline_commands::outbox_summary,
+            commands::offline_commands::outbox_entries,
+            commands::offline_commands::retry_outbox_entry,
+            commands::offline_commands::discard_outbox_entry,
+            commands::otp_commands::verify_one_time_qr,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
 
-async fn sync_schema_cli() -> Result<(), Box<dyn std::error::Error>> {
+/// `hmi codegen`: regenerates the frontend files and the Rust row types in
+/// `src-tauri/src/db/models.rs` of the checkout it was built from. Nothing
+/// is generated on startup, so `tauri dev` does not rebuild itself in a
+/// loop.
+async fn codegen_cli() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new().await?;
     let schema = DatabaseSchema::fetch(&db).await?;
 
-    let workspace_dir = std::env::current_dir()?
-        .parent()
-        .ok_or_else(|| {
-            std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "Could not find project root directory",
-            )
-        })?
-        .to_path_buf();
-
-    let types_dir = workspace_dir.join("src").join("types").join("db");
-    std::fs::create_dir_all(&types_dir)?;
-
     println!("Saving schema to files...");
 
-    let schema_path = types_dir.join("schema.json");
-    schema.save_to_file(&schema_path)?;
-    println!("✓ Schema saved to src/types/db/schema.json");
+    let workspace_dir = schema::workspace_dir()?;
+    for path in schema.write_generated_files(&workspace_dir)? {
+        let relative = path.strip_prefix(&workspace_dir).unwrap_or(&path);
+        println!("✓ Generated {}", relative.display());
+    }
 
-    let types_path = types_dir.join("generated.ts");
-    schema.generate_typescript_types(&types_path)?;
-    println!("✓ TypeScript types generated in src/types/db/generated.ts");
+    schema.generate_rust_types(schema::models_path())?;
+    println!("✓ Rust types generated in src-tauri/src/db/models.rs");
 
     Ok(())
 }
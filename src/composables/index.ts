@@ -1,4 +1,6 @@
 export * from "./useAuth";
+export * from "./useDatabaseStatus";
 export * from "./useOneTimeQR";
+export * from "./useOutbox";
 export * from "./useReceiptCache";
 export * from "./useVirtualKeyboardDetection";
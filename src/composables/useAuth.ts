@@ -1,9 +1,13 @@
-import { hmiService } from "@/lib/db";
+import {
+  hmiService,
+  loginWithHmiCode,
+  logoutSession,
+  type KioskSession,
+} from "@/lib/db";
 import { computed, ref } from "vue";
 import { useRouter } from "vue-router";
 
 const POLLING_INTERVAL_MS = 3000;
-const POLLING_TIMEOUT_MS = 5 * 60 * 1000;
 
 type LoginStatus =
   | "idle"
@@ -28,14 +32,6 @@ interface UserInfo {
   roles: { name: string; key: string }[];
 }
 
-interface AuthResponse {
-  status: "success" | "pending" | "error";
-  token?: string;
-  lab?: LabInfo;
-  user?: UserInfo;
-  message?: string;
-}
-
 function isPolling(status: LoginStatus): boolean {
   return status === "polling";
 }
@@ -46,13 +42,12 @@ export function useAuth() {
   const isLoading = ref(false);
   const isGeneratingCode = ref(false);
   const error = ref<string | null>(null);
-  const isAuthenticated = computed(() => !!localStorage.getItem("auth_token"));
+  const isAuthenticated = computed(() => !!localStorage.getItem("user_info"));
   const labInfo = ref<LabInfo | null>(null);
   const userInfo = ref<UserInfo | null>(null);
 
   const loginStatus = ref<LoginStatus>("idle");
   const pollingIntervalId = ref<number | null>(null);
-  const pollingTimeoutId = ref<number | null>(null);
 
   const generateHMICode = async () => {
     loginStatus.value = "idle";
@@ -84,52 +79,44 @@ export function useAuth() {
     return hmiCode.value;
   };
 
-  const handleSuccessfulLogin = (
-    token: string,
-    userData: UserInfo,
-    labData?: LabInfo
-  ) => {
-    localStorage.setItem("auth_token", token);
-
-    const userStorage = {
-      ...userData,
-      lab: labData || null,
+  // The session itself lives in the backend; the copy in localStorage is
+  // only for pages to show who is signed in.
+  const handleSuccessfulLogin = (session: KioskSession) => {
+    const userData: UserInfo = {
+      id: session.user.id,
+      email: session.user.email ?? "",
+      name: session.user.name,
+      avatar: session.user.image ?? "",
+      roles: session.user.roles.map((role) => ({
+        key: role.key,
+        name: role.name ?? role.key,
+      })),
     };
-    localStorage.setItem("user_info", JSON.stringify(userStorage));
+    const labData: LabInfo = {
+      id: session.lab.id,
+      name: session.lab.name ?? undefined,
+      room: session.lab.room ?? undefined,
+      branch: session.lab.branch ?? undefined,
+    };
+
+    localStorage.setItem(
+      "user_info",
+      JSON.stringify({ ...userData, lab: labData })
+    );
 
     userInfo.value = userData;
-    labInfo.value = labData || null;
+    labInfo.value = labData;
   };
 
+  // The backend reports the code as expired once its ten minutes are up,
+  // which ends the polling.
   const checkLoginStatus = async (codeWithoutSpace: string) => {
-    const apiBaseUrl =
-      import.meta.env.VITE_API_BASE_URL || "http://localhost:3000";
-    const url = `${apiBaseUrl}/api/auth/status/${encodeURIComponent(codeWithoutSpace)}`;
-
     try {
-      const response = await fetch(url, {
-        method: "GET",
-        headers: {
-          Accept: "application/json",
-        },
-      });
-
-      if (!response.ok) {
-        let errorMsg = `HTTP error! Status: ${response.status}`;
-        try {
-          const errorData = await response.json();
-          errorMsg = errorData.message || errorMsg;
-        } catch (jsonError) {
-          // Ignore if response body is not valid JSON
-        }
-        throw new Error(errorMsg);
-      }
-
-      const data = (await response.json()) as AuthResponse;
+      const data = await hmiService.getHMICodeStatus(codeWithoutSpace);
 
       switch (data.status) {
-        case "success":
-          if (!data.token || !data.user) {
+        case "authenticated":
+          if (!data.user) {
             throw new Error("Lỗi xác thực: Thiếu thông tin người dùng.");
           }
           if (!data.lab) {
@@ -137,8 +124,8 @@ export function useAuth() {
             isLoading.value = true;
             return;
           }
+          handleSuccessfulLogin(await loginWithHmiCode(codeWithoutSpace));
           loginStatus.value = "success";
-          handleSuccessfulLogin(data.token, data.user, data.lab);
           stopLoginPolling();
           break;
         case "pending":
@@ -146,27 +133,26 @@ export function useAuth() {
             loginStatus.value = "polling";
           }
           break;
-        case "error":
-          error.value =
-            data.message || "Đã xảy ra lỗi trong quá trình đăng nhập.";
-          loginStatus.value = "error";
+        case "used":
+        case "expired":
+          error.value = "Login timed out. Please try generating a new code.";
+          loginStatus.value = "timeout";
           stopLoginPolling();
           break;
         default:
           break;
       }
-    } catch (fetchError) {
+    } catch (statusError: any) {
       error.value =
-        fetchError instanceof Error
-          ? fetchError.message
-          : "Lỗi kết nối. Vui lòng kiểm tra kết nối mạng hoặc máy chủ.";
+        statusError?.message ||
+        "Lỗi kết nối. Vui lòng kiểm tra kết nối mạng hoặc máy chủ.";
       loginStatus.value = "error";
       stopLoginPolling();
     }
   };
 
   const startLoginPolling = (codeWithSpace: string) => {
-    if (pollingIntervalId.value || pollingTimeoutId.value) {
+    if (pollingIntervalId.value) {
       stopLoginPolling();
     }
     if (!codeWithSpace) {
@@ -190,17 +176,6 @@ export function useAuth() {
         stopLoginPolling();
       }
     }, POLLING_INTERVAL_MS);
-
-    pollingTimeoutId.value = window.setTimeout(() => {
-      if (
-        isPolling(loginStatus.value) ||
-        loginStatus.value === "awaiting_lab"
-      ) {
-        error.value = "Login timed out. Please try generating a new code.";
-        loginStatus.value = "timeout";
-        stopLoginPolling();
-      }
-    }, POLLING_TIMEOUT_MS);
   };
 
   const stopLoginPolling = () => {
@@ -208,10 +183,6 @@ export function useAuth() {
       window.clearInterval(pollingIntervalId.value);
       pollingIntervalId.value = null;
     }
-    if (pollingTimeoutId.value) {
-      window.clearTimeout(pollingTimeoutId.value);
-      pollingTimeoutId.value = null;
-    }
     if (
       isLoading.value &&
       loginStatus.value !== "success" &&
@@ -221,7 +192,12 @@ export function useAuth() {
     }
   };
 
-  const logout = () => {
+  const logout = async () => {
+    try {
+      await logoutSession();
+    } catch (err) {
+      console.log(err);
+    }
     localStorage.removeItem("auth_token");
     localStorage.removeItem("user_info");
     router.push({ name: "login" });
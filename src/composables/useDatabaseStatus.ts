@@ -0,0 +1,28 @@
+import { getDbStatus, onDbStatusChanged, type DbStatus } from "@/lib/db";
+import type { UnlistenFn } from "@tauri-apps/api/event";
+import { computed, readonly, ref } from "vue";
+
+const status = ref<DbStatus>({ state: "connecting" });
+let subscription: Promise<UnlistenFn> | null = null;
+
+function subscribe() {
+  if (subscription) return;
+  subscription = onDbStatusChanged((next) => {
+    status.value = next;
+  });
+  // The event may have fired before the listener was registered.
+  getDbStatus()
+    .then((current) => {
+      status.value = current;
+    })
+    .catch(() => {});
+}
+
+export function useDatabaseStatus() {
+  subscribe();
+
+  return {
+    status: readonly(status),
+    isAvailable: computed(() => status.value.state === "available"),
+  };
+}
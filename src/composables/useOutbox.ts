@@ -0,0 +1,61 @@
+import {
+  discardOutboxEntry,
+  getOutboxEntries,
+  getOutboxSummary,
+  onOutboxChanged,
+  retryOutboxEntry,
+  type OutboxEntry,
+  type OutboxSummary,
+} from "@/lib/db";
+import type { UnlistenFn } from "@tauri-apps/api/event";
+import { computed, readonly, ref } from "vue";
+
+const summary = ref<OutboxSummary>({
+  pending: 0,
+  conflicts: 0,
+  failed: 0,
+  cacheRefreshedAt: null,
+});
+const problems = ref<OutboxEntry[]>([]);
+let subscription: Promise<UnlistenFn> | null = null;
+
+async function loadProblems() {
+  const [conflicts, failed] = await Promise.all([
+    getOutboxEntries("conflict"),
+    getOutboxEntries("failed"),
+  ]);
+  problems.value = [...conflicts, ...failed].sort((a, b) => a.seq - b.seq);
+}
+
+function update(next: OutboxSummary) {
+  summary.value = next;
+  if (next.conflicts + next.failed > 0 || problems.value.length > 0) {
+    loadProblems().catch(() => {});
+  }
+}
+
+function subscribe() {
+  if (subscription) return;
+  subscription = onOutboxChanged(update);
+  // Without an offline store the command fails; keep the empty summary.
+  getOutboxSummary()
+    .then(update)
+    .catch(() => {});
+}
+
+export function useOutbox() {
+  subscribe();
+
+  return {
+    summary: readonly(summary),
+    problems: readonly(problems),
+    hasProblems: computed(
+      () => summary.value.conflicts + summary.value.failed > 0,
+    ),
+    retry: (seq: number) => retryOutboxEntry(seq),
+    discard: async (seq: number) => {
+      await discardOutboxEntry(seq);
+      await loadProblems();
+    },
+  };
+}
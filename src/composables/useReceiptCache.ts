@@ -1,5 +1,6 @@
 import { ref, readonly } from 'vue'
 import type { BorrowReturnDeviceSchema, ReadyBorrowedDeviceSchema, ReturnedReceiptDeviceSchema } from '@/components/app/borrow-return'
+import { onDbNotification } from '@/lib/db'
 
 export type CachedData<T> = {
     data: T[];
@@ -16,13 +17,34 @@ export type ReceiptCache = {
 
 const CACHE_DURATION = 5 * 60 * 1000;
 
+// Sent by the database whenever receipts change, from any kiosk.
+export const RECEIPTS_CHANNEL = 'receipts_changed';
+
 const cache = ref<ReceiptCache>({
     readyBorrow: null,
     borrowing: null,
     returned: null,
 })
 
+let subscribed = false;
+
+function subscribe() {
+    if (subscribed) return;
+    subscribed = true;
+    onDbNotification(RECEIPTS_CHANNEL, () => {
+        cache.value = {
+            readyBorrow: null,
+            borrowing: null,
+            returned: null,
+        }
+    }).catch(() => {
+        subscribed = false;
+    });
+}
+
 export function useReceiptCache() {
+    subscribe();
+
     const isCacheValid = (timestamp: number | undefined) => {
         if (!timestamp) return false;
         return Date.now() - timestamp < CACHE_DURATION;
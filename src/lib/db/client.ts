@@ -1,4 +1,6 @@
-import { invoke } from "@tauri-apps/api/core";
+import { Channel, invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import type { ShipmentStatus } from "@/types/db/generated";
 
 export type JoinParams = {
   table: string;
@@ -17,22 +19,79 @@ export type QueryParams = {
   limit?: number;
   offset?: number;
   joins?: JoinParams[];
+  // Overrides the default statement timeout for this query.
+  timeout_ms?: number;
+  // Pass the same ID to `cancelQuery` to stop the query while it runs.
+  request_id?: string;
+  // Read from the primary even when a replica is configured.
+  read_primary?: boolean;
 };
 
 export type RawQueryParams = {
   sql: string;
   params?: unknown[];
+  timeout_ms?: number;
+  request_id?: string;
+  read_primary?: boolean;
+  // Name of an additional database from the `[databases]` config section.
+  database?: string;
 };
 
-export type InsertParams<T> = {
+export type StreamOptions = {
+  // Pass the same ID to `cancelQuery` to stop the stream early.
+  streamId?: string;
+  // Rows per chunk; 500 by default.
+  chunkSize?: number;
+};
+
+export type StreamSummary = {
+  rows: number;
+  chunks: number;
+  cancelled: boolean;
+};
+
+// Called once per chunk, in order. Only a few chunks are fetched ahead of
+// the handler, so a slow handler slows the query down instead of piling up
+// rows in memory.
+export type RowsHandler<T> = (rows: T[], seq: number) => void | Promise<void>;
+
+type StreamChunk<T> = { seq: number; rows: T[] };
+
+export type InsertParams<T, I = Partial<T>> = {
   table: string;
-  value: Partial<T>;
+  value: I;
 };
 
+// Filled in by the generated schema types (`@/types/db/generated`), which
+// augment this interface with one entry per table.
+export interface DatabaseTables {}
+
+type KnownTable = keyof DatabaseTables & string;
+
+type TableDef<N extends KnownTable> = DatabaseTables[N] extends {
+  Row: infer R;
+  Insert: infer I;
+  Column: infer C extends string;
+}
+  ? { Row: R; Insert: I; Column: C }
+  : never;
+
 export interface DbClient {
   queryRaw<T>(params: RawQueryParams): Promise<T[]>;
   table<T>(name: string): TableQueryBuilder<T>;
-  insert<T>(params: InsertParams<T>): Promise<T>;
+  from<N extends KnownTable>(
+    name: N
+  ): TableQueryBuilder<
+    TableDef<N>["Row"],
+    TableDef<N>["Column"],
+    TableDef<N>["Insert"]
+  >;
+  insert<T, I = Partial<T>>(params: InsertParams<T, I>): Promise<T>;
+  streamRaw<T>(
+    params: RawQueryParams,
+    onRows: RowsHandler<T>,
+    options?: StreamOptions
+  ): Promise<StreamSummary>;
 }
 
 class TauriDbClient implements DbClient {
@@ -40,6 +99,14 @@ class TauriDbClient implements DbClient {
     return new TableQueryBuilder<T>(this, name);
   }
 
+  from<N extends KnownTable>(name: N) {
+    return new TableQueryBuilder<
+      TableDef<N>["Row"],
+      TableDef<N>["Column"],
+      TableDef<N>["Insert"]
+    >(this, name);
+  }
+
   async query<T>(params: QueryParams): Promise<T[]> {
     try {
       return await invoke<T[]>("query_table", {
@@ -51,6 +118,9 @@ class TauriDbClient implements DbClient {
           limit: params.limit,
           offset: params.offset,
           joins: params.joins,
+          timeout_ms: params.timeout_ms,
+          request_id: params.request_id,
+          read_primary: params.read_primary,
         },
       });
     } catch (error) {
@@ -64,6 +134,10 @@ class TauriDbClient implements DbClient {
         params: {
           sql: params.sql,
           params: params.params || [],
+          timeout_ms: params.timeout_ms,
+          request_id: params.request_id,
+          read_primary: params.read_primary,
+          database: params.database,
         },
       });
     } catch (error) {
@@ -71,7 +145,64 @@ class TauriDbClient implements DbClient {
     }
   }
 
-  async insert<T>(params: InsertParams<T>): Promise<T> {
+  async stream<T>(
+    params: QueryParams,
+    onRows: RowsHandler<T>,
+    options: StreamOptions = {}
+  ): Promise<StreamSummary> {
+    return this.runStream("stream_table", params, onRows, options);
+  }
+
+  // Read-only statements only.
+  async streamRaw<T>(
+    params: RawQueryParams,
+    onRows: RowsHandler<T>,
+    options: StreamOptions = {}
+  ): Promise<StreamSummary> {
+    return this.runStream(
+      "stream_raw",
+      { ...params, params: params.params || [] },
+      onRows,
+      options
+    );
+  }
+
+  private async runStream<T>(
+    command: string,
+    params: QueryParams | RawQueryParams,
+    onRows: RowsHandler<T>,
+    options: StreamOptions
+  ): Promise<StreamSummary> {
+    const streamId = options.streamId ?? crypto.randomUUID();
+    const onChunk = new Channel<StreamChunk<T>>();
+    let handled = Promise.resolve();
+    let failure: unknown = null;
+
+    onChunk.onmessage = (chunk) => {
+      handled = handled.then(async () => {
+        if (failure) return;
+        try {
+          await onRows(chunk.rows, chunk.seq);
+          await invoke("ack_stream_chunk", { streamId });
+        } catch (error) {
+          failure = error;
+          await cancelQuery(streamId).catch(() => {});
+        }
+      });
+    };
+
+    const summary = await invoke<StreamSummary>(command, {
+      params,
+      streamId,
+      chunkSize: options.chunkSize,
+      onChunk,
+    });
+    await handled;
+    if (failure) throw failure;
+    return summary;
+  }
+
+  async insert<T, I = Partial<T>>(params: InsertParams<T, I>): Promise<T> {
     try {
       const tableName = params.table.includes(".")
         ? params.table
@@ -91,16 +222,877 @@ class TauriDbClient implements DbClient {
 
 export const db = new TauriDbClient();
 
+// Resolves to false when the query had already finished.
+export async function cancelQuery(requestId: string): Promise<boolean> {
+  return await invoke<boolean>("cancel_query", { requestId });
+}
+
 export async function syncSchema(): Promise<void> {
   await invoke("sync_schema");
 }
 
-export class TableQueryBuilder<T> {
+export type DbStatus =
+  | { state: "connecting" }
+  | { state: "available" }
+  | {
+      state: "unavailable";
+      reason: string;
+      since: string;
+      attempts: number;
+      nextRetryMs: number;
+    };
+
+export async function getDbStatus(): Promise<DbStatus> {
+  return await invoke<DbStatus>("db_status");
+}
+
+export type DbHealth = {
+  pool: {
+    maxSize: number;
+    size: number;
+    idle: number;
+    inUse: number;
+    waiting: number;
+  };
+  waits: { checkouts: number; avgWaitMs: number; maxWaitMs: number };
+  errors: {
+    total: number;
+    timeouts: number;
+    recent: number;
+    recentWindowSecs: number;
+  };
+  statements: {
+    capacity: number;
+    hits: number;
+    misses: number;
+    hitRate: number;
+    evictions: number;
+    invalidations: number;
+  };
+  latencyMs: number | null;
+  serverVersion: string | null;
+  error: string | null;
+  checkedAt: string;
+  replica?: DbHealth;
+};
+
+export async function getDbHealth(database?: string): Promise<DbHealth> {
+  return await invoke<DbHealth>("db_health", { database });
+}
+
+export async function listDatabases(): Promise<string[]> {
+  return await invoke<string[]>("list_databases");
+}
+
+export function onDbStatusChanged(
+  handler: (status: DbStatus) => void,
+): Promise<UnlistenFn> {
+  return listen<DbStatus>("db://status-changed", (event) =>
+    handler(event.payload),
+  );
+}
+
+export type SchemaChanged = {
+  fingerprint: string;
+  previousFingerprint: string | null;
+  tableCount: number;
+  enumCount: number;
+};
+
+export function onSchemaChanged(
+  handler: (change: SchemaChanged) => void,
+): Promise<UnlistenFn> {
+  return listen<SchemaChanged>("db://schema-changed", (event) =>
+    handler(event.payload),
+  );
+}
+
+export type DbNotification<T = unknown> = {
+  channel: string;
+  // Parsed when the NOTIFY payload is JSON, the raw string otherwise.
+  payload: T;
+  processId: number;
+  receivedAt: string;
+};
+
+export async function subscribeChannel(channel: string): Promise<string[]> {
+  return await invoke<string[]>("subscribe_channel", { channel });
+}
+
+export async function unsubscribeChannel(channel: string): Promise<string[]> {
+  return await invoke<string[]>("unsubscribe_channel", { channel });
+}
+
+// Subscribes to `channel` and calls `handler` for its notifications. The
+// returned function stops listening and drops the subscription.
+export async function onDbNotification<T = unknown>(
+  channel: string,
+  handler: (notification: DbNotification<T>) => void,
+): Promise<UnlistenFn> {
+  const unlisten = await listen<DbNotification<T>>(
+    "db://notification",
+    (event) => {
+      if (event.payload.channel === channel) handler(event.payload);
+    },
+  );
+  try {
+    await subscribeChannel(channel);
+  } catch (error) {
+    unlisten();
+    throw error;
+  }
+  return () => {
+    unlisten();
+    unsubscribeChannel(channel).catch(() => {});
+  };
+}
+
+// The checker or auditor and the lab are taken from the kiosk session;
+// values sent here are ignored.
+export type OfflineOperation =
+  | {
+      kind: "borrow";
+      receiptId: string;
+      borrowerId: string;
+      checkerId?: string;
+      labId?: string;
+      devices: Array<{
+        id: string;
+        expectedReturnedAt: string;
+        expectedReturnedLabId?: string;
+        prevQuality?: string;
+      }>;
+      recordedAt: string;
+    }
+  | {
+      kind: "return";
+      receiptId: string;
+      returnerId: string;
+      checkerId?: string;
+      labId?: string;
+      devices: Array<{ id: string; afterQuality: string }>;
+      note?: string;
+      recordedAt: string;
+    }
+  | {
+      kind: "audit";
+      auditId: string;
+      auditorId?: string;
+      labId?: string;
+      devices: Array<{ id: string; condition: string; prevStatus?: string }>;
+      status?: string;
+      notes?: string;
+      recordedAt: string;
+    };
+
+export type SubmitOutcome = { state: "applied" } | { state: "queued"; seq: number };
+
+export type OfflineEntity = "devices" | "device_kinds" | "users" | "open_receipts";
+
+export type OutboxState = "pending" | "applied" | "conflict" | "failed";
+
+export type OutboxEntry = {
+  seq: number;
+  operation: OfflineOperation;
+  state: OutboxState;
+  attempts: number;
+  message: string | null;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export type OutboxSummary = {
+  pending: number;
+  conflicts: number;
+  failed: number;
+  cacheRefreshedAt: string | null;
+};
+
+// Applied right away when the database is reachable, queued otherwise.
+export async function submitOperation(
+  operation: OfflineOperation,
+): Promise<SubmitOutcome> {
+  return await invoke<SubmitOutcome>("submit_operation", { operation });
+}
+
+export async function offlineLookup<T>(
+  entity: OfflineEntity,
+  id: string,
+): Promise<T | null> {
+  return await invoke<T | null>("offline_lookup", { entity, id });
+}
+
+export async function offlineList<T>(entity: OfflineEntity): Promise<T[]> {
+  return await invoke<T[]>("offline_list", { entity });
+}
+
+export async function getOutboxSummary(): Promise<OutboxSummary> {
+  return await invoke<OutboxSummary>("outbox_summary");
+}
+
+export async function getOutboxEntries(
+  outboxState?: OutboxState,
+): Promise<OutboxEntry[]> {
+  return await invoke<OutboxEntry[]>("outbox_entries", { outboxState });
+}
+
+export async function retryOutboxEntry(seq: number): Promise<boolean> {
+  return await invoke<boolean>("retry_outbox_entry", { seq });
+}
+
+export async function discardOutboxEntry(seq: number): Promise<boolean> {
+  return await invoke<boolean>("discard_outbox_entry", { seq });
+}
+
+export function onOutboxChanged(
+  handler: (summary: OutboxSummary) => void,
+): Promise<UnlistenFn> {
+  return listen<OutboxSummary>("offline://outbox-changed", (event) =>
+    handler(event.payload),
+  );
+}
+
+// As with the other New* payloads, the actor and lab come from the kiosk
+// session and any values sent are ignored.
+export type NewBorrowReceipt = {
+  id: string;
+  borrowerId: string;
+  checkerId?: string;
+  labId?: string;
+  devices: Array<{
+    id: string;
+    expectedReturnedAt: string;
+    expectedReturnedLabId?: string;
+  }>;
+};
+
+export type BorrowReceipt = {
+  id: string;
+  borrowerId: string;
+  checkerId: string;
+  labId: string;
+  activityId: string;
+  createdAt: string;
+  devices: Array<{
+    deviceId: string;
+    expectedReturnedAt: string;
+    expectedReturnedLabId: string;
+    prevQuality: string;
+  }>;
+};
+
+// Rejections carry one entry per offending device in `fields`, keyed
+// `devices[i]` by position in the request.
+export async function createBorrowReceipt(
+  receipt: NewBorrowReceipt,
+): Promise<BorrowReceipt> {
+  return await invoke<BorrowReceipt>("create_borrow_receipt", { receipt });
+}
+
+export type NewReturnReceipt = {
+  id: string;
+  returnerId: string;
+  checkerId?: string;
+  labId?: string;
+  devices: Array<{
+    id: string;
+    afterQuality: "healthy" | "broken";
+    note?: string;
+  }>;
+  note?: string;
+};
+
+export type ReturnReceipt = {
+  id: string;
+  returnerId: string;
+  checkerId: string;
+  labId: string;
+  activityId: string;
+  createdAt: string;
+  devices: Array<{
+    deviceId: string;
+    borrowedReceiptId: string;
+    expectedReturnedAt: string;
+    late: boolean;
+    prevQuality: string | null;
+    afterQuality: string;
+    note: string | null;
+  }>;
+  // Borrow receipts that still have devices out after this return.
+  stillOpen: Array<{ borrowedReceiptId: string; remaining: number }>;
+};
+
+export async function createReturnReceipt(
+  receipt: NewReturnReceipt,
+): Promise<ReturnReceipt> {
+  return await invoke<ReturnReceipt>("create_return_receipt", { receipt });
+}
+
+export type StatusChange = {
+  from: string;
+  to: string;
+  activity: "borrow" | "return" | "maintenance" | "shipment" | "assessment";
+  activityId: string | null;
+  actorId: string | null;
+  reason: string | null;
+  changedAt: string;
+};
+
+// Newest first.
+export async function getDeviceStatusHistory(
+  deviceId: string,
+  limit?: number,
+): Promise<StatusChange[]> {
+  return await invoke<StatusChange[]>("device_status_history", {
+    deviceId,
+    limit,
+  });
+}
+
+export type DeviceAccess = {
+  id: string;
+  fullId: string | null;
+  status: string;
+  image: any;
+  unit: string | null;
+  deviceName: string | null;
+  allowedBorrowRoles: string[] | null;
+  allowedViewRoles: string[] | null;
+  brand: string | null;
+  manufacturer: string | null;
+  description: string | null;
+  isBorrowableLabOnly: boolean;
+  categoryName: string | null;
+  labId: string | null;
+  labRoom: string | null;
+  labBranch: string | null;
+  kind: string | null;
+};
+
+// Rejected with the roles that may view the kind when the signed-in user
+// may not.
+export async function getDeviceDetail(
+  deviceId: string,
+  labId?: string,
+): Promise<DeviceAccess> {
+  return await invoke<DeviceAccess>("device_detail", { deviceId, labId });
+}
+
+// `null` when no device matches.
+export async function findDevice(params: {
+  deviceId?: string;
+  kindId?: string;
+  labId?: string;
+}): Promise<DeviceAccess | null> {
+  return await invoke<DeviceAccess | null>("find_device", params);
+}
+
+export type DeviceReceiptAccess = {
+  fullId: string | null;
+  status: string;
+  prevQuality: string | null;
+  image: any;
+  unit: string | null;
+  deviceName: string | null;
+  allowedBorrowRoles: string[] | null;
+  allowedViewRoles: string[] | null;
+  brand: string | null;
+  manufacturer: string | null;
+  description: string | null;
+  isBorrowableLabOnly: boolean;
+  categoryName: string | null;
+  labRoom: string | null;
+  labBranch: string | null;
+  kind: string | null;
+  receiptId: string | null;
+  borrower: { id: string; name: string | null; image: string | null } | null;
+  borrowedAt: string | null;
+  expectedReturnedAt: string | null;
+  borrowedLab: string | null;
+  expectedReturnLab: string | null;
+};
+
+// Only devices of the session's lab.
+export async function getDeviceReceiptDetail(
+  deviceId: string,
+): Promise<DeviceReceiptAccess> {
+  return await invoke<DeviceReceiptAccess>("device_receipt_detail", {
+    deviceId,
+  });
+}
+
+export type DeviceAuditAccess = {
+  id: string;
+  fullId: string | null;
+  status: string;
+  currentStatus: string;
+  auditCondition: string | null;
+  image: any;
+  unit: string | null;
+  deviceName: string | null;
+  isBorrowableLabOnly: boolean;
+  labRoom: string | null;
+  labBranch: string | null;
+  kind: string | null;
+  categoryName: string | null;
+};
+
+// Only devices of the session's lab.
+export async function getDeviceAuditDetail(
+  deviceId: string,
+): Promise<DeviceAuditAccess> {
+  return await invoke<DeviceAuditAccess>("device_audit_detail", { deviceId });
+}
+
+export type DeviceMaintenanceAccess = {
+  id: string;
+  maintenanceId: string | null;
+  technician: { id: string | null; name: string | null };
+  status: string;
+  currentStatus: string;
+  outcome: string | null;
+  kind: string | null;
+  deviceName: string | null;
+  image: any;
+  unit: string | null;
+  isBorrowableLabOnly: boolean;
+  labRoom: string | null;
+  labBranch: string | null;
+  notes: string | null;
+  createdAt: string | null;
+};
+
+export async function getDeviceMaintenanceDetail(
+  deviceId: string,
+  labId?: string,
+): Promise<DeviceMaintenanceAccess> {
+  return await invoke<DeviceMaintenanceAccess>("device_maintenance_detail", {
+    deviceId,
+    labId,
+  });
+}
+
+export type DeviceShipmentAccess = {
+  status: string;
+  prevCondition: string | null;
+  afterCondition: string | null;
+  shipmentId: string | null;
+  sourceLocation: string | null;
+  destinationLocation: string | null;
+  senderName: string | null;
+  receiverName: string | null;
+  image: any;
+  unit: string | null;
+  deviceName: string | null;
+  isBorrowableLabOnly: boolean;
+};
+
+export async function getDeviceShipmentDetail(
+  deviceId: string,
+  labId?: string,
+): Promise<DeviceShipmentAccess> {
+  return await invoke<DeviceShipmentAccess>("device_shipment_detail", {
+    deviceId,
+    labId,
+  });
+}
+
+export type DeviceAccessory = {
+  id: string;
+  fullId: string | null;
+  status: string;
+  image: any;
+  name: string | null;
+  brand: string | null;
+  unit: string | null;
+};
+
+// Accessories of kinds the signed-in user may not view are left out.
+export async function getDeviceAccessories(
+  kindId: string,
+  labIds?: string[],
+): Promise<DeviceAccessory[]> {
+  return await invoke<DeviceAccessory[]>("device_accessories", {
+    kindId,
+    labIds,
+  });
+}
+
+export type AuditedDevice = {
+  id: string;
+  condition: "healthy" | "broken" | "lost" | "discarded";
+};
+
+export type NewAudit = {
+  auditorId?: string;
+  labId?: string;
+  devices: AuditedDevice[];
+  note?: string;
+};
+
+export type Audit = {
+  id: string;
+  labId: string;
+  auditorId: string | null;
+  status: "assessing" | "completed" | "cancelled";
+  createdAt: string;
+  finishedAt: string | null;
+  devices: Array<{
+    deviceId: string;
+    prevStatus: string | null;
+    afterStatus: string | null;
+  }>;
+};
+
+export type AuditReport = {
+  audit: Audit;
+  missing: string[];
+  extra: string[];
+  wrongLab: Array<{ deviceId: string; labId: string | null }>;
+  markedLost: string[];
+};
+
+export async function createAudit(audit: NewAudit): Promise<Audit> {
+  return await invoke<Audit>("create_audit", { audit });
+}
+
+export async function addAuditDevices(
+  auditId: string,
+  devices: AuditedDevice[],
+): Promise<Audit> {
+  return await invoke<Audit>("add_audit_devices", { auditId, devices });
+}
+
+export async function updateAuditConditions(
+  auditId: string,
+  devices: AuditedDevice[],
+): Promise<Audit> {
+  return await invoke<Audit>("update_audit_conditions", { auditId, devices });
+}
+
+export async function removeAuditDevice(
+  auditId: string,
+  deviceId: string,
+): Promise<Audit> {
+  return await invoke<Audit>("remove_audit_device", { auditId, deviceId });
+}
+
+// markMissingLost defaults to HMI_AUDIT_MARK_MISSING_LOST.
+export async function completeAudit(
+  auditId: string,
+  note?: string,
+  markMissingLost?: boolean,
+): Promise<AuditReport> {
+  return await invoke<AuditReport>("complete_audit", {
+    auditId,
+    note,
+    markMissingLost,
+  });
+}
+
+export async function cancelAudit(auditId: string): Promise<Audit> {
+  return await invoke<Audit>("cancel_audit", { auditId });
+}
+
+export type MaintainedDevice = {
+  id: string;
+  outcome: "healthy" | "broken" | "discarded";
+};
+
+export type NewMaintenance = {
+  technicianId?: string;
+  labId?: string;
+  devices: MaintainedDevice[];
+  note?: string;
+};
+
+export type Maintenance = {
+  id: string;
+  labId: string;
+  technicianId: string | null;
+  status: "maintaining" | "completed" | "cancelled";
+  note: string | null;
+  createdAt: string;
+  finishedAt: string | null;
+  durationSecs: number | null;
+  devices: Array<{
+    deviceId: string;
+    prevStatus: string | null;
+    afterStatus: string | null;
+  }>;
+};
+
+export async function createMaintenance(
+  maintenance: NewMaintenance,
+): Promise<Maintenance> {
+  return await invoke<Maintenance>("create_maintenance", { maintenance });
+}
+
+export async function addMaintenanceDevices(
+  maintenanceId: string,
+  devices: MaintainedDevice[],
+): Promise<Maintenance> {
+  return await invoke<Maintenance>("add_maintenance_devices", {
+    maintenanceId,
+    devices,
+  });
+}
+
+export async function updateMaintenanceOutcomes(
+  maintenanceId: string,
+  devices: MaintainedDevice[],
+): Promise<Maintenance> {
+  return await invoke<Maintenance>("update_maintenance_outcomes", {
+    maintenanceId,
+    devices,
+  });
+}
+
+export async function removeMaintenanceDevice(
+  maintenanceId: string,
+  deviceId: string,
+): Promise<Maintenance> {
+  return await invoke<Maintenance>("remove_maintenance_device", {
+    maintenanceId,
+    deviceId,
+  });
+}
+
+export async function assignMaintenanceTechnician(
+  maintenanceId: string,
+  technicianId: string,
+): Promise<Maintenance> {
+  return await invoke<Maintenance>("assign_maintenance_technician", {
+    maintenanceId,
+    technicianId,
+  });
+}
+
+// Devices left broken or discarded need a note.
+export async function completeMaintenance(
+  maintenanceId: string,
+  devices?: Array<MaintainedDevice & { note?: string }>,
+  note?: string,
+): Promise<Maintenance> {
+  return await invoke<Maintenance>("complete_maintenance", {
+    maintenanceId,
+    devices,
+    note,
+  });
+}
+
+export async function cancelMaintenance(
+  maintenanceId: string,
+): Promise<Maintenance> {
+  return await invoke<Maintenance>("cancel_maintenance", { maintenanceId });
+}
+
+export type NewShipment = {
+  id?: string;
+  senderId?: string;
+  startLabId?: string;
+  arriveLabId: string;
+  checkAtDestination?: boolean;
+  devices: string[];
+};
+
+export type Shipment = {
+  id: string;
+  senderId: string | null;
+  receiverId: string | null;
+  status: ShipmentStatus;
+  startLabId: string;
+  arriveLabId: string;
+  checkAtDestination: boolean;
+  dispatchedAt: string | null;
+  receivedAt: string | null;
+  devices: Array<{
+    deviceId: string;
+    prevStatus: string | null;
+    afterStatus: string | null;
+  }>;
+};
+
+export type ArrivedDevice = {
+  id: string;
+  condition: "healthy" | "broken";
+};
+
+export type ShipmentReport = {
+  shipment: Shipment;
+  received: string[];
+  missing: string[];
+};
+
+export type ShipmentManifest = {
+  shipment: Shipment;
+  devices: Array<{
+    deviceId: string;
+    kindId: string | null;
+    kindName: string | null;
+    status: string;
+    labId: string | null;
+    prevStatus: string | null;
+    afterStatus: string | null;
+    received: boolean;
+  }>;
+};
+
+export async function createShipment(shipment: NewShipment): Promise<Shipment> {
+  return await invoke<Shipment>("create_shipment", { shipment });
+}
+
+export async function addShipmentDevices(
+  shipmentId: string,
+  devices: string[],
+): Promise<Shipment> {
+  return await invoke<Shipment>("add_shipment_devices", {
+    shipmentId,
+    devices,
+  });
+}
+
+export async function removeShipmentDevice(
+  shipmentId: string,
+  deviceId: string,
+): Promise<Shipment> {
+  return await invoke<Shipment>("remove_shipment_device", {
+    shipmentId,
+    deviceId,
+  });
+}
+
+export async function dispatchShipment(
+  shipmentId: string,
+  note?: string,
+): Promise<Shipment> {
+  return await invoke<Shipment>("dispatch_shipment", { shipmentId, note });
+}
+
+export async function confirmShipmentArrivals(
+  shipmentId: string,
+  devices: ArrivedDevice[],
+): Promise<Shipment> {
+  return await invoke<Shipment>("confirm_shipment_arrivals", {
+    shipmentId,
+    devices,
+  });
+}
+
+export async function closeShipment(
+  shipmentId: string,
+  note?: string,
+): Promise<ShipmentReport> {
+  return await invoke<ShipmentReport>("close_shipment", { shipmentId, note });
+}
+
+export async function cancelShipment(shipmentId: string): Promise<Shipment> {
+  return await invoke<Shipment>("cancel_shipment", { shipmentId });
+}
+
+export async function getShipmentManifest(
+  shipmentId: string,
+): Promise<ShipmentManifest> {
+  return await invoke<ShipmentManifest>("shipment_manifest", { shipmentId });
+}
+
+export type IssuedHmiCode = {
+  code: string;
+  expiresIn: number;
+  expiresAt: string;
+};
+
+export type HmiCodeStatus = {
+  code: string;
+  status: "pending" | "authenticated" | "expired" | "used";
+  expiresAt: string | null;
+  token: string | null;
+  user: {
+    id: string;
+    name: string;
+    email: string | null;
+    tel: string | null;
+    image: string | null;
+    lastActiveAt: string | null;
+    roles: { key: string; name: string | null }[];
+  } | null;
+  lab: {
+    id: string;
+    name: string | null;
+    room: string | null;
+    branch: string | null;
+  } | null;
+};
+
+export async function issueHmiCode(): Promise<IssuedHmiCode> {
+  return await invoke<IssuedHmiCode>("issue_hmi_code");
+}
+
+export async function getHmiCodeStatus(code: string): Promise<HmiCodeStatus> {
+  return await invoke<HmiCodeStatus>("hmi_code_status", { code });
+}
+
+export async function verifyOneTimeQr(
+  userId: string,
+  token: string,
+): Promise<NonNullable<HmiCodeStatus["user"]>> {
+  return await invoke<NonNullable<HmiCodeStatus["user"]>>(
+    "verify_one_time_qr",
+    { userId, token },
+  );
+}
+
+export type KioskSession = {
+  user: NonNullable<HmiCodeStatus["user"]>;
+  lab: NonNullable<HmiCodeStatus["lab"]>;
+  startedAt: string;
+  lastActiveAt: string;
+  expiresAt: string | null;
+};
+
+export async function loginWithHmiCode(code: string): Promise<KioskSession> {
+  return await invoke<KioskSession>("login_with_hmi_code", { code });
+}
+
+export async function getCurrentSession(): Promise<KioskSession | null> {
+  return await invoke<KioskSession | null>("current_session");
+}
+
+export async function touchSession(): Promise<KioskSession | null> {
+  return await invoke<KioskSession | null>("touch_session");
+}
+
+export async function logoutSession(): Promise<boolean> {
+  return await invoke<boolean>("logout");
+}
+
+// `null` after a logout or an idle timeout.
+export function onSessionChanged(
+  handler: (session: KioskSession | null) => void,
+): Promise<UnlistenFn> {
+  return listen<KioskSession | null>("session://changed", (event) =>
+    handler(event.payload),
+  );
+}
+
+// Development builds only: rewrites src/types/db from the cached schema.
+export async function regenerateSchemaFiles(): Promise<string[]> {
+  return await invoke<string[]>("regenerate_schema_files");
+}
+
+export class TableQueryBuilder<
+  T,
+  C extends string = string,
+  I = Partial<T>,
+> {
   private columns?: string[];
   private conditions: Array<[string, unknown]> = [];
   private orderByColumns: Array<[string, boolean]> = [];
   private limitValue?: number;
   private offsetValue?: number;
+  private timeoutMs?: number;
+  private requestId?: string;
+  private readPrimary?: boolean;
   private includeRelations: Array<{
     table: string;
     as?: string;
@@ -116,7 +1108,7 @@ export class TableQueryBuilder<T> {
     private readonly tableName: string
   ) {}
 
-  select(columns: string[]): this {
+  select(columns: C[]): this {
     this.columns = columns;
     return this;
   }
@@ -126,7 +1118,7 @@ export class TableQueryBuilder<T> {
     as?: string;
     select?: string[];
     on: {
-      from: string;
+      from: C;
       to: string;
     };
   }): this {
@@ -134,19 +1126,19 @@ export class TableQueryBuilder<T> {
     return this;
   }
 
-  where(column: string, value: unknown): this {
+  where(column: C, value: unknown): this {
     this.conditions.push([column, value]);
     return this;
   }
 
-  whereMany(conditions: Record<string, unknown>): this {
+  whereMany(conditions: Partial<Record<C, unknown>>): this {
     Object.entries(conditions).forEach(([column, value]) => {
       this.conditions.push([column, value]);
     });
     return this;
   }
 
-  orderBy(column: string, ascending = true): this {
+  orderBy(column: C, ascending = true): this {
     this.orderByColumns.push([column, ascending]);
     return this;
   }
@@ -161,7 +1153,27 @@ export class TableQueryBuilder<T> {
     return this;
   }
 
+  timeout(ms: number): this {
+    this.timeoutMs = ms;
+    return this;
+  }
+
+  cancellable(requestId: string): this {
+    this.requestId = requestId;
+    return this;
+  }
+
+  // Read-your-writes: bypass the replica for this query.
+  fromPrimary(): this {
+    this.readPrimary = true;
+    return this;
+  }
+
   async execute(): Promise<T[]> {
+    return this.client.query<T>(this.buildParams());
+  }
+
+  private buildParams(): QueryParams {
     const joins = this.includeRelations.map((relation) => ({
       table: relation.table,
       left_column: relation.on.from,
@@ -178,7 +1190,7 @@ export class TableQueryBuilder<T> {
       ),
     ];
 
-    return this.client.query<T>({
+    return {
       table: this.tableName,
       columns: allColumns.length > 0 ? allColumns : undefined,
       conditions: this.conditions,
@@ -187,7 +1199,18 @@ export class TableQueryBuilder<T> {
       limit: this.limitValue,
       offset: this.offsetValue,
       joins: joins.length > 0 ? joins : undefined,
-    });
+      timeout_ms: this.timeoutMs,
+      request_id: this.requestId,
+      read_primary: this.readPrimary,
+    };
+  }
+
+  // Delivers the rows in chunks instead of one array; for large exports.
+  async stream(
+    onRows: RowsHandler<T>,
+    options?: StreamOptions
+  ): Promise<StreamSummary> {
+    return this.client.stream<T>(this.buildParams(), onRows, options);
   }
 
   async first(): Promise<T | null> {
@@ -196,8 +1219,8 @@ export class TableQueryBuilder<T> {
     return results[0] || null;
   }
 
-  async insert(value: Partial<T>): Promise<T> {
-    return this.client.insert<T>({
+  async insert(value: I): Promise<T> {
+    return this.client.insert<T, I>({
       table: this.tableName,
       value,
     });
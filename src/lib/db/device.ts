@@ -1,5 +1,12 @@
 import { DeviceStatus } from "@/types/db/generated";
-import { db } from "./client";
+import {
+  db,
+  getDeviceAuditDetail,
+  getDeviceDetail,
+  getDeviceMaintenanceDetail,
+  getDeviceReceiptDetail,
+  getDeviceShipmentDetail,
+} from "./client";
 
 type DeviceReceiptDetail = {
   fullId: string;
@@ -189,6 +196,12 @@ export type DeviceDetail = {
   kind: string;
 };
 
+// Backend errors carry their reason, such as why access was denied, for
+// the page to show.
+function backendError(error: any): Error {
+  return new Error(error?.message || "Device not found");
+}
+
 export const deviceService = {
   async getDeviceReceiptById(
     id: string,
@@ -198,101 +211,43 @@ export const deviceService = {
       throw new Error("Missing device ID or lab ID");
     }
 
+    // The backend only finds devices of the session's lab.
+    let device;
     try {
-      const sql = `
-        SELECT 
-          d.id,
-          d.full_id,
-          d.status,
-          d.kind,
-          d.lab_id,
-          dk.image,
-          dk.unit,
-          dk.name AS device_name,
-          dk.allowed_borrow_roles,
-          dk.allowed_view_roles,
-          dk.brand,
-          dk.manufacturer,
-          dk.description,
-          dk.is_borrowable_lab_only,
-          c.name AS category_name,
-          l.room,
-          l.branch,
-          r.id AS receipt_id,
-          a.created_at AS borrowed_at,
-          rd.expected_returned_at,
-          rd.prev_quality,
-          bl.room || ', ' || bl.branch AS borrowed_lab,
-          rl.room || ', ' || rl.branch AS expected_return_lab,
-          actor.id AS borrower_id,
-          actor.name AS borrower_name,
-          actor.image AS borrower_image
-        FROM 
-          devices d
-          LEFT JOIN device_kinds dk ON d.kind = dk.id
-          LEFT JOIN labs l ON d.lab_id = l.id
-          LEFT JOIN categories c ON dk.category_id = c.id
-          LEFT JOIN receipts_devices rd ON d.id = rd.device_id AND rd.returned_receipt_id IS NULL
-          LEFT JOIN receipts r ON rd.borrowed_receipt_id = r.id
-          LEFT JOIN users actor ON r.actor_id = actor.id
-          LEFT JOIN labs bl ON r.lab_id = bl.id
-          LEFT JOIN labs rl ON rd.expected_returned_lab_id = rl.id
-          LEFT JOIN activities a ON rd.borrow_id = a.id
-        WHERE 
-          d.id = $1
-          AND d.deleted_at IS NULL
-      `;
-
-      const results = await db.queryRaw<Record<string, unknown>>({
-        sql,
-        params: [id],
-      });
-
-      if (results.length === 0) {
-        throw new Error("Device not found");
-      }
-
-      const row = results[0];
-
-      if (row.labId !== labId) {
-        throw new Error("Device does not belong to this lab");
-      }
-
-      const deviceReceiptDetail: DeviceReceiptDetail = {
-        fullId: row.fullId as string,
-        status: row.status as DeviceStatus | null,
-        prevQuality: row.prevQuality as DeviceStatus | null,
-        image: row.image,
-        unit: row.unit as string,
-        deviceName: row.deviceName as string,
-        allowedBorrowRoles: row.allowedBorrowRoles as string[],
-        allowedViewRoles: row.allowedViewRoles as string[],
-        brand: row.brand as string | null,
-        manufacturer: row.manufacturer as string | null,
-        description: row.description as string | null,
-        isBorrowableLabOnly: row.isBorrowableLabOnly as boolean,
-        categoryName: row.categoryName as string,
-        labRoom: row.room as string | null,
-        labBranch: row.branch as string | null,
-        kind: row.kind as string,
-        receiptId: row.receiptId as string | null,
-        borrower: row.borrowerId
-          ? {
-              id: row.borrowerId as string,
-              name: row.borrowerName as string,
-              image: row.borrowerImage as string | null,
-            }
-          : null,
-        borrowedAt: row.borrowedAt ? new Date(row.borrowedAt as string) : null,
-        expectedReturnedAt: row.expectedReturnedAt as string | null,
-        borrowedLab: row.borrowedLab as string | null,
-        expectedReturnLab: row.expectedReturnLab as string | null,
-      };
-
-      return deviceReceiptDetail;
-    } catch (error) {
-      throw error;
+      device = await getDeviceReceiptDetail(id);
+    } catch (error: any) {
+      throw backendError(error);
     }
+    return {
+      fullId: device.fullId as string,
+      status: device.status as DeviceStatus,
+      prevQuality: device.prevQuality as DeviceStatus | null,
+      image: device.image,
+      unit: device.unit as string,
+      deviceName: device.deviceName as string,
+      allowedBorrowRoles: device.allowedBorrowRoles ?? [],
+      allowedViewRoles: device.allowedViewRoles ?? [],
+      brand: device.brand,
+      manufacturer: device.manufacturer,
+      description: device.description,
+      isBorrowableLabOnly: device.isBorrowableLabOnly,
+      categoryName: device.categoryName as string,
+      labRoom: device.labRoom,
+      labBranch: device.labBranch,
+      kind: device.kind as string,
+      receiptId: device.receiptId,
+      borrower: device.borrower
+        ? {
+            id: device.borrower.id,
+            name: device.borrower.name as string,
+            image: device.borrower.image,
+          }
+        : null,
+      borrowedAt: device.borrowedAt ? new Date(device.borrowedAt) : null,
+      expectedReturnedAt: device.expectedReturnedAt,
+      borrowedLab: device.borrowedLab,
+      expectedReturnLab: device.expectedReturnLab,
+    };
   },
 
   async getDeviceAuditById(
@@ -303,84 +258,30 @@ export const deviceService = {
       throw new Error("Missing device ID or lab ID");
     }
 
+    // The backend only finds devices of the session's lab.
+    let device;
     try {
-      const sql = `
-        WITH active_assessment AS (
-          SELECT 
-            ia.id
-          FROM inventory_assessments ia
-          WHERE ia.status = 'assessing'
-            AND ia.finished_at IS NULL
-            AND ia.lab_id = $2
-          LIMIT 1
-        )
-        SELECT 
-          d.id,
-          d.full_id,
-          CASE 
-            WHEN d.status = 'assessing' THEN COALESCE(iad.prev_status, d.status)
-            ELSE d.status
-          END as status,
-          iad.after_status as audit_condition,
-          d.status as current_status,
-          d.kind,
-          d.lab_id,
-          dk.image,
-          dk.unit,
-          dk.name AS device_name,
-          dk.is_borrowable_lab_only,
-          l.room,
-          l.branch,
-          c.name AS category_name
-        FROM 
-          devices d
-          LEFT JOIN device_kinds dk ON d.kind = dk.id
-          LEFT JOIN labs l ON d.lab_id = l.id
-          LEFT JOIN categories c ON dk.category_id = c.id
-          LEFT JOIN active_assessment aa ON true
-          LEFT JOIN inventory_assessments_devices iad ON iad.device_id = d.id AND iad.assessing_id = aa.id
-        WHERE 
-          d.id = $1
-          AND d.deleted_at IS NULL
-      `;
-
-      const results = await db.queryRaw<Record<string, unknown>>({
-        sql,
-        params: [id, labId],
-      });
-
-      if (results.length === 0) {
-        throw new Error("Device not found");
-      }
-
-      const row = results[0];
-
-      if (row.labId !== labId) {
-        throw new Error("Device does not belong to this lab");
-      }
-
-      const deviceAuditDetail: DeviceAuditDetail = {
-        id: row.id as string,
-        fullId: row.fullId as string,
-        status: row.status as DeviceStatus,
-        currentStatus: row.currentStatus as DeviceStatus,
-        auditCondition: row.auditCondition as DeviceStatus,
-        image: {
-          mainImage: row.image ? (row.image as any).mainImage : "",
-        },
-        unit: row.unit as string,
-        deviceName: row.deviceName as string,
-        isBorrowableLabOnly: row.isBorrowableLabOnly as boolean,
-        labRoom: row.room as string,
-        labBranch: row.branch as string,
-        kind: row.kind as string,
-        categoryName: row.categoryName as string,
-      };
-
-      return deviceAuditDetail;
-    } catch (error) {
-      throw error;
+      device = await getDeviceAuditDetail(id);
+    } catch (error: any) {
+      throw backendError(error);
     }
+    return {
+      id: device.id,
+      fullId: device.fullId as string,
+      status: device.status as DeviceStatus,
+      currentStatus: device.currentStatus as DeviceStatus,
+      auditCondition: device.auditCondition as DeviceStatus,
+      image: {
+        mainImage: device.image ? device.image.mainImage : "",
+      },
+      unit: device.unit as string,
+      deviceName: device.deviceName as string,
+      isBorrowableLabOnly: device.isBorrowableLabOnly,
+      labRoom: device.labRoom as string,
+      labBranch: device.labBranch as string,
+      kind: device.kind as string,
+      categoryName: device.categoryName as string,
+    };
   },
 
   async getDeviceMaintenanceById(
@@ -391,84 +292,34 @@ export const deviceService = {
       throw new Error("Missing device ID");
     }
 
+    let device;
     try {
-      const sql = `
-        SELECT 
-          d.id,
-          CASE 
-            WHEN d.status = 'maintaining' THEN COALESCE(md.prev_status, d.status)
-            ELSE d.status
-          END as status,
-          d.status as current_status,
-          md.after_status as outcome,
-          d.kind,
-          d.lab_id,
-          dk.image,
-          dk.unit,
-          dk.name AS device_name,
-          dk.is_borrowable_lab_only,
-          l.room,
-          l.branch,
-          m.id as maintenance_id,
-          m.maintainer_id as technician_id,
-          u.name as technician_name,
-          a.note as notes,
-          a.created_at
-        FROM 
-          devices d
-          LEFT JOIN device_kinds dk ON d.kind = dk.id
-          LEFT JOIN labs l ON d.lab_id = l.id
-          LEFT JOIN maintenances_devices md ON d.id = md.device_id
-          LEFT JOIN maintenances m ON md.maintaining_id = m.id
-          LEFT JOIN users u ON m.maintainer_id = u.id
-          LEFT JOIN activities a ON m.id = a.id
-        WHERE 
-          d.id = $1
-          AND d.deleted_at IS NULL
-        ORDER BY 
-          a.created_at DESC
-        LIMIT 1
-      `;
-
-      const results = await db.queryRaw<Record<string, unknown>>({
-        sql,
-        params: [deviceId],
-      });
-
-      if (results.length === 0) {
-        throw new Error("Device not found");
-      }
-
-      const row = results[0];
-
-      if (labId && row.labId !== labId) {
-        throw new Error("Device does not belong to this lab");
-      }
-
-      return {
-        id: row.id as string,
-        maintenanceId: row.maintenanceId as string,
-        technician: {
-          id: row.technicianId as string,
-          name: row.technicianName as string,
-        },
-        status: row.status as DeviceStatus,
-        currentStatus: row.currentStatus as DeviceStatus,
-        outcome: row.outcome as DeviceStatus,
-        kind: row.kind as string,
-        deviceName: row.deviceName as string,
-        image: row.image as any,
-        unit: row.unit as string,
-        isBorrowableLabOnly: row.isBorrowableLabOnly as boolean,
-        location: row.room && row.branch ? `${row.room}, ${row.branch}` : "",
-        notes: (row.notes as string) || undefined,
-        createdAt: row.createdAt
-          ? new Date(row.createdAt as string)
-          : new Date(),
-      };
-    } catch (error) {
-      throw error;
+      device = await getDeviceMaintenanceDetail(deviceId, labId);
+    } catch (error: any) {
+      throw backendError(error);
     }
+    return {
+      id: device.id,
+      maintenanceId: device.maintenanceId as string,
+      technician: {
+        id: device.technician.id as string,
+        name: device.technician.name as string,
+      },
+      status: device.status as DeviceStatus,
+      currentStatus: device.currentStatus as DeviceStatus,
+      outcome: device.outcome as DeviceStatus,
+      kind: device.kind as string,
+      deviceName: device.deviceName as string,
+      image: device.image,
+      unit: device.unit as string,
+      isBorrowableLabOnly: device.isBorrowableLabOnly,
+      location:
+        device.labRoom && device.labBranch
+          ? `${device.labRoom}, ${device.labBranch}`
+          : "",
+      notes: device.notes || undefined,
+      createdAt: device.createdAt ? new Date(device.createdAt) : new Date(),
+    };
   },
 
   async getDeviceShipmentById(
@@ -479,87 +330,26 @@ export const deviceService = {
       throw new Error("Missing device ID");
     }
 
+    let device;
     try {
-      const sql = `
-        SELECT 
-          d.id,
-          d.full_id,
-          d.status,
-          d.kind,
-          d.lab_id,
-          dk.image,
-          dk.unit,
-          dk.name AS device_name,
-          dk.allowed_borrow_roles,
-          dk.allowed_view_roles,
-          dk.brand,
-          dk.manufacturer,
-          dk.description,
-          dk.is_borrowable_lab_only,
-          c.name AS category_name,
-          l.room,
-          l.branch,
-          sd.prev_status as prev_condition,
-          sd.after_status as after_condition,
-          s.id as shipment_id,
-          s.status as shipment_status,
-          s_start.room || ', ' || s_start.branch AS source_location,
-          s_arrive.room || ', ' || s_arrive.branch AS destination_location,
-          sender.name AS sender_name,
-          receiver.name AS receiver_name
-        FROM 
-          devices d
-          LEFT JOIN device_kinds dk ON d.kind = dk.id
-          LEFT JOIN labs l ON d.lab_id = l.id
-          LEFT JOIN categories c ON dk.category_id = c.id
-          LEFT JOIN shipments_devices sd ON d.id = sd.device_id
-          LEFT JOIN shipments s ON sd.shipment_id = s.id
-          LEFT JOIN labs s_start ON s.start_lab_id = s_start.id
-          LEFT JOIN labs s_arrive ON s.arrive_lab_id = s_arrive.id
-          LEFT JOIN users sender ON s.sender_id = sender.id
-          LEFT JOIN users receiver ON s.receiver_id = receiver.id
-        WHERE 
-          d.id = $1
-          AND d.deleted_at IS NULL
-        ORDER BY
-          s.from_at DESC
-        LIMIT 1
-      `;
-
-      const results = await db.queryRaw<Record<string, unknown>>({
-        sql,
-        params: [deviceId],
-      });
-
-      if (results.length === 0) {
-        throw new Error("Device not found");
-      }
-
-      const row = results[0];
-
-      if (labId && row.labId !== labId) {
-        throw new Error("Device does not belong to this lab");
-      }
-
-      const deviceShipmentDetail: DeviceShipmentDetail = {
-        status: row.status as DeviceStatus | null,
-        prevCondition: row.prevCondition as DeviceStatus | null,
-        afterCondition: row.afterCondition as DeviceStatus | null,
-        shipmentId: row.shipmentId as string | null,
-        sourceLocation: row.sourceLocation as string | null,
-        destinationLocation: row.destinationLocation as string | null,
-        senderName: row.senderName as string | null,
-        receiverName: row.receiverName as string | null,
-        image: row.image as any,
-        unit: row.unit as string,
-        deviceName: row.deviceName as string,
-        isBorrowableLabOnly: row.isBorrowableLabOnly as boolean,
-      };
-
-      return deviceShipmentDetail;
-    } catch (error) {
-      throw error;
+      device = await getDeviceShipmentDetail(deviceId, labId);
+    } catch (error: any) {
+      throw backendError(error);
     }
+    return {
+      status: device.status as DeviceStatus,
+      prevCondition: device.prevCondition as DeviceStatus | null,
+      afterCondition: device.afterCondition as DeviceStatus | null,
+      shipmentId: device.shipmentId,
+      sourceLocation: device.sourceLocation,
+      destinationLocation: device.destinationLocation,
+      senderName: device.senderName,
+      receiverName: device.receiverName,
+      image: device.image,
+      unit: device.unit as string,
+      deviceName: device.deviceName as string,
+      isBorrowableLabOnly: device.isBorrowableLabOnly,
+    };
   },
 
   async getDeviceById(id: string, labId?: string): Promise<DeviceDetail> {
@@ -567,77 +357,33 @@ export const deviceService = {
       throw new Error("Missing device ID");
     }
 
+    let device;
     try {
-      const sql = `
-        SELECT 
-          d.id,
-          d.full_id,
-          d.status,
-          d.kind,
-          d.lab_id,
-          dk.image,
-          dk.unit,
-          dk.name AS device_name,
-          dk.allowed_borrow_roles,
-          dk.allowed_view_roles,
-          dk.brand,
-          dk.manufacturer,
-          dk.description,
-          dk.is_borrowable_lab_only,
-          c.name AS category_name,
-          l.room,
-          l.branch
-        FROM 
-          devices d
-          LEFT JOIN device_kinds dk ON d.kind = dk.id
-          LEFT JOIN labs l ON d.lab_id = l.id
-          LEFT JOIN categories c ON dk.category_id = c.id
-        WHERE 
-          d.id = $1
-          AND d.deleted_at IS NULL
-      `;
-
-      const results = await db.queryRaw<Record<string, unknown>>({
-        sql,
-        params: [id],
-      });
-
-      if (results.length === 0) {
-        throw new Error("Device not found");
-      }
-
-      const row = results[0];
-
-      if (labId && row.labId !== labId) {
-        throw new Error("Device does not belong to this lab");
-      }
-
-      const deviceDetail: DeviceDetail = {
-        id: row.id as string,
-        fullId: row.fullId as string,
-        status: row.status as DeviceStatus,
-        image: {
-          mainImage: row.image ? (row.image as any).mainImage : "",
-        },
-        unit: row.unit as string,
-        deviceName: row.deviceName as string,
-        allowedBorrowRoles: row.allowedBorrowRoles as string[],
-        allowedViewRoles: row.allowedViewRoles as string[],
-        brand: row.brand as string | null,
-        manufacturer: row.manufacturer as string | null,
-        description: row.description as string | null,
-        isBorrowableLabOnly: row.isBorrowableLabOnly as boolean,
-        categoryName: row.categoryName as string,
-        labId: row.labId as string | null,
-        labRoom: row.room as string | null,
-        labBranch: row.branch as string | null,
-        kind: row.kind as string,
-      };
-
-      return deviceDetail;
-    } catch (error) {
-      throw error;
+      device = await getDeviceDetail(id, labId);
+    } catch (error: any) {
+      throw backendError(error);
     }
+    return {
+      id: device.id,
+      fullId: device.fullId as string,
+      status: device.status as DeviceStatus,
+      image: {
+        mainImage: device.image ? device.image.mainImage : "",
+      },
+      unit: device.unit as string,
+      deviceName: device.deviceName as string,
+      allowedBorrowRoles: device.allowedBorrowRoles ?? [],
+      allowedViewRoles: device.allowedViewRoles ?? [],
+      brand: device.brand,
+      manufacturer: device.manufacturer,
+      description: device.description,
+      isBorrowableLabOnly: device.isBorrowableLabOnly,
+      categoryName: device.categoryName as string,
+      labId: device.labId,
+      labRoom: device.labRoom,
+      labBranch: device.labBranch,
+      kind: device.kind as string,
+    };
   },
 
   async getDeviceInventoryInAudit(
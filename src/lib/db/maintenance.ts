@@ -1,114 +1,49 @@
 import { DeviceStatus, MaintenanceStatus } from "@/types/db/generated";
-import { db } from "./client";
+import {
+  addMaintenanceDevices,
+  assignMaintenanceTechnician,
+  cancelMaintenance,
+  completeMaintenance,
+  createMaintenance,
+  db,
+  MaintainedDevice,
+  removeMaintenanceDevice,
+  updateMaintenanceOutcomes,
+} from "./client";
 
 type CreateMaintenanceParams = {
   technicianId: string;
   location: string;
   notes?: string;
-  status?: MaintenanceStatus;
   devices: {
     id: string;
     maintenanceOutcome: DeviceStatus;
-    prevStatus?: DeviceStatus;
   }[];
 };
 
+// Outcomes the backend only accepts with a note.
+const NOTE_REQUIRED: DeviceStatus[] = [
+  DeviceStatus.BROKEN,
+  DeviceStatus.DISCARDED,
+];
+
+function maintained(id: string, outcome: DeviceStatus): MaintainedDevice {
+  return { id, outcome: outcome as MaintainedDevice["outcome"] };
+}
+
 export const maintenanceService = {
   async createMaintenance(
     params: CreateMaintenanceParams
   ): Promise<{ id: string; success: boolean }> {
-    try {
-      const status = params.status || MaintenanceStatus.MAINTAINING;
-      const finishedAt =
-        status === MaintenanceStatus.COMPLETED ? "CURRENT_TIMESTAMP" : "NULL";
-
-      const deviceRows = params.devices
-        .map((device) => {
-          const prev = device.prevStatus
-            ? `'${device.prevStatus}'::device_status`
-            : "NULL";
-          const after = `'${device.maintenanceOutcome}'::device_status`;
-          return `(${prev}, ${after}, '${device.id}')`;
-        })
-        .join(", ");
-      const updateCases = params.devices
-        .map(
-          (device) =>
-            `WHEN id = '${device.id}' THEN 'maintaining'::device_status`
-        )
-        .join(" ");
-      const updateIds = params.devices
-        .map((device) => `'${device.id}'`)
-        .join(", ");
-
-      const sql = `
-        WITH activity_insert AS (
-          INSERT INTO activities (type${params.notes ? ", note" : ""})
-          VALUES (
-            'maintenance'::activity_type${params.notes ? `, '${params.notes.replace(/'/g, "''")}'` : ""}
-          )
-          RETURNING id
-        ),
-        maintenance_insert AS (
-          INSERT INTO maintenances (
-            id,
-            lab_id,
-            maintainer_id,
-            status${status === MaintenanceStatus.COMPLETED ? ", finished_at" : ""}
-          )
-          SELECT
-            id,
-            '${params.location}'::uuid,
-            '${params.technicianId}',
-            '${status}'::maintenance_status${status === MaintenanceStatus.COMPLETED ? `, ${finishedAt}` : ""}
-          FROM activity_insert
-          RETURNING id
-        ),
-        device_rows(prev_status, after_status, device_id) AS (
-          VALUES
-            ${deviceRows}
-        ),
-        device_insert AS (
-          INSERT INTO maintenances_devices (
-            prev_status,
-            after_status,
-            maintaining_id,
-            device_id
-          )
-          SELECT
-            dr.prev_status,
-            dr.after_status,
-            mi.id,
-            dr.device_id
-          FROM device_rows dr, maintenance_insert mi
-          RETURNING maintaining_id
-        )${
-          updateCases
-            ? `,
-        device_update AS (
-          UPDATE devices
-          SET status = CASE
-            ${updateCases}
-            ELSE status
-          END
-          WHERE id IN (${updateIds})
-        )`
-            : ""
-        }
-        SELECT id FROM maintenance_insert;
-      `;
-
-      const result = await db.queryRaw<{ id: string }>({ sql });
-      const maintenanceId = result[0]?.id;
-
-      if (!maintenanceId) {
-        throw new Error("Failed to create maintenance record");
-      }
-
-      return { id: maintenanceId, success: true };
-    } catch (error) {
-      throw error;
-    }
+    const maintenance = await createMaintenance({
+      technicianId: params.technicianId,
+      labId: params.location,
+      devices: params.devices.map((d) =>
+        maintained(d.id, d.maintenanceOutcome)
+      ),
+      note: params.notes || undefined,
+    });
+    return { id: maintenance.id, success: true };
   },
 
   async getIncompleteMaintenance(labId: string) {
@@ -171,55 +106,9 @@ export const maintenanceService = {
   async addDeviceToMaintenance(
     maintenanceId: string,
     deviceId: string,
-    prevStatus: DeviceStatus,
-    afterStatus: DeviceStatus
+    outcome: DeviceStatus
   ): Promise<void> {
-    try {
-      const currentMaintenanceSql = `
-        SELECT status
-        FROM maintenances
-        WHERE id = '${maintenanceId}'
-      `;
-      const currentMaintenance = await db.queryRaw<{
-        status: MaintenanceStatus;
-      }>({
-        sql: currentMaintenanceSql,
-      });
-      if (currentMaintenance.length === 0) {
-        throw new Error(`Maintenance with ID ${maintenanceId} not found`);
-      }
-      if (currentMaintenance[0].status !== MaintenanceStatus.MAINTAINING) {
-        return;
-      }
-
-      const sql = `
-        WITH ins AS (
-          INSERT INTO maintenances_devices (
-            prev_status,
-            after_status,
-            maintaining_id,
-            device_id
-          )
-          VALUES (
-            '${prevStatus}'::device_status,
-            '${afterStatus}'::device_status,
-            '${maintenanceId}',
-            '${deviceId}'
-          )
-          RETURNING id
-        ),
-        upd AS (
-          UPDATE devices
-          SET status = 'maintaining'::device_status
-          WHERE id = '${deviceId}'
-          RETURNING id
-        )
-        SELECT * FROM ins;
-      `;
-      await db.queryRaw({ sql });
-    } catch (error) {
-      throw error;
-    }
+    await addMaintenanceDevices(maintenanceId, [maintained(deviceId, outcome)]);
   },
 
   async updateListDeviceConditions(
@@ -229,28 +118,11 @@ export const maintenanceService = {
       condition: DeviceStatus;
     }[]
   ): Promise<void> {
-    try {
-      if (!devices.length) return;
-
-      const values = devices
-        .map((d) => `('${d.id}', '${d.condition}'::device_status)`)
-        .join(", ");
-
-      const sql = `
-        WITH device_updates(device_id, new_condition) AS (
-          VALUES ${values}
-        )
-        UPDATE maintenances_devices
-        SET after_status = du.new_condition
-        FROM device_updates du
-        WHERE maintenances_devices.maintaining_id = '${maintenanceId}'
-          AND maintenances_devices.device_id = du.device_id
-      `;
-
-      await db.queryRaw({ sql });
-    } catch (error) {
-      throw error;
-    }
+    if (!devices.length) return;
+    await updateMaintenanceOutcomes(
+      maintenanceId,
+      devices.map((d) => maintained(d.id, d.condition))
+    );
   },
 
   async updateDeviceCondition(
@@ -258,130 +130,49 @@ export const maintenanceService = {
     deviceId: string,
     condition: DeviceStatus
   ): Promise<void> {
-    try {
-      const sql = `
-        WITH update_device AS (
-          UPDATE maintenances_devices
-          SET after_status = '${condition}'::device_status
-          WHERE maintaining_id = '${maintenanceId}'
-            AND device_id = '${deviceId}'
-          RETURNING device_id
-        )
-        SELECT device_id FROM update_device
-      `;
-      await db.queryRaw({ sql });
-    } catch (error) {
-      throw error;
-    }
+    await updateMaintenanceOutcomes(maintenanceId, [
+      maintained(deviceId, condition),
+    ]);
   },
 
   async removeDeviceFromMaintenance(
     maintenanceId: string,
     deviceId: string
   ): Promise<void> {
-    try {
-      const sql = `
-        WITH device_info AS (
-          SELECT prev_status 
-          FROM maintenances_devices
-          WHERE maintaining_id = '${maintenanceId}'
-            AND device_id = '${deviceId}'
-        ),
-        delete_device AS (
-          DELETE FROM maintenances_devices
-          WHERE maintaining_id = '${maintenanceId}'
-            AND device_id = '${deviceId}'
-          RETURNING device_id
-        ),
-        update_device AS (
-          UPDATE devices
-          SET status = COALESCE((SELECT prev_status FROM device_info), '${DeviceStatus.HEALTHY}'::device_status)
-          WHERE id = '${deviceId}'
-          RETURNING id
-        )
-        SELECT id FROM update_device
-      `;
+    await removeMaintenanceDevice(maintenanceId, deviceId);
+  },
 
-      await db.queryRaw({ sql });
-    } catch (error) {
-      throw error;
-    }
+  async assignTechnician(
+    maintenanceId: string,
+    technicianId: string
+  ): Promise<void> {
+    await assignMaintenanceTechnician(maintenanceId, technicianId);
   },
 
+  // The page has a single notes field, so devices left broken or
+  // discarded without a note of their own are explained by it.
   async completeMaintenance(
     maintenanceId: string,
+    devices: {
+      id: string;
+      outcome: DeviceStatus;
+      note?: string;
+    }[],
     notes?: string
-  ): Promise<void> {
-    try {
-      const notesValue = notes ? `'${notes.replace(/'/g, "''")}'` : "NULL";
-
-      const sql = `
-        WITH update_maintenance AS (
-          UPDATE maintenances
-          SET 
-            status = '${MaintenanceStatus.COMPLETED}'::maintenance_status,
-            finished_at = CURRENT_TIMESTAMP
-          WHERE id = '${maintenanceId}'
-          RETURNING id
-        ),
-        device_rows AS (
-          SELECT 
-            device_id,
-            COALESCE(after_status, prev_status, '${DeviceStatus.HEALTHY}'::device_status) AS status_to_set
-          FROM maintenances_devices
-          WHERE maintaining_id = '${maintenanceId}'
-        ),
-        update_devices AS (
-          UPDATE devices
-          SET status = dr.status_to_set
-          FROM device_rows dr
-          WHERE devices.id = dr.device_id
-          RETURNING id
-        ),
-        update_activities AS (
-          UPDATE activities
-          SET note = ${notesValue}
-          WHERE id = '${maintenanceId}'
-          RETURNING id
-        )
-        SELECT id FROM update_maintenance
-      `;
-      await db.queryRaw({ sql });
-    } catch (error) {
-      throw error;
-    }
+  ) {
+    return await completeMaintenance(
+      maintenanceId,
+      devices.map((d) => ({
+        ...maintained(d.id, d.outcome),
+        note:
+          d.note ||
+          (NOTE_REQUIRED.includes(d.outcome) ? notes || undefined : undefined),
+      })),
+      notes || undefined
+    );
   },
 
   async cancelMaintenance(maintenanceId: string): Promise<void> {
-    try {
-      const sql = `
-        WITH upd_maintenance AS (
-          UPDATE maintenances
-          SET
-            status = '${MaintenanceStatus.CANCELLED}'::maintenance_status,
-            finished_at = CURRENT_TIMESTAMP
-          WHERE id = '${maintenanceId}'
-          RETURNING id
-        ),
-        device_rows AS (
-          SELECT
-            device_id,
-            COALESCE(prev_status, '${DeviceStatus.HEALTHY}'::device_status) AS status_to_set
-          FROM maintenances_devices
-          WHERE maintaining_id = '${maintenanceId}'
-        ),
-        upd_devices AS (
-          UPDATE devices
-          SET status = dr.status_to_set::device_status
-          FROM device_rows dr
-          WHERE devices.id = dr.device_id
-          RETURNING devices.id
-        )
-        SELECT id FROM upd_maintenance;
-      `;
-      await db.queryRaw({ sql });
-    } catch (error) {
-      throw error;
-    }
+    await cancelMaintenance(maintenanceId);
   },
 };
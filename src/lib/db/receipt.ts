@@ -1,5 +1,5 @@
 import { DeviceStatus } from "@/types/db/generated";
-import { db } from "./client";
+import { createBorrowReceipt, createReturnReceipt, db } from "./client";
 
 interface CreateReceiptParams {
   id: string;
@@ -10,7 +10,7 @@ interface CreateReceiptParams {
     id: string;
     expectedReturnedAt: Date;
     expectedReturnedLabId?: string;
-    prevQuality: DeviceStatus;
+    prevQuality?: DeviceStatus;
   }[];
 }
 
@@ -22,6 +22,7 @@ interface ReturnReceiptParams {
   devices: {
     id: string;
     afterQuality: DeviceStatus;
+    note?: string;
   }[];
   note?: string;
 }
@@ -326,111 +327,46 @@ export const receiptService = {
   },
 
   async createReceipt(params: CreateReceiptParams) {
-    try {
-      if (!params.devices || params.devices.length === 0) {
-        throw new Error("No devices specified for borrowing");
-      }
-
-      const deviceValues = params.devices
-        .map((item) => {
-          const prevQuality = item.prevQuality || DeviceStatus.HEALTHY;
-          const expectedReturnedLabId =
-            item.expectedReturnedLabId || params.borrowedLabId;
-          return `'${item.id}', '${item.expectedReturnedAt.toISOString()}'::timestamptz, '${expectedReturnedLabId}'::uuid, '${prevQuality}'::device_status`;
-        })
-        .join("),(");
-
-      await db.queryRaw<{ id: string }>({
-        sql: `
-          WITH receipt AS (
-            INSERT INTO receipts (id, actor_id, checker_id, lab_id)
-            VALUES ('${params.id}', '${params.borrowerId}', '${params.borrowCheckerId}', '${params.borrowedLabId}'::uuid)
-            RETURNING id
-          ),
-          activity AS (
-            INSERT INTO activities (type)
-            VALUES ('borrow'::activity_type)
-            RETURNING id
-          ),
-          receipts_devices_insert AS (
-            INSERT INTO receipts_devices (
-              borrowed_receipt_id,
-              device_id,
-              borrow_id,
-              expected_returned_at,
-              expected_returned_lab_id,
-              prev_quality
-            )
-            SELECT 
-              '${params.id}',
-              v.device_id,
-              (SELECT id FROM activity),
-              v.expected_returned_at,
-              v.expected_returned_lab_id,
-              v.prev_quality
-            FROM (
-              VALUES (${deviceValues})
-            ) AS v(device_id, expected_returned_at, expected_returned_lab_id, prev_quality)
-            RETURNING device_id
-          )
-          UPDATE devices 
-          SET status = 'borrowing'::device_status 
-          WHERE id IN (SELECT device_id FROM receipts_devices_insert)
-        `,
-      });
-
-      return { success: true, id: params.id };
-    } catch (error) {
-      throw error;
+    if (!params.devices || params.devices.length === 0) {
+      throw new Error("No devices specified for borrowing");
     }
+
+    const receipt = await createBorrowReceipt({
+      id: params.id,
+      borrowerId: params.borrowerId,
+      checkerId: params.borrowCheckerId,
+      labId: params.borrowedLabId,
+      devices: params.devices.map((item) => ({
+        id: item.id,
+        expectedReturnedAt: item.expectedReturnedAt.toISOString(),
+        expectedReturnedLabId: item.expectedReturnedLabId,
+      })),
+    });
+
+    return { success: true, id: receipt.id, receipt };
   },
 
   async returnReceipt(params: ReturnReceiptParams) {
-    try {
-      if (!params.devices || params.devices.length === 0) {
-        throw new Error("No devices specified for return");
-      }
-
-      const deviceValues = params.devices
-        .map((item) => `('${item.id}', '${item.afterQuality}'::device_status)`)
-        .join(",");
-
-      const overallNote = params.note ? `'${params.note}'` : "NULL";
-
-      await db.queryRaw({
-        sql: `
-          WITH return_receipt AS (
-            INSERT INTO receipts (id, actor_id, checker_id, lab_id)
-            VALUES ('${params.id}', '${params.returnerId}', '${params.returnedCheckerId}', '${params.returnedLabId}'::uuid)
-            RETURNING id
-          ),
-          return_activity AS (
-            INSERT INTO activities (type, note)
-            VALUES ('return'::activity_type, ${overallNote})
-            RETURNING id
-          ),
-          update_receipts_devices AS (
-            UPDATE receipts_devices rd
-            SET
-              returned_receipt_id = (SELECT id FROM return_receipt),
-              after_quality = v.after_quality,
-              return_id = (SELECT id FROM return_activity)
-            FROM
-              (VALUES ${deviceValues}) AS v(device_id, after_quality)
-            WHERE
-              rd.device_id = v.device_id::text
-              AND rd.returned_receipt_id IS NULL
-            RETURNING rd.device_id, v.after_quality
-          )
-          UPDATE devices d
-          SET
-            status = urd.after_quality::device_status
-          FROM update_receipts_devices urd
-          WHERE d.id = urd.device_id
-        `,
-      });
+    if (!params.devices || params.devices.length === 0) {
+      throw new Error("No devices specified for return");
+    }
 
-      return { success: true, id: params.id };
+    const receipt = await createReturnReceipt({
+      id: params.id,
+      returnerId: params.returnerId,
+      checkerId: params.returnedCheckerId,
+      labId: params.returnedLabId,
+      devices: params.devices.map((item) => ({
+        id: item.id,
+        afterQuality: item.afterQuality as "healthy" | "broken",
+        note: item.note,
+      })),
+      note: params.note || undefined,
+    });
+
+    return { success: true, id: receipt.id, receipt };
+  },
+};
     } catch (error) {
       throw error;
     }
@@ -1,130 +1,48 @@
 import { DeviceStatus, ShipmentStatus } from "@/types/db/generated";
-import { db } from "./client";
-
-type ShipmentResult = { id: string };
-
-function generateUniqueId(): string {
-  const now = new Date();
-  const datePrefix = now.toISOString().split("T")[0].replace(/-/g, "");
-  const randomSuffix = Math.floor(Math.random() * 1000000)
-    .toString()
-    .padStart(6, "0");
-  return `${datePrefix}/${randomSuffix}`;
-}
+import {
+  ArrivedDevice,
+  cancelShipment,
+  closeShipment,
+  confirmShipmentArrivals,
+  createShipment,
+  db,
+  dispatchShipment,
+  getShipmentManifest,
+} from "./client";
 
 export const shipmentService = {
+  // Sends devices from this lab: the shipment is prepared and dispatched
+  // in one go.
   async confirmInboundShipment({
-    technicianId,
     sourceLabId,
     destinationLabId,
     notes,
     devices,
     checkAtDestination,
   }: {
-    technicianId: string;
     sourceLabId: string;
     destinationLabId: string;
     notes?: string;
-    devices: {
-      id: string;
-      inboundCondition: DeviceStatus;
-    }[];
+    devices: { id: string }[];
     checkAtDestination?: boolean;
   }) {
-    try {
-      const shipmentId = generateUniqueId();
-
-      const deviceRows =
-        devices.length > 0
-          ? devices
-              .map((device) => {
-                return `('${shipmentId}', '${device.id}', '${device.inboundCondition}'::device_status)`;
-              })
-              .join(", ")
-          : "";
-
-      const deviceIds =
-        devices.length > 0
-          ? devices.map((device) => `'${device.id}'`).join(", ")
-          : "";
-
-      const notesValue = notes ? `'${notes.replace(/'/g, "''")}'` : "NULL";
-      const checkAtDestinationValue = checkAtDestination ? "true" : "false";
-
-      const sql = `
-        WITH activity_insert AS (
-          INSERT INTO activities (
-            type, 
-            note
-          )
-          VALUES ('shipment', ${notesValue})
-          RETURNING id
-        ),
-        shipment_insert AS (
-          INSERT INTO shipments (
-            id,
-            sender_id, 
-            start_lab_id, 
-            arrive_lab_id, 
-            status,
-            from_at,
-            check_at_destination
-          )
-          VALUES (
-            '${shipmentId}',
-            '${technicianId}',
-            '${sourceLabId}'::uuid,
-            '${destinationLabId}'::uuid,
-            '${ShipmentStatus.SHIPPING}'::shipment_status,
-            (SELECT id FROM activity_insert),
-            ${checkAtDestinationValue}
-          )
-          RETURNING id
-        )
-        ${
-          devices.length > 0
-            ? `, device_rows(shipment_id, device_id, prev_status) AS (
-            VALUES ${deviceRows}
-          ),
-          device_insert AS (
-            INSERT INTO shipments_devices (
-              shipment_id, 
-              device_id, 
-              prev_status
-            )
-            SELECT
-              dr.shipment_id,
-              dr.device_id,
-              dr.prev_status
-            FROM device_rows dr
-            RETURNING device_id
-          ),
-          device_update AS (
-            UPDATE devices
-            SET status = '${DeviceStatus.SHIPPING}'::device_status
-            WHERE id IN (${deviceIds})
-            RETURNING id
-          )
-          SELECT id FROM device_update`
-            : `SELECT id FROM shipment_insert`
-        }
-      `;
-
-      await db.queryRaw<ShipmentResult>({ sql });
-
-      return { id: shipmentId };
-    } catch (error) {
-      throw error;
-    }
+    const shipment = await createShipment({
+      startLabId: sourceLabId,
+      arriveLabId: destinationLabId,
+      checkAtDestination: checkAtDestination ?? false,
+      devices: devices.map((device) => device.id),
+    });
+    await dispatchShipment(shipment.id, notes || undefined);
+    return { id: shipment.id };
   },
 
+  // Receives the scanned devices and closes the shipment; devices that
+  // were not scanned are reported missing.
   async confirmOutboundShipment({
-    technicianId,
     shipmentId,
     notes,
     devices,
   }: {
-    technicianId: string;
     shipmentId: string;
     notes?: string;
     devices: {
@@ -132,73 +50,27 @@ export const shipmentService = {
       outboundCondition: DeviceStatus;
     }[];
   }) {
-    try {
-      if (devices.length === 0) {
-        throw new Error("No devices provided");
-      }
-
-      const deviceUpdateCases = devices
-        .map(
-          (device) =>
-            `WHEN device_id = '${device.id}' THEN '${device.outboundCondition}'::device_status`
-        )
-        .join(" ");
-
-      const deviceStatusUpdates = devices
-        .map(
-          (device) =>
-            `WHEN id = '${device.id}' THEN '${device.outboundCondition}'::device_status`
-        )
-        .join(" ");
-
-      const deviceIds = devices.map((device) => `'${device.id}'`).join(", ");
-
-      const notesValue = notes ? `'${notes.replace(/'/g, "''")}'` : "NULL";
-
-      const sql = `
-        WITH activity_insert AS (
-          INSERT INTO activities (
-            type, 
-            note
-          )
-          VALUES ('shipment', ${notesValue})
-          RETURNING id
-        ),
-        shipment_update AS (
-          UPDATE shipments
-          SET 
-            receiver_id = '${technicianId}',
-            status = '${ShipmentStatus.COMPLETED}'::shipment_status,
-            to_at = (SELECT id FROM activity_insert)
-          WHERE id = '${shipmentId}'
-          RETURNING id
-        ),
-        shipment_device_update AS (
-          UPDATE shipments_devices
-          SET after_status = CASE ${deviceUpdateCases} END
-          WHERE shipment_id = '${shipmentId}'
-            AND device_id IN (${deviceIds})
-          RETURNING device_id
-        ),
-        device_update AS (
-          UPDATE devices
-          SET status = CASE ${deviceStatusUpdates} END
-          WHERE id IN (${deviceIds})
-          RETURNING id
-        )
-        SELECT id FROM shipment_update
-      `;
+    if (devices.length === 0) {
+      throw new Error("No devices provided");
+    }
 
-      const result = await db.queryRaw<ShipmentResult>({ sql });
+    await confirmShipmentArrivals(
+      shipmentId,
+      devices.map((device) => ({
+        id: device.id,
+        condition: device.outboundCondition as ArrivedDevice["condition"],
+      }))
+    );
+    const report = await closeShipment(shipmentId, notes || undefined);
+    return { id: report.shipment.id, report };
+  },
 
-      if (!result || result.length === 0) {
-        throw new Error("No shipment found for the provided ID");
-      }
+  async cancelShipment(shipmentId: string): Promise<void> {
+    await cancelShipment(shipmentId);
+  },
 
-      return { id: result[0].id };
-    } catch (error) {
-      throw error;
-    }
+  async getShipmentManifest(shipmentId: string) {
+    return await getShipmentManifest(shipmentId);
   },
 
   async getShipmentById(id: string) {
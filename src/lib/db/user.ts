@@ -1,4 +1,4 @@
-import { db } from "./client";
+import { db, verifyOneTimeQr } from "./client";
 
 export type UserDetail = {
   id: string;
@@ -13,12 +13,6 @@ export type UserDetail = {
   }[];
 };
 
-type OneTimeQrCodeParams = {
-  token: string;
-  userId: string;
-  timestamp: number;
-};
-
 export const userService = {
   async getUserById(id: string): Promise<UserDetail | null> {
     try {
@@ -81,78 +75,26 @@ export const userService = {
     }
   },
 
-  async checkOneTimeQrCode(params: OneTimeQrCodeParams) {
-    const { token, userId, timestamp } = params;
-
-    const existingTokenSQL = `
-      SELECT EXISTS (
-        SELECT 1 
-        FROM used_qr_tokens 
-        WHERE token = $1 AND user_id = $2
-      ) AS token_exists;
-    `;
-
-    const existingToken = await db.queryRaw<{ token_exists: boolean }>({
-      sql: existingTokenSQL,
-      params: [token, userId],
-    });
-
-    if (existingToken[0].token_exists) {
-      throw "Mã QR đã được sử dụng";
-    }
-
-    const userSQL = `
-        SELECT 
-            u.id,
-            u.name,
-            u.email,
-            u.tel,
-            u.image as avatar,
-            u.last_active_at,
-            COALESCE(
-                json_agg(
-                json_build_object(
-                    'name', r.name,
-                    'key', r.key
-                )
-                ) FILTER (WHERE r.id IS NOT NULL),
-                '[]'
-            ) as roles
-        FROM users u
-        LEFT JOIN user_roles ur ON u.id = ur.user_id
-        LEFT JOIN roles r ON ur.role_id = r.id
-        WHERE 
-            u.deleted_at IS NULL 
-            AND u.id = $1
-        GROUP BY 
-            u.id, 
-            u.name, 
-            u.email, 
-            u.tel,
-            u.image, 
-            u.last_active_at
-    `;
-
-    const user = await db.queryRaw<UserDetail>({
-      sql: userSQL,
-      params: [userId],
-    });
-
-    if (!user) {
-      throw "Không tìm thấy người dùng";
-    }
-
-    try {
-      await db.table("used_qr_tokens").insert({
-        token: token,
-        user_id: userId,
-        created_at: timestamp,
-      });
-    } catch (_error) {
-      throw "Mã QR đã được sử dụng";
-    }
-
-    return { user: user[0] };
+  // The code is checked, and marked used, by the backend.
+  async checkOneTimeQrCode(params: {
+    token: string;
+    userId: string;
+  }): Promise<{ user: UserDetail }> {
+    const user = await verifyOneTimeQr(params.userId, params.token);
+    return {
+      user: {
+        id: user.id,
+        name: user.name,
+        email: user.email ?? "",
+        tel: user.tel ?? "",
+        avatar: user.image ?? "",
+        lastActiveAt: user.lastActiveAt ? new Date(user.lastActiveAt) : null,
+        roles: user.roles.map((role) => ({
+          key: role.key,
+          name: role.name ?? role.key,
+        })),
+      },
+    };
   },
 
   async getBorrowedHistoryByUser(
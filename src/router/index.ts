@@ -5,6 +5,7 @@ import ConfirmReturnForm from "@/components/app/device/ConfirmReturnForm.vue";
 import ReturnForm from "@/components/app/device/ReturnForm.vue";
 import ReturnInvoice from "@/components/app/device/ReturnInvoice.vue";
 import AuthLayout from "@/layouts/AuthLayout.vue";
+import { getCurrentSession } from "@/lib/db";
 import AuditPage from "@/pages/audit/AuditPage.vue";
 import LoginPage from "@/pages/auth/LoginPage.vue";
 import BorrowReturn from "@/pages/borrow-return/index.vue";
@@ -98,8 +99,13 @@ const router = createRouter({
   ],
 });
 
-router.beforeEach((to, _from, next) => {
-  const isAuthenticated = localStorage.getItem("auth_token");
+// The backend holds the session, so a kiosk left idle is signed out even
+// if the page still has the user cached.
+router.beforeEach(async (to, _from, next) => {
+  const isAuthenticated = await getCurrentSession().catch(() => null);
+  if (!isAuthenticated) {
+    localStorage.removeItem("user_info");
+  }
 
   if (
     to.matched.some((record) => record.meta.requiresAuth) &&
@@ -1,5 +1,13 @@
 // This file is auto-generated. Do not edit manually.
 
+export type Json =
+  | string
+  | number
+  | boolean
+  | null
+  | { [key: string]: Json | undefined }
+  | Json[]
+
 export enum ActivityType {
   BORROW = 'borrow',
   RETURN = 'return',
@@ -8,38 +16,12 @@ export enum ActivityType {
   ASSESSMENT = 'assessment',
 }
 
-export enum MaintenanceStatus {
-  MAINTAINING = 'maintaining',
-  COMPLETED = 'completed',
-  CANCELLED = 'cancelled',
-}
-
-export enum UserRole {
-  SYSADMIN = 'sysadmin',
-  LAB_ADMIN = 'lab_admin',
-  TEACHER = 'teacher',
-  STUDENT = 'student',
-}
-
 export enum AssessmentStatus {
   ASSESSING = 'assessing',
   COMPLETED = 'completed',
   CANCELLED = 'cancelled',
 }
 
-export enum ReservationStatus {
-  PENDING = 'pending',
-  APPROVED = 'approved',
-  READY = 'ready',
-  CANCELLED = 'cancelled',
-}
-
-export enum ShipmentStatus {
-  SHIPPING = 'shipping',
-  COMPLETED = 'completed',
-  CANCELLED = 'cancelled',
-}
-
 export enum DeviceStatus {
   HEALTHY = 'healthy',
   BROKEN = 'broken',
@@ -51,6 +33,12 @@ export enum DeviceStatus {
   LOST = 'lost',
 }
 
+export enum MaintenanceStatus {
+  MAINTAINING = 'maintaining',
+  COMPLETED = 'completed',
+  CANCELLED = 'cancelled',
+}
+
 export enum RequestStatus {
   PENDING = 'pending',
   APPROVED = 'approved',
@@ -58,203 +46,94 @@ export enum RequestStatus {
   CANCELLED = 'cancelled',
 }
 
-export interface FlowState {
-  id: string
-  userId: string | null
-  authCode: string
-  codeChallengeMethod: string
-  codeChallenge: string
-  providerType: string
-  providerAccessToken: string | null
-  providerRefreshToken: string | null
-  createdAt: Date | null
-  updatedAt: Date | null
-  authenticationMethod: string
-  authCodeIssuedAt: Date | null
+export enum ReservationStatus {
+  PENDING = 'pending',
+  APPROVED = 'approved',
+  READY = 'ready',
+  CANCELLED = 'cancelled',
 }
 
-export interface RefreshTokens {
-  instanceId: string | null
-  id: number
-  token: string | null
-  userId: string | null
-  revoked: boolean | null
-  createdAt: Date | null
-  updatedAt: Date | null
-  parent: string | null
-  sessionId: string | null
+export enum ShipmentStatus {
+  PREPARING = 'preparing',
+  SHIPPING = 'shipping',
+  COMPLETED = 'completed',
+  CANCELLED = 'cancelled',
+  RECEIVED = 'received',
+  PARTIALLY_RECEIVED = 'partially_received',
 }
 
-export interface Key {
-  id: string
-  status: string | null
-  created: Date
-  expires: Date | null
-  keyType: string | null
-  keyId: number | null
-  keyContext: unknown | null
-  name: string | null
-  associatedData: string | null
-  rawKey: unknown | null
-  rawKeyNonce: unknown | null
-  parentKey: string | null
-  comment: string | null
-  userData: string | null
+export enum UserRole {
+  SYSADMIN = 'sysadmin',
+  LAB_ADMIN = 'lab_admin',
+  TEACHER = 'teacher',
+  STUDENT = 'student',
 }
 
-export interface ValidKey {
-  id: string | null
-  name: string | null
-  status: string | null
-  keyType: string | null
-  keyId: number | null
-  keyContext: unknown | null
-  created: Date | null
-  expires: Date | null
-  associatedData: string | null
+export interface Extensions {
+  id: string
+  type: string | null
+  settings: Json | null
+  tenantExternalId: string | null
+  insertedAt: string
+  updatedAt: string
 }
 
-export interface HmiCodes {
-  code: number
-  createdAt: Date
-  userId: string | null
-  authToken: string | null
-  status: string
-  updatedAt: Date | null
-  expiresAt: Date | null
-  labId: string | null
+export interface ExtensionsInsert {
+  id: string
+  type?: string | null
+  settings?: Json | null
+  tenant_external_id?: string | null
+  inserted_at: string | number
+  updated_at: string | number
 }
 
-export interface SchemaMigrations {
-  version: number
-  insertedAt: Date | null
+export interface ExtensionsUpdate {
+  id?: string
+  type?: string | null
+  settings?: Json | null
+  tenant_external_id?: string | null
+  inserted_at?: string | number
+  updated_at?: string | number
 }
 
-export interface UsedQrTokens {
-  id: number
-  createdAt: Date
-  token: string
-  userId: string
+export interface ExtensionsRelations {
+  tenantExternalId: Tenants | null
 }
 
-export interface RoleHistories {
-  createdAt: Date
-  granteeId: string
-  granterId: string
-  permissions: any | null
-  effectiveStart: Date
-  effectiveEnd: Date
-}
+export const ExtensionsColumns = {
+  id: 'id',
+  type: 'type',
+  settings: 'settings',
+  tenantExternalId: 'tenant_external_id',
+  insertedAt: 'inserted_at',
+  updatedAt: 'updated_at',
+} as const
 
-export interface Secrets {
-  id: string
-  name: string | null
-  description: string
-  secret: string
-  keyId: string | null
-  nonce: unknown | null
-  createdAt: Date
-  updatedAt: Date
-}
+export type ExtensionsColumn = (typeof ExtensionsColumns)[keyof typeof ExtensionsColumns]
 
-export interface Instances {
-  id: string
-  uuid: string | null
-  rawBaseConfig: string | null
-  createdAt: Date | null
-  updatedAt: Date | null
+export interface SchemaMigrations_Realtime {
+  version: number
+  insertedAt: string | null
 }
 
-export interface HttpResponse {
-  id: number | null
-  statusCode: number | null
-  contentType: string | null
-  headers: any | null
-  content: string | null
-  timedOut: boolean | null
-  errorMsg: string | null
-  created: Date
+export interface SchemaMigrations_RealtimeInsert {
+  version: number
+  inserted_at?: string | number | null
 }
 
-export interface Subscription {
-  id: number
-  subscriptionId: string
-  entity: string
-  filters: string[]
-  claims: any
-  claimsRole: string
-  createdAt: Date
+export interface SchemaMigrations_RealtimeUpdate {
+  version?: number
+  inserted_at?: string | number | null
 }
 
-export interface S3MultipartUploads {
-  id: string
-  inProgressSize: number
-  uploadSignature: string
-  bucketId: string
-  key: string
-  version: string
-  ownerId: string | null
-  createdAt: Date
-  userMetadata: any | null
-}
+export interface SchemaMigrations_RealtimeRelations {}
 
-export interface DecryptedSecrets {
-  id: string | null
-  name: string | null
-  description: string | null
-  secret: string | null
-  decryptedSecret: string | null
-  keyId: string | null
-  nonce: unknown | null
-  createdAt: Date | null
-  updatedAt: Date | null
-}
+export const SchemaMigrations_RealtimeColumns = {
+  version: 'version',
+  insertedAt: 'inserted_at',
+} as const
 
-export interface PgStatStatements {
-  userid: string | null
-  dbid: string | null
-  toplevel: boolean | null
-  queryid: number | null
-  query: string | null
-  plans: number | null
-  totalPlanTime: number | null
-  minPlanTime: number | null
-  maxPlanTime: number | null
-  meanPlanTime: number | null
-  stddevPlanTime: number | null
-  calls: number | null
-  totalExecTime: number | null
-  minExecTime: number | null
-  maxExecTime: number | null
-  meanExecTime: number | null
-  stddevExecTime: number | null
-  rows: number | null
-  sharedBlksHit: number | null
-  sharedBlksRead: number | null
-  sharedBlksDirtied: number | null
-  sharedBlksWritten: number | null
-  localBlksHit: number | null
-  localBlksRead: number | null
-  localBlksDirtied: number | null
-  localBlksWritten: number | null
-  tempBlksRead: number | null
-  tempBlksWritten: number | null
-  blkReadTime: number | null
-  blkWriteTime: number | null
-  tempBlkReadTime: number | null
-  tempBlkWriteTime: number | null
-  walRecords: number | null
-  walFpi: number | null
-  walBytes: number | null
-  jitFunctions: number | null
-  jitGenerationTime: number | null
-  jitInliningCount: number | null
-  jitInliningTime: number | null
-  jitOptimizationCount: number | null
-  jitOptimizationTime: number | null
-  jitEmissionCount: number | null
-  jitEmissionTime: number | null
-}
+export type SchemaMigrations_RealtimeColumn = (typeof SchemaMigrations_RealtimeColumns)[keyof typeof SchemaMigrations_RealtimeColumns]
 
 export interface Tenants {
   id: string
@@ -262,486 +141,4047 @@ export interface Tenants {
   externalId: string | null
   jwtSecret: string | null
   maxConcurrentUsers: number
-  insertedAt: Date
-  updatedAt: Date
+  insertedAt: string
+  updatedAt: string
   maxEventsPerSecond: number
   postgresCdcDefault: string | null
   maxBytesPerSecond: number
   maxChannelsPerClient: number
   maxJoinsPerSecond: number
   suspend: boolean | null
-  jwtJwks: any | null
+  jwtJwks: Json | null
   notifyPrivateAlpha: boolean | null
 }
 
-export interface Resources {
-  id: number
-  createdAt: Date
-  name: string
-  type: string | null
-}
-
-export interface Activities {
+export interface TenantsInsert {
   id: string
-  type: ActivityType
-  createdAt: Date
-  note: string | null
+  name?: string | null
+  external_id?: string | null
+  jwt_secret?: string | null
+  max_concurrent_users?: number
+  inserted_at: string | number
+  updated_at: string | number
+  max_events_per_second?: number
+  postgres_cdc_default?: string | null
+  max_bytes_per_second?: number
+  max_channels_per_client?: number
+  max_joins_per_second?: number
+  suspend?: boolean | null
+  jwt_jwks?: Json | null
+  notify_private_alpha?: boolean | null
 }
 
-export interface Migrations {
-  version: string
-  insertedAt: Date
+export interface TenantsUpdate {
+  id?: string
+  name?: string | null
+  external_id?: string | null
+  jwt_secret?: string | null
+  max_concurrent_users?: number
+  inserted_at?: string | number
+  updated_at?: string | number
+  max_events_per_second?: number
+  postgres_cdc_default?: string | null
+  max_bytes_per_second?: number
+  max_channels_per_client?: number
+  max_joins_per_second?: number
+  suspend?: boolean | null
+  jwt_jwks?: Json | null
+  notify_private_alpha?: boolean | null
 }
 
-export interface Devices {
-  createdAt: Date
-  kind: string
-  labId: string | null
-  deletedAt: Date | null
-  status: DeviceStatus
+export interface TenantsRelations {}
+
+export const TenantsColumns = {
+  id: 'id',
+  name: 'name',
+  externalId: 'external_id',
+  jwtSecret: 'jwt_secret',
+  maxConcurrentUsers: 'max_concurrent_users',
+  insertedAt: 'inserted_at',
+  updatedAt: 'updated_at',
+  maxEventsPerSecond: 'max_events_per_second',
+  postgresCdcDefault: 'postgres_cdc_default',
+  maxBytesPerSecond: 'max_bytes_per_second',
+  maxChannelsPerClient: 'max_channels_per_client',
+  maxJoinsPerSecond: 'max_joins_per_second',
+  suspend: 'suspend',
+  jwtJwks: 'jwt_jwks',
+  notifyPrivateAlpha: 'notify_private_alpha',
+} as const
+
+export type TenantsColumn = (typeof TenantsColumns)[keyof typeof TenantsColumns]
+
+export interface AuditLogEntries {
+  instanceId: string | null
   id: string
-  fullId: string
-  printedAt: Date | null
-  accessoryForKindId: string | null
+  payload: Json | null
+  createdAt: string | null
+  ipAddress: string
 }
 
-export interface Objects {
+export interface AuditLogEntriesInsert {
+  instance_id?: string | null
   id: string
-  bucketId: string | null
-  name: string | null
-  owner: string | null
-  createdAt: Date | null
-  updatedAt: Date | null
-  lastAccessedAt: Date | null
-  metadata: any | null
-  pathTokens: string[] | null
-  version: string | null
-  ownerId: string | null
-  userMetadata: any | null
+  payload?: Json | null
+  created_at?: string | number | null
+  ip_address?: string
 }
 
-export interface DecryptedKey {
-  id: string | null
-  status: string | null
-  created: Date | null
-  expires: Date | null
-  keyType: string | null
-  keyId: number | null
-  keyContext: unknown | null
-  name: string | null
-  associatedData: string | null
-  rawKey: unknown | null
-  decryptedRawKey: unknown | null
-  rawKeyNonce: unknown | null
-  parentKey: string | null
-  comment: string | null
+export interface AuditLogEntriesUpdate {
+  instance_id?: string | null
+  id?: string
+  payload?: Json | null
+  created_at?: string | number | null
+  ip_address?: string
 }
 
-export interface UserRoles {
-  createdAt: Date
-  userId: string
-  roleId: number
-}
+export interface AuditLogEntriesRelations {}
 
-export interface ReceiptsDevices {
-  borrowedReceiptId: string
-  deviceId: string
-  prevQuality: DeviceStatus | null
-  borrowId: string | null
-  returnId: string | null
-  expectedReturnedAt: Date
-  id: string
-  expectedReturnedLabId: string | null
-  afterQuality: DeviceStatus | null
-  returnedReceiptId: string | null
-}
+export const AuditLogEntriesColumns = {
+  instanceId: 'instance_id',
+  id: 'id',
+  payload: 'payload',
+  createdAt: 'created_at',
+  ipAddress: 'ip_address',
+} as const
+
+export type AuditLogEntriesColumn = (typeof AuditLogEntriesColumns)[keyof typeof AuditLogEntriesColumns]
+
+export interface FlowState {
+  id: string
+  userId: string | null
+  authCode: string
+  codeChallengeMethod: string
+  codeChallenge: string
+  providerType: string
+  providerAccessToken: string | null
+  providerRefreshToken: string | null
+  createdAt: string | null
+  updatedAt: string | null
+  authenticationMethod: string
+  authCodeIssuedAt: string | null
+}
+
+export interface FlowStateInsert {
+  id: string
+  user_id?: string | null
+  auth_code: string
+  code_challenge_method: string
+  code_challenge: string
+  provider_type: string
+  provider_access_token?: string | null
+  provider_refresh_token?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  authentication_method: string
+  auth_code_issued_at?: string | number | null
+}
+
+export interface FlowStateUpdate {
+  id?: string
+  user_id?: string | null
+  auth_code?: string
+  code_challenge_method?: string
+  code_challenge?: string
+  provider_type?: string
+  provider_access_token?: string | null
+  provider_refresh_token?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  authentication_method?: string
+  auth_code_issued_at?: string | number | null
+}
+
+export interface FlowStateRelations {}
+
+export const FlowStateColumns = {
+  id: 'id',
+  userId: 'user_id',
+  authCode: 'auth_code',
+  codeChallengeMethod: 'code_challenge_method',
+  codeChallenge: 'code_challenge',
+  providerType: 'provider_type',
+  providerAccessToken: 'provider_access_token',
+  providerRefreshToken: 'provider_refresh_token',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  authenticationMethod: 'authentication_method',
+  authCodeIssuedAt: 'auth_code_issued_at',
+} as const
+
+export type FlowStateColumn = (typeof FlowStateColumns)[keyof typeof FlowStateColumns]
 
 export interface Identities {
   providerId: string
   userId: string
-  identityData: any
+  identityData: Json
   provider: string
-  lastSignInAt: Date | null
-  createdAt: Date | null
-  updatedAt: Date | null
+  lastSignInAt: string | null
+  createdAt: string | null
+  updatedAt: string | null
   email: string | null
   id: string
 }
 
+export interface IdentitiesInsert {
+  provider_id: string
+  user_id: string
+  identity_data: Json
+  provider: string
+  last_sign_in_at?: string | number | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  id?: string
+}
+
+export interface IdentitiesUpdate {
+  provider_id?: string
+  user_id?: string
+  identity_data?: Json
+  provider?: string
+  last_sign_in_at?: string | number | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  id?: string
+}
+
+export interface IdentitiesRelations {
+  userId: Users_Auth
+}
+
+export const IdentitiesColumns = {
+  providerId: 'provider_id',
+  userId: 'user_id',
+  identityData: 'identity_data',
+  provider: 'provider',
+  lastSignInAt: 'last_sign_in_at',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  email: 'email',
+  id: 'id',
+} as const
+
+export type IdentitiesColumn = (typeof IdentitiesColumns)[keyof typeof IdentitiesColumns]
+
+export interface Instances {
+  id: string
+  uuid: string | null
+  rawBaseConfig: string | null
+  createdAt: string | null
+  updatedAt: string | null
+}
+
+export interface InstancesInsert {
+  id: string
+  uuid?: string | null
+  raw_base_config?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+}
+
+export interface InstancesUpdate {
+  id?: string
+  uuid?: string | null
+  raw_base_config?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+}
+
+export interface InstancesRelations {}
+
+export const InstancesColumns = {
+  id: 'id',
+  uuid: 'uuid',
+  rawBaseConfig: 'raw_base_config',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+} as const
+
+export type InstancesColumn = (typeof InstancesColumns)[keyof typeof InstancesColumns]
+
 export interface MfaAmrClaims {
   sessionId: string
-  createdAt: Date
-  updatedAt: Date
+  createdAt: string
+  updatedAt: string
   authenticationMethod: string
   id: string
 }
 
-export interface Categories {
-  id: number
-  createdAt: Date
-  name: string
-  quantity: number | null
-}
-
-export interface Users {
+export interface MfaAmrClaimsInsert {
+  session_id: string
+  created_at: string | number
+  updated_at: string | number
+  authentication_method: string
   id: string
-  createdAt: Date
-  name: string
-  meta: any
-  email: string | null
-  password: string | null
-  image: string | null
-  tel: string | null
-  deletedAt: Date | null
-  lastActiveAt: Date | null
 }
 
-export interface Migrations_Storage {
-  id: number
-  name: string
-  hash: string
-  executedAt: Date | null
+export interface MfaAmrClaimsUpdate {
+  session_id?: string
+  created_at?: string | number
+  updated_at?: string | number
+  authentication_method?: string
+  id?: string
 }
 
-export interface Actions {
-  id: number
-  createdAt: Date
-  name: string
+export interface MfaAmrClaimsRelations {
+  sessionId: Sessions
 }
 
-export interface Hooks {
-  id: number
-  hookTableId: number
-  hookName: string
-  createdAt: Date
-  requestId: number | null
+export const MfaAmrClaimsColumns = {
+  sessionId: 'session_id',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  authenticationMethod: 'authentication_method',
+  id: 'id',
+} as const
+
+export type MfaAmrClaimsColumn = (typeof MfaAmrClaimsColumns)[keyof typeof MfaAmrClaimsColumns]
+
+export interface MfaChallenges {
+  id: string
+  factorId: string
+  createdAt: string
+  verifiedAt: string | null
+  ipAddress: string
+  otpCode: string | null
 }
 
-export interface Users_Auth {
-  instanceId: string | null
+export interface MfaChallengesInsert {
   id: string
-  aud: string | null
-  role: string | null
-  email: string | null
-  encryptedPassword: string | null
-  emailConfirmedAt: Date | null
-  invitedAt: Date | null
-  confirmationToken: string | null
-  confirmationSentAt: Date | null
-  recoveryToken: string | null
-  recoverySentAt: Date | null
-  emailChangeTokenNew: string | null
-  emailChange: string | null
-  emailChangeSentAt: Date | null
-  lastSignInAt: Date | null
-  rawAppMetaData: any | null
-  rawUserMetaData: any | null
-  isSuperAdmin: boolean | null
-  createdAt: Date | null
-  updatedAt: Date | null
-  phone: string | null
-  phoneConfirmedAt: Date | null
-  phoneChange: string | null
-  phoneChangeToken: string | null
-  phoneChangeSentAt: Date | null
-  confirmedAt: Date | null
-  emailChangeTokenCurrent: string | null
-  emailChangeConfirmStatus: number | null
-  bannedUntil: Date | null
-  reauthenticationToken: string | null
-  reauthenticationSentAt: Date | null
-  isSsoUser: boolean
-  deletedAt: Date | null
-  isAnonymous: boolean
+  factor_id: string
+  created_at: string | number
+  verified_at?: string | number | null
+  ip_address: string
+  otp_code?: string | null
 }
 
-export interface MaskColumns {
-  attname: string | null
-  attrelid: string | null
-  keyId: string | null
-  keyIdColumn: string | null
-  associatedColumns: string | null
-  nonceColumn: string | null
-  formatType: string | null
+export interface MfaChallengesUpdate {
+  id?: string
+  factor_id?: string
+  created_at?: string | number
+  verified_at?: string | number | null
+  ip_address?: string
+  otp_code?: string | null
 }
 
-export interface MaskingRule {
-  attrelid: string | null
-  attnum: number | null
-  relnamespace: string | null
-  relname: string | null
-  attname: string | null
-  formatType: string | null
-  colDescription: string | null
-  keyIdColumn: string | null
-  keyId: string | null
-  associatedColumns: string | null
-  nonceColumn: string | null
-  viewName: string | null
-  priority: number | null
-  securityInvoker: boolean | null
+export interface MfaChallengesRelations {
+  factorId: MfaFactors
 }
 
+export const MfaChallengesColumns = {
+  id: 'id',
+  factorId: 'factor_id',
+  createdAt: 'created_at',
+  verifiedAt: 'verified_at',
+  ipAddress: 'ip_address',
+  otpCode: 'otp_code',
+} as const
+
+export type MfaChallengesColumn = (typeof MfaChallengesColumns)[keyof typeof MfaChallengesColumns]
+
 export interface MfaFactors {
   id: string
   userId: string
   friendlyName: string | null
   factorType: string
   status: string
-  createdAt: Date
-  updatedAt: Date
+  createdAt: string
+  updatedAt: string
   secret: string | null
   phone: string | null
-  lastChallengedAt: Date | null
+  lastChallengedAt: string | null
+}
+
+export interface MfaFactorsInsert {
+  id: string
+  user_id: string
+  friendly_name?: string | null
+  factor_type: string
+  status: string
+  created_at: string | number
+  updated_at: string | number
+  secret?: string | null
+  phone?: string | null
+  last_challenged_at?: string | number | null
+}
+
+export interface MfaFactorsUpdate {
+  id?: string
+  user_id?: string
+  friendly_name?: string | null
+  factor_type?: string
+  status?: string
+  created_at?: string | number
+  updated_at?: string | number
+  secret?: string | null
+  phone?: string | null
+  last_challenged_at?: string | number | null
+}
+
+export interface MfaFactorsRelations {
+  userId: Users_Auth
 }
 
+export const MfaFactorsColumns = {
+  id: 'id',
+  userId: 'user_id',
+  friendlyName: 'friendly_name',
+  factorType: 'factor_type',
+  status: 'status',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  secret: 'secret',
+  phone: 'phone',
+  lastChallengedAt: 'last_challenged_at',
+} as const
+
+export type MfaFactorsColumn = (typeof MfaFactorsColumns)[keyof typeof MfaFactorsColumns]
+
 export interface OneTimeTokens {
   id: string
   userId: string
   tokenType: string
   tokenHash: string
   relatesTo: string
-  createdAt: Date
-  updatedAt: Date
+  createdAt: string
+  updatedAt: string
 }
 
-export interface Sessions {
+export interface OneTimeTokensInsert {
   id: string
-  userId: string
-  createdAt: Date | null
-  updatedAt: Date | null
-  factorId: string | null
-  aal: string | null
-  notAfter: Date | null
-  refreshedAt: Date | null
-  userAgent: string | null
-  ip: string | null
-  tag: string | null
+  user_id: string
+  token_type: string
+  token_hash: string
+  relates_to: string
+  created_at?: string | number
+  updated_at?: string | number
 }
 
-export interface MfaChallenges {
-  id: string
-  factorId: string
-  createdAt: Date
-  verifiedAt: Date | null
-  ipAddress: string
-  otpCode: string | null
+export interface OneTimeTokensUpdate {
+  id?: string
+  user_id?: string
+  token_type?: string
+  token_hash?: string
+  relates_to?: string
+  created_at?: string | number
+  updated_at?: string | number
 }
 
-export interface Shipments {
-  id: string
-  senderId: string | null
-  receiverId: string | null
-  status: ShipmentStatus
-  startLabId: string
-  arriveLabId: string
-  fromAt: string | null
-  toAt: string | null
-  checkAtDestination: boolean | null
+export interface OneTimeTokensRelations {
+  userId: Users_Auth
 }
 
-export interface S3MultipartUploadsParts {
-  id: string
-  uploadId: string
-  size: number
-  partNumber: number
-  bucketId: string
-  key: string
-  etag: string
-  ownerId: string | null
-  version: string
-  createdAt: Date
+export const OneTimeTokensColumns = {
+  id: 'id',
+  userId: 'user_id',
+  tokenType: 'token_type',
+  tokenHash: 'token_hash',
+  relatesTo: 'relates_to',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+} as const
+
+export type OneTimeTokensColumn = (typeof OneTimeTokensColumns)[keyof typeof OneTimeTokensColumns]
+
+export interface RefreshTokens {
+  instanceId: string | null
+  id: number
+  token: string | null
+  userId: string | null
+  revoked: boolean | null
+  createdAt: string | null
+  updatedAt: string | null
+  parent: string | null
+  sessionId: string | null
 }
 
-export interface MaintenancesDevices {
-  id: string
-  prevStatus: DeviceStatus | null
-  afterStatus: DeviceStatus | null
-  maintainingId: string
-  deviceId: string | null
+export interface RefreshTokensInsert {
+  instance_id?: string | null
+  id?: number
+  token?: string | null
+  user_id?: string | null
+  revoked?: boolean | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  parent?: string | null
+  session_id?: string | null
 }
 
-export interface SchemaMigrations_Auth {
-  version: string
+export interface RefreshTokensUpdate {
+  instance_id?: string | null
+  id?: number
+  token?: string | null
+  user_id?: string | null
+  revoked?: boolean | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  parent?: string | null
+  session_id?: string | null
 }
 
-export interface SsoDomains {
-  id: string
-  ssoProviderId: string
-  domain: string
-  createdAt: Date | null
-  updatedAt: Date | null
+export interface RefreshTokensRelations {
+  sessionId: Sessions | null
 }
 
+export const RefreshTokensColumns = {
+  instanceId: 'instance_id',
+  id: 'id',
+  token: 'token',
+  userId: 'user_id',
+  revoked: 'revoked',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  parent: 'parent',
+  sessionId: 'session_id',
+} as const
+
+export type RefreshTokensColumn = (typeof RefreshTokensColumns)[keyof typeof RefreshTokensColumns]
+
 export interface SamlProviders {
   id: string
   ssoProviderId: string
   entityId: string
   metadataXml: string
   metadataUrl: string | null
-  attributeMapping: any | null
-  createdAt: Date | null
-  updatedAt: Date | null
+  attributeMapping: Json | null
+  createdAt: string | null
+  updatedAt: string | null
   nameIdFormat: string | null
 }
 
+export interface SamlProvidersInsert {
+  id: string
+  sso_provider_id: string
+  entity_id: string
+  metadata_xml: string
+  metadata_url?: string | null
+  attribute_mapping?: Json | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  name_id_format?: string | null
+}
+
+export interface SamlProvidersUpdate {
+  id?: string
+  sso_provider_id?: string
+  entity_id?: string
+  metadata_xml?: string
+  metadata_url?: string | null
+  attribute_mapping?: Json | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  name_id_format?: string | null
+}
+
+export interface SamlProvidersRelations {
+  ssoProviderId: SsoProviders
+}
+
+export const SamlProvidersColumns = {
+  id: 'id',
+  ssoProviderId: 'sso_provider_id',
+  entityId: 'entity_id',
+  metadataXml: 'metadata_xml',
+  metadataUrl: 'metadata_url',
+  attributeMapping: 'attribute_mapping',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  nameIdFormat: 'name_id_format',
+} as const
+
+export type SamlProvidersColumn = (typeof SamlProvidersColumns)[keyof typeof SamlProvidersColumns]
+
 export interface SamlRelayStates {
   id: string
   ssoProviderId: string
   requestId: string
   forEmail: string | null
   redirectTo: string | null
-  createdAt: Date | null
-  updatedAt: Date | null
+  createdAt: string | null
+  updatedAt: string | null
   flowStateId: string | null
 }
 
-export interface Permissions {
-  createdAt: Date
-  roleId: number
-  resourceId: number
-  actionId: number
-  priority: number
+export interface SamlRelayStatesInsert {
+  id: string
+  sso_provider_id: string
+  request_id: string
+  for_email?: string | null
+  redirect_to?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  flow_state_id?: string | null
 }
 
-export interface PgStatStatementsInfo {
-  dealloc: number | null
-  statsReset: Date | null
+export interface SamlRelayStatesUpdate {
+  id?: string
+  sso_provider_id?: string
+  request_id?: string
+  for_email?: string | null
+  redirect_to?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  flow_state_id?: string | null
 }
 
-export interface Roles {
-  id: number
-  createdAt: Date
-  key: string
-  name: string | null
+export interface SamlRelayStatesRelations {
+  flowStateId: FlowState | null
+  ssoProviderId: SsoProviders
 }
 
-export interface InventoryAssessments {
-  id: string
-  finishedAt: Date | null
-  labId: string
-  accountantId: string | null
-  status: AssessmentStatus
+export const SamlRelayStatesColumns = {
+  id: 'id',
+  ssoProviderId: 'sso_provider_id',
+  requestId: 'request_id',
+  forEmail: 'for_email',
+  redirectTo: 'redirect_to',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  flowStateId: 'flow_state_id',
+} as const
+
+export type SamlRelayStatesColumn = (typeof SamlRelayStatesColumns)[keyof typeof SamlRelayStatesColumns]
+
+export interface SchemaMigrations_Auth {
+  version: string
 }
 
-export interface DeviceKinds {
-  createdAt: Date
-  categoryId: number | null
-  name: string | null
-  meta: any
-  image: any | null
-  brand: string | null
-  manufacturer: string | null
-  description: string | null
-  datasheet: string | null
-  unit: string | null
-  price: string | null
-  deletedAt: Date | null
-  id: string
-  allowedBorrowRoles: string[] | null
-  allowedViewRoles: string[] | null
-  isBorrowableLabOnly: boolean
+export interface SchemaMigrations_AuthInsert {
+  version: string
 }
 
-export interface AuditLogEntries {
-  instanceId: string | null
+export interface SchemaMigrations_AuthUpdate {
+  version?: string
+}
+
+export interface SchemaMigrations_AuthRelations {}
+
+export const SchemaMigrations_AuthColumns = {
+  version: 'version',
+} as const
+
+export type SchemaMigrations_AuthColumn = (typeof SchemaMigrations_AuthColumns)[keyof typeof SchemaMigrations_AuthColumns]
+
+export interface Sessions {
   id: string
-  payload: any | null
-  createdAt: Date | null
-  ipAddress: string
+  userId: string
+  createdAt: string | null
+  updatedAt: string | null
+  factorId: string | null
+  aal: string | null
+  notAfter: string | null
+  refreshedAt: string | null
+  userAgent: string | null
+  ip: string | null
+  tag: string | null
 }
 
-export interface Extensions {
+export interface SessionsInsert {
   id: string
-  type: string | null
-  settings: any | null
-  tenantExternalId: string | null
-  insertedAt: Date
-  updatedAt: Date
+  user_id: string
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  factor_id?: string | null
+  aal?: string | null
+  not_after?: string | number | null
+  refreshed_at?: string | number | null
+  user_agent?: string | null
+  ip?: string | null
+  tag?: string | null
 }
 
-export interface HttpRequestQueue {
-  id: number
-  method: string
-  url: string
-  headers: any
-  body: unknown | null
-  timeoutMilliseconds: number
+export interface SessionsUpdate {
+  id?: string
+  user_id?: string
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  factor_id?: string | null
+  aal?: string | null
+  not_after?: string | number | null
+  refreshed_at?: string | number | null
+  user_agent?: string | null
+  ip?: string | null
+  tag?: string | null
 }
 
-export interface Buckets {
-  id: string
-  name: string
-  owner: string | null
-  createdAt: Date | null
-  updatedAt: Date | null
-  public: boolean | null
+export interface SessionsRelations {
+  userId: Users_Auth
+}
+
+export const SessionsColumns = {
+  id: 'id',
+  userId: 'user_id',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  factorId: 'factor_id',
+  aal: 'aal',
+  notAfter: 'not_after',
+  refreshedAt: 'refreshed_at',
+  userAgent: 'user_agent',
+  ip: 'ip',
+  tag: 'tag',
+} as const
+
+export type SessionsColumn = (typeof SessionsColumns)[keyof typeof SessionsColumns]
+
+export interface SsoDomains {
+  id: string
+  ssoProviderId: string
+  domain: string
+  createdAt: string | null
+  updatedAt: string | null
+}
+
+export interface SsoDomainsInsert {
+  id: string
+  sso_provider_id: string
+  domain: string
+  created_at?: string | number | null
+  updated_at?: string | number | null
+}
+
+export interface SsoDomainsUpdate {
+  id?: string
+  sso_provider_id?: string
+  domain?: string
+  created_at?: string | number | null
+  updated_at?: string | number | null
+}
+
+export interface SsoDomainsRelations {
+  ssoProviderId: SsoProviders
+}
+
+export const SsoDomainsColumns = {
+  id: 'id',
+  ssoProviderId: 'sso_provider_id',
+  domain: 'domain',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+} as const
+
+export type SsoDomainsColumn = (typeof SsoDomainsColumns)[keyof typeof SsoDomainsColumns]
+
+export interface SsoProviders {
+  id: string
+  resourceId: string | null
+  createdAt: string | null
+  updatedAt: string | null
+}
+
+export interface SsoProvidersInsert {
+  id: string
+  resource_id?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+}
+
+export interface SsoProvidersUpdate {
+  id?: string
+  resource_id?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+}
+
+export interface SsoProvidersRelations {}
+
+export const SsoProvidersColumns = {
+  id: 'id',
+  resourceId: 'resource_id',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+} as const
+
+export type SsoProvidersColumn = (typeof SsoProvidersColumns)[keyof typeof SsoProvidersColumns]
+
+export interface Users_Auth {
+  instanceId: string | null
+  id: string
+  aud: string | null
+  role: string | null
+  email: string | null
+  encryptedPassword: string | null
+  emailConfirmedAt: string | null
+  invitedAt: string | null
+  confirmationToken: string | null
+  confirmationSentAt: string | null
+  recoveryToken: string | null
+  recoverySentAt: string | null
+  emailChangeTokenNew: string | null
+  emailChange: string | null
+  emailChangeSentAt: string | null
+  lastSignInAt: string | null
+  rawAppMetaData: Json | null
+  rawUserMetaData: Json | null
+  isSuperAdmin: boolean | null
+  createdAt: string | null
+  updatedAt: string | null
+  phone: string | null
+  phoneConfirmedAt: string | null
+  phoneChange: string | null
+  phoneChangeToken: string | null
+  phoneChangeSentAt: string | null
+  confirmedAt: string | null
+  emailChangeTokenCurrent: string | null
+  emailChangeConfirmStatus: number | null
+  bannedUntil: string | null
+  reauthenticationToken: string | null
+  reauthenticationSentAt: string | null
+  isSsoUser: boolean
+  deletedAt: string | null
+  isAnonymous: boolean
+}
+
+export interface Users_AuthInsert {
+  instance_id?: string | null
+  id: string
+  aud?: string | null
+  role?: string | null
+  email?: string | null
+  encrypted_password?: string | null
+  email_confirmed_at?: string | number | null
+  invited_at?: string | number | null
+  confirmation_token?: string | null
+  confirmation_sent_at?: string | number | null
+  recovery_token?: string | null
+  recovery_sent_at?: string | number | null
+  email_change_token_new?: string | null
+  email_change?: string | null
+  email_change_sent_at?: string | number | null
+  last_sign_in_at?: string | number | null
+  raw_app_meta_data?: Json | null
+  raw_user_meta_data?: Json | null
+  is_super_admin?: boolean | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  phone?: string | null
+  phone_confirmed_at?: string | number | null
+  phone_change?: string | null
+  phone_change_token?: string | null
+  phone_change_sent_at?: string | number | null
+  email_change_token_current?: string | null
+  email_change_confirm_status?: number | null
+  banned_until?: string | number | null
+  reauthentication_token?: string | null
+  reauthentication_sent_at?: string | number | null
+  is_sso_user?: boolean
+  deleted_at?: string | number | null
+  is_anonymous?: boolean
+}
+
+export interface Users_AuthUpdate {
+  instance_id?: string | null
+  id?: string
+  aud?: string | null
+  role?: string | null
+  email?: string | null
+  encrypted_password?: string | null
+  email_confirmed_at?: string | number | null
+  invited_at?: string | number | null
+  confirmation_token?: string | null
+  confirmation_sent_at?: string | number | null
+  recovery_token?: string | null
+  recovery_sent_at?: string | number | null
+  email_change_token_new?: string | null
+  email_change?: string | null
+  email_change_sent_at?: string | number | null
+  last_sign_in_at?: string | number | null
+  raw_app_meta_data?: Json | null
+  raw_user_meta_data?: Json | null
+  is_super_admin?: boolean | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  phone?: string | null
+  phone_confirmed_at?: string | number | null
+  phone_change?: string | null
+  phone_change_token?: string | null
+  phone_change_sent_at?: string | number | null
+  email_change_token_current?: string | null
+  email_change_confirm_status?: number | null
+  banned_until?: string | number | null
+  reauthentication_token?: string | null
+  reauthentication_sent_at?: string | number | null
+  is_sso_user?: boolean
+  deleted_at?: string | number | null
+  is_anonymous?: boolean
+}
+
+export interface Users_AuthRelations {}
+
+export const Users_AuthColumns = {
+  instanceId: 'instance_id',
+  id: 'id',
+  aud: 'aud',
+  role: 'role',
+  email: 'email',
+  encryptedPassword: 'encrypted_password',
+  emailConfirmedAt: 'email_confirmed_at',
+  invitedAt: 'invited_at',
+  confirmationToken: 'confirmation_token',
+  confirmationSentAt: 'confirmation_sent_at',
+  recoveryToken: 'recovery_token',
+  recoverySentAt: 'recovery_sent_at',
+  emailChangeTokenNew: 'email_change_token_new',
+  emailChange: 'email_change',
+  emailChangeSentAt: 'email_change_sent_at',
+  lastSignInAt: 'last_sign_in_at',
+  rawAppMetaData: 'raw_app_meta_data',
+  rawUserMetaData: 'raw_user_meta_data',
+  isSuperAdmin: 'is_super_admin',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  phone: 'phone',
+  phoneConfirmedAt: 'phone_confirmed_at',
+  phoneChange: 'phone_change',
+  phoneChangeToken: 'phone_change_token',
+  phoneChangeSentAt: 'phone_change_sent_at',
+  confirmedAt: 'confirmed_at',
+  emailChangeTokenCurrent: 'email_change_token_current',
+  emailChangeConfirmStatus: 'email_change_confirm_status',
+  bannedUntil: 'banned_until',
+  reauthenticationToken: 'reauthentication_token',
+  reauthenticationSentAt: 'reauthentication_sent_at',
+  isSsoUser: 'is_sso_user',
+  deletedAt: 'deleted_at',
+  isAnonymous: 'is_anonymous',
+} as const
+
+export type Users_AuthColumn = (typeof Users_AuthColumns)[keyof typeof Users_AuthColumns]
+
+export interface PgStatStatements {
+  userid: string | null
+  dbid: string | null
+  toplevel: boolean | null
+  queryid: number | null
+  query: string | null
+  plans: number | null
+  totalPlanTime: number | null
+  minPlanTime: number | null
+  maxPlanTime: number | null
+  meanPlanTime: number | null
+  stddevPlanTime: number | null
+  calls: number | null
+  totalExecTime: number | null
+  minExecTime: number | null
+  maxExecTime: number | null
+  meanExecTime: number | null
+  stddevExecTime: number | null
+  rows: number | null
+  sharedBlksHit: number | null
+  sharedBlksRead: number | null
+  sharedBlksDirtied: number | null
+  sharedBlksWritten: number | null
+  localBlksHit: number | null
+  localBlksRead: number | null
+  localBlksDirtied: number | null
+  localBlksWritten: number | null
+  tempBlksRead: number | null
+  tempBlksWritten: number | null
+  blkReadTime: number | null
+  blkWriteTime: number | null
+  tempBlkReadTime: number | null
+  tempBlkWriteTime: number | null
+  walRecords: number | null
+  walFpi: number | null
+  walBytes: number | null
+  jitFunctions: number | null
+  jitGenerationTime: number | null
+  jitInliningCount: number | null
+  jitInliningTime: number | null
+  jitOptimizationCount: number | null
+  jitOptimizationTime: number | null
+  jitEmissionCount: number | null
+  jitEmissionTime: number | null
+}
+
+export interface PgStatStatementsInsert {
+  userid?: string | null
+  dbid?: string | null
+  toplevel?: boolean | null
+  queryid?: number | null
+  query?: string | null
+  plans?: number | null
+  total_plan_time?: number | null
+  min_plan_time?: number | null
+  max_plan_time?: number | null
+  mean_plan_time?: number | null
+  stddev_plan_time?: number | null
+  calls?: number | null
+  total_exec_time?: number | null
+  min_exec_time?: number | null
+  max_exec_time?: number | null
+  mean_exec_time?: number | null
+  stddev_exec_time?: number | null
+  rows?: number | null
+  shared_blks_hit?: number | null
+  shared_blks_read?: number | null
+  shared_blks_dirtied?: number | null
+  shared_blks_written?: number | null
+  local_blks_hit?: number | null
+  local_blks_read?: number | null
+  local_blks_dirtied?: number | null
+  local_blks_written?: number | null
+  temp_blks_read?: number | null
+  temp_blks_written?: number | null
+  blk_read_time?: number | null
+  blk_write_time?: number | null
+  temp_blk_read_time?: number | null
+  temp_blk_write_time?: number | null
+  wal_records?: number | null
+  wal_fpi?: number | null
+  wal_bytes?: number | null
+  jit_functions?: number | null
+  jit_generation_time?: number | null
+  jit_inlining_count?: number | null
+  jit_inlining_time?: number | null
+  jit_optimization_count?: number | null
+  jit_optimization_time?: number | null
+  jit_emission_count?: number | null
+  jit_emission_time?: number | null
+}
+
+export interface PgStatStatementsUpdate {
+  userid?: string | null
+  dbid?: string | null
+  toplevel?: boolean | null
+  queryid?: number | null
+  query?: string | null
+  plans?: number | null
+  total_plan_time?: number | null
+  min_plan_time?: number | null
+  max_plan_time?: number | null
+  mean_plan_time?: number | null
+  stddev_plan_time?: number | null
+  calls?: number | null
+  total_exec_time?: number | null
+  min_exec_time?: number | null
+  max_exec_time?: number | null
+  mean_exec_time?: number | null
+  stddev_exec_time?: number | null
+  rows?: number | null
+  shared_blks_hit?: number | null
+  shared_blks_read?: number | null
+  shared_blks_dirtied?: number | null
+  shared_blks_written?: number | null
+  local_blks_hit?: number | null
+  local_blks_read?: number | null
+  local_blks_dirtied?: number | null
+  local_blks_written?: number | null
+  temp_blks_read?: number | null
+  temp_blks_written?: number | null
+  blk_read_time?: number | null
+  blk_write_time?: number | null
+  temp_blk_read_time?: number | null
+  temp_blk_write_time?: number | null
+  wal_records?: number | null
+  wal_fpi?: number | null
+  wal_bytes?: number | null
+  jit_functions?: number | null
+  jit_generation_time?: number | null
+  jit_inlining_count?: number | null
+  jit_inlining_time?: number | null
+  jit_optimization_count?: number | null
+  jit_optimization_time?: number | null
+  jit_emission_count?: number | null
+  jit_emission_time?: number | null
+}
+
+export interface PgStatStatementsRelations {}
+
+export const PgStatStatementsColumns = {
+  userid: 'userid',
+  dbid: 'dbid',
+  toplevel: 'toplevel',
+  queryid: 'queryid',
+  query: 'query',
+  plans: 'plans',
+  totalPlanTime: 'total_plan_time',
+  minPlanTime: 'min_plan_time',
+  maxPlanTime: 'max_plan_time',
+  meanPlanTime: 'mean_plan_time',
+  stddevPlanTime: 'stddev_plan_time',
+  calls: 'calls',
+  totalExecTime: 'total_exec_time',
+  minExecTime: 'min_exec_time',
+  maxExecTime: 'max_exec_time',
+  meanExecTime: 'mean_exec_time',
+  stddevExecTime: 'stddev_exec_time',
+  rows: 'rows',
+  sharedBlksHit: 'shared_blks_hit',
+  sharedBlksRead: 'shared_blks_read',
+  sharedBlksDirtied: 'shared_blks_dirtied',
+  sharedBlksWritten: 'shared_blks_written',
+  localBlksHit: 'local_blks_hit',
+  localBlksRead: 'local_blks_read',
+  localBlksDirtied: 'local_blks_dirtied',
+  localBlksWritten: 'local_blks_written',
+  tempBlksRead: 'temp_blks_read',
+  tempBlksWritten: 'temp_blks_written',
+  blkReadTime: 'blk_read_time',
+  blkWriteTime: 'blk_write_time',
+  tempBlkReadTime: 'temp_blk_read_time',
+  tempBlkWriteTime: 'temp_blk_write_time',
+  walRecords: 'wal_records',
+  walFpi: 'wal_fpi',
+  walBytes: 'wal_bytes',
+  jitFunctions: 'jit_functions',
+  jitGenerationTime: 'jit_generation_time',
+  jitInliningCount: 'jit_inlining_count',
+  jitInliningTime: 'jit_inlining_time',
+  jitOptimizationCount: 'jit_optimization_count',
+  jitOptimizationTime: 'jit_optimization_time',
+  jitEmissionCount: 'jit_emission_count',
+  jitEmissionTime: 'jit_emission_time',
+} as const
+
+export type PgStatStatementsColumn = (typeof PgStatStatementsColumns)[keyof typeof PgStatStatementsColumns]
+
+export interface PgStatStatementsInfo {
+  dealloc: number | null
+  statsReset: string | null
+}
+
+export interface PgStatStatementsInfoInsert {
+  dealloc?: number | null
+  stats_reset?: string | number | null
+}
+
+export interface PgStatStatementsInfoUpdate {
+  dealloc?: number | null
+  stats_reset?: string | number | null
+}
+
+export interface PgStatStatementsInfoRelations {}
+
+export const PgStatStatementsInfoColumns = {
+  dealloc: 'dealloc',
+  statsReset: 'stats_reset',
+} as const
+
+export type PgStatStatementsInfoColumn = (typeof PgStatStatementsInfoColumns)[keyof typeof PgStatStatementsInfoColumns]
+
+export interface HttpResponse {
+  id: number | null
+  statusCode: number | null
+  contentType: string | null
+  headers: Json | null
+  content: string | null
+  timedOut: boolean | null
+  errorMsg: string | null
+  created: string
+}
+
+export interface HttpResponseInsert {
+  id?: number | null
+  status_code?: number | null
+  content_type?: string | null
+  headers?: Json | null
+  content?: string | null
+  timed_out?: boolean | null
+  error_msg?: string | null
+  created?: string | number
+}
+
+export interface HttpResponseUpdate {
+  id?: number | null
+  status_code?: number | null
+  content_type?: string | null
+  headers?: Json | null
+  content?: string | null
+  timed_out?: boolean | null
+  error_msg?: string | null
+  created?: string | number
+}
+
+export interface HttpResponseRelations {}
+
+export const HttpResponseColumns = {
+  id: 'id',
+  statusCode: 'status_code',
+  contentType: 'content_type',
+  headers: 'headers',
+  content: 'content',
+  timedOut: 'timed_out',
+  errorMsg: 'error_msg',
+  created: 'created',
+} as const
+
+export type HttpResponseColumn = (typeof HttpResponseColumns)[keyof typeof HttpResponseColumns]
+
+export interface HttpRequestQueue {
+  id: number
+  method: string
+  url: string
+  headers: Json
+  body: unknown | null
+  timeoutMilliseconds: number
+}
+
+export interface HttpRequestQueueInsert {
+  id?: number
+  method: string
+  url: string
+  headers: Json
+  body?: unknown | null
+  timeout_milliseconds: number
+}
+
+export interface HttpRequestQueueUpdate {
+  id?: number
+  method?: string
+  url?: string
+  headers?: Json
+  body?: unknown | null
+  timeout_milliseconds?: number
+}
+
+export interface HttpRequestQueueRelations {}
+
+export const HttpRequestQueueColumns = {
+  id: 'id',
+  method: 'method',
+  url: 'url',
+  headers: 'headers',
+  body: 'body',
+  timeoutMilliseconds: 'timeout_milliseconds',
+} as const
+
+export type HttpRequestQueueColumn = (typeof HttpRequestQueueColumns)[keyof typeof HttpRequestQueueColumns]
+
+export interface DecryptedKey {
+  id: string | null
+  status: string | null
+  created: string | null
+  expires: string | null
+  keyType: string | null
+  keyId: number | null
+  keyContext: unknown | null
+  name: string | null
+  associatedData: string | null
+  rawKey: unknown | null
+  decryptedRawKey: unknown | null
+  rawKeyNonce: unknown | null
+  parentKey: string | null
+  comment: string | null
+}
+
+export interface DecryptedKeyInsert {
+  id?: string | null
+  status?: string | null
+  created?: string | number | null
+  expires?: string | number | null
+  key_type?: string | null
+  key_id?: number | null
+  key_context?: unknown | null
+  name?: string | null
+  associated_data?: string | null
+  raw_key?: unknown | null
+  decrypted_raw_key?: unknown | null
+  raw_key_nonce?: unknown | null
+  parent_key?: string | null
+  comment?: string | null
+}
+
+export interface DecryptedKeyUpdate {
+  id?: string | null
+  status?: string | null
+  created?: string | number | null
+  expires?: string | number | null
+  key_type?: string | null
+  key_id?: number | null
+  key_context?: unknown | null
+  name?: string | null
+  associated_data?: string | null
+  raw_key?: unknown | null
+  decrypted_raw_key?: unknown | null
+  raw_key_nonce?: unknown | null
+  parent_key?: string | null
+  comment?: string | null
+}
+
+export interface DecryptedKeyRelations {}
+
+export const DecryptedKeyColumns = {
+  id: 'id',
+  status: 'status',
+  created: 'created',
+  expires: 'expires',
+  keyType: 'key_type',
+  keyId: 'key_id',
+  keyContext: 'key_context',
+  name: 'name',
+  associatedData: 'associated_data',
+  rawKey: 'raw_key',
+  decryptedRawKey: 'decrypted_raw_key',
+  rawKeyNonce: 'raw_key_nonce',
+  parentKey: 'parent_key',
+  comment: 'comment',
+} as const
+
+export type DecryptedKeyColumn = (typeof DecryptedKeyColumns)[keyof typeof DecryptedKeyColumns]
+
+export interface Key {
+  id: string
+  status: string | null
+  created: string
+  expires: string | null
+  keyType: string | null
+  keyId: number | null
+  keyContext: unknown | null
+  name: string | null
+  associatedData: string | null
+  rawKey: unknown | null
+  rawKeyNonce: unknown | null
+  parentKey: string | null
+  comment: string | null
+  userData: string | null
+}
+
+export interface KeyInsert {
+  id?: string
+  status?: string | null
+  created?: string | number
+  expires?: string | number | null
+  key_type?: string | null
+  key_id?: number | null
+  key_context?: unknown | null
+  name?: string | null
+  associated_data?: string | null
+  raw_key?: unknown | null
+  raw_key_nonce?: unknown | null
+  parent_key?: string | null
+  comment?: string | null
+  user_data?: string | null
+}
+
+export interface KeyUpdate {
+  id?: string
+  status?: string | null
+  created?: string | number
+  expires?: string | number | null
+  key_type?: string | null
+  key_id?: number | null
+  key_context?: unknown | null
+  name?: string | null
+  associated_data?: string | null
+  raw_key?: unknown | null
+  raw_key_nonce?: unknown | null
+  parent_key?: string | null
+  comment?: string | null
+  user_data?: string | null
+}
+
+export interface KeyRelations {
+  parentKey: Key | null
+}
+
+export const KeyColumns = {
+  id: 'id',
+  status: 'status',
+  created: 'created',
+  expires: 'expires',
+  keyType: 'key_type',
+  keyId: 'key_id',
+  keyContext: 'key_context',
+  name: 'name',
+  associatedData: 'associated_data',
+  rawKey: 'raw_key',
+  rawKeyNonce: 'raw_key_nonce',
+  parentKey: 'parent_key',
+  comment: 'comment',
+  userData: 'user_data',
+} as const
+
+export type KeyColumn = (typeof KeyColumns)[keyof typeof KeyColumns]
+
+export interface MaskColumns {
+  attname: string | null
+  attrelid: string | null
+  keyId: string | null
+  keyIdColumn: string | null
+  associatedColumns: string | null
+  nonceColumn: string | null
+  formatType: string | null
+}
+
+export interface MaskColumnsInsert {
+  attname?: string | null
+  attrelid?: string | null
+  key_id?: string | null
+  key_id_column?: string | null
+  associated_columns?: string | null
+  nonce_column?: string | null
+  format_type?: string | null
+}
+
+export interface MaskColumnsUpdate {
+  attname?: string | null
+  attrelid?: string | null
+  key_id?: string | null
+  key_id_column?: string | null
+  associated_columns?: string | null
+  nonce_column?: string | null
+  format_type?: string | null
+}
+
+export interface MaskColumnsRelations {}
+
+export const MaskColumnsColumns = {
+  attname: 'attname',
+  attrelid: 'attrelid',
+  keyId: 'key_id',
+  keyIdColumn: 'key_id_column',
+  associatedColumns: 'associated_columns',
+  nonceColumn: 'nonce_column',
+  formatType: 'format_type',
+} as const
+
+export type MaskColumnsColumn = (typeof MaskColumnsColumns)[keyof typeof MaskColumnsColumns]
+
+export interface MaskingRule {
+  attrelid: string | null
+  attnum: number | null
+  relnamespace: string | null
+  relname: string | null
+  attname: string | null
+  formatType: string | null
+  colDescription: string | null
+  keyIdColumn: string | null
+  keyId: string | null
+  associatedColumns: string | null
+  nonceColumn: string | null
+  viewName: string | null
+  priority: number | null
+  securityInvoker: boolean | null
+}
+
+export interface MaskingRuleInsert {
+  attrelid?: string | null
+  attnum?: number | null
+  relnamespace?: string | null
+  relname?: string | null
+  attname?: string | null
+  format_type?: string | null
+  col_description?: string | null
+  key_id_column?: string | null
+  key_id?: string | null
+  associated_columns?: string | null
+  nonce_column?: string | null
+  view_name?: string | null
+  priority?: number | null
+  security_invoker?: boolean | null
+}
+
+export interface MaskingRuleUpdate {
+  attrelid?: string | null
+  attnum?: number | null
+  relnamespace?: string | null
+  relname?: string | null
+  attname?: string | null
+  format_type?: string | null
+  col_description?: string | null
+  key_id_column?: string | null
+  key_id?: string | null
+  associated_columns?: string | null
+  nonce_column?: string | null
+  view_name?: string | null
+  priority?: number | null
+  security_invoker?: boolean | null
+}
+
+export interface MaskingRuleRelations {}
+
+export const MaskingRuleColumns = {
+  attrelid: 'attrelid',
+  attnum: 'attnum',
+  relnamespace: 'relnamespace',
+  relname: 'relname',
+  attname: 'attname',
+  formatType: 'format_type',
+  colDescription: 'col_description',
+  keyIdColumn: 'key_id_column',
+  keyId: 'key_id',
+  associatedColumns: 'associated_columns',
+  nonceColumn: 'nonce_column',
+  viewName: 'view_name',
+  priority: 'priority',
+  securityInvoker: 'security_invoker',
+} as const
+
+export type MaskingRuleColumn = (typeof MaskingRuleColumns)[keyof typeof MaskingRuleColumns]
+
+export interface ValidKey {
+  id: string | null
+  name: string | null
+  status: string | null
+  keyType: string | null
+  keyId: number | null
+  keyContext: unknown | null
+  created: string | null
+  expires: string | null
+  associatedData: string | null
+}
+
+export interface ValidKeyInsert {
+  id?: string | null
+  name?: string | null
+  status?: string | null
+  key_type?: string | null
+  key_id?: number | null
+  key_context?: unknown | null
+  created?: string | number | null
+  expires?: string | number | null
+  associated_data?: string | null
+}
+
+export interface ValidKeyUpdate {
+  id?: string | null
+  name?: string | null
+  status?: string | null
+  key_type?: string | null
+  key_id?: number | null
+  key_context?: unknown | null
+  created?: string | number | null
+  expires?: string | number | null
+  associated_data?: string | null
+}
+
+export interface ValidKeyRelations {}
+
+export const ValidKeyColumns = {
+  id: 'id',
+  name: 'name',
+  status: 'status',
+  keyType: 'key_type',
+  keyId: 'key_id',
+  keyContext: 'key_context',
+  created: 'created',
+  expires: 'expires',
+  associatedData: 'associated_data',
+} as const
+
+export type ValidKeyColumn = (typeof ValidKeyColumns)[keyof typeof ValidKeyColumns]
+
+export interface Actions {
+  id: number
+  createdAt: string
+  name: string
+}
+
+export interface ActionsInsert {
+  id?: number
+  created_at?: string | number
+  name: string
+}
+
+export interface ActionsUpdate {
+  id?: number
+  created_at?: string | number
+  name?: string
+}
+
+export interface ActionsRelations {}
+
+export const ActionsColumns = {
+  id: 'id',
+  createdAt: 'created_at',
+  name: 'name',
+} as const
+
+export type ActionsColumn = (typeof ActionsColumns)[keyof typeof ActionsColumns]
+
+export interface Activities {
+  id: string
+  type: ActivityType
+  createdAt: string
+  note: string | null
+}
+
+export interface ActivitiesInsert {
+  id?: string
+  type: ActivityType
+  created_at?: string | number
+  note?: string | null
+}
+
+export interface ActivitiesUpdate {
+  id?: string
+  type?: ActivityType
+  created_at?: string | number
+  note?: string | null
+}
+
+export interface ActivitiesRelations {}
+
+export const ActivitiesColumns = {
+  id: 'id',
+  type: 'type',
+  createdAt: 'created_at',
+  note: 'note',
+} as const
+
+export type ActivitiesColumn = (typeof ActivitiesColumns)[keyof typeof ActivitiesColumns]
+
+export interface Categories {
+  id: number
+  createdAt: string
+  name: string
+  quantity: number | null
+}
+
+export interface CategoriesInsert {
+  id?: number
+  created_at?: string | number
+  name: string
+  quantity?: number | null
+}
+
+export interface CategoriesUpdate {
+  id?: number
+  created_at?: string | number
+  name?: string
+  quantity?: number | null
+}
+
+export interface CategoriesRelations {}
+
+export const CategoriesColumns = {
+  id: 'id',
+  createdAt: 'created_at',
+  name: 'name',
+  quantity: 'quantity',
+} as const
+
+export type CategoriesColumn = (typeof CategoriesColumns)[keyof typeof CategoriesColumns]
+
+export interface DeviceKinds {
+  createdAt: string
+  categoryId: number | null
+  name: string | null
+  meta: Json
+  image: Json | null
+  brand: string | null
+  manufacturer: string | null
+  description: string | null
+  datasheet: string | null
+  unit: string | null
+  price: string | null
+  deletedAt: string | null
+  id: string
+  allowedBorrowRoles: UserRole[] | null
+  allowedViewRoles: UserRole[] | null
+  isBorrowableLabOnly: boolean
+}
+
+export interface DeviceKindsInsert {
+  created_at?: string | number
+  category_id?: number | null
+  name?: string | null
+  meta?: Json
+  image?: Json | null
+  brand?: string | null
+  manufacturer?: string | null
+  description?: string | null
+  datasheet?: string | null
+  unit?: string | null
+  price?: string | null
+  deleted_at?: string | number | null
+  id?: string
+  allowed_borrow_roles?: UserRole[] | null
+  allowed_view_roles?: UserRole[] | null
+  is_borrowable_lab_only?: boolean
+}
+
+export interface DeviceKindsUpdate {
+  created_at?: string | number
+  category_id?: number | null
+  name?: string | null
+  meta?: Json
+  image?: Json | null
+  brand?: string | null
+  manufacturer?: string | null
+  description?: string | null
+  datasheet?: string | null
+  unit?: string | null
+  price?: string | null
+  deleted_at?: string | number | null
+  id?: string
+  allowed_borrow_roles?: UserRole[] | null
+  allowed_view_roles?: UserRole[] | null
+  is_borrowable_lab_only?: boolean
+}
+
+export interface DeviceKindsRelations {
+  categoryId: Categories | null
+}
+
+export const DeviceKindsColumns = {
+  createdAt: 'created_at',
+  categoryId: 'category_id',
+  name: 'name',
+  meta: 'meta',
+  image: 'image',
+  brand: 'brand',
+  manufacturer: 'manufacturer',
+  description: 'description',
+  datasheet: 'datasheet',
+  unit: 'unit',
+  price: 'price',
+  deletedAt: 'deleted_at',
+  id: 'id',
+  allowedBorrowRoles: 'allowed_borrow_roles',
+  allowedViewRoles: 'allowed_view_roles',
+  isBorrowableLabOnly: 'is_borrowable_lab_only',
+} as const
+
+export type DeviceKindsColumn = (typeof DeviceKindsColumns)[keyof typeof DeviceKindsColumns]
+
+export interface DeviceStatusChanges {
+  id: number
+  deviceId: string
+  fromStatus: DeviceStatus
+  toStatus: DeviceStatus
+  activityType: ActivityType
+  activityId: string | null
+  actorId: string | null
+  reason: string | null
+  changedAt: string
+}
+
+export interface DeviceStatusChangesInsert {
+  id?: number
+  device_id: string
+  from_status: DeviceStatus
+  to_status: DeviceStatus
+  activity_type: ActivityType
+  activity_id?: string | null
+  actor_id?: string | null
+  reason?: string | null
+  changed_at?: string | number
+}
+
+export interface DeviceStatusChangesUpdate {
+  id?: number
+  device_id?: string
+  from_status?: DeviceStatus
+  to_status?: DeviceStatus
+  activity_type?: ActivityType
+  activity_id?: string | null
+  actor_id?: string | null
+  reason?: string | null
+  changed_at?: string | number
+}
+
+export interface DeviceStatusChangesRelations {}
+
+export const DeviceStatusChangesColumns = {
+  id: 'id',
+  deviceId: 'device_id',
+  fromStatus: 'from_status',
+  toStatus: 'to_status',
+  activityType: 'activity_type',
+  activityId: 'activity_id',
+  actorId: 'actor_id',
+  reason: 'reason',
+  changedAt: 'changed_at',
+} as const
+
+export type DeviceStatusChangesColumn = (typeof DeviceStatusChangesColumns)[keyof typeof DeviceStatusChangesColumns]
+
+export interface Devices {
+  createdAt: string
+  kind: string
+  labId: string | null
+  deletedAt: string | null
+  status: DeviceStatus
+  id: string
+  fullId: string
+  printedAt: string | null
+  accessoryForKindId: string | null
+}
+
+export interface DevicesInsert {
+  created_at?: string | number
+  kind: string
+  lab_id?: string | null
+  deleted_at?: string | number | null
+  status?: DeviceStatus
+  id?: string
+  full_id: string
+  printed_at?: string | number | null
+  accessory_for_kind_id?: string | null
+}
+
+export interface DevicesUpdate {
+  created_at?: string | number
+  kind?: string
+  lab_id?: string | null
+  deleted_at?: string | number | null
+  status?: DeviceStatus
+  id?: string
+  full_id?: string
+  printed_at?: string | number | null
+  accessory_for_kind_id?: string | null
+}
+
+export interface DevicesRelations {
+  accessoryForKindId: DeviceKinds | null
+  kind: DeviceKinds
+  labId: Labs | null
+}
+
+export const DevicesColumns = {
+  createdAt: 'created_at',
+  kind: 'kind',
+  labId: 'lab_id',
+  deletedAt: 'deleted_at',
+  status: 'status',
+  id: 'id',
+  fullId: 'full_id',
+  printedAt: 'printed_at',
+  accessoryForKindId: 'accessory_for_kind_id',
+} as const
+
+export type DevicesColumn = (typeof DevicesColumns)[keyof typeof DevicesColumns]
+
+export interface HmiCodes {
+  code: number
+  createdAt: string
+  userId: string | null
+  authToken: string | null
+  status: string
+  updatedAt: string | null
+  expiresAt: string | null
+  labId: string | null
+}
+
+export interface HmiCodesInsert {
+  code: number
+  created_at?: string | number
+  user_id?: string | null
+  auth_token?: string | null
+  status?: string
+  updated_at?: string | number | null
+  expires_at?: string | number | null
+  lab_id?: string | null
+}
+
+export interface HmiCodesUpdate {
+  code?: number
+  created_at?: string | number
+  user_id?: string | null
+  auth_token?: string | null
+  status?: string
+  updated_at?: string | number | null
+  expires_at?: string | number | null
+  lab_id?: string | null
+}
+
+export interface HmiCodesRelations {
+  labId: Labs | null
+  userId: Users | null
+}
+
+export const HmiCodesColumns = {
+  code: 'code',
+  createdAt: 'created_at',
+  userId: 'user_id',
+  authToken: 'auth_token',
+  status: 'status',
+  updatedAt: 'updated_at',
+  expiresAt: 'expires_at',
+  labId: 'lab_id',
+} as const
+
+export type HmiCodesColumn = (typeof HmiCodesColumns)[keyof typeof HmiCodesColumns]
+
+export interface InventoryAssessments {
+  id: string
+  finishedAt: string | null
+  labId: string
+  accountantId: string | null
+  status: AssessmentStatus
+}
+
+export interface InventoryAssessmentsInsert {
+  id: string
+  finished_at?: string | number | null
+  lab_id: string
+  accountant_id?: string | null
+  status?: AssessmentStatus
+}
+
+export interface InventoryAssessmentsUpdate {
+  id?: string
+  finished_at?: string | number | null
+  lab_id?: string
+  accountant_id?: string | null
+  status?: AssessmentStatus
+}
+
+export interface InventoryAssessmentsRelations {
+  accountantId: Users | null
+  id: Activities
+  labId: Labs
+}
+
+export const InventoryAssessmentsColumns = {
+  id: 'id',
+  finishedAt: 'finished_at',
+  labId: 'lab_id',
+  accountantId: 'accountant_id',
+  status: 'status',
+} as const
+
+export type InventoryAssessmentsColumn = (typeof InventoryAssessmentsColumns)[keyof typeof InventoryAssessmentsColumns]
+
+export interface InventoryAssessmentsDevices {
+  id: string
+  prevStatus: DeviceStatus | null
+  afterStatus: DeviceStatus | null
+  assessingId: string | null
+  deviceId: string | null
+}
+
+export interface InventoryAssessmentsDevicesInsert {
+  id?: string
+  prev_status?: DeviceStatus | null
+  after_status?: DeviceStatus | null
+  assessing_id?: string | null
+  device_id?: string | null
+}
+
+export interface InventoryAssessmentsDevicesUpdate {
+  id?: string
+  prev_status?: DeviceStatus | null
+  after_status?: DeviceStatus | null
+  assessing_id?: string | null
+  device_id?: string | null
+}
+
+export interface InventoryAssessmentsDevicesRelations {
+  assessingId: InventoryAssessments | null
+  deviceId: Devices | null
+}
+
+export const InventoryAssessmentsDevicesColumns = {
+  id: 'id',
+  prevStatus: 'prev_status',
+  afterStatus: 'after_status',
+  assessingId: 'assessing_id',
+  deviceId: 'device_id',
+} as const
+
+export type InventoryAssessmentsDevicesColumn = (typeof InventoryAssessmentsDevicesColumns)[keyof typeof InventoryAssessmentsDevicesColumns]
+
+export interface Labs {
+  id: string
+  createdAt: string
+  name: string | null
+  faculty: string | null
+  room: string | null
+  branch: string | null
+  timetable: Json
+  adminId: string | null
+  deletedAt: string | null
+}
+
+export interface LabsInsert {
+  id?: string
+  created_at?: string | number
+  name?: string | null
+  faculty?: string | null
+  room?: string | null
+  branch?: string | null
+  timetable?: Json
+  admin_id?: string | null
+  deleted_at?: string | number | null
+}
+
+export interface LabsUpdate {
+  id?: string
+  created_at?: string | number
+  name?: string | null
+  faculty?: string | null
+  room?: string | null
+  branch?: string | null
+  timetable?: Json
+  admin_id?: string | null
+  deleted_at?: string | number | null
+}
+
+export interface LabsRelations {
+  adminId: Users | null
+}
+
+export const LabsColumns = {
+  id: 'id',
+  createdAt: 'created_at',
+  name: 'name',
+  faculty: 'faculty',
+  room: 'room',
+  branch: 'branch',
+  timetable: 'timetable',
+  adminId: 'admin_id',
+  deletedAt: 'deleted_at',
+} as const
+
+export type LabsColumn = (typeof LabsColumns)[keyof typeof LabsColumns]
+
+export interface Maintenances {
+  id: string
+  status: MaintenanceStatus
+  maintainerId: string | null
+  finishedAt: string | null
+  labId: string
+}
+
+export interface MaintenancesInsert {
+  id: string
+  status?: MaintenanceStatus
+  maintainer_id?: string | null
+  finished_at?: string | number | null
+  lab_id: string
+}
+
+export interface MaintenancesUpdate {
+  id?: string
+  status?: MaintenanceStatus
+  maintainer_id?: string | null
+  finished_at?: string | number | null
+  lab_id?: string
+}
+
+export interface MaintenancesRelations {
+  id: Activities
+  labId: Labs
+  maintainerId: Users | null
+}
+
+export const MaintenancesColumns = {
+  id: 'id',
+  status: 'status',
+  maintainerId: 'maintainer_id',
+  finishedAt: 'finished_at',
+  labId: 'lab_id',
+} as const
+
+export type MaintenancesColumn = (typeof MaintenancesColumns)[keyof typeof MaintenancesColumns]
+
+export interface MaintenancesDevices {
+  id: string
+  prevStatus: DeviceStatus | null
+  afterStatus: DeviceStatus | null
+  maintainingId: string
+  deviceId: string | null
+}
+
+export interface MaintenancesDevicesInsert {
+  id?: string
+  prev_status?: DeviceStatus | null
+  after_status?: DeviceStatus | null
+  maintaining_id: string
+  device_id?: string | null
+}
+
+export interface MaintenancesDevicesUpdate {
+  id?: string
+  prev_status?: DeviceStatus | null
+  after_status?: DeviceStatus | null
+  maintaining_id?: string
+  device_id?: string | null
+}
+
+export interface MaintenancesDevicesRelations {
+  deviceId: Devices | null
+  maintainingId: Maintenances
+}
+
+export const MaintenancesDevicesColumns = {
+  id: 'id',
+  prevStatus: 'prev_status',
+  afterStatus: 'after_status',
+  maintainingId: 'maintaining_id',
+  deviceId: 'device_id',
+} as const
+
+export type MaintenancesDevicesColumn = (typeof MaintenancesDevicesColumns)[keyof typeof MaintenancesDevicesColumns]
+
+export interface Permissions {
+  createdAt: string
+  roleId: number
+  resourceId: number
+  actionId: number
+  priority: number
+}
+
+export interface PermissionsInsert {
+  created_at?: string | number
+  role_id: number
+  resource_id: number
+  action_id: number
+  priority?: number
+}
+
+export interface PermissionsUpdate {
+  created_at?: string | number
+  role_id?: number
+  resource_id?: number
+  action_id?: number
+  priority?: number
+}
+
+export interface PermissionsRelations {
+  actionId: Actions
+  resourceId: Resources
+  roleId: Roles
+}
+
+export const PermissionsColumns = {
+  createdAt: 'created_at',
+  roleId: 'role_id',
+  resourceId: 'resource_id',
+  actionId: 'action_id',
+  priority: 'priority',
+} as const
+
+export type PermissionsColumn = (typeof PermissionsColumns)[keyof typeof PermissionsColumns]
+
+export interface Receipts {
+  id: string
+  actorId: string
+  checkerId: string
+  labId: string
+}
+
+export interface ReceiptsInsert {
+  id?: string
+  actor_id: string
+  checker_id: string
+  lab_id: string
+}
+
+export interface ReceiptsUpdate {
+  id?: string
+  actor_id?: string
+  checker_id?: string
+  lab_id?: string
+}
+
+export interface ReceiptsRelations {
+  actorId: Users
+  checkerId: Users
+  labId: Labs
+}
+
+export const ReceiptsColumns = {
+  id: 'id',
+  actorId: 'actor_id',
+  checkerId: 'checker_id',
+  labId: 'lab_id',
+} as const
+
+export type ReceiptsColumn = (typeof ReceiptsColumns)[keyof typeof ReceiptsColumns]
+
+export interface ReceiptsDevices {
+  borrowedReceiptId: string
+  deviceId: string
+  prevQuality: DeviceStatus | null
+  borrowId: string | null
+  returnId: string | null
+  expectedReturnedAt: string
+  id: string
+  expectedReturnedLabId: string | null
+  afterQuality: DeviceStatus | null
+  returnedReceiptId: string | null
+}
+
+export interface ReceiptsDevicesInsert {
+  borrowed_receipt_id: string
+  device_id: string
+  prev_quality?: DeviceStatus | null
+  borrow_id?: string | null
+  return_id?: string | null
+  expected_returned_at: string | number
+  id?: string
+  expected_returned_lab_id?: string | null
+  after_quality?: DeviceStatus | null
+  returned_receipt_id?: string | null
+}
+
+export interface ReceiptsDevicesUpdate {
+  borrowed_receipt_id?: string
+  device_id?: string
+  prev_quality?: DeviceStatus | null
+  borrow_id?: string | null
+  return_id?: string | null
+  expected_returned_at?: string | number
+  id?: string
+  expected_returned_lab_id?: string | null
+  after_quality?: DeviceStatus | null
+  returned_receipt_id?: string | null
+}
+
+export interface ReceiptsDevicesRelations {
+  borrowId: Activities | null
+  borrowedReceiptId: Receipts
+  deviceId: Devices
+  expectedReturnedLabId: Labs | null
+  returnId: Activities | null
+  returnedReceiptId: Receipts | null
+}
+
+export const ReceiptsDevicesColumns = {
+  borrowedReceiptId: 'borrowed_receipt_id',
+  deviceId: 'device_id',
+  prevQuality: 'prev_quality',
+  borrowId: 'borrow_id',
+  returnId: 'return_id',
+  expectedReturnedAt: 'expected_returned_at',
+  id: 'id',
+  expectedReturnedLabId: 'expected_returned_lab_id',
+  afterQuality: 'after_quality',
+  returnedReceiptId: 'returned_receipt_id',
+} as const
+
+export type ReceiptsDevicesColumn = (typeof ReceiptsDevicesColumns)[keyof typeof ReceiptsDevicesColumns]
+
+export interface Resources {
+  id: number
+  createdAt: string
+  name: string
+  type: string | null
+}
+
+export interface ResourcesInsert {
+  id?: number
+  created_at?: string | number
+  name: string
+  type?: string | null
+}
+
+export interface ResourcesUpdate {
+  id?: number
+  created_at?: string | number
+  name?: string
+  type?: string | null
+}
+
+export interface ResourcesRelations {}
+
+export const ResourcesColumns = {
+  id: 'id',
+  createdAt: 'created_at',
+  name: 'name',
+  type: 'type',
+} as const
+
+export type ResourcesColumn = (typeof ResourcesColumns)[keyof typeof ResourcesColumns]
+
+export interface RoleHistories {
+  createdAt: string
+  granteeId: string
+  granterId: string
+  permissions: Json | null
+  effectiveStart: string
+  effectiveEnd: string
+}
+
+export interface RoleHistoriesInsert {
+  created_at?: string | number
+  grantee_id: string
+  granter_id: string
+  permissions?: Json | null
+  effective_start: string | number
+  effective_end: string | number
+}
+
+export interface RoleHistoriesUpdate {
+  created_at?: string | number
+  grantee_id?: string
+  granter_id?: string
+  permissions?: Json | null
+  effective_start?: string | number
+  effective_end?: string | number
+}
+
+export interface RoleHistoriesRelations {
+  granteeId: Users
+  granterId: Users
+}
+
+export const RoleHistoriesColumns = {
+  createdAt: 'created_at',
+  granteeId: 'grantee_id',
+  granterId: 'granter_id',
+  permissions: 'permissions',
+  effectiveStart: 'effective_start',
+  effectiveEnd: 'effective_end',
+} as const
+
+export type RoleHistoriesColumn = (typeof RoleHistoriesColumns)[keyof typeof RoleHistoriesColumns]
+
+export interface Roles {
+  id: number
+  createdAt: string
+  key: string
+  name: string | null
+}
+
+export interface RolesInsert {
+  id?: number
+  created_at?: string | number
+  key: string
+  name?: string | null
+}
+
+export interface RolesUpdate {
+  id?: number
+  created_at?: string | number
+  key?: string
+  name?: string | null
+}
+
+export interface RolesRelations {}
+
+export const RolesColumns = {
+  id: 'id',
+  createdAt: 'created_at',
+  key: 'key',
+  name: 'name',
+} as const
+
+export type RolesColumn = (typeof RolesColumns)[keyof typeof RolesColumns]
+
+export interface Shipments {
+  id: string
+  senderId: string | null
+  receiverId: string | null
+  status: ShipmentStatus
+  startLabId: string
+  arriveLabId: string
+  fromAt: string | null
+  toAt: string | null
+  checkAtDestination: boolean | null
+}
+
+export interface ShipmentsInsert {
+  id: string
+  sender_id?: string | null
+  receiver_id?: string | null
+  status?: ShipmentStatus
+  start_lab_id: string
+  arrive_lab_id: string
+  from_at?: string | null
+  to_at?: string | null
+  check_at_destination?: boolean | null
+}
+
+export interface ShipmentsUpdate {
+  id?: string
+  sender_id?: string | null
+  receiver_id?: string | null
+  status?: ShipmentStatus
+  start_lab_id?: string
+  arrive_lab_id?: string
+  from_at?: string | null
+  to_at?: string | null
+  check_at_destination?: boolean | null
+}
+
+export interface ShipmentsRelations {
+  arriveLabId: Labs
+  fromAt: Activities | null
+  receiverId: Users | null
+  senderId: Users | null
+  startLabId: Labs
+  toAt: Activities | null
+}
+
+export const ShipmentsColumns = {
+  id: 'id',
+  senderId: 'sender_id',
+  receiverId: 'receiver_id',
+  status: 'status',
+  startLabId: 'start_lab_id',
+  arriveLabId: 'arrive_lab_id',
+  fromAt: 'from_at',
+  toAt: 'to_at',
+  checkAtDestination: 'check_at_destination',
+} as const
+
+export type ShipmentsColumn = (typeof ShipmentsColumns)[keyof typeof ShipmentsColumns]
+
+export interface ShipmentsDevices {
+  id: string
+  prevStatus: DeviceStatus | null
+  afterStatus: DeviceStatus | null
+  shipmentId: string
+  deviceId: string | null
+}
+
+export interface ShipmentsDevicesInsert {
+  id?: string
+  prev_status?: DeviceStatus | null
+  after_status?: DeviceStatus | null
+  shipment_id: string
+  device_id?: string | null
+}
+
+export interface ShipmentsDevicesUpdate {
+  id?: string
+  prev_status?: DeviceStatus | null
+  after_status?: DeviceStatus | null
+  shipment_id?: string
+  device_id?: string | null
+}
+
+export interface ShipmentsDevicesRelations {
+  deviceId: Devices | null
+  shipmentId: Shipments
+}
+
+export const ShipmentsDevicesColumns = {
+  id: 'id',
+  prevStatus: 'prev_status',
+  afterStatus: 'after_status',
+  shipmentId: 'shipment_id',
+  deviceId: 'device_id',
+} as const
+
+export type ShipmentsDevicesColumn = (typeof ShipmentsDevicesColumns)[keyof typeof ShipmentsDevicesColumns]
+
+export interface UsedQrTokens {
+  id: number
+  createdAt: string
+  token: string
+  userId: string
+}
+
+export interface UsedQrTokensInsert {
+  id?: number
+  created_at?: string | number
+  token: string
+  user_id: string
+}
+
+export interface UsedQrTokensUpdate {
+  id?: number
+  created_at?: string | number
+  token?: string
+  user_id?: string
+}
+
+export interface UsedQrTokensRelations {
+  userId: Users
+}
+
+export const UsedQrTokensColumns = {
+  id: 'id',
+  createdAt: 'created_at',
+  token: 'token',
+  userId: 'user_id',
+} as const
+
+export type UsedQrTokensColumn = (typeof UsedQrTokensColumns)[keyof typeof UsedQrTokensColumns]
+
+export interface UserRoles {
+  createdAt: string
+  userId: string
+  roleId: number
+}
+
+export interface UserRolesInsert {
+  created_at?: string | number
+  user_id: string
+  role_id: number
+}
+
+export interface UserRolesUpdate {
+  created_at?: string | number
+  user_id?: string
+  role_id?: number
+}
+
+export interface UserRolesRelations {
+  roleId: Roles
+  userId: Users
+}
+
+export const UserRolesColumns = {
+  createdAt: 'created_at',
+  userId: 'user_id',
+  roleId: 'role_id',
+} as const
+
+export type UserRolesColumn = (typeof UserRolesColumns)[keyof typeof UserRolesColumns]
+
+export interface Users {
+  id: string
+  createdAt: string
+  name: string
+  meta: Json
+  email: string | null
+  password: string | null
+  image: string | null
+  tel: string | null
+  deletedAt: string | null
+  lastActiveAt: string | null
+}
+
+export interface UsersInsert {
+  id?: string
+  created_at?: string | number
+  name: string
+  meta?: Json
+  email?: string | null
+  password?: string | null
+  image?: string | null
+  tel?: string | null
+  deleted_at?: string | number | null
+  last_active_at?: string | number | null
+}
+
+export interface UsersUpdate {
+  id?: string
+  created_at?: string | number
+  name?: string
+  meta?: Json
+  email?: string | null
+  password?: string | null
+  image?: string | null
+  tel?: string | null
+  deleted_at?: string | number | null
+  last_active_at?: string | number | null
+}
+
+export interface UsersRelations {}
+
+export const UsersColumns = {
+  id: 'id',
+  createdAt: 'created_at',
+  name: 'name',
+  meta: 'meta',
+  email: 'email',
+  password: 'password',
+  image: 'image',
+  tel: 'tel',
+  deletedAt: 'deleted_at',
+  lastActiveAt: 'last_active_at',
+} as const
+
+export type UsersColumn = (typeof UsersColumns)[keyof typeof UsersColumns]
+
+export interface Messages {
+  id: number
+  topic: string
+  extension: string
+  insertedAt: string
+  updatedAt: string
+}
+
+export interface MessagesInsert {
+  id?: number
+  topic: string
+  extension: string
+  inserted_at?: string | number
+  updated_at?: string | number
+}
+
+export interface MessagesUpdate {
+  id?: number
+  topic?: string
+  extension?: string
+  inserted_at?: string | number
+  updated_at?: string | number
+}
+
+export interface MessagesRelations {}
+
+export const MessagesColumns = {
+  id: 'id',
+  topic: 'topic',
+  extension: 'extension',
+  insertedAt: 'inserted_at',
+  updatedAt: 'updated_at',
+} as const
+
+export type MessagesColumn = (typeof MessagesColumns)[keyof typeof MessagesColumns]
+
+export interface SchemaMigrations {
+  version: number
+  insertedAt: string | null
+}
+
+export interface SchemaMigrationsInsert {
+  version: number
+  inserted_at?: string | number | null
+}
+
+export interface SchemaMigrationsUpdate {
+  version?: number
+  inserted_at?: string | number | null
+}
+
+export interface SchemaMigrationsRelations {}
+
+export const SchemaMigrationsColumns = {
+  version: 'version',
+  insertedAt: 'inserted_at',
+} as const
+
+export type SchemaMigrationsColumn = (typeof SchemaMigrationsColumns)[keyof typeof SchemaMigrationsColumns]
+
+export interface Subscription {
+  id: number
+  subscriptionId: string
+  entity: string
+  filters: string[]
+  claims: Json
+  claimsRole: string
+  createdAt: string
+}
+
+export interface SubscriptionInsert {
+  id?: number
+  subscription_id: string
+  entity: string
+  filters?: string[]
+  claims: Json
+  claims_role: string
+  created_at?: string | number
+}
+
+export interface SubscriptionUpdate {
+  id?: number
+  subscription_id?: string
+  entity?: string
+  filters?: string[]
+  claims?: Json
+  claims_role?: string
+  created_at?: string | number
+}
+
+export interface SubscriptionRelations {}
+
+export const SubscriptionColumns = {
+  id: 'id',
+  subscriptionId: 'subscription_id',
+  entity: 'entity',
+  filters: 'filters',
+  claims: 'claims',
+  claimsRole: 'claims_role',
+  createdAt: 'created_at',
+} as const
+
+export type SubscriptionColumn = (typeof SubscriptionColumns)[keyof typeof SubscriptionColumns]
+
+export interface Buckets {
+  id: string
+  name: string
+  owner: string | null
+  createdAt: string | null
+  updatedAt: string | null
+  public: boolean | null
   avifAutodetection: boolean | null
   fileSizeLimit: number | null
   allowedMimeTypes: string[] | null
   ownerId: string | null
 }
 
-export interface ShipmentsDevices {
+export interface BucketsInsert {
   id: string
-  prevStatus: DeviceStatus | null
-  afterStatus: DeviceStatus | null
-  shipmentId: string
-  deviceId: string | null
+  name: string
+  owner?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  public?: boolean | null
+  avif_autodetection?: boolean | null
+  file_size_limit?: number | null
+  allowed_mime_types?: string[] | null
+  owner_id?: string | null
 }
 
-export interface Labs {
+export interface BucketsUpdate {
+  id?: string
+  name?: string
+  owner?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  public?: boolean | null
+  avif_autodetection?: boolean | null
+  file_size_limit?: number | null
+  allowed_mime_types?: string[] | null
+  owner_id?: string | null
+}
+
+export interface BucketsRelations {}
+
+export const BucketsColumns = {
+  id: 'id',
+  name: 'name',
+  owner: 'owner',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  public: 'public',
+  avifAutodetection: 'avif_autodetection',
+  fileSizeLimit: 'file_size_limit',
+  allowedMimeTypes: 'allowed_mime_types',
+  ownerId: 'owner_id',
+} as const
+
+export type BucketsColumn = (typeof BucketsColumns)[keyof typeof BucketsColumns]
+
+export interface Migrations_Storage {
+  id: number
+  name: string
+  hash: string
+  executedAt: string | null
+}
+
+export interface Migrations_StorageInsert {
+  id: number
+  name: string
+  hash: string
+  executed_at?: string | number | null
+}
+
+export interface Migrations_StorageUpdate {
+  id?: number
+  name?: string
+  hash?: string
+  executed_at?: string | number | null
+}
+
+export interface Migrations_StorageRelations {}
+
+export const Migrations_StorageColumns = {
+  id: 'id',
+  name: 'name',
+  hash: 'hash',
+  executedAt: 'executed_at',
+} as const
+
+export type Migrations_StorageColumn = (typeof Migrations_StorageColumns)[keyof typeof Migrations_StorageColumns]
+
+export interface Objects {
   id: string
-  createdAt: Date
+  bucketId: string | null
   name: string | null
-  faculty: string | null
-  room: string | null
-  branch: string | null
-  timetable: any
-  adminId: string | null
-  deletedAt: Date | null
+  owner: string | null
+  createdAt: string | null
+  updatedAt: string | null
+  lastAccessedAt: string | null
+  metadata: Json | null
+  pathTokens: string[] | null
+  version: string | null
+  ownerId: string | null
+  userMetadata: Json | null
 }
 
-export interface SchemaMigrations_Realtime {
-  version: number
-  insertedAt: Date | null
+export interface ObjectsInsert {
+  id?: string
+  bucket_id?: string | null
+  name?: string | null
+  owner?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  last_accessed_at?: string | number | null
+  metadata?: Json | null
+  version?: string | null
+  owner_id?: string | null
+  user_metadata?: Json | null
 }
 
-export interface SsoProviders {
-  id: string
-  resourceId: string | null
-  createdAt: Date | null
-  updatedAt: Date | null
+export interface ObjectsUpdate {
+  id?: string
+  bucket_id?: string | null
+  name?: string | null
+  owner?: string | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+  last_accessed_at?: string | number | null
+  metadata?: Json | null
+  version?: string | null
+  owner_id?: string | null
+  user_metadata?: Json | null
 }
 
-export interface InventoryAssessmentsDevices {
+export interface ObjectsRelations {
+  bucketId: Buckets | null
+}
+
+export const ObjectsColumns = {
+  id: 'id',
+  bucketId: 'bucket_id',
+  name: 'name',
+  owner: 'owner',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+  lastAccessedAt: 'last_accessed_at',
+  metadata: 'metadata',
+  pathTokens: 'path_tokens',
+  version: 'version',
+  ownerId: 'owner_id',
+  userMetadata: 'user_metadata',
+} as const
+
+export type ObjectsColumn = (typeof ObjectsColumns)[keyof typeof ObjectsColumns]
+
+export interface S3MultipartUploads {
   id: string
-  prevStatus: DeviceStatus | null
-  afterStatus: DeviceStatus | null
-  assessingId: string | null
-  deviceId: string | null
+  inProgressSize: number
+  uploadSignature: string
+  bucketId: string
+  key: string
+  version: string
+  ownerId: string | null
+  createdAt: string
+  userMetadata: Json | null
 }
 
-export interface Receipts {
+export interface S3MultipartUploadsInsert {
   id: string
-  actorId: string
-  checkerId: string
-  labId: string
+  in_progress_size?: number
+  upload_signature: string
+  bucket_id: string
+  key: string
+  version: string
+  owner_id?: string | null
+  created_at?: string | number
+  user_metadata?: Json | null
 }
 
-export interface Maintenances {
+export interface S3MultipartUploadsUpdate {
+  id?: string
+  in_progress_size?: number
+  upload_signature?: string
+  bucket_id?: string
+  key?: string
+  version?: string
+  owner_id?: string | null
+  created_at?: string | number
+  user_metadata?: Json | null
+}
+
+export interface S3MultipartUploadsRelations {
+  bucketId: Buckets
+}
+
+export const S3MultipartUploadsColumns = {
+  id: 'id',
+  inProgressSize: 'in_progress_size',
+  uploadSignature: 'upload_signature',
+  bucketId: 'bucket_id',
+  key: 'key',
+  version: 'version',
+  ownerId: 'owner_id',
+  createdAt: 'created_at',
+  userMetadata: 'user_metadata',
+} as const
+
+export type S3MultipartUploadsColumn = (typeof S3MultipartUploadsColumns)[keyof typeof S3MultipartUploadsColumns]
+
+export interface S3MultipartUploadsParts {
   id: string
-  status: MaintenanceStatus
-  maintainerId: string | null
-  finishedAt: Date | null
-  labId: string
+  uploadId: string
+  size: number
+  partNumber: number
+  bucketId: string
+  key: string
+  etag: string
+  ownerId: string | null
+  version: string
+  createdAt: string
 }
 
-export interface Messages {
+export interface S3MultipartUploadsPartsInsert {
+  id?: string
+  upload_id: string
+  size?: number
+  part_number: number
+  bucket_id: string
+  key: string
+  etag: string
+  owner_id?: string | null
+  version: string
+  created_at?: string | number
+}
+
+export interface S3MultipartUploadsPartsUpdate {
+  id?: string
+  upload_id?: string
+  size?: number
+  part_number?: number
+  bucket_id?: string
+  key?: string
+  etag?: string
+  owner_id?: string | null
+  version?: string
+  created_at?: string | number
+}
+
+export interface S3MultipartUploadsPartsRelations {
+  bucketId: Buckets
+  uploadId: S3MultipartUploads
+}
+
+export const S3MultipartUploadsPartsColumns = {
+  id: 'id',
+  uploadId: 'upload_id',
+  size: 'size',
+  partNumber: 'part_number',
+  bucketId: 'bucket_id',
+  key: 'key',
+  etag: 'etag',
+  ownerId: 'owner_id',
+  version: 'version',
+  createdAt: 'created_at',
+} as const
+
+export type S3MultipartUploadsPartsColumn = (typeof S3MultipartUploadsPartsColumns)[keyof typeof S3MultipartUploadsPartsColumns]
+
+export interface Hooks {
   id: number
-  topic: string
-  extension: string
-  insertedAt: Date
-  updatedAt: Date
+  hookTableId: number
+  hookName: string
+  createdAt: string
+  requestId: number | null
+}
+
+export interface HooksInsert {
+  id?: number
+  hook_table_id: number
+  hook_name: string
+  created_at?: string | number
+  request_id?: number | null
+}
+
+export interface HooksUpdate {
+  id?: number
+  hook_table_id?: number
+  hook_name?: string
+  created_at?: string | number
+  request_id?: number | null
+}
+
+export interface HooksRelations {}
+
+export const HooksColumns = {
+  id: 'id',
+  hookTableId: 'hook_table_id',
+  hookName: 'hook_name',
+  createdAt: 'created_at',
+  requestId: 'request_id',
+} as const
+
+export type HooksColumn = (typeof HooksColumns)[keyof typeof HooksColumns]
+
+export interface Migrations {
+  version: string
+  insertedAt: string
+}
+
+export interface MigrationsInsert {
+  version: string
+  inserted_at?: string | number
+}
+
+export interface MigrationsUpdate {
+  version?: string
+  inserted_at?: string | number
+}
+
+export interface MigrationsRelations {}
+
+export const MigrationsColumns = {
+  version: 'version',
+  insertedAt: 'inserted_at',
+} as const
+
+export type MigrationsColumn = (typeof MigrationsColumns)[keyof typeof MigrationsColumns]
+
+export interface DecryptedSecrets {
+  id: string | null
+  name: string | null
+  description: string | null
+  secret: string | null
+  decryptedSecret: string | null
+  keyId: string | null
+  nonce: unknown | null
+  createdAt: string | null
+  updatedAt: string | null
+}
+
+export interface DecryptedSecretsInsert {
+  id?: string | null
+  name?: string | null
+  description?: string | null
+  secret?: string | null
+  decrypted_secret?: string | null
+  key_id?: string | null
+  nonce?: unknown | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+}
+
+export interface DecryptedSecretsUpdate {
+  id?: string | null
+  name?: string | null
+  description?: string | null
+  secret?: string | null
+  decrypted_secret?: string | null
+  key_id?: string | null
+  nonce?: unknown | null
+  created_at?: string | number | null
+  updated_at?: string | number | null
+}
+
+export interface DecryptedSecretsRelations {}
+
+export const DecryptedSecretsColumns = {
+  id: 'id',
+  name: 'name',
+  description: 'description',
+  secret: 'secret',
+  decryptedSecret: 'decrypted_secret',
+  keyId: 'key_id',
+  nonce: 'nonce',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+} as const
+
+export type DecryptedSecretsColumn = (typeof DecryptedSecretsColumns)[keyof typeof DecryptedSecretsColumns]
+
+export interface Secrets {
+  id: string
+  name: string | null
+  description: string
+  secret: string
+  keyId: string | null
+  nonce: unknown | null
+  createdAt: string
+  updatedAt: string
+}
+
+export interface SecretsInsert {
+  id?: string
+  name?: string | null
+  description?: string
+  secret: string
+  key_id?: string | null
+  nonce?: unknown | null
+  created_at?: string | number
+  updated_at?: string | number
 }
 
+export interface SecretsUpdate {
+  id?: string
+  name?: string | null
+  description?: string
+  secret?: string
+  key_id?: string | null
+  nonce?: unknown | null
+  created_at?: string | number
+  updated_at?: string | number
+}
+
+export interface SecretsRelations {
+  keyId: Key | null
+}
+
+export const SecretsColumns = {
+  id: 'id',
+  name: 'name',
+  description: 'description',
+  secret: 'secret',
+  keyId: 'key_id',
+  nonce: 'nonce',
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+} as const
+
+export type SecretsColumn = (typeof SecretsColumns)[keyof typeof SecretsColumns]
+
+export type TableName =
+  | '_http_response'
+  | '_realtime.extensions'
+  | '_realtime.schema_migrations'
+  | '_realtime.tenants'
+  | 'actions'
+  | 'activities'
+  | 'audit_log_entries'
+  | 'auth.audit_log_entries'
+  | 'auth.flow_state'
+  | 'auth.identities'
+  | 'auth.instances'
+  | 'auth.mfa_amr_claims'
+  | 'auth.mfa_challenges'
+  | 'auth.mfa_factors'
+  | 'auth.one_time_tokens'
+  | 'auth.refresh_tokens'
+  | 'auth.saml_providers'
+  | 'auth.saml_relay_states'
+  | 'auth.schema_migrations'
+  | 'auth.sessions'
+  | 'auth.sso_domains'
+  | 'auth.sso_providers'
+  | 'auth.users'
+  | 'buckets'
+  | 'categories'
+  | 'decrypted_key'
+  | 'decrypted_secrets'
+  | 'device_kinds'
+  | 'device_status_changes'
+  | 'devices'
+  | 'extensions'
+  | 'extensions.pg_stat_statements'
+  | 'extensions.pg_stat_statements_info'
+  | 'flow_state'
+  | 'hmi_codes'
+  | 'hooks'
+  | 'http_request_queue'
+  | 'identities'
+  | 'instances'
+  | 'inventory_assessments'
+  | 'inventory_assessments_devices'
+  | 'key'
+  | 'labs'
+  | 'maintenances'
+  | 'maintenances_devices'
+  | 'mask_columns'
+  | 'masking_rule'
+  | 'messages'
+  | 'mfa_amr_claims'
+  | 'mfa_challenges'
+  | 'mfa_factors'
+  | 'migrations'
+  | 'net._http_response'
+  | 'net.http_request_queue'
+  | 'objects'
+  | 'one_time_tokens'
+  | 'permissions'
+  | 'pg_stat_statements'
+  | 'pg_stat_statements_info'
+  | 'pgsodium.decrypted_key'
+  | 'pgsodium.key'
+  | 'pgsodium.mask_columns'
+  | 'pgsodium.masking_rule'
+  | 'pgsodium.valid_key'
+  | 'public.actions'
+  | 'public.activities'
+  | 'public.categories'
+  | 'public.device_kinds'
+  | 'public.device_status_changes'
+  | 'public.devices'
+  | 'public.hmi_codes'
+  | 'public.inventory_assessments'
+  | 'public.inventory_assessments_devices'
+  | 'public.labs'
+  | 'public.maintenances'
+  | 'public.maintenances_devices'
+  | 'public.permissions'
+  | 'public.receipts'
+  | 'public.receipts_devices'
+  | 'public.resources'
+  | 'public.role_histories'
+  | 'public.roles'
+  | 'public.shipments'
+  | 'public.shipments_devices'
+  | 'public.used_qr_tokens'
+  | 'public.user_roles'
+  | 'public.users'
+  | 'realtime.messages'
+  | 'realtime.schema_migrations'
+  | 'realtime.subscription'
+  | 'receipts'
+  | 'receipts_devices'
+  | 'refresh_tokens'
+  | 'resources'
+  | 'role_histories'
+  | 'roles'
+  | 's3_multipart_uploads'
+  | 's3_multipart_uploads_parts'
+  | 'saml_providers'
+  | 'saml_relay_states'
+  | 'schema_migrations'
+  | 'secrets'
+  | 'sessions'
+  | 'shipments'
+  | 'shipments_devices'
+  | 'sso_domains'
+  | 'sso_providers'
+  | 'storage.buckets'
+  | 'storage.migrations'
+  | 'storage.objects'
+  | 'storage.s3_multipart_uploads'
+  | 'storage.s3_multipart_uploads_parts'
+  | 'subscription'
+  | 'supabase_functions.hooks'
+  | 'supabase_functions.migrations'
+  | 'tenants'
+  | 'used_qr_tokens'
+  | 'user_roles'
+  | 'users'
+  | 'valid_key'
+  | 'vault.decrypted_secrets'
+  | 'vault.secrets'
+
+declare module '@/lib/db/client' {
+  interface DatabaseTables {
+    '_http_response': {
+      Row: HttpResponse
+      Insert: HttpResponseInsert
+      Update: HttpResponseUpdate
+      Relations: HttpResponseRelations
+      Column: HttpResponseColumn
+    }
+    '_realtime.extensions': {
+      Row: Extensions
+      Insert: ExtensionsInsert
+      Update: ExtensionsUpdate
+      Relations: ExtensionsRelations
+      Column: ExtensionsColumn
+    }
+    '_realtime.schema_migrations': {
+      Row: SchemaMigrations_Realtime
+      Insert: SchemaMigrations_RealtimeInsert
+      Update: SchemaMigrations_RealtimeUpdate
+      Relations: SchemaMigrations_RealtimeRelations
+      Column: SchemaMigrations_RealtimeColumn
+    }
+    '_realtime.tenants': {
+      Row: Tenants
+      Insert: TenantsInsert
+      Update: TenantsUpdate
+      Relations: TenantsRelations
+      Column: TenantsColumn
+    }
+    'actions': {
+      Row: Actions
+      Insert: ActionsInsert
+      Update: ActionsUpdate
+      Relations: ActionsRelations
+      Column: ActionsColumn
+    }
+    'activities': {
+      Row: Activities
+      Insert: ActivitiesInsert
+      Update: ActivitiesUpdate
+      Relations: ActivitiesRelations
+      Column: ActivitiesColumn
+    }
+    'audit_log_entries': {
+      Row: AuditLogEntries
+      Insert: AuditLogEntriesInsert
+      Update: AuditLogEntriesUpdate
+      Relations: AuditLogEntriesRelations
+      Column: AuditLogEntriesColumn
+    }
+    'auth.audit_log_entries': {
+      Row: AuditLogEntries
+      Insert: AuditLogEntriesInsert
+      Update: AuditLogEntriesUpdate
+      Relations: AuditLogEntriesRelations
+      Column: AuditLogEntriesColumn
+    }
+    'auth.flow_state': {
+      Row: FlowState
+      Insert: FlowStateInsert
+      Update: FlowStateUpdate
+      Relations: FlowStateRelations
+      Column: FlowStateColumn
+    }
+    'auth.identities': {
+      Row: Identities
+      Insert: IdentitiesInsert
+      Update: IdentitiesUpdate
+      Relations: IdentitiesRelations
+      Column: IdentitiesColumn
+    }
+    'auth.instances': {
+      Row: Instances
+      Insert: InstancesInsert
+      Update: InstancesUpdate
+      Relations: InstancesRelations
+      Column: InstancesColumn
+    }
+    'auth.mfa_amr_claims': {
+      Row: MfaAmrClaims
+      Insert: MfaAmrClaimsInsert
+      Update: MfaAmrClaimsUpdate
+      Relations: MfaAmrClaimsRelations
+      Column: MfaAmrClaimsColumn
+    }
+    'auth.mfa_challenges': {
+      Row: MfaChallenges
+      Insert: MfaChallengesInsert
+      Update: MfaChallengesUpdate
+      Relations: MfaChallengesRelations
+      Column: MfaChallengesColumn
+    }
+    'auth.mfa_factors': {
+      Row: MfaFactors
+      Insert: MfaFactorsInsert
+      Update: MfaFactorsUpdate
+      Relations: MfaFactorsRelations
+      Column: MfaFactorsColumn
+    }
+    'auth.one_time_tokens': {
+      Row: OneTimeTokens
+      Insert: OneTimeTokensInsert
+      Update: OneTimeTokensUpdate
+      Relations: OneTimeTokensRelations
+      Column: OneTimeTokensColumn
+    }
+    'auth.refresh_tokens': {
+      Row: RefreshTokens
+      Insert: RefreshTokensInsert
+      Update: RefreshTokensUpdate
+      Relations: RefreshTokensRelations
+      Column: RefreshTokensColumn
+    }
+    'auth.saml_providers': {
+      Row: SamlProviders
+      Insert: SamlProvidersInsert
+      Update: SamlProvidersUpdate
+      Relations: SamlProvidersRelations
+      Column: SamlProvidersColumn
+    }
+    'auth.saml_relay_states': {
+      Row: SamlRelayStates
+      Insert: SamlRelayStatesInsert
+      Update: SamlRelayStatesUpdate
+      Relations: SamlRelayStatesRelations
+      Column: SamlRelayStatesColumn
+    }
+    'auth.schema_migrations': {
+      Row: SchemaMigrations_Auth
+      Insert: SchemaMigrations_AuthInsert
+      Update: SchemaMigrations_AuthUpdate
+      Relations: SchemaMigrations_AuthRelations
+      Column: SchemaMigrations_AuthColumn
+    }
+    'auth.sessions': {
+      Row: Sessions
+      Insert: SessionsInsert
+      Update: SessionsUpdate
+      Relations: SessionsRelations
+      Column: SessionsColumn
+    }
+    'auth.sso_domains': {
+      Row: SsoDomains
+      Insert: SsoDomainsInsert
+      Update: SsoDomainsUpdate
+      Relations: SsoDomainsRelations
+      Column: SsoDomainsColumn
+    }
+    'auth.sso_providers': {
+      Row: SsoProviders
+      Insert: SsoProvidersInsert
+      Update: SsoProvidersUpdate
+      Relations: SsoProvidersRelations
+      Column: SsoProvidersColumn
+    }
+    'auth.users': {
+      Row: Users_Auth
+      Insert: Users_AuthInsert
+      Update: Users_AuthUpdate
+      Relations: Users_AuthRelations
+      Column: Users_AuthColumn
+    }
+    'buckets': {
+      Row: Buckets
+      Insert: BucketsInsert
+      Update: BucketsUpdate
+      Relations: BucketsRelations
+      Column: BucketsColumn
+    }
+    'categories': {
+      Row: Categories
+      Insert: CategoriesInsert
+      Update: CategoriesUpdate
+      Relations: CategoriesRelations
+      Column: CategoriesColumn
+    }
+    'decrypted_key': {
+      Row: DecryptedKey
+      Insert: DecryptedKeyInsert
+      Update: DecryptedKeyUpdate
+      Relations: DecryptedKeyRelations
+      Column: DecryptedKeyColumn
+    }
+    'decrypted_secrets': {
+      Row: DecryptedSecrets
+      Insert: DecryptedSecretsInsert
+      Update: DecryptedSecretsUpdate
+      Relations: DecryptedSecretsRelations
+      Column: DecryptedSecretsColumn
+    }
+    'device_kinds': {
+      Row: DeviceKinds
+      Insert: DeviceKindsInsert
+      Update: DeviceKindsUpdate
+      Relations: DeviceKindsRelations
+      Column: DeviceKindsColumn
+    }
+    'device_status_changes': {
+      Row: DeviceStatusChanges
+      Insert: DeviceStatusChangesInsert
+      Update: DeviceStatusChangesUpdate
+      Relations: DeviceStatusChangesRelations
+      Column: DeviceStatusChangesColumn
+    }
+    'devices': {
+      Row: Devices
+      Insert: DevicesInsert
+      Update: DevicesUpdate
+      Relations: DevicesRelations
+      Column: DevicesColumn
+    }
+    'extensions': {
+      Row: Extensions
+      Insert: ExtensionsInsert
+      Update: ExtensionsUpdate
+      Relations: ExtensionsRelations
+      Column: ExtensionsColumn
+    }
+    'extensions.pg_stat_statements': {
+      Row: PgStatStatements
+      Insert: PgStatStatementsInsert
+      Update: PgStatStatementsUpdate
+      Relations: PgStatStatementsRelations
+      Column: PgStatStatementsColumn
+    }
+    'extensions.pg_stat_statements_info': {
+      Row: PgStatStatementsInfo
+      Insert: PgStatStatementsInfoInsert
+      Update: PgStatStatementsInfoUpdate
+      Relations: PgStatStatementsInfoRelations
+      Column: PgStatStatementsInfoColumn
+    }
+    'flow_state': {
+      Row: FlowState
+      Insert: FlowStateInsert
+      Update: FlowStateUpdate
+      Relations: FlowStateRelations
+      Column: FlowStateColumn
+    }
+    'hmi_codes': {
+      Row: HmiCodes
+      Insert: HmiCodesInsert
+      Update: HmiCodesUpdate
+      Relations: HmiCodesRelations
+      Column: HmiCodesColumn
+    }
+    'hooks': {
+      Row: Hooks
+      Insert: HooksInsert
+      Update: HooksUpdate
+      Relations: HooksRelations
+      Column: HooksColumn
+    }
+    'http_request_queue': {
+      Row: HttpRequestQueue
+      Insert: HttpRequestQueueInsert
+      Update: HttpRequestQueueUpdate
+      Relations: HttpRequestQueueRelations
+      Column: HttpRequestQueueColumn
+    }
+    'identities': {
+      Row: Identities
+      Insert: IdentitiesInsert
+      Update: IdentitiesUpdate
+      Relations: IdentitiesRelations
+      Column: IdentitiesColumn
+    }
+    'instances': {
+      Row: Instances
+      Insert: InstancesInsert
+      Update: InstancesUpdate
+      Relations: InstancesRelations
+      Column: InstancesColumn
+    }
+    'inventory_assessments': {
+      Row: InventoryAssessments
+      Insert: InventoryAssessmentsInsert
+      Update: InventoryAssessmentsUpdate
+      Relations: InventoryAssessmentsRelations
+      Column: InventoryAssessmentsColumn
+    }
+    'inventory_assessments_devices': {
+      Row: InventoryAssessmentsDevices
+      Insert: InventoryAssessmentsDevicesInsert
+      Update: InventoryAssessmentsDevicesUpdate
+      Relations: InventoryAssessmentsDevicesRelations
+      Column: InventoryAssessmentsDevicesColumn
+    }
+    'key': {
+      Row: Key
+      Insert: KeyInsert
+      Update: KeyUpdate
+      Relations: KeyRelations
+      Column: KeyColumn
+    }
+    'labs': {
+      Row: Labs
+      Insert: LabsInsert
+      Update: LabsUpdate
+      Relations: LabsRelations
+      Column: LabsColumn
+    }
+    'maintenances': {
+      Row: Maintenances
+      Insert: MaintenancesInsert
+      Update: MaintenancesUpdate
+      Relations: MaintenancesRelations
+      Column: MaintenancesColumn
+    }
+    'maintenances_devices': {
+      Row: MaintenancesDevices
+      Insert: MaintenancesDevicesInsert
+      Update: MaintenancesDevicesUpdate
+      Relations: MaintenancesDevicesRelations
+      Column: MaintenancesDevicesColumn
+    }
+    'mask_columns': {
+      Row: MaskColumns
+      Insert: MaskColumnsInsert
+      Update: MaskColumnsUpdate
+      Relations: MaskColumnsRelations
+      Column: MaskColumnsColumn
+    }
+    'masking_rule': {
+      Row: MaskingRule
+      Insert: MaskingRuleInsert
+      Update: MaskingRuleUpdate
+      Relations: MaskingRuleRelations
+      Column: MaskingRuleColumn
+    }
+    'messages': {
+      Row: Messages
+      Insert: MessagesInsert
+      Update: MessagesUpdate
+      Relations: MessagesRelations
+      Column: MessagesColumn
+    }
+    'mfa_amr_claims': {
+      Row: MfaAmrClaims
+      Insert: MfaAmrClaimsInsert
+      Update: MfaAmrClaimsUpdate
+      Relations: MfaAmrClaimsRelations
+      Column: MfaAmrClaimsColumn
+    }
+    'mfa_challenges': {
+      Row: MfaChallenges
+      Insert: MfaChallengesInsert
+      Update: MfaChallengesUpdate
+      Relations: MfaChallengesRelations
+      Column: MfaChallengesColumn
+    }
+    'mfa_factors': {
+      Row: MfaFactors
+      Insert: MfaFactorsInsert
+      Update: MfaFactorsUpdate
+      Relations: MfaFactorsRelations
+      Column: MfaFactorsColumn
+    }
+    'migrations': {
+      Row: Migrations
+      Insert: MigrationsInsert
+      Update: MigrationsUpdate
+      Relations: MigrationsRelations
+      Column: MigrationsColumn
+    }
+    'net._http_response': {
+      Row: HttpResponse
+      Insert: HttpResponseInsert
+      Update: HttpResponseUpdate
+      Relations: HttpResponseRelations
+      Column: HttpResponseColumn
+    }
+    'net.http_request_queue': {
+      Row: HttpRequestQueue
+      Insert: HttpRequestQueueInsert
+      Update: HttpRequestQueueUpdate
+      Relations: HttpRequestQueueRelations
+      Column: HttpRequestQueueColumn
+    }
+    'objects': {
+      Row: Objects
+      Insert: ObjectsInsert
+      Update: ObjectsUpdate
+      Relations: ObjectsRelations
+      Column: ObjectsColumn
+    }
+    'one_time_tokens': {
+      Row: OneTimeTokens
+      Insert: OneTimeTokensInsert
+      Update: OneTimeTokensUpdate
+      Relations: OneTimeTokensRelations
+      Column: OneTimeTokensColumn
+    }
+    'permissions': {
+      Row: Permissions
+      Insert: PermissionsInsert
+      Update: PermissionsUpdate
+      Relations: PermissionsRelations
+      Column: PermissionsColumn
+    }
+    'pg_stat_statements': {
+      Row: PgStatStatements
+      Insert: PgStatStatementsInsert
+      Update: PgStatStatementsUpdate
+      Relations: PgStatStatementsRelations
+      Column: PgStatStatementsColumn
+    }
+    'pg_stat_statements_info': {
+      Row: PgStatStatementsInfo
+      Insert: PgStatStatementsInfoInsert
+      Update: PgStatStatementsInfoUpdate
+      Relations: PgStatStatementsInfoRelations
+      Column: PgStatStatementsInfoColumn
+    }
+    'pgsodium.decrypted_key': {
+      Row: DecryptedKey
+      Insert: DecryptedKeyInsert
+      Update: DecryptedKeyUpdate
+      Relations: DecryptedKeyRelations
+      Column: DecryptedKeyColumn
+    }
+    'pgsodium.key': {
+      Row: Key
+      Insert: KeyInsert
+      Update: KeyUpdate
+      Relations: KeyRelations
+      Column: KeyColumn
+    }
+    'pgsodium.mask_columns': {
+      Row: MaskColumns
+      Insert: MaskColumnsInsert
+      Update: MaskColumnsUpdate
+      Relations: MaskColumnsRelations
+      Column: MaskColumnsColumn
+    }
+    'pgsodium.masking_rule': {
+      Row: MaskingRule
+      Insert: MaskingRuleInsert
+      Update: MaskingRuleUpdate
+      Relations: MaskingRuleRelations
+      Column: MaskingRuleColumn
+    }
+    'pgsodium.valid_key': {
+      Row: ValidKey
+      Insert: ValidKeyInsert
+      Update: ValidKeyUpdate
+      Relations: ValidKeyRelations
+      Column: ValidKeyColumn
+    }
+    'public.actions': {
+      Row: Actions
+      Insert: ActionsInsert
+      Update: ActionsUpdate
+      Relations: ActionsRelations
+      Column: ActionsColumn
+    }
+    'public.activities': {
+      Row: Activities
+      Insert: ActivitiesInsert
+      Update: ActivitiesUpdate
+      Relations: ActivitiesRelations
+      Column: ActivitiesColumn
+    }
+    'public.categories': {
+      Row: Categories
+      Insert: CategoriesInsert
+      Update: CategoriesUpdate
+      Relations: CategoriesRelations
+      Column: CategoriesColumn
+    }
+    'public.device_kinds': {
+      Row: DeviceKinds
+      Insert: DeviceKindsInsert
+      Update: DeviceKindsUpdate
+      Relations: DeviceKindsRelations
+      Column: DeviceKindsColumn
+    }
+    'public.device_status_changes': {
+      Row: DeviceStatusChanges
+      Insert: DeviceStatusChangesInsert
+      Update: DeviceStatusChangesUpdate
+      Relations: DeviceStatusChangesRelations
+      Column: DeviceStatusChangesColumn
+    }
+    'public.devices': {
+      Row: Devices
+      Insert: DevicesInsert
+      Update: DevicesUpdate
+      Relations: DevicesRelations
+      Column: DevicesColumn
+    }
+    'public.hmi_codes': {
+      Row: HmiCodes
+      Insert: HmiCodesInsert
+      Update: HmiCodesUpdate
+      Relations: HmiCodesRelations
+      Column: HmiCodesColumn
+    }
+    'public.inventory_assessments': {
+      Row: InventoryAssessments
+      Insert: InventoryAssessmentsInsert
+      Update: InventoryAssessmentsUpdate
+      Relations: InventoryAssessmentsRelations
+      Column: InventoryAssessmentsColumn
+    }
+    'public.inventory_assessments_devices': {
+      Row: InventoryAssessmentsDevices
+      Insert: InventoryAssessmentsDevicesInsert
+      Update: InventoryAssessmentsDevicesUpdate
+      Relations: InventoryAssessmentsDevicesRelations
+      Column: InventoryAssessmentsDevicesColumn
+    }
+    'public.labs': {
+      Row: Labs
+      Insert: LabsInsert
+      Update: LabsUpdate
+      Relations: LabsRelations
+      Column: LabsColumn
+    }
+    'public.maintenances': {
+      Row: Maintenances
+      Insert: MaintenancesInsert
+      Update: MaintenancesUpdate
+      Relations: MaintenancesRelations
+      Column: MaintenancesColumn
+    }
+    'public.maintenances_devices': {
+      Row: MaintenancesDevices
+      Insert: MaintenancesDevicesInsert
+      Update: MaintenancesDevicesUpdate
+      Relations: MaintenancesDevicesRelations
+      Column: MaintenancesDevicesColumn
+    }
+    'public.permissions': {
+      Row: Permissions
+      Insert: PermissionsInsert
+      Update: PermissionsUpdate
+      Relations: PermissionsRelations
+      Column: PermissionsColumn
+    }
+    'public.receipts': {
+      Row: Receipts
+      Insert: ReceiptsInsert
+      Update: ReceiptsUpdate
+      Relations: ReceiptsRelations
+      Column: ReceiptsColumn
+    }
+    'public.receipts_devices': {
+      Row: ReceiptsDevices
+      Insert: ReceiptsDevicesInsert
+      Update: ReceiptsDevicesUpdate
+      Relations: ReceiptsDevicesRelations
+      Column: ReceiptsDevicesColumn
+    }
+    'public.resources': {
+      Row: Resources
+      Insert: ResourcesInsert
+      Update: ResourcesUpdate
+      Relations: ResourcesRelations
+      Column: ResourcesColumn
+    }
+    'public.role_histories': {
+      Row: RoleHistories
+      Insert: RoleHistoriesInsert
+      Update: RoleHistoriesUpdate
+      Relations: RoleHistoriesRelations
+      Column: RoleHistoriesColumn
+    }
+    'public.roles': {
+      Row: Roles
+      Insert: RolesInsert
+      Update: RolesUpdate
+      Relations: RolesRelations
+      Column: RolesColumn
+    }
+    'public.shipments': {
+      Row: Shipments
+      Insert: ShipmentsInsert
+      Update: ShipmentsUpdate
+      Relations: ShipmentsRelations
+      Column: ShipmentsColumn
+    }
+    'public.shipments_devices': {
+      Row: ShipmentsDevices
+      Insert: ShipmentsDevicesInsert
+      Update: ShipmentsDevicesUpdate
+      Relations: ShipmentsDevicesRelations
+      Column: ShipmentsDevicesColumn
+    }
+    'public.used_qr_tokens': {
+      Row: UsedQrTokens
+      Insert: UsedQrTokensInsert
+      Update: UsedQrTokensUpdate
+      Relations: UsedQrTokensRelations
+      Column: UsedQrTokensColumn
+    }
+    'public.user_roles': {
+      Row: UserRoles
+      Insert: UserRolesInsert
+      Update: UserRolesUpdate
+      Relations: UserRolesRelations
+      Column: UserRolesColumn
+    }
+    'public.users': {
+      Row: Users
+      Insert: UsersInsert
+      Update: UsersUpdate
+      Relations: UsersRelations
+      Column: UsersColumn
+    }
+    'realtime.messages': {
+      Row: Messages
+      Insert: MessagesInsert
+      Update: MessagesUpdate
+      Relations: MessagesRelations
+      Column: MessagesColumn
+    }
+    'realtime.schema_migrations': {
+      Row: SchemaMigrations
+      Insert: SchemaMigrationsInsert
+      Update: SchemaMigrationsUpdate
+      Relations: SchemaMigrationsRelations
+      Column: SchemaMigrationsColumn
+    }
+    'realtime.subscription': {
+      Row: Subscription
+      Insert: SubscriptionInsert
+      Update: SubscriptionUpdate
+      Relations: SubscriptionRelations
+      Column: SubscriptionColumn
+    }
+    'receipts': {
+      Row: Receipts
+      Insert: ReceiptsInsert
+      Update: ReceiptsUpdate
+      Relations: ReceiptsRelations
+      Column: ReceiptsColumn
+    }
+    'receipts_devices': {
+      Row: ReceiptsDevices
+      Insert: ReceiptsDevicesInsert
+      Update: ReceiptsDevicesUpdate
+      Relations: ReceiptsDevicesRelations
+      Column: ReceiptsDevicesColumn
+    }
+    'refresh_tokens': {
+      Row: RefreshTokens
+      Insert: RefreshTokensInsert
+      Update: RefreshTokensUpdate
+      Relations: RefreshTokensRelations
+      Column: RefreshTokensColumn
+    }
+    'resources': {
+      Row: Resources
+      Insert: ResourcesInsert
+      Update: ResourcesUpdate
+      Relations: ResourcesRelations
+      Column: ResourcesColumn
+    }
+    'role_histories': {
+      Row: RoleHistories
+      Insert: RoleHistoriesInsert
+      Update: RoleHistoriesUpdate
+      Relations: RoleHistoriesRelations
+      Column: RoleHistoriesColumn
+    }
+    'roles': {
+      Row: Roles
+      Insert: RolesInsert
+      Update: RolesUpdate
+      Relations: RolesRelations
+      Column: RolesColumn
+    }
+    's3_multipart_uploads': {
+      Row: S3MultipartUploads
+      Insert: S3MultipartUploadsInsert
+      Update: S3MultipartUploadsUpdate
+      Relations: S3MultipartUploadsRelations
+      Column: S3MultipartUploadsColumn
+    }
+    's3_multipart_uploads_parts': {
+      Row: S3MultipartUploadsParts
+      Insert: S3MultipartUploadsPartsInsert
+      Update: S3MultipartUploadsPartsUpdate
+      Relations: S3MultipartUploadsPartsRelations
+      Column: S3MultipartUploadsPartsColumn
+    }
+    'saml_providers': {
+      Row: SamlProviders
+      Insert: SamlProvidersInsert
+      Update: SamlProvidersUpdate
+      Relations: SamlProvidersRelations
+      Column: SamlProvidersColumn
+    }
+    'saml_relay_states': {
+      Row: SamlRelayStates
+      Insert: SamlRelayStatesInsert
+      Update: SamlRelayStatesUpdate
+      Relations: SamlRelayStatesRelations
+      Column: SamlRelayStatesColumn
+    }
+    'schema_migrations': {
+      Row: SchemaMigrations
+      Insert: SchemaMigrationsInsert
+      Update: SchemaMigrationsUpdate
+      Relations: SchemaMigrationsRelations
+      Column: SchemaMigrationsColumn
+    }
+    'secrets': {
+      Row: Secrets
+      Insert: SecretsInsert
+      Update: SecretsUpdate
+      Relations: SecretsRelations
+      Column: SecretsColumn
+    }
+    'sessions': {
+      Row: Sessions
+      Insert: SessionsInsert
+      Update: SessionsUpdate
+      Relations: SessionsRelations
+      Column: SessionsColumn
+    }
+    'shipments': {
+      Row: Shipments
+      Insert: ShipmentsInsert
+      Update: ShipmentsUpdate
+      Relations: ShipmentsRelations
+      Column: ShipmentsColumn
+    }
+    'shipments_devices': {
+      Row: ShipmentsDevices
+      Insert: ShipmentsDevicesInsert
+      Update: ShipmentsDevicesUpdate
+      Relations: ShipmentsDevicesRelations
+      Column: ShipmentsDevicesColumn
+    }
+    'sso_domains': {
+      Row: SsoDomains
+      Insert: SsoDomainsInsert
+      Update: SsoDomainsUpdate
+      Relations: SsoDomainsRelations
+      Column: SsoDomainsColumn
+    }
+    'sso_providers': {
+      Row: SsoProviders
+      Insert: SsoProvidersInsert
+      Update: SsoProvidersUpdate
+      Relations: SsoProvidersRelations
+      Column: SsoProvidersColumn
+    }
+    'storage.buckets': {
+      Row: Buckets
+      Insert: BucketsInsert
+      Update: BucketsUpdate
+      Relations: BucketsRelations
+      Column: BucketsColumn
+    }
+    'storage.migrations': {
+      Row: Migrations_Storage
+      Insert: Migrations_StorageInsert
+      Update: Migrations_StorageUpdate
+      Relations: Migrations_StorageRelations
+      Column: Migrations_StorageColumn
+    }
+    'storage.objects': {
+      Row: Objects
+      Insert: ObjectsInsert
+      Update: ObjectsUpdate
+      Relations: ObjectsRelations
+      Column: ObjectsColumn
+    }
+    'storage.s3_multipart_uploads': {
+      Row: S3MultipartUploads
+      Insert: S3MultipartUploadsInsert
+      Update: S3MultipartUploadsUpdate
+      Relations: S3MultipartUploadsRelations
+      Column: S3MultipartUploadsColumn
+    }
+    'storage.s3_multipart_uploads_parts': {
+      Row: S3MultipartUploadsParts
+      Insert: S3MultipartUploadsPartsInsert
+      Update: S3MultipartUploadsPartsUpdate
+      Relations: S3MultipartUploadsPartsRelations
+      Column: S3MultipartUploadsPartsColumn
+    }
+    'subscription': {
+      Row: Subscription
+      Insert: SubscriptionInsert
+      Update: SubscriptionUpdate
+      Relations: SubscriptionRelations
+      Column: SubscriptionColumn
+    }
+    'supabase_functions.hooks': {
+      Row: Hooks
+      Insert: HooksInsert
+      Update: HooksUpdate
+      Relations: HooksRelations
+      Column: HooksColumn
+    }
+    'supabase_functions.migrations': {
+      Row: Migrations
+      Insert: MigrationsInsert
+      Update: MigrationsUpdate
+      Relations: MigrationsRelations
+      Column: MigrationsColumn
+    }
+    'tenants': {
+      Row: Tenants
+      Insert: TenantsInsert
+      Update: TenantsUpdate
+      Relations: TenantsRelations
+      Column: TenantsColumn
+    }
+    'used_qr_tokens': {
+      Row: UsedQrTokens
+      Insert: UsedQrTokensInsert
+      Update: UsedQrTokensUpdate
+      Relations: UsedQrTokensRelations
+      Column: UsedQrTokensColumn
+    }
+    'user_roles': {
+      Row: UserRoles
+      Insert: UserRolesInsert
+      Update: UserRolesUpdate
+      Relations: UserRolesRelations
+      Column: UserRolesColumn
+    }
+    'users': {
+      Row: Users
+      Insert: UsersInsert
+      Update: UsersUpdate
+      Relations: UsersRelations
+      Column: UsersColumn
+    }
+    'valid_key': {
+      Row: ValidKey
+      Insert: ValidKeyInsert
+      Update: ValidKeyUpdate
+      Relations: ValidKeyRelations
+      Column: ValidKeyColumn
+    }
+    'vault.decrypted_secrets': {
+      Row: DecryptedSecrets
+      Insert: DecryptedSecretsInsert
+      Update: DecryptedSecretsUpdate
+      Relations: DecryptedSecretsRelations
+      Column: DecryptedSecretsColumn
+    }
+    'vault.secrets': {
+      Row: Secrets
+      Insert: SecretsInsert
+      Update: SecretsUpdate
+      Relations: SecretsRelations
+      Column: SecretsColumn
+    }
+  }
+}
@@ -0,0 +1,11361 @@
+// This file is auto-generated. Do not edit manually.
+
+import type {
+  ActionsInsert,
+  ActionsUpdate,
+  ActivitiesInsert,
+  ActivitiesUpdate,
+  AuditLogEntriesInsert,
+  AuditLogEntriesUpdate,
+  BucketsInsert,
+  BucketsUpdate,
+  CategoriesInsert,
+  CategoriesUpdate,
+  DecryptedKeyInsert,
+  DecryptedKeyUpdate,
+  DecryptedSecretsInsert,
+  DecryptedSecretsUpdate,
+  DeviceKindsInsert,
+  DeviceKindsUpdate,
+  DeviceStatusChangesInsert,
+  DeviceStatusChangesUpdate,
+  DevicesInsert,
+  DevicesUpdate,
+  ExtensionsInsert,
+  ExtensionsUpdate,
+  FlowStateInsert,
+  FlowStateUpdate,
+  HmiCodesInsert,
+  HmiCodesUpdate,
+  HooksInsert,
+  HooksUpdate,
+  HttpRequestQueueInsert,
+  HttpRequestQueueUpdate,
+  HttpResponseInsert,
+  HttpResponseUpdate,
+  IdentitiesInsert,
+  IdentitiesUpdate,
+  InstancesInsert,
+  InstancesUpdate,
+  InventoryAssessmentsDevicesInsert,
+  InventoryAssessmentsDevicesUpdate,
+  InventoryAssessmentsInsert,
+  InventoryAssessmentsUpdate,
+  KeyInsert,
+  KeyUpdate,
+  LabsInsert,
+  LabsUpdate,
+  MaintenancesDevicesInsert,
+  MaintenancesDevicesUpdate,
+  MaintenancesInsert,
+  MaintenancesUpdate,
+  MaskColumnsInsert,
+  MaskColumnsUpdate,
+  MaskingRuleInsert,
+  MaskingRuleUpdate,
+  MessagesInsert,
+  MessagesUpdate,
+  MfaAmrClaimsInsert,
+  MfaAmrClaimsUpdate,
+  MfaChallengesInsert,
+  MfaChallengesUpdate,
+  MfaFactorsInsert,
+  MfaFactorsUpdate,
+  MigrationsInsert,
+  MigrationsUpdate,
+  Migrations_StorageInsert,
+  Migrations_StorageUpdate,
+  ObjectsInsert,
+  ObjectsUpdate,
+  OneTimeTokensInsert,
+  OneTimeTokensUpdate,
+  PermissionsInsert,
+  PermissionsUpdate,
+  PgStatStatementsInfoInsert,
+  PgStatStatementsInfoUpdate,
+  PgStatStatementsInsert,
+  PgStatStatementsUpdate,
+  ReceiptsDevicesInsert,
+  ReceiptsDevicesUpdate,
+  ReceiptsInsert,
+  ReceiptsUpdate,
+  RefreshTokensInsert,
+  RefreshTokensUpdate,
+  ResourcesInsert,
+  ResourcesUpdate,
+  RoleHistoriesInsert,
+  RoleHistoriesUpdate,
+  RolesInsert,
+  RolesUpdate,
+  S3MultipartUploadsInsert,
+  S3MultipartUploadsPartsInsert,
+  S3MultipartUploadsPartsUpdate,
+  S3MultipartUploadsUpdate,
+  SamlProvidersInsert,
+  SamlProvidersUpdate,
+  SamlRelayStatesInsert,
+  SamlRelayStatesUpdate,
+  SchemaMigrationsInsert,
+  SchemaMigrationsUpdate,
+  SchemaMigrations_AuthInsert,
+  SchemaMigrations_AuthUpdate,
+  SchemaMigrations_RealtimeInsert,
+  SchemaMigrations_RealtimeUpdate,
+  SecretsInsert,
+  SecretsUpdate,
+  SessionsInsert,
+  SessionsUpdate,
+  ShipmentsDevicesInsert,
+  ShipmentsDevicesUpdate,
+  ShipmentsInsert,
+  ShipmentsUpdate,
+  SsoDomainsInsert,
+  SsoDomainsUpdate,
+  SsoProvidersInsert,
+  SsoProvidersUpdate,
+  SubscriptionInsert,
+  SubscriptionUpdate,
+  TenantsInsert,
+  TenantsUpdate,
+  UsedQrTokensInsert,
+  UsedQrTokensUpdate,
+  UserRolesInsert,
+  UserRolesUpdate,
+  UsersInsert,
+  UsersUpdate,
+  Users_AuthInsert,
+  Users_AuthUpdate,
+  ValidKeyInsert,
+  ValidKeyUpdate,
+} from '@/types/db/generated'
+
+export interface FieldError {
+  field: string
+  message: string
+}
+
+export type ValidationResult<T> =
+  | { ok: true; value: T }
+  | { ok: false; errors: FieldError[] }
+
+export interface JsonSchema {
+  title?: string
+  type?: string | string[]
+  enum?: unknown[]
+  format?: string
+  minimum?: number
+  maximum?: number
+  anyOf?: JsonSchema[]
+  items?: JsonSchema
+  properties?: Record<string, JsonSchema>
+  required?: string[]
+  additionalProperties?: boolean
+}
+
+const UUID_PATTERN = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$/i
+const DATE_TIME_PATTERN =
+  /^\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$/
+const DATE_PATTERN = /^\d{4}-\d{2}-\d{2}$/
+
+function isObject(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null && !Array.isArray(value)
+}
+
+function isType(name: string, value: unknown): boolean {
+  switch (name) {
+    case 'null':
+      return value === null
+    case 'boolean':
+      return typeof value === 'boolean'
+    case 'string':
+      return typeof value === 'string'
+    case 'number':
+      return typeof value === 'number' && Number.isFinite(value)
+    case 'integer':
+      return Number.isInteger(value)
+    case 'array':
+      return Array.isArray(value)
+    case 'object':
+      return isObject(value)
+    default:
+      return false
+  }
+}
+
+function acceptsType(schema: JsonSchema, value: unknown): boolean {
+  if (typeof schema.type === 'string') return isType(schema.type, value)
+  if (Array.isArray(schema.type)) return schema.type.some((name) => isType(name, value))
+  if (schema.anyOf) return schema.anyOf.some((branch) => acceptsType(branch, value))
+  return true
+}
+
+function describe(schema: JsonSchema): string {
+  if (schema.anyOf) return schema.anyOf.map(describe).join(' or ')
+  if (schema.type === undefined) return 'any value'
+  const name = Array.isArray(schema.type) ? schema.type.join(' or ') : schema.type
+  return schema.format ? `${name} (${schema.format})` : name
+}
+
+function formatError(format: string, value: string): string | null {
+  switch (format) {
+    case 'uuid':
+      return UUID_PATTERN.test(value) ? null : 'must be a UUID'
+    case 'date-time':
+      return DATE_TIME_PATTERN.test(value) && !Number.isNaN(Date.parse(value))
+        ? null
+        : 'must be an RFC 3339 timestamp'
+    case 'date':
+      return DATE_PATTERN.test(value) && !Number.isNaN(Date.parse(value))
+        ? null
+        : 'must be a date (YYYY-MM-DD)'
+    default:
+      return null
+  }
+}
+
+function check(schema: JsonSchema, value: unknown, path: string, errors: FieldError[]): void {
+  const fail = (message: string) => errors.push({ field: path, message })
+
+  if (schema.anyOf) {
+    let candidate: FieldError[] | undefined
+    for (const branch of schema.anyOf) {
+      const branchErrors: FieldError[] = []
+      check(branch, value, path, branchErrors)
+      if (branchErrors.length === 0) return
+      if (!candidate && acceptsType(branch, value)) candidate = branchErrors
+    }
+    if (candidate) errors.push(...candidate)
+    else fail(`expected ${describe(schema)}`)
+    return
+  }
+
+  if (!acceptsType(schema, value)) {
+    fail(`expected ${describe(schema)}`)
+    return
+  }
+
+  if (schema.enum && !schema.enum.includes(value)) {
+    fail(`must be one of: ${schema.enum.map(String).join(', ')}`)
+    return
+  }
+
+  if (schema.format && typeof value === 'string') {
+    const message = formatError(schema.format, value)
+    if (message) {
+      fail(message)
+      return
+    }
+  }
+
+  if (typeof value === 'number') {
+    const { minimum, maximum } = schema
+    if ((minimum !== undefined && value < minimum) || (maximum !== undefined && value > maximum)) {
+      fail(`must be between ${minimum} and ${maximum}`)
+      return
+    }
+  }
+
+  const items = schema.items
+  if (items && Array.isArray(value)) {
+    value.forEach((item, i) => check(items, item, `${path}[${i}]`, errors))
+  }
+
+  if (isObject(value)) {
+    const child = (key: string) => (path ? `${path}.${key}` : key)
+
+    for (const key of schema.required ?? []) {
+      if (value[key] === undefined) errors.push({ field: child(key), message: 'is required' })
+    }
+
+    for (const [key, item] of Object.entries(value)) {
+      if (item === undefined) continue
+      const property = schema.properties?.[key]
+      if (property) check(property, item, child(key), errors)
+      else if (schema.additionalProperties === false)
+        errors.push({ field: child(key), message: 'is not a column of this table' })
+    }
+  }
+}
+
+export function validate<T>(schema: JsonSchema, value: unknown): ValidationResult<T> {
+  const errors: FieldError[] = []
+  check(schema, value, '', errors)
+  return errors.length === 0 ? { ok: true, value: value as T } : { ok: false, errors }
+}
+
+export const schemas: Record<string, JsonSchema> = {
+  "_realtime.extensions.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "inserted_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "settings": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "tenant_external_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "inserted_at",
+      "updated_at"
+    ],
+    "title": "_realtime.extensions insert",
+    "type": "object"
+  },
+  "_realtime.extensions.update": {
+    "additionalProperties": false,
+    "properties": {
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "inserted_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "settings": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "tenant_external_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "_realtime.extensions update",
+    "type": "object"
+  },
+  "_realtime.schema_migrations.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "inserted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "version": {
+        "type": "integer"
+      }
+    },
+    "required": [
+      "version"
+    ],
+    "title": "_realtime.schema_migrations insert",
+    "type": "object"
+  },
+  "_realtime.schema_migrations.update": {
+    "additionalProperties": false,
+    "properties": {
+      "inserted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "version": {
+        "type": "integer"
+      }
+    },
+    "required": [],
+    "title": "_realtime.schema_migrations update",
+    "type": "object"
+  },
+  "_realtime.tenants.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "external_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "inserted_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "jwt_jwks": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jwt_secret": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "max_bytes_per_second": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "max_channels_per_client": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "max_concurrent_users": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "max_events_per_second": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "max_joins_per_second": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "notify_private_alpha": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "postgres_cdc_default": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "suspend": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "inserted_at",
+      "updated_at"
+    ],
+    "title": "_realtime.tenants insert",
+    "type": "object"
+  },
+  "_realtime.tenants.update": {
+    "additionalProperties": false,
+    "properties": {
+      "external_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "inserted_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "jwt_jwks": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jwt_secret": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "max_bytes_per_second": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "max_channels_per_client": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "max_concurrent_users": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "max_events_per_second": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "max_joins_per_second": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "notify_private_alpha": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "postgres_cdc_default": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "suspend": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "_realtime.tenants update",
+    "type": "object"
+  },
+  "auth.audit_log_entries.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "instance_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "ip_address": {
+        "type": "string"
+      },
+      "payload": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id"
+    ],
+    "title": "auth.audit_log_entries insert",
+    "type": "object"
+  },
+  "auth.audit_log_entries.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "instance_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "ip_address": {
+        "type": "string"
+      },
+      "payload": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.audit_log_entries update",
+    "type": "object"
+  },
+  "auth.flow_state.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "auth_code": {
+        "type": "string"
+      },
+      "auth_code_issued_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "authentication_method": {
+        "type": "string"
+      },
+      "code_challenge": {
+        "type": "string"
+      },
+      "code_challenge_method": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "provider_access_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "provider_refresh_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "provider_type": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "auth_code",
+      "code_challenge_method",
+      "code_challenge",
+      "provider_type",
+      "authentication_method"
+    ],
+    "title": "auth.flow_state insert",
+    "type": "object"
+  },
+  "auth.flow_state.update": {
+    "additionalProperties": false,
+    "properties": {
+      "auth_code": {
+        "type": "string"
+      },
+      "auth_code_issued_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "authentication_method": {
+        "type": "string"
+      },
+      "code_challenge": {
+        "type": "string"
+      },
+      "code_challenge_method": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "provider_access_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "provider_refresh_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "provider_type": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.flow_state update",
+    "type": "object"
+  },
+  "auth.identities.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "identity_data": {},
+      "last_sign_in_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "provider": {
+        "type": "string"
+      },
+      "provider_id": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [
+      "provider_id",
+      "user_id",
+      "identity_data",
+      "provider"
+    ],
+    "title": "auth.identities insert",
+    "type": "object"
+  },
+  "auth.identities.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "identity_data": {},
+      "last_sign_in_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "provider": {
+        "type": "string"
+      },
+      "provider_id": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "auth.identities update",
+    "type": "object"
+  },
+  "auth.instances.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "raw_base_config": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "uuid": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id"
+    ],
+    "title": "auth.instances insert",
+    "type": "object"
+  },
+  "auth.instances.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "raw_base_config": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "uuid": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.instances update",
+    "type": "object"
+  },
+  "auth.mfa_amr_claims.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "authentication_method": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "session_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [
+      "session_id",
+      "created_at",
+      "updated_at",
+      "authentication_method",
+      "id"
+    ],
+    "title": "auth.mfa_amr_claims insert",
+    "type": "object"
+  },
+  "auth.mfa_amr_claims.update": {
+    "additionalProperties": false,
+    "properties": {
+      "authentication_method": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "session_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.mfa_amr_claims update",
+    "type": "object"
+  },
+  "auth.mfa_challenges.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "factor_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "ip_address": {
+        "type": "string"
+      },
+      "otp_code": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "verified_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "factor_id",
+      "created_at",
+      "ip_address"
+    ],
+    "title": "auth.mfa_challenges insert",
+    "type": "object"
+  },
+  "auth.mfa_challenges.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "factor_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "ip_address": {
+        "type": "string"
+      },
+      "otp_code": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "verified_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.mfa_challenges update",
+    "type": "object"
+  },
+  "auth.mfa_factors.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "factor_type": {
+        "type": "string"
+      },
+      "friendly_name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "last_challenged_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "secret": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [
+      "id",
+      "user_id",
+      "factor_type",
+      "status",
+      "created_at",
+      "updated_at"
+    ],
+    "title": "auth.mfa_factors insert",
+    "type": "object"
+  },
+  "auth.mfa_factors.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "factor_type": {
+        "type": "string"
+      },
+      "friendly_name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "last_challenged_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "secret": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "auth.mfa_factors update",
+    "type": "object"
+  },
+  "auth.one_time_tokens.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "relates_to": {
+        "type": "string"
+      },
+      "token_hash": {
+        "type": "string"
+      },
+      "token_type": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [
+      "id",
+      "user_id",
+      "token_type",
+      "token_hash",
+      "relates_to"
+    ],
+    "title": "auth.one_time_tokens insert",
+    "type": "object"
+  },
+  "auth.one_time_tokens.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "relates_to": {
+        "type": "string"
+      },
+      "token_hash": {
+        "type": "string"
+      },
+      "token_type": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "auth.one_time_tokens update",
+    "type": "object"
+  },
+  "auth.refresh_tokens.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "instance_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "parent": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "revoked": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "session_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.refresh_tokens insert",
+    "type": "object"
+  },
+  "auth.refresh_tokens.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "instance_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "parent": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "revoked": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "session_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.refresh_tokens update",
+    "type": "object"
+  },
+  "auth.saml_providers.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "attribute_mapping": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "entity_id": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "metadata_url": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "metadata_xml": {
+        "type": "string"
+      },
+      "name_id_format": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "sso_provider_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "sso_provider_id",
+      "entity_id",
+      "metadata_xml"
+    ],
+    "title": "auth.saml_providers insert",
+    "type": "object"
+  },
+  "auth.saml_providers.update": {
+    "additionalProperties": false,
+    "properties": {
+      "attribute_mapping": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "entity_id": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "metadata_url": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "metadata_xml": {
+        "type": "string"
+      },
+      "name_id_format": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "sso_provider_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.saml_providers update",
+    "type": "object"
+  },
+  "auth.saml_relay_states.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "flow_state_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "for_email": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "redirect_to": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "request_id": {
+        "type": "string"
+      },
+      "sso_provider_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "sso_provider_id",
+      "request_id"
+    ],
+    "title": "auth.saml_relay_states insert",
+    "type": "object"
+  },
+  "auth.saml_relay_states.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "flow_state_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "for_email": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "redirect_to": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "request_id": {
+        "type": "string"
+      },
+      "sso_provider_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.saml_relay_states update",
+    "type": "object"
+  },
+  "auth.schema_migrations.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "version": {
+        "type": "string"
+      }
+    },
+    "required": [
+      "version"
+    ],
+    "title": "auth.schema_migrations insert",
+    "type": "object"
+  },
+  "auth.schema_migrations.update": {
+    "additionalProperties": false,
+    "properties": {
+      "version": {
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "auth.schema_migrations update",
+    "type": "object"
+  },
+  "auth.sessions.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "aal": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "factor_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "ip": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "not_after": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "refreshed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "tag": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_agent": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [
+      "id",
+      "user_id"
+    ],
+    "title": "auth.sessions insert",
+    "type": "object"
+  },
+  "auth.sessions.update": {
+    "additionalProperties": false,
+    "properties": {
+      "aal": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "factor_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "ip": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "not_after": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "refreshed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "tag": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_agent": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "auth.sessions update",
+    "type": "object"
+  },
+  "auth.sso_domains.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "domain": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "sso_provider_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "sso_provider_id",
+      "domain"
+    ],
+    "title": "auth.sso_domains insert",
+    "type": "object"
+  },
+  "auth.sso_domains.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "domain": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "sso_provider_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.sso_domains update",
+    "type": "object"
+  },
+  "auth.sso_providers.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "resource_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id"
+    ],
+    "title": "auth.sso_providers insert",
+    "type": "object"
+  },
+  "auth.sso_providers.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "resource_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.sso_providers update",
+    "type": "object"
+  },
+  "auth.users.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "aud": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "banned_until": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "confirmation_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "confirmation_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change_confirm_status": {
+        "anyOf": [
+          {
+            "maximum": 32767,
+            "minimum": -32768,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change_token_current": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change_token_new": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_confirmed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "encrypted_password": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "instance_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "invited_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "is_anonymous": {
+        "type": "boolean"
+      },
+      "is_sso_user": {
+        "type": "boolean"
+      },
+      "is_super_admin": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "last_sign_in_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone_change": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone_change_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone_change_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone_confirmed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_app_meta_data": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_user_meta_data": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "reauthentication_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "reauthentication_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "recovery_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "recovery_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "role": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id"
+    ],
+    "title": "auth.users insert",
+    "type": "object"
+  },
+  "auth.users.update": {
+    "additionalProperties": false,
+    "properties": {
+      "aud": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "banned_until": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "confirmation_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "confirmation_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change_confirm_status": {
+        "anyOf": [
+          {
+            "maximum": 32767,
+            "minimum": -32768,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change_token_current": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_change_token_new": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email_confirmed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "encrypted_password": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "instance_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "invited_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "is_anonymous": {
+        "type": "boolean"
+      },
+      "is_sso_user": {
+        "type": "boolean"
+      },
+      "is_super_admin": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "last_sign_in_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone_change": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone_change_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone_change_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "phone_confirmed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_app_meta_data": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_user_meta_data": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "reauthentication_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "reauthentication_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "recovery_sent_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "recovery_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "role": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "auth.users update",
+    "type": "object"
+  },
+  "extensions.pg_stat_statements.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "blk_read_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "blk_write_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "calls": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "dbid": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_emission_count": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_emission_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_functions": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_generation_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_inlining_count": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_inlining_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_optimization_count": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_optimization_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "local_blks_dirtied": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "local_blks_hit": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "local_blks_read": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "local_blks_written": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "max_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "max_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "mean_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "mean_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "min_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "min_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "plans": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "query": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "queryid": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "rows": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shared_blks_dirtied": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shared_blks_hit": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shared_blks_read": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shared_blks_written": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "stddev_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "stddev_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "temp_blk_read_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "temp_blk_write_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "temp_blks_read": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "temp_blks_written": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "toplevel": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "total_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "total_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "userid": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "wal_bytes": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "wal_fpi": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "wal_records": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "extensions.pg_stat_statements insert",
+    "type": "object"
+  },
+  "extensions.pg_stat_statements.update": {
+    "additionalProperties": false,
+    "properties": {
+      "blk_read_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "blk_write_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "calls": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "dbid": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_emission_count": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_emission_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_functions": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_generation_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_inlining_count": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_inlining_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_optimization_count": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "jit_optimization_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "local_blks_dirtied": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "local_blks_hit": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "local_blks_read": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "local_blks_written": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "max_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "max_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "mean_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "mean_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "min_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "min_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "plans": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "query": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "queryid": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "rows": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shared_blks_dirtied": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shared_blks_hit": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shared_blks_read": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shared_blks_written": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "stddev_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "stddev_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "temp_blk_read_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "temp_blk_write_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "temp_blks_read": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "temp_blks_written": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "toplevel": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "total_exec_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "total_plan_time": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "userid": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "wal_bytes": {
+        "anyOf": [
+          {
+            "type": "number"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "wal_fpi": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "wal_records": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "extensions.pg_stat_statements update",
+    "type": "object"
+  },
+  "extensions.pg_stat_statements_info.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "dealloc": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "stats_reset": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "extensions.pg_stat_statements_info insert",
+    "type": "object"
+  },
+  "extensions.pg_stat_statements_info.update": {
+    "additionalProperties": false,
+    "properties": {
+      "dealloc": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "stats_reset": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "extensions.pg_stat_statements_info update",
+    "type": "object"
+  },
+  "net._http_response.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "content": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "content_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "error_msg": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "headers": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status_code": {
+        "anyOf": [
+          {
+            "maximum": 2147483647,
+            "minimum": -2147483648,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "timed_out": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "net._http_response insert",
+    "type": "object"
+  },
+  "net._http_response.update": {
+    "additionalProperties": false,
+    "properties": {
+      "content": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "content_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "error_msg": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "headers": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status_code": {
+        "anyOf": [
+          {
+            "maximum": 2147483647,
+            "minimum": -2147483648,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "timed_out": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "net._http_response update",
+    "type": "object"
+  },
+  "net.http_request_queue.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "body": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "headers": {},
+      "id": {
+        "type": "integer"
+      },
+      "method": {
+        "type": "string"
+      },
+      "timeout_milliseconds": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "url": {
+        "type": "string"
+      }
+    },
+    "required": [
+      "method",
+      "url",
+      "headers",
+      "timeout_milliseconds"
+    ],
+    "title": "net.http_request_queue insert",
+    "type": "object"
+  },
+  "net.http_request_queue.update": {
+    "additionalProperties": false,
+    "properties": {
+      "body": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "headers": {},
+      "id": {
+        "type": "integer"
+      },
+      "method": {
+        "type": "string"
+      },
+      "timeout_milliseconds": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "url": {
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "net.http_request_queue update",
+    "type": "object"
+  },
+  "pgsodium.decrypted_key.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_data": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "comment": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "decrypted_raw_key": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "expires": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_context": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "parent_key": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_key": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_key_nonce": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.decrypted_key insert",
+    "type": "object"
+  },
+  "pgsodium.decrypted_key.update": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_data": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "comment": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "decrypted_raw_key": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "expires": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_context": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "parent_key": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_key": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_key_nonce": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.decrypted_key update",
+    "type": "object"
+  },
+  "pgsodium.key.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_data": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "comment": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "expires": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "key_context": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "parent_key": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_key": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_key_nonce": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_data": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.key insert",
+    "type": "object"
+  },
+  "pgsodium.key.update": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_data": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "comment": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "expires": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "key_context": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "parent_key": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_key": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "raw_key_nonce": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_data": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.key update",
+    "type": "object"
+  },
+  "pgsodium.mask_columns.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_columns": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attname": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attrelid": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "format_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id_column": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "nonce_column": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.mask_columns insert",
+    "type": "object"
+  },
+  "pgsodium.mask_columns.update": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_columns": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attname": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attrelid": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "format_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id_column": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "nonce_column": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.mask_columns update",
+    "type": "object"
+  },
+  "pgsodium.masking_rule.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_columns": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attname": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attnum": {
+        "anyOf": [
+          {
+            "maximum": 2147483647,
+            "minimum": -2147483648,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attrelid": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "col_description": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "format_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id_column": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "nonce_column": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "priority": {
+        "anyOf": [
+          {
+            "maximum": 2147483647,
+            "minimum": -2147483648,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "relname": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "relnamespace": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "security_invoker": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "view_name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.masking_rule insert",
+    "type": "object"
+  },
+  "pgsodium.masking_rule.update": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_columns": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attname": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attnum": {
+        "anyOf": [
+          {
+            "maximum": 2147483647,
+            "minimum": -2147483648,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "attrelid": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "col_description": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "format_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id_column": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "nonce_column": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "priority": {
+        "anyOf": [
+          {
+            "maximum": 2147483647,
+            "minimum": -2147483648,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "relname": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "relnamespace": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "security_invoker": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "view_name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.masking_rule update",
+    "type": "object"
+  },
+  "pgsodium.valid_key.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_data": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "expires": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_context": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.valid_key insert",
+    "type": "object"
+  },
+  "pgsodium.valid_key.update": {
+    "additionalProperties": false,
+    "properties": {
+      "associated_data": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "expires": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_context": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "pgsodium.valid_key update",
+    "type": "object"
+  },
+  "public.actions.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "name": {
+        "type": "string"
+      }
+    },
+    "required": [
+      "name"
+    ],
+    "title": "public.actions insert",
+    "type": "object"
+  },
+  "public.actions.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "name": {
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.actions update",
+    "type": "object"
+  },
+  "public.activities.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "note": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "type": {
+        "enum": [
+          "borrow",
+          "return",
+          "maintenance",
+          "shipment",
+          "assessment"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [
+      "type"
+    ],
+    "title": "public.activities insert",
+    "type": "object"
+  },
+  "public.activities.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "note": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "type": {
+        "enum": [
+          "borrow",
+          "return",
+          "maintenance",
+          "shipment",
+          "assessment"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.activities update",
+    "type": "object"
+  },
+  "public.categories.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "name": {
+        "type": "string"
+      },
+      "quantity": {
+        "anyOf": [
+          {
+            "maximum": 2147483647,
+            "minimum": -2147483648,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "name"
+    ],
+    "title": "public.categories insert",
+    "type": "object"
+  },
+  "public.categories.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "name": {
+        "type": "string"
+      },
+      "quantity": {
+        "anyOf": [
+          {
+            "maximum": 2147483647,
+            "minimum": -2147483648,
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.categories update",
+    "type": "object"
+  },
+  "public.device_kinds.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "allowed_borrow_roles": {
+        "anyOf": [
+          {
+            "items": {
+              "enum": [
+                "sysadmin",
+                "lab_admin",
+                "teacher",
+                "student"
+              ],
+              "type": "string"
+            },
+            "type": "array"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "allowed_view_roles": {
+        "anyOf": [
+          {
+            "items": {
+              "enum": [
+                "sysadmin",
+                "lab_admin",
+                "teacher",
+                "student"
+              ],
+              "type": "string"
+            },
+            "type": "array"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "brand": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "category_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "datasheet": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "description": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "image": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "is_borrowable_lab_only": {
+        "type": "boolean"
+      },
+      "manufacturer": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "meta": {},
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "price": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "unit": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.device_kinds insert",
+    "type": "object"
+  },
+  "public.device_kinds.update": {
+    "additionalProperties": false,
+    "properties": {
+      "allowed_borrow_roles": {
+        "anyOf": [
+          {
+            "items": {
+              "enum": [
+                "sysadmin",
+                "lab_admin",
+                "teacher",
+                "student"
+              ],
+              "type": "string"
+            },
+            "type": "array"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "allowed_view_roles": {
+        "anyOf": [
+          {
+            "items": {
+              "enum": [
+                "sysadmin",
+                "lab_admin",
+                "teacher",
+                "student"
+              ],
+              "type": "string"
+            },
+            "type": "array"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "brand": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "category_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "datasheet": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "description": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "image": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "is_borrowable_lab_only": {
+        "type": "boolean"
+      },
+      "manufacturer": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "meta": {},
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "price": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "unit": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.device_kinds update",
+    "type": "object"
+  },
+  "public.device_status_changes.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "activity_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "activity_type": {
+        "enum": [
+          "borrow",
+          "return",
+          "maintenance",
+          "shipment",
+          "assessment"
+        ],
+        "type": "string"
+      },
+      "actor_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "changed_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "device_id": {
+        "type": "string"
+      },
+      "from_status": {
+        "enum": [
+          "healthy",
+          "broken",
+          "discarded",
+          "assessing",
+          "maintaining",
+          "shipping",
+          "borrowing",
+          "lost"
+        ],
+        "type": "string"
+      },
+      "id": {
+        "type": "integer"
+      },
+      "reason": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "to_status": {
+        "enum": [
+          "healthy",
+          "broken",
+          "discarded",
+          "assessing",
+          "maintaining",
+          "shipping",
+          "borrowing",
+          "lost"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [
+      "device_id",
+      "from_status",
+      "to_status",
+      "activity_type"
+    ],
+    "title": "public.device_status_changes insert",
+    "type": "object"
+  },
+  "public.device_status_changes.update": {
+    "additionalProperties": false,
+    "properties": {
+      "activity_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "activity_type": {
+        "enum": [
+          "borrow",
+          "return",
+          "maintenance",
+          "shipment",
+          "assessment"
+        ],
+        "type": "string"
+      },
+      "actor_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "changed_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "device_id": {
+        "type": "string"
+      },
+      "from_status": {
+        "enum": [
+          "healthy",
+          "broken",
+          "discarded",
+          "assessing",
+          "maintaining",
+          "shipping",
+          "borrowing",
+          "lost"
+        ],
+        "type": "string"
+      },
+      "id": {
+        "type": "integer"
+      },
+      "reason": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "to_status": {
+        "enum": [
+          "healthy",
+          "broken",
+          "discarded",
+          "assessing",
+          "maintaining",
+          "shipping",
+          "borrowing",
+          "lost"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.device_status_changes update",
+    "type": "object"
+  },
+  "public.devices.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "accessory_for_kind_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "full_id": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "kind": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "lab_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "printed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "enum": [
+          "healthy",
+          "broken",
+          "discarded",
+          "assessing",
+          "maintaining",
+          "shipping",
+          "borrowing",
+          "lost"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [
+      "kind",
+      "full_id"
+    ],
+    "title": "public.devices insert",
+    "type": "object"
+  },
+  "public.devices.update": {
+    "additionalProperties": false,
+    "properties": {
+      "accessory_for_kind_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "full_id": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "kind": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "lab_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "printed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "enum": [
+          "healthy",
+          "broken",
+          "discarded",
+          "assessing",
+          "maintaining",
+          "shipping",
+          "borrowing",
+          "lost"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.devices update",
+    "type": "object"
+  },
+  "public.hmi_codes.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "auth_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "code": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "expires_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "lab_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "code"
+    ],
+    "title": "public.hmi_codes insert",
+    "type": "object"
+  },
+  "public.hmi_codes.update": {
+    "additionalProperties": false,
+    "properties": {
+      "auth_token": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "code": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "expires_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "lab_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.hmi_codes update",
+    "type": "object"
+  },
+  "public.inventory_assessments.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "accountant_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "finished_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "lab_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "status": {
+        "enum": [
+          "assessing",
+          "completed",
+          "cancelled"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [
+      "id",
+      "lab_id"
+    ],
+    "title": "public.inventory_assessments insert",
+    "type": "object"
+  },
+  "public.inventory_assessments.update": {
+    "additionalProperties": false,
+    "properties": {
+      "accountant_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "finished_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "lab_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "status": {
+        "enum": [
+          "assessing",
+          "completed",
+          "cancelled"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.inventory_assessments update",
+    "type": "object"
+  },
+  "public.inventory_assessments_devices.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "after_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "assessing_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "device_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "prev_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.inventory_assessments_devices insert",
+    "type": "object"
+  },
+  "public.inventory_assessments_devices.update": {
+    "additionalProperties": false,
+    "properties": {
+      "after_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "assessing_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "device_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "prev_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.inventory_assessments_devices update",
+    "type": "object"
+  },
+  "public.labs.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "admin_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "branch": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "faculty": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "room": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "timetable": {}
+    },
+    "required": [],
+    "title": "public.labs insert",
+    "type": "object"
+  },
+  "public.labs.update": {
+    "additionalProperties": false,
+    "properties": {
+      "admin_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "branch": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "faculty": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "room": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "timetable": {}
+    },
+    "required": [],
+    "title": "public.labs update",
+    "type": "object"
+  },
+  "public.maintenances.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "finished_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "lab_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "maintainer_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "enum": [
+          "maintaining",
+          "completed",
+          "cancelled"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [
+      "id",
+      "lab_id"
+    ],
+    "title": "public.maintenances insert",
+    "type": "object"
+  },
+  "public.maintenances.update": {
+    "additionalProperties": false,
+    "properties": {
+      "finished_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "lab_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "maintainer_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "status": {
+        "enum": [
+          "maintaining",
+          "completed",
+          "cancelled"
+        ],
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.maintenances update",
+    "type": "object"
+  },
+  "public.maintenances_devices.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "after_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "device_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "maintaining_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "prev_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "maintaining_id"
+    ],
+    "title": "public.maintenances_devices insert",
+    "type": "object"
+  },
+  "public.maintenances_devices.update": {
+    "additionalProperties": false,
+    "properties": {
+      "after_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "device_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "maintaining_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "prev_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.maintenances_devices update",
+    "type": "object"
+  },
+  "public.permissions.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "action_id": {
+        "type": "integer"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "priority": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "resource_id": {
+        "type": "integer"
+      },
+      "role_id": {
+        "type": "integer"
+      }
+    },
+    "required": [
+      "role_id",
+      "resource_id",
+      "action_id"
+    ],
+    "title": "public.permissions insert",
+    "type": "object"
+  },
+  "public.permissions.update": {
+    "additionalProperties": false,
+    "properties": {
+      "action_id": {
+        "type": "integer"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "priority": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "resource_id": {
+        "type": "integer"
+      },
+      "role_id": {
+        "type": "integer"
+      }
+    },
+    "required": [],
+    "title": "public.permissions update",
+    "type": "object"
+  },
+  "public.receipts.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "actor_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "checker_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "lab_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [
+      "actor_id",
+      "checker_id",
+      "lab_id"
+    ],
+    "title": "public.receipts insert",
+    "type": "object"
+  },
+  "public.receipts.update": {
+    "additionalProperties": false,
+    "properties": {
+      "actor_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "checker_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "lab_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.receipts update",
+    "type": "object"
+  },
+  "public.receipts_devices.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "after_quality": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "borrow_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "borrowed_receipt_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "device_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "expected_returned_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "expected_returned_lab_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "prev_quality": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "return_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "returned_receipt_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "borrowed_receipt_id",
+      "device_id",
+      "expected_returned_at"
+    ],
+    "title": "public.receipts_devices insert",
+    "type": "object"
+  },
+  "public.receipts_devices.update": {
+    "additionalProperties": false,
+    "properties": {
+      "after_quality": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "borrow_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "borrowed_receipt_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "device_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "expected_returned_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "expected_returned_lab_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "prev_quality": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "return_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "returned_receipt_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.receipts_devices update",
+    "type": "object"
+  },
+  "public.resources.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "name": {
+        "type": "string"
+      },
+      "type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "name"
+    ],
+    "title": "public.resources insert",
+    "type": "object"
+  },
+  "public.resources.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "name": {
+        "type": "string"
+      },
+      "type": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.resources update",
+    "type": "object"
+  },
+  "public.role_histories.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "effective_end": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "effective_start": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "grantee_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "granter_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "permissions": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "grantee_id",
+      "granter_id",
+      "effective_start",
+      "effective_end"
+    ],
+    "title": "public.role_histories insert",
+    "type": "object"
+  },
+  "public.role_histories.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "effective_end": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "effective_start": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "grantee_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "granter_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "permissions": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.role_histories update",
+    "type": "object"
+  },
+  "public.roles.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "key": {
+        "type": "string"
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "key"
+    ],
+    "title": "public.roles insert",
+    "type": "object"
+  },
+  "public.roles.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "key": {
+        "type": "string"
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.roles update",
+    "type": "object"
+  },
+  "public.shipments.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "arrive_lab_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "check_at_destination": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "from_at": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "type": "string"
+      },
+      "receiver_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "sender_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "start_lab_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "status": {
+        "enum": [
+          "preparing",
+          "shipping",
+          "completed",
+          "cancelled",
+          "received",
+          "partially_received"
+        ],
+        "type": "string"
+      },
+      "to_at": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "start_lab_id",
+      "arrive_lab_id"
+    ],
+    "title": "public.shipments insert",
+    "type": "object"
+  },
+  "public.shipments.update": {
+    "additionalProperties": false,
+    "properties": {
+      "arrive_lab_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "check_at_destination": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "from_at": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "type": "string"
+      },
+      "receiver_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "sender_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "start_lab_id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "status": {
+        "enum": [
+          "preparing",
+          "shipping",
+          "completed",
+          "cancelled",
+          "received",
+          "partially_received"
+        ],
+        "type": "string"
+      },
+      "to_at": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.shipments update",
+    "type": "object"
+  },
+  "public.shipments_devices.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "after_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "device_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "prev_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shipment_id": {
+        "type": "string"
+      }
+    },
+    "required": [
+      "shipment_id"
+    ],
+    "title": "public.shipments_devices insert",
+    "type": "object"
+  },
+  "public.shipments_devices.update": {
+    "additionalProperties": false,
+    "properties": {
+      "after_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "device_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "prev_status": {
+        "anyOf": [
+          {
+            "enum": [
+              "healthy",
+              "broken",
+              "discarded",
+              "assessing",
+              "maintaining",
+              "shipping",
+              "borrowing",
+              "lost"
+            ],
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "shipment_id": {
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.shipments_devices update",
+    "type": "object"
+  },
+  "public.used_qr_tokens.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "token": {
+        "type": "string"
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [
+      "token",
+      "user_id"
+    ],
+    "title": "public.used_qr_tokens insert",
+    "type": "object"
+  },
+  "public.used_qr_tokens.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "integer"
+      },
+      "token": {
+        "type": "string"
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.used_qr_tokens update",
+    "type": "object"
+  },
+  "public.user_roles.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "role_id": {
+        "type": "integer"
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [
+      "user_id",
+      "role_id"
+    ],
+    "title": "public.user_roles insert",
+    "type": "object"
+  },
+  "public.user_roles.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "role_id": {
+        "type": "integer"
+      },
+      "user_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "public.user_roles update",
+    "type": "object"
+  },
+  "public.users.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "image": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "last_active_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "meta": {},
+      "name": {
+        "type": "string"
+      },
+      "password": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "tel": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "name"
+    ],
+    "title": "public.users insert",
+    "type": "object"
+  },
+  "public.users.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "deleted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "email": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "image": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "last_active_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "meta": {},
+      "name": {
+        "type": "string"
+      },
+      "password": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "tel": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "public.users update",
+    "type": "object"
+  },
+  "realtime.messages.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "extension": {
+        "type": "string"
+      },
+      "id": {
+        "type": "integer"
+      },
+      "inserted_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "topic": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [
+      "topic",
+      "extension"
+    ],
+    "title": "realtime.messages insert",
+    "type": "object"
+  },
+  "realtime.messages.update": {
+    "additionalProperties": false,
+    "properties": {
+      "extension": {
+        "type": "string"
+      },
+      "id": {
+        "type": "integer"
+      },
+      "inserted_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "topic": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "realtime.messages update",
+    "type": "object"
+  },
+  "realtime.schema_migrations.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "inserted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "version": {
+        "type": "integer"
+      }
+    },
+    "required": [
+      "version"
+    ],
+    "title": "realtime.schema_migrations insert",
+    "type": "object"
+  },
+  "realtime.schema_migrations.update": {
+    "additionalProperties": false,
+    "properties": {
+      "inserted_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "version": {
+        "type": "integer"
+      }
+    },
+    "required": [],
+    "title": "realtime.schema_migrations update",
+    "type": "object"
+  },
+  "realtime.subscription.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "claims": {},
+      "claims_role": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "entity": {
+        "type": "string"
+      },
+      "filters": {
+        "items": {
+          "type": "string"
+        },
+        "type": "array"
+      },
+      "id": {
+        "type": "integer"
+      },
+      "subscription_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [
+      "subscription_id",
+      "entity",
+      "claims",
+      "claims_role"
+    ],
+    "title": "realtime.subscription insert",
+    "type": "object"
+  },
+  "realtime.subscription.update": {
+    "additionalProperties": false,
+    "properties": {
+      "claims": {},
+      "claims_role": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "entity": {
+        "type": "string"
+      },
+      "filters": {
+        "items": {
+          "type": "string"
+        },
+        "type": "array"
+      },
+      "id": {
+        "type": "integer"
+      },
+      "subscription_id": {
+        "format": "uuid",
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "realtime.subscription update",
+    "type": "object"
+  },
+  "storage.buckets.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "allowed_mime_types": {
+        "anyOf": [
+          {
+            "items": {
+              "type": "string"
+            },
+            "type": "array"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "avif_autodetection": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "file_size_limit": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "type": "string"
+      },
+      "name": {
+        "type": "string"
+      },
+      "owner": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "owner_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "public": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "id",
+      "name"
+    ],
+    "title": "storage.buckets insert",
+    "type": "object"
+  },
+  "storage.buckets.update": {
+    "additionalProperties": false,
+    "properties": {
+      "allowed_mime_types": {
+        "anyOf": [
+          {
+            "items": {
+              "type": "string"
+            },
+            "type": "array"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "avif_autodetection": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "file_size_limit": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "type": "string"
+      },
+      "name": {
+        "type": "string"
+      },
+      "owner": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "owner_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "public": {
+        "anyOf": [
+          {
+            "type": "boolean"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "storage.buckets update",
+    "type": "object"
+  },
+  "storage.migrations.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "executed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "hash": {
+        "type": "string"
+      },
+      "id": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "name": {
+        "type": "string"
+      }
+    },
+    "required": [
+      "id",
+      "name",
+      "hash"
+    ],
+    "title": "storage.migrations insert",
+    "type": "object"
+  },
+  "storage.migrations.update": {
+    "additionalProperties": false,
+    "properties": {
+      "executed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "hash": {
+        "type": "string"
+      },
+      "id": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "name": {
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "storage.migrations update",
+    "type": "object"
+  },
+  "storage.objects.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "bucket_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "last_accessed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "metadata": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "owner": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "owner_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_metadata": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "version": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "storage.objects insert",
+    "type": "object"
+  },
+  "storage.objects.update": {
+    "additionalProperties": false,
+    "properties": {
+      "bucket_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "last_accessed_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "metadata": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "owner": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "owner_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "user_metadata": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "version": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "storage.objects update",
+    "type": "object"
+  },
+  "storage.s3_multipart_uploads.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "bucket_id": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "string"
+      },
+      "in_progress_size": {
+        "type": "integer"
+      },
+      "key": {
+        "type": "string"
+      },
+      "owner_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "upload_signature": {
+        "type": "string"
+      },
+      "user_metadata": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "version": {
+        "type": "string"
+      }
+    },
+    "required": [
+      "id",
+      "upload_signature",
+      "bucket_id",
+      "key",
+      "version"
+    ],
+    "title": "storage.s3_multipart_uploads insert",
+    "type": "object"
+  },
+  "storage.s3_multipart_uploads.update": {
+    "additionalProperties": false,
+    "properties": {
+      "bucket_id": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "id": {
+        "type": "string"
+      },
+      "in_progress_size": {
+        "type": "integer"
+      },
+      "key": {
+        "type": "string"
+      },
+      "owner_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "upload_signature": {
+        "type": "string"
+      },
+      "user_metadata": {
+        "anyOf": [
+          {},
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "version": {
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "storage.s3_multipart_uploads update",
+    "type": "object"
+  },
+  "storage.s3_multipart_uploads_parts.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "bucket_id": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "etag": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "key": {
+        "type": "string"
+      },
+      "owner_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "part_number": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "size": {
+        "type": "integer"
+      },
+      "upload_id": {
+        "type": "string"
+      },
+      "version": {
+        "type": "string"
+      }
+    },
+    "required": [
+      "upload_id",
+      "part_number",
+      "bucket_id",
+      "key",
+      "etag",
+      "version"
+    ],
+    "title": "storage.s3_multipart_uploads_parts insert",
+    "type": "object"
+  },
+  "storage.s3_multipart_uploads_parts.update": {
+    "additionalProperties": false,
+    "properties": {
+      "bucket_id": {
+        "type": "string"
+      },
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "etag": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "key": {
+        "type": "string"
+      },
+      "owner_id": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "part_number": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "size": {
+        "type": "integer"
+      },
+      "upload_id": {
+        "type": "string"
+      },
+      "version": {
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "storage.s3_multipart_uploads_parts update",
+    "type": "object"
+  },
+  "supabase_functions.hooks.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "hook_name": {
+        "type": "string"
+      },
+      "hook_table_id": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "id": {
+        "type": "integer"
+      },
+      "request_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [
+      "hook_table_id",
+      "hook_name"
+    ],
+    "title": "supabase_functions.hooks insert",
+    "type": "object"
+  },
+  "supabase_functions.hooks.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "hook_name": {
+        "type": "string"
+      },
+      "hook_table_id": {
+        "maximum": 2147483647,
+        "minimum": -2147483648,
+        "type": "integer"
+      },
+      "id": {
+        "type": "integer"
+      },
+      "request_id": {
+        "anyOf": [
+          {
+            "type": "integer"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "supabase_functions.hooks update",
+    "type": "object"
+  },
+  "supabase_functions.migrations.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "inserted_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "version": {
+        "type": "string"
+      }
+    },
+    "required": [
+      "version"
+    ],
+    "title": "supabase_functions.migrations insert",
+    "type": "object"
+  },
+  "supabase_functions.migrations.update": {
+    "additionalProperties": false,
+    "properties": {
+      "inserted_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "version": {
+        "type": "string"
+      }
+    },
+    "required": [],
+    "title": "supabase_functions.migrations update",
+    "type": "object"
+  },
+  "vault.decrypted_secrets.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "decrypted_secret": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "description": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "nonce": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "secret": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "vault.decrypted_secrets insert",
+    "type": "object"
+  },
+  "vault.decrypted_secrets.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "decrypted_secret": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "description": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "nonce": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "secret": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "anyOf": [
+              {
+                "format": "date-time",
+                "type": "string"
+              },
+              {
+                "type": "integer"
+              }
+            ]
+          },
+          {
+            "type": "null"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "vault.decrypted_secrets update",
+    "type": "object"
+  },
+  "vault.secrets.insert": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "description": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "nonce": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "secret": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [
+      "secret"
+    ],
+    "title": "vault.secrets insert",
+    "type": "object"
+  },
+  "vault.secrets.update": {
+    "additionalProperties": false,
+    "properties": {
+      "created_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      },
+      "description": {
+        "type": "string"
+      },
+      "id": {
+        "format": "uuid",
+        "type": "string"
+      },
+      "key_id": {
+        "anyOf": [
+          {
+            "format": "uuid",
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "name": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "nonce": {
+        "anyOf": [
+          {
+            "type": "string"
+          },
+          {
+            "type": "null"
+          }
+        ]
+      },
+      "secret": {
+        "type": "string"
+      },
+      "updated_at": {
+        "anyOf": [
+          {
+            "format": "date-time",
+            "type": "string"
+          },
+          {
+            "type": "integer"
+          }
+        ]
+      }
+    },
+    "required": [],
+    "title": "vault.secrets update",
+    "type": "object"
+  }
+}
+
+export const validateExtensionsInsert = (value: unknown) =>
+  validate<ExtensionsInsert>(schemas['_realtime.extensions.insert'], value)
+
+export const validateExtensionsUpdate = (value: unknown) =>
+  validate<ExtensionsUpdate>(schemas['_realtime.extensions.update'], value)
+
+export const validateSchemaMigrations_RealtimeInsert = (value: unknown) =>
+  validate<SchemaMigrations_RealtimeInsert>(schemas['_realtime.schema_migrations.insert'], value)
+
+export const validateSchemaMigrations_RealtimeUpdate = (value: unknown) =>
+  validate<SchemaMigrations_RealtimeUpdate>(schemas['_realtime.schema_migrations.update'], value)
+
+export const validateTenantsInsert = (value: unknown) =>
+  validate<TenantsInsert>(schemas['_realtime.tenants.insert'], value)
+
+export const validateTenantsUpdate = (value: unknown) =>
+  validate<TenantsUpdate>(schemas['_realtime.tenants.update'], value)
+
+export const validateAuditLogEntriesInsert = (value: unknown) =>
+  validate<AuditLogEntriesInsert>(schemas['auth.audit_log_entries.insert'], value)
+
+export const validateAuditLogEntriesUpdate = (value: unknown) =>
+  validate<AuditLogEntriesUpdate>(schemas['auth.audit_log_entries.update'], value)
+
+export const validateFlowStateInsert = (value: unknown) =>
+  validate<FlowStateInsert>(schemas['auth.flow_state.insert'], value)
+
+export const validateFlowStateUpdate = (value: unknown) =>
+  validate<FlowStateUpdate>(schemas['auth.flow_state.update'], value)
+
+export const validateIdentitiesInsert = (value: unknown) =>
+  validate<IdentitiesInsert>(schemas['auth.identities.insert'], value)
+
+export const validateIdentitiesUpdate = (value: unknown) =>
+  validate<IdentitiesUpdate>(schemas['auth.identities.update'], value)
+
+export const validateInstancesInsert = (value: unknown) =>
+  validate<InstancesInsert>(schemas['auth.instances.insert'], value)
+
+export const validateInstancesUpdate = (value: unknown) =>
+  validate<InstancesUpdate>(schemas['auth.instances.update'], value)
+
+export const validateMfaAmrClaimsInsert = (value: unknown) =>
+  validate<MfaAmrClaimsInsert>(schemas['auth.mfa_amr_claims.insert'], value)
+
+export const validateMfaAmrClaimsUpdate = (value: unknown) =>
+  validate<MfaAmrClaimsUpdate>(schemas['auth.mfa_amr_claims.update'], value)
+
+export const validateMfaChallengesInsert = (value: unknown) =>
+  validate<MfaChallengesInsert>(schemas['auth.mfa_challenges.insert'], value)
+
+export const validateMfaChallengesUpdate = (value: unknown) =>
+  validate<MfaChallengesUpdate>(schemas['auth.mfa_challenges.update'], value)
+
+export const validateMfaFactorsInsert = (value: unknown) =>
+  validate<MfaFactorsInsert>(schemas['auth.mfa_factors.insert'], value)
+
+export const validateMfaFactorsUpdate = (value: unknown) =>
+  validate<MfaFactorsUpdate>(schemas['auth.mfa_factors.update'], value)
+
+export const validateOneTimeTokensInsert = (value: unknown) =>
+  validate<OneTimeTokensInsert>(schemas['auth.one_time_tokens.insert'], value)
+
+export const validateOneTimeTokensUpdate = (value: unknown) =>
+  validate<OneTimeTokensUpdate>(schemas['auth.one_time_tokens.update'], value)
+
+export const validateRefreshTokensInsert = (value: unknown) =>
+  validate<RefreshTokensInsert>(schemas['auth.refresh_tokens.insert'], value)
+
+export const validateRefreshTokensUpdate = (value: unknown) =>
+  validate<RefreshTokensUpdate>(schemas['auth.refresh_tokens.update'], value)
+
+export const validateSamlProvidersInsert = (value: unknown) =>
+  validate<SamlProvidersInsert>(schemas['auth.saml_providers.insert'], value)
+
+export const validateSamlProvidersUpdate = (value: unknown) =>
+  validate<SamlProvidersUpdate>(schemas['auth.saml_providers.update'], value)
+
+export const validateSamlRelayStatesInsert = (value: unknown) =>
+  validate<SamlRelayStatesInsert>(schemas['auth.saml_relay_states.insert'], value)
+
+export const validateSamlRelayStatesUpdate = (value: unknown) =>
+  validate<SamlRelayStatesUpdate>(schemas['auth.saml_relay_states.update'], value)
+
+export const validateSchemaMigrations_AuthInsert = (value: unknown) =>
+  validate<SchemaMigrations_AuthInsert>(schemas['auth.schema_migrations.insert'], value)
+
+export const validateSchemaMigrations_AuthUpdate = (value: unknown) =>
+  validate<SchemaMigrations_AuthUpdate>(schemas['auth.schema_migrations.update'], value)
+
+export const validateSessionsInsert = (value: unknown) =>
+  validate<SessionsInsert>(schemas['auth.sessions.insert'], value)
+
+export const validateSessionsUpdate = (value: unknown) =>
+  validate<SessionsUpdate>(schemas['auth.sessions.update'], value)
+
+export const validateSsoDomainsInsert = (value: unknown) =>
+  validate<SsoDomainsInsert>(schemas['auth.sso_domains.insert'], value)
+
+export const validateSsoDomainsUpdate = (value: unknown) =>
+  validate<SsoDomainsUpdate>(schemas['auth.sso_domains.update'], value)
+
+export const validateSsoProvidersInsert = (value: unknown) =>
+  validate<SsoProvidersInsert>(schemas['auth.sso_providers.insert'], value)
+
+export const validateSsoProvidersUpdate = (value: unknown) =>
+  validate<SsoProvidersUpdate>(schemas['auth.sso_providers.update'], value)
+
+export const validateUsers_AuthInsert = (value: unknown) =>
+  validate<Users_AuthInsert>(schemas['auth.users.insert'], value)
+
+export const validateUsers_AuthUpdate = (value: unknown) =>
+  validate<Users_AuthUpdate>(schemas['auth.users.update'], value)
+
+export const validatePgStatStatementsInsert = (value: unknown) =>
+  validate<PgStatStatementsInsert>(schemas['extensions.pg_stat_statements.insert'], value)
+
+export const validatePgStatStatementsUpdate = (value: unknown) =>
+  validate<PgStatStatementsUpdate>(schemas['extensions.pg_stat_statements.update'], value)
+
+export const validatePgStatStatementsInfoInsert = (value: unknown) =>
+  validate<PgStatStatementsInfoInsert>(schemas['extensions.pg_stat_statements_info.insert'], value)
+
+export const validatePgStatStatementsInfoUpdate = (value: unknown) =>
+  validate<PgStatStatementsInfoUpdate>(schemas['extensions.pg_stat_statements_info.update'], value)
+
+export const validateHttpResponseInsert = (value: unknown) =>
+  validate<HttpResponseInsert>(schemas['net._http_response.insert'], value)
+
+export const validateHttpResponseUpdate = (value: unknown) =>
+  validate<HttpResponseUpdate>(schemas['net._http_response.update'], value)
+
+export const validateHttpRequestQueueInsert = (value: unknown) =>
+  validate<HttpRequestQueueInsert>(schemas['net.http_request_queue.insert'], value)
+
+export const validateHttpRequestQueueUpdate = (value: unknown) =>
+  validate<HttpRequestQueueUpdate>(schemas['net.http_request_queue.update'], value)
+
+export const validateDecryptedKeyInsert = (value: unknown) =>
+  validate<DecryptedKeyInsert>(schemas['pgsodium.decrypted_key.insert'], value)
+
+export const validateDecryptedKeyUpdate = (value: unknown) =>
+  validate<DecryptedKeyUpdate>(schemas['pgsodium.decrypted_key.update'], value)
+
+export const validateKeyInsert = (value: unknown) =>
+  validate<KeyInsert>(schemas['pgsodium.key.insert'], value)
+
+export const validateKeyUpdate = (value: unknown) =>
+  validate<KeyUpdate>(schemas['pgsodium.key.update'], value)
+
+export const validateMaskColumnsInsert = (value: unknown) =>
+  validate<MaskColumnsInsert>(schemas['pgsodium.mask_columns.insert'], value)
+
+export const validateMaskColumnsUpdate = (value: unknown) =>
+  validate<MaskColumnsUpdate>(schemas['pgsodium.mask_columns.update'], value)
+
+export const validateMaskingRuleInsert = (value: unknown) =>
+  validate<MaskingRuleInsert>(schemas['pgsodium.masking_rule.insert'], value)
+
+export const validateMaskingRuleUpdate = (value: unknown) =>
+  validate<MaskingRuleUpdate>(schemas['pgsodium.masking_rule.update'], value)
+
+export const validateValidKeyInsert = (value: unknown) =>
+  validate<ValidKeyInsert>(schemas['pgsodium.valid_key.insert'], value)
+
+export const validateValidKeyUpdate = (value: unknown) =>
+  validate<ValidKeyUpdate>(schemas['pgsodium.valid_key.update'], value)
+
+export const validateActionsInsert = (value: unknown) =>
+  validate<ActionsInsert>(schemas['public.actions.insert'], value)
+
+export const validateActionsUpdate = (value: unknown) =>
+  validate<ActionsUpdate>(schemas['public.actions.update'], value)
+
+export const validateActivitiesInsert = (value: unknown) =>
+  validate<ActivitiesInsert>(schemas['public.activities.insert'], value)
+
+export const validateActivitiesUpdate = (value: unknown) =>
+  validate<ActivitiesUpdate>(schemas['public.activities.update'], value)
+
+export const validateCategoriesInsert = (value: unknown) =>
+  validate<CategoriesInsert>(schemas['public.categories.insert'], value)
+
+export const validateCategoriesUpdate = (value: unknown) =>
+  validate<CategoriesUpdate>(schemas['public.categories.update'], value)
+
+export const validateDeviceKindsInsert = (value: unknown) =>
+  validate<DeviceKindsInsert>(schemas['public.device_kinds.insert'], value)
+
+export const validateDeviceKindsUpdate = (value: unknown) =>
+  validate<DeviceKindsUpdate>(schemas['public.device_kinds.update'], value)
+
+export const validateDeviceStatusChangesInsert = (value: unknown) =>
+  validate<DeviceStatusChangesInsert>(schemas['public.device_status_changes.insert'], value)
+
+export const validateDeviceStatusChangesUpdate = (value: unknown) =>
+  validate<DeviceStatusChangesUpdate>(schemas['public.device_status_changes.update'], value)
+
+export const validateDevicesInsert = (value: unknown) =>
+  validate<DevicesInsert>(schemas['public.devices.insert'], value)
+
+export const validateDevicesUpdate = (value: unknown) =>
+  validate<DevicesUpdate>(schemas['public.devices.update'], value)
+
+export const validateHmiCodesInsert = (value: unknown) =>
+  validate<HmiCodesInsert>(schemas['public.hmi_codes.insert'], value)
+
+export const validateHmiCodesUpdate = (value: unknown) =>
+  validate<HmiCodesUpdate>(schemas['public.hmi_codes.update'], value)
+
+export const validateInventoryAssessmentsInsert = (value: unknown) =>
+  validate<InventoryAssessmentsInsert>(schemas['public.inventory_assessments.insert'], value)
+
+export const validateInventoryAssessmentsUpdate = (value: unknown) =>
+  validate<InventoryAssessmentsUpdate>(schemas['public.inventory_assessments.update'], value)
+
+export const validateInventoryAssessmentsDevicesInsert = (value: unknown) =>
+  validate<InventoryAssessmentsDevicesInsert>(schemas['public.inventory_assessments_devices.insert'], value)
+
+export const validateInventoryAssessmentsDevicesUpdate = (value: unknown) =>
+  validate<InventoryAssessmentsDevicesUpdate>(schemas['public.inventory_assessments_devices.update'], value)
+
+export const validateLabsInsert = (value: unknown) =>
+  validate<LabsInsert>(schemas['public.labs.insert'], value)
+
+export const validateLabsUpdate = (value: unknown) =>
+  validate<LabsUpdate>(schemas['public.labs.update'], value)
+
+export const validateMaintenancesInsert = (value: unknown) =>
+  validate<MaintenancesInsert>(schemas['public.maintenances.insert'], value)
+
+export const validateMaintenancesUpdate = (value: unknown) =>
+  validate<MaintenancesUpdate>(schemas['public.maintenances.update'], value)
+
+export const validateMaintenancesDevicesInsert = (value: unknown) =>
+  validate<MaintenancesDevicesInsert>(schemas['public.maintenances_devices.insert'], value)
+
+export const validateMaintenancesDevicesUpdate = (value: unknown) =>
+  validate<MaintenancesDevicesUpdate>(schemas['public.maintenances_devices.update'], value)
+
+export const validatePermissionsInsert = (value: unknown) =>
+  validate<PermissionsInsert>(schemas['public.permissions.insert'], value)
+
+export const validatePermissionsUpdate = (value: unknown) =>
+  validate<PermissionsUpdate>(schemas['public.permissions.update'], value)
+
+export const validateReceiptsInsert = (value: unknown) =>
+  validate<ReceiptsInsert>(schemas['public.receipts.insert'], value)
+
+export const validateReceiptsUpdate = (value: unknown) =>
+  validate<ReceiptsUpdate>(schemas['public.receipts.update'], value)
+
+export const validateReceiptsDevicesInsert = (value: unknown) =>
+  validate<ReceiptsDevicesInsert>(schemas['public.receipts_devices.insert'], value)
+
+export const validateReceiptsDevicesUpdate = (value: unknown) =>
+  validate<ReceiptsDevicesUpdate>(schemas['public.receipts_devices.update'], value)
+
+export const validateResourcesInsert = (value: unknown) =>
+  validate<ResourcesInsert>(schemas['public.resources.insert'], value)
+
+export const validateResourcesUpdate = (value: unknown) =>
+  validate<ResourcesUpdate>(schemas['public.resources.update'], value)
+
+export const validateRoleHistoriesInsert = (value: unknown) =>
+  validate<RoleHistoriesInsert>(schemas['public.role_histories.insert'], value)
+
+export const validateRoleHistoriesUpdate = (value: unknown) =>
+  validate<RoleHistoriesUpdate>(schemas['public.role_histories.update'], value)
+
+export const validateRolesInsert = (value: unknown) =>
+  validate<RolesInsert>(schemas['public.roles.insert'], value)
+
+export const validateRolesUpdate = (value: unknown) =>
+  validate<RolesUpdate>(schemas['public.roles.update'], value)
+
+export const validateShipmentsInsert = (value: unknown) =>
+  validate<ShipmentsInsert>(schemas['public.shipments.insert'], value)
+
+export const validateShipmentsUpdate = (value: unknown) =>
+  validate<ShipmentsUpdate>(schemas['public.shipments.update'], value)
+
+export const validateShipmentsDevicesInsert = (value: unknown) =>
+  validate<ShipmentsDevicesInsert>(schemas['public.shipments_devices.insert'], value)
+
+export const validateShipmentsDevicesUpdate = (value: unknown) =>
+  validate<ShipmentsDevicesUpdate>(schemas['public.shipments_devices.update'], value)
+
+export const validateUsedQrTokensInsert = (value: unknown) =>
+  validate<UsedQrTokensInsert>(schemas['public.used_qr_tokens.insert'], value)
+
+export const validateUsedQrTokensUpdate = (value: unknown) =>
+  validate<UsedQrTokensUpdate>(schemas['public.used_qr_tokens.update'], value)
+
+export const validateUserRolesInsert = (value: unknown) =>
+  validate<UserRolesInsert>(schemas['public.user_roles.insert'], value)
+
+export const validateUserRolesUpdate = (value: unknown) =>
+  validate<UserRolesUpdate>(schemas['public.user_roles.update'], value)
+
+export const validateUsersInsert = (value: unknown) =>
+  validate<UsersInsert>(schemas['public.users.insert'], value)
+
+export const validateUsersUpdate = (value: unknown) =>
+  validate<UsersUpdate>(schemas['public.users.update'], value)
+
+export const validateMessagesInsert = (value: unknown) =>
+  validate<MessagesInsert>(schemas['realtime.messages.insert'], value)
+
+export const validateMessagesUpdate = (value: unknown) =>
+  validate<MessagesUpdate>(schemas['realtime.messages.update'], value)
+
+export const validateSchemaMigrationsInsert = (value: unknown) =>
+  validate<SchemaMigrationsInsert>(schemas['realtime.schema_migrations.insert'], value)
+
+export const validateSchemaMigrationsUpdate = (value: unknown) =>
+  validate<SchemaMigrationsUpdate>(schemas['realtime.schema_migrations.update'], value)
+
+export const validateSubscriptionInsert = (value: unknown) =>
+  validate<SubscriptionInsert>(schemas['realtime.subscription.insert'], value)
+
+export const validateSubscriptionUpdate = (value: unknown) =>
+  validate<SubscriptionUpdate>(schemas['realtime.subscription.update'], value)
+
+export const validateBucketsInsert = (value: unknown) =>
+  validate<BucketsInsert>(schemas['storage.buckets.insert'], value)
+
+export const validateBucketsUpdate = (value: unknown) =>
+  validate<BucketsUpdate>(schemas['storage.buckets.update'], value)
+
+export const validateMigrations_StorageInsert = (value: unknown) =>
+  validate<Migrations_StorageInsert>(schemas['storage.migrations.insert'], value)
+
+export const validateMigrations_StorageUpdate = (value: unknown) =>
+  validate<Migrations_StorageUpdate>(schemas['storage.migrations.update'], value)
+
+export const validateObjectsInsert = (value: unknown) =>
+  validate<ObjectsInsert>(schemas['storage.objects.insert'], value)
+
+export const validateObjectsUpdate = (value: unknown) =>
+  validate<ObjectsUpdate>(schemas['storage.objects.update'], value)
+
+export const validateS3MultipartUploadsInsert = (value: unknown) =>
+  validate<S3MultipartUploadsInsert>(schemas['storage.s3_multipart_uploads.insert'], value)
+
+export const validateS3MultipartUploadsUpdate = (value: unknown) =>
+  validate<S3MultipartUploadsUpdate>(schemas['storage.s3_multipart_uploads.update'], value)
+
+export const validateS3MultipartUploadsPartsInsert = (value: unknown) =>
+  validate<S3MultipartUploadsPartsInsert>(schemas['storage.s3_multipart_uploads_parts.insert'], value)
+
+export const validateS3MultipartUploadsPartsUpdate = (value: unknown) =>
+  validate<S3MultipartUploadsPartsUpdate>(schemas['storage.s3_multipart_uploads_parts.update'], value)
+
+export const validateHooksInsert = (value: unknown) =>
+  validate<HooksInsert>(schemas['supabase_functions.hooks.insert'], value)
+
+export const validateHooksUpdate = (value: unknown) =>
+  validate<HooksUpdate>(schemas['supabase_functions.hooks.update'], value)
+
+export const validateMigrationsInsert = (value: unknown) =>
+  validate<MigrationsInsert>(schemas['supabase_functions.migrations.insert'], value)
+
+export const validateMigrationsUpdate = (value: unknown) =>
+  validate<MigrationsUpdate>(schemas['supabase_functions.migrations.update'], value)
+
+export const validateDecryptedSecretsInsert = (value: unknown) =>
+  validate<DecryptedSecretsInsert>(schemas['vault.decrypted_secrets.insert'], value)
+
+export const validateDecryptedSecretsUpdate = (value: unknown) =>
+  validate<DecryptedSecretsUpdate>(schemas['vault.decrypted_secrets.update'], value)
+
+export const validateSecretsInsert = (value: unknown) =>
+  validate<SecretsInsert>(schemas['vault.secrets.insert'], value)
+
+export const validateSecretsUpdate = (value: unknown) =>
+  validate<SecretsUpdate>(schemas['vault.secrets.update'], value)
+
@@ -53,6 +53,7 @@ export type AuditDeviceItem = BaseDeviceItem & {
 
 export type MaintenanceDeviceItem = BaseDeviceItem & {
   maintenanceOutcome: DeviceStatus;
+  maintenanceNote?: string;
 };
 
 export type QualityDeviceItem = BaseDeviceItem & {
@@ -0,0 +1,7 @@
+-- States the original shipment_status enum lacks: shipments are put
+-- together before they are dispatched, and can be closed with devices
+-- missing. 'completed' stays for shipments closed before this and reads as
+-- 'received'.
+ALTER TYPE shipment_status ADD VALUE IF NOT EXISTS 'preparing' BEFORE 'shipping';
+ALTER TYPE shipment_status ADD VALUE IF NOT EXISTS 'received';
+ALTER TYPE shipment_status ADD VALUE IF NOT EXISTS 'partially_received';
@@ -0,0 +1,17 @@
+-- Every device status change, with the activity it was part of and who
+-- confirmed it. IDs are kept as text so the table works whatever type the
+-- referenced columns have.
+CREATE TABLE IF NOT EXISTS device_status_changes (
+    id bigserial PRIMARY KEY,
+    device_id text NOT NULL,
+    from_status device_status NOT NULL,
+    to_status device_status NOT NULL,
+    activity_type activity_type NOT NULL,
+    activity_id text,
+    actor_id text,
+    reason text,
+    changed_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS device_status_changes_device_idx
+    ON device_status_changes (device_id, changed_at DESC);
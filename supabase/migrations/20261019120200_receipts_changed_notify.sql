@@ -0,0 +1,13 @@
+-- Tells the kiosks that the receipt lists changed, so they drop their
+-- cached copies. Sent once per statement; the payload is empty.
+CREATE OR REPLACE FUNCTION notify_receipts_changed() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('receipts_changed', '');
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS receipts_devices_changed ON receipts_devices;
+CREATE TRIGGER receipts_devices_changed
+    AFTER INSERT OR UPDATE OR DELETE ON receipts_devices
+    FOR EACH STATEMENT EXECUTE FUNCTION notify_receipts_changed();